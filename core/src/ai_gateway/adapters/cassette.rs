@@ -0,0 +1,606 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::ai_gateway::{
+    chat::{
+        runtime::turn_payload_json,
+        types::{
+            AdapterEventStream, AdapterInvocation, BackendCompleteResponse, BackendIdentity,
+            BackendRawEvent, FinishReason, TurnPayload,
+        },
+    },
+    embeddings::{BackendEmbeddingsResponse, EmbeddingsRequest},
+    error::{GatewayError, GatewayErrorKind},
+    types::{AdapterContext, BackendCapabilities, BackendDialect, CassetteConfig, CassetteMode},
+};
+
+use super::BackendAdapter;
+
+/// One recorded request and the backend events it produced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CassetteInteraction {
+    pub request: Value,
+    pub events: Vec<BackendRawEvent>,
+    #[serde(default)]
+    pub created_at_unix_s: Option<i64>,
+    /// Vectors returned by a recorded embeddings call; chat interactions leave this unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embeddings: Option<Vec<Vec<f32>>>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<CassetteInteraction>,
+}
+
+/// The request shape interactions are matched on: backend, model, and payload content.
+/// Per-thread metadata is left out so replays are independent of thread and request ids.
+pub(crate) fn cassette_request(ctx: &AdapterContext, payload: &TurnPayload) -> Value {
+    let mut content = turn_payload_json(payload);
+    if let Some(object) = content.as_object_mut() {
+        object.remove("metadata");
+    }
+    json!({
+        "backend_id": ctx.backend_id,
+        "model": ctx.model,
+        "payload": content,
+    })
+}
+
+/// The embeddings counterpart of [`cassette_request`].
+fn cassette_embed_request(ctx: &AdapterContext, request: &EmbeddingsRequest) -> Value {
+    json!({
+        "backend_id": ctx.backend_id,
+        "model": ctx.model,
+        "embed": {
+            "inputs": request.inputs,
+            "dimensions": request.dimensions,
+        },
+    })
+}
+
+/// Wraps every adapter so interactions are recorded to, or replayed from, the cassette file.
+pub(crate) fn wrap_adapters(
+    adapters: HashMap<BackendDialect, Arc<dyn BackendAdapter>>,
+    config: &CassetteConfig,
+) -> Result<HashMap<BackendDialect, Arc<dyn BackendAdapter>>, GatewayError> {
+    match config.mode {
+        CassetteMode::Record => {
+            let recorder = Arc::new(CassetteRecorder::open(config.path.clone())?);
+            Ok(adapters
+                .into_iter()
+                .map(|(dialect, inner)| {
+                    let adapter: Arc<dyn BackendAdapter> = Arc::new(RecordingAdapter {
+                        inner,
+                        recorder: Arc::clone(&recorder),
+                    });
+                    (dialect, adapter)
+                })
+                .collect())
+        }
+        CassetteMode::Replay => {
+            let player = Arc::new(CassettePlayer::load(&config.path)?);
+            Ok(adapters
+                .into_iter()
+                .map(|(dialect, inner)| {
+                    let adapter: Arc<dyn BackendAdapter> = Arc::new(ReplayAdapter {
+                        dialect: dialect.clone(),
+                        capabilities: inner.static_capabilities(),
+                        supports_tool_retry: inner.supports_tool_retry(),
+                        player: Arc::clone(&player),
+                    });
+                    (dialect, adapter)
+                })
+                .collect())
+        }
+    }
+}
+
+/// Appends interactions to a cassette file, rewriting it after each one.
+///
+/// Interactions already in the file are kept, so several recording sessions extend one cassette.
+pub(crate) struct CassetteRecorder {
+    path: PathBuf,
+    cassette: AsyncMutex<CassetteFile>,
+}
+
+impl CassetteRecorder {
+    /// Starts from the existing cassette at `path`, or an empty one when the file is missing.
+    pub(crate) fn open(path: PathBuf) -> Result<Self, GatewayError> {
+        let cassette = if path.exists() {
+            read_cassette(&path)?
+        } else {
+            CassetteFile::default()
+        };
+        Ok(Self {
+            path,
+            cassette: AsyncMutex::new(cassette),
+        })
+    }
+
+    /// Records one interaction; a failed write is logged so it never fails the call itself.
+    async fn record(&self, interaction: CassetteInteraction) {
+        if let Err(err) = self.try_record(interaction).await {
+            tracing::warn!(
+                target: "ai_gateway",
+                path = %self.path.display(),
+                error = %err,
+                "cassette_record_failed"
+            );
+        }
+    }
+
+    /// Holds the cassette lock across the write so rewrites land in recording order.
+    async fn try_record(&self, interaction: CassetteInteraction) -> Result<(), GatewayError> {
+        let mut cassette = self.cassette.lock().await;
+        cassette.interactions.push(interaction);
+        let encoded = serde_json::to_vec_pretty(&*cassette)
+            .map_err(|err| internal_error(format!("failed to encode cassette: {err}")))?;
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|err| {
+                internal_error(format!(
+                    "failed to create cassette directory '{}': {err}",
+                    parent.display()
+                ))
+            })?;
+        }
+        tokio::fs::write(&self.path, encoded).await.map_err(|err| {
+            internal_error(format!(
+                "failed to write cassette '{}': {err}",
+                self.path.display()
+            ))
+        })
+    }
+}
+
+/// Serves recorded interactions; each one is replayed at most once, in recording order.
+pub(crate) struct CassettePlayer {
+    remaining: Mutex<Vec<Option<CassetteInteraction>>>,
+}
+
+impl CassettePlayer {
+    pub(crate) fn load(path: &Path) -> Result<Self, GatewayError> {
+        let cassette = read_cassette(path)?;
+        Ok(Self {
+            remaining: Mutex::new(cassette.interactions.into_iter().map(Some).collect()),
+        })
+    }
+
+    fn take(&self, request: &Value) -> Result<CassetteInteraction, GatewayError> {
+        let mut remaining = self.remaining.lock().expect("lock poisoned");
+        remaining
+            .iter_mut()
+            .find(|slot| {
+                slot.as_ref()
+                    .is_some_and(|interaction| &interaction.request == request)
+            })
+            .and_then(Option::take)
+            .ok_or_else(|| {
+                GatewayError::new(
+                    GatewayErrorKind::InvalidRequest,
+                    "no recorded cassette interaction matches the request",
+                )
+                .with_retryable(false)
+            })
+    }
+}
+
+fn read_cassette(path: &Path) -> Result<CassetteFile, GatewayError> {
+    let raw = std::fs::read(path).map_err(|err| {
+        GatewayError::new(
+            GatewayErrorKind::InvalidRequest,
+            format!("failed to read cassette '{}': {err}", path.display()),
+        )
+        .with_retryable(false)
+    })?;
+    serde_json::from_slice(&raw).map_err(|err| {
+        GatewayError::new(
+            GatewayErrorKind::InvalidRequest,
+            format!("failed to parse cassette '{}': {err}", path.display()),
+        )
+        .with_retryable(false)
+    })
+}
+
+struct RecordingAdapter {
+    inner: Arc<dyn BackendAdapter>,
+    recorder: Arc<CassetteRecorder>,
+}
+
+#[async_trait]
+impl BackendAdapter for RecordingAdapter {
+    fn dialect(&self) -> BackendDialect {
+        self.inner.dialect()
+    }
+
+    fn static_capabilities(&self) -> BackendCapabilities {
+        self.inner.static_capabilities()
+    }
+
+    fn supports_tool_retry(&self) -> bool {
+        self.inner.supports_tool_retry()
+    }
+
+    async fn complete(
+        &self,
+        ctx: AdapterContext,
+        payload: &TurnPayload,
+    ) -> Result<BackendCompleteResponse, GatewayError> {
+        let request = cassette_request(&ctx, payload);
+        let response = self.inner.complete(ctx, payload).await;
+        let interaction = match &response {
+            Ok(response) => CassetteInteraction {
+                request,
+                events: complete_response_events(response),
+                created_at_unix_s: response.created_at_unix_s,
+                embeddings: None,
+            },
+            Err(error) => CassetteInteraction {
+                request,
+                events: vec![BackendRawEvent::Failed {
+                    error: error.clone(),
+                }],
+                created_at_unix_s: None,
+                embeddings: None,
+            },
+        };
+        self.recorder.record(interaction).await;
+        response
+    }
+
+    async fn stream(
+        &self,
+        ctx: AdapterContext,
+        payload: &TurnPayload,
+    ) -> Result<AdapterInvocation, GatewayError> {
+        let request = cassette_request(&ctx, payload);
+        let mut invocation = self.inner.stream(ctx, payload).await?;
+        invocation.stream = tee_to_cassette(invocation.stream, request, Arc::clone(&self.recorder));
+        Ok(invocation)
+    }
+
+    async fn embed(
+        &self,
+        ctx: AdapterContext,
+        request: &EmbeddingsRequest,
+    ) -> Result<BackendEmbeddingsResponse, GatewayError> {
+        let recorded_request = cassette_embed_request(&ctx, request);
+        let response = self.inner.embed(ctx, request).await;
+        let interaction = match &response {
+            Ok(response) => CassetteInteraction {
+                request: recorded_request,
+                events: response
+                    .usage
+                    .iter()
+                    .cloned()
+                    .map(|usage| BackendRawEvent::Usage { usage })
+                    .collect(),
+                created_at_unix_s: None,
+                embeddings: Some(response.embeddings.clone()),
+            },
+            Err(error) => CassetteInteraction {
+                request: recorded_request,
+                events: vec![BackendRawEvent::Failed {
+                    error: error.clone(),
+                }],
+                created_at_unix_s: None,
+                embeddings: None,
+            },
+        };
+        self.recorder.record(interaction).await;
+        response
+    }
+}
+
+/// Passes each event through as it arrives and records the interaction once the stream ends.
+fn tee_to_cassette(
+    upstream: AdapterEventStream,
+    request: Value,
+    recorder: Arc<CassetteRecorder>,
+) -> AdapterEventStream {
+    let state = (upstream, request, Vec::new(), recorder);
+    Box::pin(futures_util::stream::unfold(
+        state,
+        |(mut upstream, request, mut events, recorder)| async move {
+            match upstream.next().await {
+                Some(item) => {
+                    events.push(match &item {
+                        Ok(event) => event.clone(),
+                        Err(error) => BackendRawEvent::Failed {
+                            error: error.clone(),
+                        },
+                    });
+                    Some((item, (upstream, request, events, recorder)))
+                }
+                None => {
+                    recorder
+                        .record(CassetteInteraction {
+                            request,
+                            events,
+                            created_at_unix_s: None,
+                            embeddings: None,
+                        })
+                        .await;
+                    None
+                }
+            }
+        },
+    ))
+}
+
+struct ReplayAdapter {
+    dialect: BackendDialect,
+    capabilities: BackendCapabilities,
+    supports_tool_retry: bool,
+    player: Arc<CassettePlayer>,
+}
+
+impl ReplayAdapter {
+    fn backend_identity(&self, ctx: &AdapterContext) -> BackendIdentity {
+        BackendIdentity {
+            backend_id: ctx.backend_id.clone(),
+            dialect: self.dialect.clone(),
+            model: ctx.model.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl BackendAdapter for ReplayAdapter {
+    fn dialect(&self) -> BackendDialect {
+        self.dialect.clone()
+    }
+
+    fn static_capabilities(&self) -> BackendCapabilities {
+        self.capabilities.clone()
+    }
+
+    fn supports_tool_retry(&self) -> bool {
+        self.supports_tool_retry
+    }
+
+    async fn complete(
+        &self,
+        ctx: AdapterContext,
+        payload: &TurnPayload,
+    ) -> Result<BackendCompleteResponse, GatewayError> {
+        let interaction = self.player.take(&cassette_request(&ctx, payload))?;
+        let mut response = BackendCompleteResponse {
+            backend_identity: self.backend_identity(&ctx),
+            output_text: String::new(),
+            reasoning_text: None,
+            tool_calls: Vec::new(),
+            usage: None,
+            finish_reason: FinishReason::Stop,
+            created_at_unix_s: interaction.created_at_unix_s,
+        };
+        for event in interaction.events {
+            match event {
+                BackendRawEvent::OutputTextDelta { delta } => response.output_text.push_str(&delta),
+                BackendRawEvent::ReasoningDelta { delta } => response
+                    .reasoning_text
+                    .get_or_insert_with(String::new)
+                    .push_str(&delta),
+                BackendRawEvent::ToolCallDelta { .. } => {}
+                BackendRawEvent::ToolCallReady { call } => response.tool_calls.push(call),
+                BackendRawEvent::Usage { usage } => response.usage = Some(usage),
+                BackendRawEvent::Completed { finish_reason } => {
+                    response.finish_reason = finish_reason;
+                }
+                BackendRawEvent::Failed { error } => return Err(error),
+            }
+        }
+        Ok(response)
+    }
+
+    async fn stream(
+        &self,
+        ctx: AdapterContext,
+        payload: &TurnPayload,
+    ) -> Result<AdapterInvocation, GatewayError> {
+        let interaction = self.player.take(&cassette_request(&ctx, payload))?;
+        Ok(AdapterInvocation {
+            stream: Box::pin(futures_util::stream::iter(
+                interaction.events.into_iter().map(Ok),
+            )),
+            backend_identity: self.backend_identity(&ctx),
+            cancel: None,
+        })
+    }
+
+    async fn embed(
+        &self,
+        ctx: AdapterContext,
+        request: &EmbeddingsRequest,
+    ) -> Result<BackendEmbeddingsResponse, GatewayError> {
+        let interaction = self.player.take(&cassette_embed_request(&ctx, request))?;
+        let mut usage = None;
+        for event in interaction.events {
+            match event {
+                BackendRawEvent::Usage { usage: recorded } => usage = Some(recorded),
+                BackendRawEvent::Failed { error } => return Err(error),
+                _ => {}
+            }
+        }
+        let embeddings = interaction.embeddings.ok_or_else(|| {
+            internal_error("recorded embeddings interaction has no vectors".to_string())
+        })?;
+        Ok(BackendEmbeddingsResponse { embeddings, usage })
+    }
+}
+
+/// Expresses a complete response as the event stream a streaming call would have produced.
+fn complete_response_events(response: &BackendCompleteResponse) -> Vec<BackendRawEvent> {
+    let mut events = Vec::new();
+    if !response.output_text.is_empty() {
+        events.push(BackendRawEvent::OutputTextDelta {
+            delta: response.output_text.clone(),
+        });
+    }
+    events.extend(
+        response
+            .tool_calls
+            .iter()
+            .cloned()
+            .map(|call| BackendRawEvent::ToolCallReady { call }),
+    );
+    if let Some(usage) = &response.usage {
+        events.push(BackendRawEvent::Usage {
+            usage: usage.clone(),
+        });
+    }
+    events.push(BackendRawEvent::Completed {
+        finish_reason: response.finish_reason.clone(),
+    });
+    events
+}
+
+fn internal_error(message: String) -> GatewayError {
+    GatewayError::new(GatewayErrorKind::Internal, message).with_retryable(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::ai_gateway::{
+        chat::types::{OutputMode, TurnLimits, TurnSampling},
+        types::{BackendProfile, ResolvedCredential},
+    };
+
+    /// Streams whatever the test sends on its channel, so the test controls when upstream ends.
+    struct ChannelAdapter {
+        events: Mutex<Option<mpsc::UnboundedReceiver<Result<BackendRawEvent, GatewayError>>>>,
+    }
+
+    #[async_trait]
+    impl BackendAdapter for ChannelAdapter {
+        fn dialect(&self) -> BackendDialect {
+            BackendDialect::OpenAiCompatible
+        }
+
+        fn static_capabilities(&self) -> BackendCapabilities {
+            BackendCapabilities::default()
+        }
+
+        async fn stream(
+            &self,
+            ctx: AdapterContext,
+            _payload: &TurnPayload,
+        ) -> Result<AdapterInvocation, GatewayError> {
+            let events = self
+                .events
+                .lock()
+                .expect("lock poisoned")
+                .take()
+                .expect("one stream per test");
+            Ok(AdapterInvocation {
+                stream: Box::pin(tokio_stream::wrappers::UnboundedReceiverStream::new(events)),
+                backend_identity: BackendIdentity {
+                    backend_id: ctx.backend_id,
+                    dialect: BackendDialect::OpenAiCompatible,
+                    model: ctx.model,
+                },
+                cancel: None,
+            })
+        }
+    }
+
+    fn adapter_context() -> AdapterContext {
+        let profile = serde_json::from_value::<BackendProfile>(json!({
+            "id": "recorded",
+            "dialect": "openai_compatible",
+            "endpoint": "http://127.0.0.1:1",
+            "credential": {"type": "none"},
+            "models": [{"id": "model"}],
+        }))
+        .expect("profile should parse");
+        AdapterContext {
+            backend_id: "recorded".to_string(),
+            model: "model".to_string(),
+            profile,
+            credential: ResolvedCredential::none(),
+            timeout: Duration::from_secs(1),
+            request_id: "req-1".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn recorded_stream_forwards_events_before_upstream_finishes() {
+        let path = std::env::temp_dir()
+            .join(format!("beluna-cassette-{}", uuid::Uuid::new_v4()))
+            .join("cassette.json");
+        let (tx, rx) = mpsc::unbounded_channel();
+        let adapter = RecordingAdapter {
+            inner: Arc::new(ChannelAdapter {
+                events: Mutex::new(Some(rx)),
+            }),
+            recorder: Arc::new(CassetteRecorder::open(path.clone()).expect("open recorder")),
+        };
+        let payload = TurnPayload {
+            messages: Arc::new(Vec::new()),
+            tools: Vec::new(),
+            output_mode: OutputMode::Text,
+            limits: TurnLimits::default(),
+            sampling: TurnSampling::default(),
+            enable_thinking: false,
+            metadata: Default::default(),
+        };
+
+        let mut stream = adapter
+            .stream(adapter_context(), &payload)
+            .await
+            .expect("stream should open")
+            .stream;
+        tx.send(Ok(BackendRawEvent::OutputTextDelta {
+            delta: "Hello".to_string(),
+        }))
+        .expect("send delta");
+        let first = tokio::time::timeout(Duration::from_secs(1), stream.next())
+            .await
+            .expect("delta should arrive while upstream is still open");
+        assert!(matches!(
+            first,
+            Some(Ok(BackendRawEvent::OutputTextDelta { ref delta })) if delta == "Hello"
+        ));
+        assert!(!path.exists());
+
+        tx.send(Ok(BackendRawEvent::Completed {
+            finish_reason: FinishReason::Stop,
+        }))
+        .expect("send completion");
+        drop(tx);
+        assert_eq!(stream.collect::<Vec<_>>().await.len(), 1);
+
+        let recorded = read_cassette(&path).expect("cassette should be written");
+        assert_eq!(recorded.interactions.len(), 1);
+        assert_eq!(
+            recorded.interactions[0].request,
+            cassette_request(&adapter_context(), &payload)
+        );
+        assert!(matches!(
+            recorded.interactions[0].events.as_slice(),
+            [
+                BackendRawEvent::OutputTextDelta { .. },
+                BackendRawEvent::Completed { .. }
+            ]
+        ));
+
+        let _ = std::fs::remove_file(&path);
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::remove_dir(parent);
+        }
+    }
+}
@@ -0,0 +1,501 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use serde_json::{Value, json};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::Instrument;
+
+use crate::ai_gateway::{
+    adapters::{
+        BackendAdapter, build_http_client,
+        http_stream::{self, HttpRequestConfig},
+    },
+    chat::types::{
+        AdapterInvocation, BackendCompleteResponse, BackendIdentity, BackendRawEvent, FinishReason,
+        ToolCallResult, ToolCallStatus, TurnPayload, UsageStats,
+    },
+    error::{GatewayError, GatewayErrorKind},
+    types::{
+        AdapterContext, BackendCapabilities, BackendDialect, HttpClientConfig, ResolvedCredential,
+    },
+};
+
+use super::wire as gemini_wire;
+
+const API_KEY_HEADER: &str = "x-goog-api-key";
+
+// ---------------------------------------------------------------------------
+// Adapter
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+pub struct GeminiAdapter {
+    client: reqwest::Client,
+}
+
+impl GeminiAdapter {
+    pub fn new(http: &HttpClientConfig) -> Self {
+        Self {
+            client: build_http_client(http),
+        }
+    }
+}
+
+impl Default for GeminiAdapter {
+    fn default() -> Self {
+        Self::new(&HttpClientConfig::default())
+    }
+}
+
+#[async_trait]
+impl BackendAdapter for GeminiAdapter {
+    fn dialect(&self) -> BackendDialect {
+        BackendDialect::Gemini
+    }
+
+    fn static_capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            streaming: true,
+            tool_calls: true,
+            parallel_tool_calls: true,
+            json_mode: false,
+            json_schema_mode: false,
+            vision: false,
+            resumable_streaming: false,
+        }
+    }
+
+    async fn complete(
+        &self,
+        ctx: AdapterContext,
+        payload: &TurnPayload,
+    ) -> Result<BackendCompleteResponse, GatewayError> {
+        let url = validated_url(&ctx, "generateContent")?;
+        let backend_id = ctx.backend_id.clone();
+
+        let json_response = http_stream::post_json(&HttpRequestConfig {
+            client: self.client.clone(),
+            url,
+            body: build_body(payload),
+            backend_id: backend_id.clone(),
+            request_id: ctx.request_id.clone(),
+            credential: api_key_credential(ctx.credential),
+            timeout: ctx.timeout,
+        })
+        .await?;
+
+        parse_complete_response(&json_response, &backend_id, &ctx.model)
+    }
+
+    async fn stream(
+        &self,
+        ctx: AdapterContext,
+        payload: &TurnPayload,
+    ) -> Result<AdapterInvocation, GatewayError> {
+        let url = validated_url(&ctx, "streamGenerateContent?alt=sse")?;
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let cancel_flag_task = cancel_flag.clone();
+
+        let (tx, rx) = mpsc::channel::<Result<BackendRawEvent, GatewayError>>(64);
+        let backend_id = ctx.backend_id.clone();
+        let model = ctx.model.clone();
+        let request_id = ctx.request_id.clone();
+        let dispatch_span = tracing::debug_span!(
+            target: "ai_gateway.gemini",
+            "gemini_dispatch",
+            request_id = %request_id,
+            backend_id = %backend_id,
+            model = %model,
+        );
+
+        let http_config = HttpRequestConfig {
+            client: self.client.clone(),
+            url,
+            body: build_body(payload),
+            backend_id: backend_id.clone(),
+            request_id,
+            credential: api_key_credential(ctx.credential.clone()),
+            timeout: ctx.timeout,
+        };
+
+        tokio::spawn(
+            async move {
+                let response = match http_stream::send_post(&http_config).await {
+                    Ok(r) => r,
+                    Err(err) => {
+                        let _ = tx.send(Err(err)).await;
+                        return;
+                    }
+                };
+
+                let mut byte_stream = response.bytes_stream();
+                let mut buffer = String::new();
+                let mut decoder = ChunkDecoder::default();
+                let mut saw_terminal = false;
+
+                while let Some(item) = byte_stream.next().await {
+                    if cancel_flag_task.load(Ordering::SeqCst) {
+                        return;
+                    }
+
+                    let chunk = match item {
+                        Ok(chunk) => chunk,
+                        Err(err) => {
+                            let _ = tx
+                                .send(Err(GatewayError::new(
+                                    GatewayErrorKind::BackendTransient,
+                                    format!("gemini stream chunk error: {}", err),
+                                )
+                                .with_retryable(true)
+                                .with_backend_id(backend_id.clone())))
+                                .await;
+                            return;
+                        }
+                    };
+
+                    buffer.push_str(&String::from_utf8_lossy(&chunk));
+                    let (frames, _) =
+                        match http_stream::extract_sse_frames(&mut buffer, &backend_id) {
+                            Ok(result) => result,
+                            Err(err) => {
+                                let _ = tx.send(Err(err)).await;
+                                return;
+                            }
+                        };
+
+                    for json in frames {
+                        for event in decoder.decode(&json) {
+                            if matches!(event, BackendRawEvent::Completed { .. }) {
+                                saw_terminal = true;
+                            }
+                            if tx.send(Ok(event)).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+
+                if !saw_terminal {
+                    let _ = tx
+                        .send(Ok(BackendRawEvent::Completed {
+                            finish_reason: FinishReason::Stop,
+                        }))
+                        .await;
+                }
+            }
+            .instrument(dispatch_span),
+        );
+
+        let cancel = {
+            let cancel_flag = cancel_flag.clone();
+            Arc::new(move || {
+                cancel_flag.store(true, Ordering::SeqCst);
+            })
+        };
+
+        Ok(AdapterInvocation {
+            stream: Box::pin(ReceiverStream::new(rx)),
+            backend_identity: BackendIdentity {
+                backend_id: ctx.backend_id,
+                dialect: BackendDialect::Gemini,
+                model: ctx.model,
+            },
+            cancel: Some(cancel),
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers — URL / body / credential
+// ---------------------------------------------------------------------------
+
+fn validated_url(ctx: &AdapterContext, method: &str) -> Result<String, GatewayError> {
+    let endpoint = ctx.profile.endpoint.clone().ok_or_else(|| {
+        GatewayError::new(
+            GatewayErrorKind::InvalidRequest,
+            "gemini backend requires endpoint",
+        )
+        .with_retryable(false)
+        .with_backend_id(ctx.backend_id.clone())
+    })?;
+    Ok(format!(
+        "{}/models/{}:{}",
+        endpoint.trim_end_matches('/'),
+        ctx.model,
+        method
+    ))
+}
+
+fn build_body(payload: &TurnPayload) -> Value {
+    let mut body = json!({
+        "contents": gemini_wire::messages_to_contents(&payload.messages),
+    });
+
+    if let Some(system_instruction) = gemini_wire::system_instruction(&payload.messages) {
+        body["systemInstruction"] = system_instruction;
+    }
+    if !payload.tools.is_empty() {
+        body["tools"] = Value::Array(gemini_wire::tools_to_gemini(&payload.tools));
+    }
+
+    let mut generation_config = serde_json::Map::new();
+    if let Some(max_tokens) = payload.limits.max_output_tokens {
+        generation_config.insert("maxOutputTokens".to_string(), json!(max_tokens));
+    }
+    if let Some(temperature) = payload.sampling.temperature {
+        generation_config.insert("temperature".to_string(), json!(temperature));
+    }
+    if let Some(top_p) = payload.sampling.top_p {
+        generation_config.insert("topP".to_string(), json!(top_p));
+    }
+    if let Some(seed) = payload.sampling.seed {
+        generation_config.insert("seed".to_string(), json!(seed));
+    }
+    if !generation_config.is_empty() {
+        body["generationConfig"] = Value::Object(generation_config);
+    }
+
+    body
+}
+
+/// Moves the resolved API key from `Authorization` into the header Gemini authenticates with.
+fn api_key_credential(mut credential: ResolvedCredential) -> ResolvedCredential {
+    if let Some(auth_header) = credential.auth_header.take() {
+        let api_key = auth_header
+            .strip_prefix("Bearer ")
+            .unwrap_or(&auth_header)
+            .to_string();
+        credential
+            .extra_headers
+            .push((API_KEY_HEADER.to_string(), api_key));
+    }
+    credential
+}
+
+// ---------------------------------------------------------------------------
+// Response parsing — complete (non-stream)
+// ---------------------------------------------------------------------------
+
+fn parse_complete_response(
+    payload: &Value,
+    backend_id: &str,
+    model: &str,
+) -> Result<BackendCompleteResponse, GatewayError> {
+    if payload
+        .get("candidates")
+        .and_then(Value::as_array)
+        .is_none_or(Vec::is_empty)
+    {
+        return Err(GatewayError::new(
+            GatewayErrorKind::ProtocolViolation,
+            "gemini response missing candidates",
+        )
+        .with_retryable(false)
+        .with_backend_id(backend_id.to_string()));
+    }
+
+    let mut output_text = String::new();
+    let mut tool_calls = Vec::new();
+    let mut usage = None;
+    let mut finish_reason = FinishReason::Stop;
+    for event in ChunkDecoder::default().decode(payload) {
+        match event {
+            BackendRawEvent::OutputTextDelta { delta } => output_text.push_str(&delta),
+            BackendRawEvent::ToolCallReady { call } => tool_calls.push(call),
+            BackendRawEvent::Usage { usage: stats } => usage = Some(stats),
+            BackendRawEvent::Completed {
+                finish_reason: reason,
+            } => finish_reason = reason,
+            _ => {}
+        }
+    }
+
+    Ok(BackendCompleteResponse {
+        backend_identity: BackendIdentity {
+            backend_id: backend_id.to_string(),
+            dialect: BackendDialect::Gemini,
+            model: model.to_string(),
+        },
+        output_text,
+        reasoning_text: None,
+        tool_calls,
+        usage,
+        finish_reason,
+        created_at_unix_s: None,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Response parsing — stream
+// ---------------------------------------------------------------------------
+
+/// Decodes `GenerateContentResponse` chunks, numbering function calls across the stream.
+#[derive(Default)]
+struct ChunkDecoder {
+    tool_calls_seen: usize,
+}
+
+impl ChunkDecoder {
+    fn decode(&mut self, payload: &Value) -> Vec<BackendRawEvent> {
+        let mut events = Vec::new();
+        let Some(candidate) = payload
+            .get("candidates")
+            .and_then(Value::as_array)
+            .and_then(|candidates| candidates.first())
+        else {
+            return events;
+        };
+
+        let parts = candidate
+            .get("content")
+            .and_then(|content| content.get("parts"))
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for part in parts {
+            if part.get("thought").and_then(Value::as_bool) == Some(true) {
+                continue;
+            }
+            if let Some(text) = part.get("text").and_then(Value::as_str)
+                && !text.is_empty()
+            {
+                events.push(BackendRawEvent::OutputTextDelta {
+                    delta: text.to_string(),
+                });
+            }
+            if let Some(function_call) = part.get("functionCall") {
+                events.push(BackendRawEvent::ToolCallReady {
+                    call: self.tool_call(function_call),
+                });
+            }
+        }
+
+        if let Some(reason) = candidate.get("finishReason").and_then(Value::as_str) {
+            if let Some(usage) = payload.get("usageMetadata") {
+                events.push(BackendRawEvent::Usage {
+                    usage: parse_usage(usage),
+                });
+            }
+            events.push(BackendRawEvent::Completed {
+                finish_reason: parse_finish_reason(reason, self.tool_calls_seen > 0),
+            });
+        }
+
+        events
+    }
+
+    fn tool_call(&mut self, function_call: &Value) -> ToolCallResult {
+        let id = function_call
+            .get("id")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("call_{}", self.tool_calls_seen));
+        self.tool_calls_seen += 1;
+        let name = function_call
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown_tool")
+            .to_string();
+        let arguments_json = function_call
+            .get("args")
+            .map(Value::to_string)
+            .unwrap_or_else(|| "{}".to_string());
+
+        ToolCallResult {
+            id,
+            name,
+            arguments_json,
+            status: ToolCallStatus::Ready,
+        }
+    }
+}
+
+/// Gemini reports `STOP` even when the turn ended on function calls.
+fn parse_finish_reason(reason: &str, saw_tool_calls: bool) -> FinishReason {
+    match reason {
+        "STOP" if saw_tool_calls => FinishReason::ToolCalls,
+        "STOP" => FinishReason::Stop,
+        "MAX_TOKENS" => FinishReason::Length,
+        other => FinishReason::Other(other.to_ascii_lowercase()),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Usage parsing
+// ---------------------------------------------------------------------------
+
+fn parse_usage(usage: &Value) -> UsageStats {
+    UsageStats {
+        input_tokens: usage.get("promptTokenCount").and_then(Value::as_u64),
+        output_tokens: usage.get("candidatesTokenCount").and_then(Value::as_u64),
+        total_tokens: usage.get("totalTokenCount").and_then(Value::as_u64),
+        provider_usage_raw: Some(usage.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn streamed_chunks_decode_text_tool_calls_usage_and_max_tokens() {
+        let mut decoder = ChunkDecoder::default();
+        let first = decoder.decode(&json!({
+            "candidates": [{"content": {"role": "model", "parts": [{"text": "Hel"}]}}]
+        }));
+        assert!(matches!(
+            first.as_slice(),
+            [BackendRawEvent::OutputTextDelta { delta }] if delta == "Hel"
+        ));
+
+        let last = decoder.decode(&json!({
+            "candidates": [{
+                "content": {"role": "model", "parts": [
+                    {"text": "lo"},
+                    {"functionCall": {"name": "lookup", "args": {"q": "x"}}}
+                ]},
+                "finishReason": "MAX_TOKENS"
+            }],
+            "usageMetadata": {"promptTokenCount": 7, "candidatesTokenCount": 3, "totalTokenCount": 10}
+        }));
+        assert_eq!(last.len(), 4);
+        assert!(matches!(
+            &last[1],
+            BackendRawEvent::ToolCallReady { call }
+                if call.id == "call_0" && call.name == "lookup" && call.arguments_json == r#"{"q":"x"}"#
+        ));
+        assert!(matches!(
+            &last[2],
+            BackendRawEvent::Usage { usage }
+                if usage.input_tokens == Some(7)
+                    && usage.output_tokens == Some(3)
+                    && usage.total_tokens == Some(10)
+        ));
+        assert!(matches!(
+            last[3],
+            BackendRawEvent::Completed {
+                finish_reason: FinishReason::Length
+            }
+        ));
+    }
+
+    #[test]
+    fn api_key_moves_from_authorization_to_goog_header() {
+        let credential = api_key_credential(ResolvedCredential {
+            auth_header: Some("Bearer secret".to_string()),
+            extra_headers: Vec::new(),
+            opaque: Default::default(),
+        });
+
+        assert_eq!(credential.auth_header, None);
+        assert_eq!(
+            credential.extra_headers,
+            vec![(API_KEY_HEADER.to_string(), "secret".to_string())]
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod chat;
+pub(crate) mod wire;
+
+pub use chat::GeminiAdapter;
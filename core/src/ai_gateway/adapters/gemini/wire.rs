@@ -0,0 +1,233 @@
+//! Gemini `generateContent` wire-format serialization.
+
+use serde_json::{Value, json};
+
+use crate::ai_gateway::chat::{
+    tool::ChatToolDefinition,
+    types::{ChatMessage, ChatRole, ContentPart, MessageToolCall},
+};
+
+/// Folds every system message into the single `systemInstruction` Gemini accepts.
+pub(crate) fn system_instruction(messages: &[ChatMessage]) -> Option<Value> {
+    let parts = messages
+        .iter()
+        .filter(|message| message.role == ChatRole::System)
+        .flat_map(|message| message.parts.iter().map(part_to_gemini))
+        .collect::<Vec<_>>();
+    if parts.is_empty() {
+        return None;
+    }
+    Some(json!({ "parts": parts }))
+}
+
+/// Consecutive tool results share one `user` content, matching the parallel `functionCall`s
+/// Gemini emitted in the preceding `model` turn.
+pub(crate) fn messages_to_contents(messages: &[ChatMessage]) -> Vec<Value> {
+    let mut contents = Vec::new();
+    let mut tool_results = Vec::new();
+    for message in messages
+        .iter()
+        .filter(|message| message.role != ChatRole::System)
+    {
+        if message.role == ChatRole::Tool {
+            tool_results.push(tool_result_to_gemini(message));
+            continue;
+        }
+        flush_tool_results(&mut contents, &mut tool_results);
+        contents.push(match message.role {
+            ChatRole::Assistant => {
+                let mut parts = message.parts.iter().map(part_to_gemini).collect::<Vec<_>>();
+                parts.extend(message.tool_calls.iter().map(tool_call_to_gemini));
+                json!({ "role": "model", "parts": parts })
+            }
+            _ => json!({
+                "role": "user",
+                "parts": message.parts.iter().map(part_to_gemini).collect::<Vec<_>>(),
+            }),
+        });
+    }
+    flush_tool_results(&mut contents, &mut tool_results);
+    contents
+}
+
+fn flush_tool_results(contents: &mut Vec<Value>, tool_results: &mut Vec<Value>) {
+    if !tool_results.is_empty() {
+        contents.push(json!({
+            "role": "user",
+            "parts": std::mem::take(tool_results),
+        }));
+    }
+}
+
+pub(crate) fn tools_to_gemini(tools: &[ChatToolDefinition]) -> Vec<Value> {
+    let declarations = tools
+        .iter()
+        .map(|tool| {
+            json!({
+                "name": tool.name,
+                "description": tool.description,
+                "parameters": tool.input_schema,
+            })
+        })
+        .collect::<Vec<_>>();
+    vec![json!({ "functionDeclarations": declarations })]
+}
+
+fn tool_call_to_gemini(call: &MessageToolCall) -> Value {
+    let args = serde_json::from_str::<Value>(&call.arguments_json).unwrap_or_else(|_| json!({}));
+    json!({
+        "functionCall": {
+            "name": call.name,
+            "args": args,
+        }
+    })
+}
+
+fn tool_result_to_gemini(message: &ChatMessage) -> Value {
+    let text = message
+        .parts
+        .iter()
+        .map(part_to_wire_text)
+        .collect::<Vec<_>>()
+        .join("");
+    let response = match serde_json::from_str::<Value>(&text) {
+        Ok(Value::Object(object)) => Value::Object(object),
+        _ => json!({ "content": text }),
+    };
+    json!({
+        "functionResponse": {
+            "name": message.tool_name.clone().unwrap_or_default(),
+            "response": response,
+        }
+    })
+}
+
+fn part_to_gemini(part: &ContentPart) -> Value {
+    match part {
+        ContentPart::ImageUrl {
+            url,
+            mime_type: Some(mime_type),
+        } => json!({
+            "fileData": {
+                "mimeType": mime_type,
+                "fileUri": url,
+            }
+        }),
+        other => json!({ "text": part_to_wire_text(other) }),
+    }
+}
+
+fn part_to_wire_text(part: &ContentPart) -> String {
+    match part {
+        ContentPart::Text { text } => text.clone(),
+        ContentPart::Json { value } => value.to_string(),
+        ContentPart::ImageUrl { url, .. } => url.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_message(role: ChatRole, text: &str) -> ChatMessage {
+        ChatMessage {
+            role,
+            parts: vec![ContentPart::Text {
+                text: text.to_string(),
+            }],
+            tool_call_id: None,
+            tool_name: None,
+            tool_calls: Vec::new(),
+        }
+    }
+
+    fn tool_result(name: &str, text: &str) -> ChatMessage {
+        ChatMessage {
+            tool_call_id: Some(format!("call_{name}")),
+            tool_name: Some(name.to_string()),
+            ..text_message(ChatRole::Tool, text)
+        }
+    }
+
+    #[test]
+    fn system_messages_fold_into_one_system_instruction() {
+        let messages = [
+            text_message(ChatRole::System, "be brief"),
+            text_message(ChatRole::User, "hi"),
+            text_message(ChatRole::System, "answer in French"),
+        ];
+
+        assert_eq!(
+            system_instruction(&messages),
+            Some(json!({ "parts": [{ "text": "be brief" }, { "text": "answer in French" }] }))
+        );
+        assert_eq!(
+            messages_to_contents(&messages),
+            vec![json!({ "role": "user", "parts": [{ "text": "hi" }] })]
+        );
+        assert_eq!(
+            system_instruction(&[text_message(ChatRole::User, "hi")]),
+            None
+        );
+    }
+
+    #[test]
+    fn parallel_tool_results_share_one_user_content() {
+        let messages = [
+            text_message(ChatRole::User, "weather and time?"),
+            ChatMessage {
+                tool_calls: vec![
+                    MessageToolCall {
+                        id: "call_weather".to_string(),
+                        name: "weather".to_string(),
+                        arguments_json: r#"{"city":"Paris"}"#.to_string(),
+                    },
+                    MessageToolCall {
+                        id: "call_time".to_string(),
+                        name: "time".to_string(),
+                        arguments_json: "not json".to_string(),
+                    },
+                ],
+                ..text_message(ChatRole::Assistant, "checking")
+            },
+            tool_result("weather", r#"{"celsius":21}"#),
+            tool_result("time", "noon"),
+            text_message(ChatRole::User, "thanks"),
+        ];
+
+        assert_eq!(
+            messages_to_contents(&messages),
+            vec![
+                json!({ "role": "user", "parts": [{ "text": "weather and time?" }] }),
+                json!({ "role": "model", "parts": [
+                    { "text": "checking" },
+                    { "functionCall": { "name": "weather", "args": { "city": "Paris" } } },
+                    { "functionCall": { "name": "time", "args": {} } },
+                ] }),
+                json!({ "role": "user", "parts": [
+                    { "functionResponse": { "name": "weather", "response": { "celsius": 21 } } },
+                    { "functionResponse": { "name": "time", "response": { "content": "noon" } } },
+                ] }),
+                json!({ "role": "user", "parts": [{ "text": "thanks" }] }),
+            ]
+        );
+    }
+
+    #[test]
+    fn tools_become_one_function_declarations_entry() {
+        let tools = [ChatToolDefinition {
+            name: "weather".to_string(),
+            description: Some("Current weather".to_string()),
+            input_schema: json!({ "type": "object" }),
+        }];
+
+        assert_eq!(
+            tools_to_gemini(&tools),
+            vec![json!({ "functionDeclarations": [{
+                "name": "weather",
+                "description": "Current weather",
+                "parameters": { "type": "object" },
+            }] })]
+        );
+    }
+}
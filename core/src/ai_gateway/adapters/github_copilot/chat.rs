@@ -69,7 +69,8 @@ impl BackendAdapter for GitHubCopilotAdapter {
                     break;
                 }
                 BackendRawEvent::Failed { error } => return Err(error),
-                BackendRawEvent::ToolCallDelta { .. }
+                BackendRawEvent::ReasoningDelta { .. }
+                | BackendRawEvent::ToolCallDelta { .. }
                 | BackendRawEvent::ToolCallReady { .. }
                 | BackendRawEvent::Usage { .. } => {}
             }
@@ -91,9 +92,11 @@ impl BackendAdapter for GitHubCopilotAdapter {
                 model,
             },
             output_text,
+            reasoning_text: None,
             tool_calls: Vec::new(),
             usage: None,
             finish_reason,
+            created_at_unix_s: None,
         })
     }
 
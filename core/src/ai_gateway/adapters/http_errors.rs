@@ -1,7 +1,17 @@
+use std::time::SystemTime;
+
 use crate::ai_gateway::error::{GatewayError, GatewayErrorKind};
 
 /// Map an HTTP status code + body into a structured [`GatewayError`].
-pub(crate) fn map_http_error(status: u16, backend_id: &str, body: &str) -> GatewayError {
+///
+/// A `Retry-After` value, given in delay-seconds or as an HTTP-date, is carried on retryable
+/// errors as a delay from now.
+pub(crate) fn map_http_error(
+    status: u16,
+    backend_id: &str,
+    body: &str,
+    retry_after: Option<&str>,
+) -> GatewayError {
     let normalized_body = body.chars().take(240).collect::<String>();
 
     let mut err = if status == 401 {
@@ -16,6 +26,9 @@ pub(crate) fn map_http_error(status: u16, backend_id: &str, body: &str) -> Gatew
             format!("backend returned status {}", status),
         )
         .with_retryable(true)
+    } else if status == 503 {
+        GatewayError::new(GatewayErrorKind::BackendTransient, "backend unavailable")
+            .with_retryable(true)
     } else if (400..500).contains(&status) {
         GatewayError::new(
             GatewayErrorKind::InvalidRequest,
@@ -34,9 +47,72 @@ pub(crate) fn map_http_error(status: u16, backend_id: &str, body: &str) -> Gatew
         .with_backend_id(backend_id.to_string())
         .with_provider_http_status(status);
 
+    if let Some(retry_after_ms) = retry_after
+        .filter(|_| err.retryable)
+        .and_then(parse_retry_after_ms)
+    {
+        err = err.with_retry_after_ms(retry_after_ms);
+    }
+
     if !normalized_body.is_empty() {
         err.message = format!("{}: {}", err.message, normalized_body);
     }
 
     err
 }
+
+fn parse_retry_after_ms(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(seconds.saturating_mul(1_000));
+    }
+    let retry_at = httpdate::parse_http_date(value).ok()?;
+    let delay = retry_at
+        .duration_since(SystemTime::now())
+        .unwrap_or_default();
+    Some(u64::try_from(delay.as_millis()).unwrap_or(u64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::ai_gateway::{resilience::ResilienceEngine, types::ResilienceConfig};
+
+    #[test]
+    fn unavailable_with_retry_after_maps_to_retryable_error_carrying_delay() {
+        let err = map_http_error(503, "primary", "maintenance", Some("7"));
+        assert_eq!(err.kind, GatewayErrorKind::BackendTransient);
+        assert!(err.retryable);
+        assert_eq!(err.provider_http_status, Some(503));
+        assert_eq!(err.retry_after_ms, Some(7_000));
+
+        let err = map_http_error(503, "primary", "", Some("not a delay"));
+        assert!(err.retryable);
+        assert_eq!(err.retry_after_ms, None);
+
+        let err = map_http_error(400, "primary", "", Some("7"));
+        assert_eq!(err.retry_after_ms, None);
+    }
+
+    #[test]
+    fn rate_limited_retry_after_delays_the_next_attempt() {
+        let resilience = ResilienceEngine::new(ResilienceConfig {
+            backoff_base_ms: 10,
+            ..ResilienceConfig::default()
+        });
+
+        let err = map_http_error(429, "primary", "slow down", Some("2"));
+        assert_eq!(err.kind, GatewayErrorKind::RateLimited);
+        assert!(resilience.retry_delay(1, &err) >= Duration::from_secs(2));
+
+        let retry_at = SystemTime::now() + Duration::from_secs(30);
+        let err = map_http_error(429, "primary", "", Some(&httpdate::fmt_http_date(retry_at)));
+        let retry_after_ms = err.retry_after_ms.expect("http-date should be honored");
+        assert!((28_000..=30_000).contains(&retry_after_ms));
+
+        let err = map_http_error(429, "primary", "", Some("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert_eq!(err.retry_after_ms, Some(0));
+    }
+}
@@ -68,8 +68,18 @@ pub(crate) async fn send_post(
 
     if !response.status().is_success() {
         let status = response.status().as_u16();
+        let retry_after = response
+            .headers()
+            .get(header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
         let body = response.text().await.unwrap_or_default();
-        return Err(map_http_error(status, &config.backend_id, &body));
+        return Err(map_http_error(
+            status,
+            &config.backend_id,
+            &body,
+            retry_after.as_deref(),
+        ));
     }
 
     Ok(response)
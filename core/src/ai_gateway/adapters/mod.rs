@@ -1,13 +1,16 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 
 use crate::ai_gateway::{
     chat::types::{AdapterInvocation, BackendCompleteResponse, TurnPayload},
+    embeddings::{BackendEmbeddingsResponse, EmbeddingsRequest},
     error::GatewayError,
-    types::{AdapterContext, BackendCapabilities, BackendDialect},
+    types::{AdapterContext, BackendCapabilities, BackendDialect, HttpClientConfig},
 };
 
+pub(crate) mod cassette;
+pub mod gemini;
 pub mod github_copilot;
 pub(crate) mod http_errors;
 pub(crate) mod http_stream;
@@ -41,21 +44,52 @@ pub(crate) trait BackendAdapter: Send + Sync {
         ctx: AdapterContext,
         payload: &TurnPayload,
     ) -> Result<AdapterInvocation, GatewayError>;
+
+    async fn embed(
+        &self,
+        _ctx: AdapterContext,
+        _request: &EmbeddingsRequest,
+    ) -> Result<BackendEmbeddingsResponse, GatewayError> {
+        Err(GatewayError::new(
+            crate::ai_gateway::error::GatewayErrorKind::UnsupportedCapability,
+            "adapter does not implement embeddings",
+        )
+        .with_retryable(false))
+    }
+}
+
+pub(crate) fn build_http_client(http: &HttpClientConfig) -> reqwest::Client {
+    http_client_builder(http)
+        .build()
+        .expect("reqwest client must build")
+}
+
+fn http_client_builder(http: &HttpClientConfig) -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .pool_idle_timeout(Duration::from_millis(http.pool_idle_timeout_ms))
+        .tcp_keepalive(http.tcp_keepalive_ms.map(Duration::from_millis))
+        .tcp_keepalive_interval(http.tcp_keepalive_interval_ms.map(Duration::from_millis))
 }
 
-pub(crate) fn build_default_adapters() -> HashMap<BackendDialect, Arc<dyn BackendAdapter>> {
+pub(crate) fn build_default_adapters(
+    http: &HttpClientConfig,
+) -> HashMap<BackendDialect, Arc<dyn BackendAdapter>> {
     let mut adapters: HashMap<BackendDialect, Arc<dyn BackendAdapter>> = HashMap::new();
     adapters.insert(
         BackendDialect::OpenAiCompatible,
-        Arc::new(openai_compatible::OpenAiCompatibleAdapter::default()),
+        Arc::new(openai_compatible::OpenAiCompatibleAdapter::new(http)),
     );
     adapters.insert(
         BackendDialect::OpenAiResponses,
-        Arc::new(openai_responses::OpenAiResponsesAdapter::default()),
+        Arc::new(openai_responses::OpenAiResponsesAdapter::new(http)),
     );
     adapters.insert(
         BackendDialect::Ollama,
-        Arc::new(ollama::OllamaAdapter::default()),
+        Arc::new(ollama::OllamaAdapter::new(http)),
+    );
+    adapters.insert(
+        BackendDialect::Gemini,
+        Arc::new(gemini::GeminiAdapter::new(http)),
     );
     adapters.insert(
         BackendDialect::GitHubCopilotSdk,
@@ -63,3 +97,53 @@ pub(crate) fn build_default_adapters() -> HashMap<BackendDialect, Arc<dyn Backen
     );
     adapters
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::local_http::LocalJsonServer;
+
+    #[test]
+    fn keepalive_settings_default_and_override_when_building_clients() {
+        let defaults = HttpClientConfig::default();
+        assert_eq!(defaults.tcp_keepalive_ms, Some(30_000));
+        assert_eq!(defaults.tcp_keepalive_interval_ms, Some(15_000));
+
+        let overridden = serde_json::from_value::<HttpClientConfig>(serde_json::json!({
+            "tcp_keepalive_ms": 45_000,
+            "tcp_keepalive_interval_ms": null,
+        }))
+        .expect("http config should parse");
+        assert_eq!(overridden.pool_idle_timeout_ms, 30_000);
+        assert_eq!(overridden.tcp_keepalive_ms, Some(45_000));
+        assert_eq!(overridden.tcp_keepalive_interval_ms, None);
+
+        for http in [&defaults, &overridden] {
+            let adapters = build_default_adapters(http);
+            assert!(adapters.contains_key(&BackendDialect::OpenAiResponses));
+            drop(build_http_client(http));
+        }
+    }
+
+    #[tokio::test]
+    async fn sequential_requests_reuse_one_kept_alive_connection() {
+        let server = LocalJsonServer::start(vec![
+            serde_json::json!({ "turn": 1 }),
+            serde_json::json!({ "turn": 2 }),
+        ])
+        .await;
+        let client = build_http_client(&HttpClientConfig::default());
+
+        for _ in 0..2 {
+            let response = client
+                .get(server.endpoint())
+                .send()
+                .await
+                .expect("request should succeed");
+            assert!(response.status().is_success());
+            response.bytes().await.expect("body should read");
+        }
+
+        assert_eq!(server.accepted_connections(), 1);
+    }
+}
@@ -12,7 +12,7 @@ use tracing::Instrument;
 
 use crate::ai_gateway::{
     adapters::{
-        BackendAdapter,
+        BackendAdapter, build_http_client,
         http_stream::{self, HttpRequestConfig},
     },
     chat::types::{
@@ -20,7 +20,7 @@ use crate::ai_gateway::{
         ToolCallResult, ToolCallStatus, TurnPayload, UsageStats,
     },
     error::{GatewayError, GatewayErrorKind},
-    types::{AdapterContext, BackendCapabilities, BackendDialect},
+    types::{AdapterContext, BackendCapabilities, BackendDialect, HttpClientConfig},
 };
 
 use super::wire as ollama_wire;
@@ -34,17 +34,20 @@ pub struct OllamaAdapter {
     client: reqwest::Client,
 }
 
-impl Default for OllamaAdapter {
-    fn default() -> Self {
+impl OllamaAdapter {
+    pub fn new(http: &HttpClientConfig) -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .pool_idle_timeout(std::time::Duration::from_secs(30))
-                .build()
-                .expect("reqwest client must build"),
+            client: build_http_client(http),
         }
     }
 }
 
+impl Default for OllamaAdapter {
+    fn default() -> Self {
+        Self::new(&HttpClientConfig::default())
+    }
+}
+
 #[async_trait]
 impl BackendAdapter for OllamaAdapter {
     fn dialect(&self) -> BackendDialect {
@@ -238,8 +241,21 @@ fn build_body(model: &str, payload: &TurnPayload, stream: bool) -> Value {
     if !payload.tools.is_empty() {
         body["tools"] = Value::Array(ollama_wire::tools_to_ollama(&payload.tools));
     }
+    let mut options = serde_json::Map::new();
     if let Some(max_tokens) = payload.limits.max_output_tokens {
-        body["options"] = json!({"num_predict": max_tokens});
+        options.insert("num_predict".to_string(), json!(max_tokens));
+    }
+    if let Some(temperature) = payload.sampling.temperature {
+        options.insert("temperature".to_string(), json!(temperature));
+    }
+    if let Some(top_p) = payload.sampling.top_p {
+        options.insert("top_p".to_string(), json!(top_p));
+    }
+    if let Some(seed) = payload.sampling.seed {
+        options.insert("seed".to_string(), json!(seed));
+    }
+    if !options.is_empty() {
+        body["options"] = Value::Object(options);
     }
     if payload.enable_thinking {
         body["think"] = Value::Bool(true);
@@ -288,9 +304,11 @@ fn parse_complete_response(
             model: model.to_string(),
         },
         output_text,
+        reasoning_text: None,
         tool_calls,
         usage: Some(usage),
         finish_reason: FinishReason::Stop,
+        created_at_unix_s: None,
     })
 }
 
@@ -1,9 +1,6 @@
-use std::{
-    sync::{
-        Arc,
-        atomic::{AtomicBool, Ordering},
-    },
-    time::Duration,
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
 };
 
 use async_trait::async_trait;
@@ -15,7 +12,7 @@ use tracing::Instrument;
 
 use crate::ai_gateway::{
     adapters::{
-        BackendAdapter,
+        BackendAdapter, build_http_client,
         http_stream::{self, HttpRequestConfig},
         wire,
     },
@@ -23,8 +20,12 @@ use crate::ai_gateway::{
         AdapterInvocation, BackendCompleteResponse, BackendIdentity, BackendRawEvent, FinishReason,
         OutputMode, ToolCallResult, ToolCallStatus, TurnPayload, UsageStats,
     },
+    embeddings::{BackendEmbeddingsResponse, EmbeddingsRequest},
     error::{GatewayError, GatewayErrorKind},
-    types::{AdapterContext, BackendCapabilities, BackendDialect},
+    types::{
+        AdapterContext, BackendCapabilities, BackendDialect, HttpClientConfig,
+        PostFinishContentPolicy,
+    },
 };
 
 use super::wire as openai_wire;
@@ -38,17 +39,20 @@ pub struct OpenAiCompatibleAdapter {
     client: reqwest::Client,
 }
 
-impl Default for OpenAiCompatibleAdapter {
-    fn default() -> Self {
+impl OpenAiCompatibleAdapter {
+    pub fn new(http: &HttpClientConfig) -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .pool_idle_timeout(Duration::from_secs(30))
-                .build()
-                .expect("reqwest client must build"),
+            client: build_http_client(http),
         }
     }
 }
 
+impl Default for OpenAiCompatibleAdapter {
+    fn default() -> Self {
+        Self::new(&HttpClientConfig::default())
+    }
+}
+
 fn default_openai_capabilities() -> BackendCapabilities {
     BackendCapabilities {
         streaming: true,
@@ -102,6 +106,14 @@ impl BackendAdapter for OpenAiCompatibleAdapter {
         parse_complete_response(&json_response, &backend_id, &ctx.model)
     }
 
+    async fn embed(
+        &self,
+        ctx: AdapterContext,
+        request: &EmbeddingsRequest,
+    ) -> Result<BackendEmbeddingsResponse, GatewayError> {
+        super::embeddings::embed(&self.client, ctx, request).await
+    }
+
     async fn stream(
         &self,
         ctx: AdapterContext,
@@ -117,6 +129,7 @@ impl BackendAdapter for OpenAiCompatibleAdapter {
         let backend_id = ctx.backend_id.clone();
         let model = ctx.model.clone();
         let request_id = ctx.request_id.clone();
+        let post_finish_content = ctx.profile.post_finish_content;
         let dispatch_span = tracing::debug_span!(
             target: "ai_gateway.openai_compatible",
             "openai_dispatch",
@@ -148,7 +161,7 @@ impl BackendAdapter for OpenAiCompatibleAdapter {
 
                 let mut byte_stream = response.bytes_stream();
                 let mut buffer = String::new();
-                let mut saw_terminal = false;
+                let mut finish = StreamFinishState::default();
 
                 while let Some(item) = byte_stream.next().await {
                     if cancel_flag_task.load(Ordering::SeqCst) {
@@ -181,12 +194,17 @@ impl BackendAdapter for OpenAiCompatibleAdapter {
                         };
 
                     for json in frames {
-                        match parse_stream_delta(&json, &backend_id) {
+                        let events = parse_stream_delta(&json, &backend_id).and_then(|events| {
+                            drop_post_finish_content(
+                                events,
+                                &mut finish,
+                                post_finish_content,
+                                &backend_id,
+                            )
+                        });
+                        match events {
                             Ok(events) => {
                                 for event in events {
-                                    if matches!(event, BackendRawEvent::Completed { .. }) {
-                                        saw_terminal = true;
-                                    }
                                     if tx.send(Ok(event)).await.is_err() {
                                         return;
                                     }
@@ -200,7 +218,7 @@ impl BackendAdapter for OpenAiCompatibleAdapter {
                     }
 
                     if done {
-                        if !saw_terminal {
+                        if !finish.saw_terminal {
                             let _ = tx
                                 .send(Ok(BackendRawEvent::Completed {
                                     finish_reason: FinishReason::Stop,
@@ -211,7 +229,7 @@ impl BackendAdapter for OpenAiCompatibleAdapter {
                     }
                 }
 
-                if !saw_terminal {
+                if !finish.saw_terminal {
                     let _ = tx
                         .send(Ok(BackendRawEvent::Completed {
                             finish_reason: FinishReason::Stop,
@@ -305,6 +323,18 @@ fn build_body(
     if let Some(max_tokens) = payload.limits.max_output_tokens {
         body["max_tokens"] = Value::Number(max_tokens.into());
     }
+    if let Some(temperature) = payload.sampling.temperature {
+        body["temperature"] = json!(temperature);
+    }
+    if let Some(top_p) = payload.sampling.top_p {
+        body["top_p"] = json!(top_p);
+    }
+    if let Some(effort) = payload.sampling.reasoning_effort {
+        body["reasoning_effort"] = json!(effort.as_str());
+    }
+    if let Some(seed) = payload.sampling.seed {
+        body["seed"] = json!(seed);
+    }
 
     if payload.enable_thinking {
         body["thinking"] = json!({
@@ -366,6 +396,12 @@ fn parse_complete_response(
         .unwrap_or("")
         .to_string();
 
+    let reasoning_text = choice
+        .get("message")
+        .and_then(reasoning_of)
+        .filter(|reasoning| !reasoning.is_empty())
+        .map(str::to_string);
+
     let tool_calls = parse_tool_calls_from_message(choice.get("message"));
 
     let usage = payload.get("usage").map(parse_usage);
@@ -380,12 +416,22 @@ fn parse_complete_response(
             model: model.to_string(),
         },
         output_text,
+        reasoning_text,
         tool_calls,
         usage,
         finish_reason,
+        created_at_unix_s: payload.get("created").and_then(Value::as_i64),
     })
 }
 
+/// Reasoning text, which servers name `reasoning_content` (DeepSeek, Qwen, vLLM) or `reasoning`.
+fn reasoning_of(message_or_delta: &Value) -> Option<&str> {
+    message_or_delta
+        .get("reasoning_content")
+        .or_else(|| message_or_delta.get("reasoning"))
+        .and_then(Value::as_str)
+}
+
 fn parse_tool_calls_from_message(message: Option<&Value>) -> Vec<ToolCallResult> {
     let Some(tool_calls) = message
         .and_then(|m| m.get("tool_calls"))
@@ -460,6 +506,14 @@ fn parse_stream_delta(
 
     for choice in choices {
         if let Some(delta) = choice.get("delta") {
+            if let Some(reasoning) = reasoning_of(delta)
+                && !reasoning.is_empty()
+            {
+                events.push(BackendRawEvent::ReasoningDelta {
+                    delta: reasoning.to_string(),
+                });
+            }
+
             if let Some(content) = delta.get("content").and_then(Value::as_str) {
                 if !content.is_empty() {
                     events.push(BackendRawEvent::OutputTextDelta {
@@ -507,11 +561,67 @@ fn parse_stream_delta(
     Ok(events)
 }
 
+/// Finish-reason bookkeeping of one stream.
+#[derive(Debug, Default)]
+struct StreamFinishState {
+    saw_terminal: bool,
+    dropped_events: u64,
+}
+
+/// Tracks the finish reason across frames and handles content a backend sends after it.
+///
+/// Under `Lenient` the first dropped event of a stream is logged; later ones are only counted.
+fn drop_post_finish_content(
+    events: Vec<BackendRawEvent>,
+    finish: &mut StreamFinishState,
+    policy: PostFinishContentPolicy,
+    backend_id: &str,
+) -> Result<Vec<BackendRawEvent>, GatewayError> {
+    let mut admitted = Vec::with_capacity(events.len());
+    for event in events {
+        let is_content = matches!(
+            event,
+            BackendRawEvent::OutputTextDelta { .. }
+                | BackendRawEvent::ReasoningDelta { .. }
+                | BackendRawEvent::ToolCallDelta { .. }
+                | BackendRawEvent::ToolCallReady { .. }
+        );
+        if finish.saw_terminal && is_content {
+            match policy {
+                PostFinishContentPolicy::Strict => {
+                    return Err(GatewayError::new(
+                        GatewayErrorKind::ProtocolViolation,
+                        "openai-compatible stream sent content after finish_reason",
+                    )
+                    .with_retryable(false)
+                    .with_backend_id(backend_id.to_string()));
+                }
+                PostFinishContentPolicy::Lenient => {
+                    if finish.dropped_events == 0 {
+                        tracing::warn!(
+                            target: "ai_gateway.openai_compatible",
+                            backend_id = %backend_id,
+                            "post_finish_content_dropped"
+                        );
+                    }
+                    finish.dropped_events += 1;
+                    continue;
+                }
+            }
+        }
+        if matches!(event, BackendRawEvent::Completed { .. }) {
+            finish.saw_terminal = true;
+        }
+        admitted.push(event);
+    }
+    Ok(admitted)
+}
+
 // ---------------------------------------------------------------------------
 // Usage parsing
 // ---------------------------------------------------------------------------
 
-fn parse_usage(usage: &Value) -> UsageStats {
+pub(super) fn parse_usage(usage: &Value) -> UsageStats {
     UsageStats {
         input_tokens: usage
             .get("prompt_tokens")
@@ -525,3 +635,123 @@ fn parse_usage(usage: &Value) -> UsageStats {
         provider_usage_raw: Some(usage.clone()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai_gateway::chat::types::{TurnLimits, TurnSampling};
+
+    #[test]
+    fn content_after_finish_reason_is_dropped_when_lenient_and_rejected_when_strict() {
+        let finishing = json!({
+            "choices": [{"delta": {"content": "done"}, "finish_reason": "stop"}]
+        });
+        let trailing = json!({
+            "choices": [{"delta": {"content": " and more"}, "finish_reason": null}]
+        });
+
+        let mut finish = StreamFinishState::default();
+        let mut lenient = Vec::new();
+        for frame in [&finishing, &trailing, &trailing] {
+            let events = parse_stream_delta(frame, "compat").expect("frame parses");
+            lenient.extend(
+                drop_post_finish_content(
+                    events,
+                    &mut finish,
+                    PostFinishContentPolicy::Lenient,
+                    "compat",
+                )
+                .expect("lenient keeps streaming"),
+            );
+        }
+        assert_eq!(lenient.len(), 2);
+        assert!(matches!(
+            &lenient[0],
+            BackendRawEvent::OutputTextDelta { delta } if delta == "done"
+        ));
+        assert!(matches!(lenient[1], BackendRawEvent::Completed { .. }));
+        assert_eq!(finish.dropped_events, 2);
+
+        let mut finish = StreamFinishState::default();
+        let events = parse_stream_delta(&finishing, "compat").expect("frame parses");
+        drop_post_finish_content(
+            events,
+            &mut finish,
+            PostFinishContentPolicy::Strict,
+            "compat",
+        )
+        .expect("content before finish is admitted");
+        let events = parse_stream_delta(&trailing, "compat").expect("frame parses");
+        let error = drop_post_finish_content(
+            events,
+            &mut finish,
+            PostFinishContentPolicy::Strict,
+            "compat",
+        )
+        .expect_err("strict rejects content after finish");
+        assert_eq!(error.kind, GatewayErrorKind::ProtocolViolation);
+    }
+
+    #[test]
+    fn reasoning_fields_are_captured_apart_from_the_answer() {
+        let thinking = json!({
+            "choices": [{"delta": {"reasoning_content": "weigh options", "content": ""}}]
+        });
+        let answering = json!({
+            "choices": [{"delta": {"reasoning": "pick one", "content": "done"}}]
+        });
+        let events = [&thinking, &answering]
+            .into_iter()
+            .flat_map(|frame| parse_stream_delta(frame, "compat").expect("frame parses"))
+            .collect::<Vec<_>>();
+        assert!(matches!(
+            events.as_slice(),
+            [
+                BackendRawEvent::ReasoningDelta { delta: first },
+                BackendRawEvent::ReasoningDelta { delta: second },
+                BackendRawEvent::OutputTextDelta { delta: answer },
+            ] if first == "weigh options" && second == "pick one" && answer == "done"
+        ));
+
+        let complete = |message: Value| {
+            parse_complete_response(
+                &json!({"choices": [{"message": message, "finish_reason": "stop"}]}),
+                "compat",
+                "qwen3",
+            )
+            .expect("response parses")
+        };
+        let reasoned = complete(json!({"content": "42", "reasoning_content": "6 x 7"}));
+        assert_eq!(reasoned.output_text, "42");
+        assert_eq!(reasoned.reasoning_text.as_deref(), Some("6 x 7"));
+        assert_eq!(complete(json!({"content": "42"})).reasoning_text, None);
+    }
+
+    #[test]
+    fn sampling_fields_are_sent_only_when_set() {
+        let mut payload = TurnPayload {
+            messages: Arc::new(Vec::new()),
+            tools: Vec::new(),
+            output_mode: OutputMode::Text,
+            limits: TurnLimits::default(),
+            sampling: TurnSampling::default(),
+            enable_thinking: false,
+            metadata: Default::default(),
+        };
+        let body = build_body("qwen3", &payload, false, true);
+        for field in ["temperature", "top_p", "seed"] {
+            assert!(body.get(field).is_none(), "{field} should be omitted");
+        }
+
+        payload.sampling = TurnSampling {
+            temperature: Some(0.0),
+            top_p: Some(0.5),
+            seed: Some(42),
+            ..TurnSampling::default()
+        };
+        let body = build_body("qwen3", &payload, false, true);
+        assert_eq!(body["temperature"], json!(0.0));
+        assert_eq!(body["top_p"], json!(0.5));
+        assert_eq!(body["seed"], json!(42));
+    }
+}
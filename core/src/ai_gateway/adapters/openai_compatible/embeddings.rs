@@ -0,0 +1,105 @@
+use serde_json::{Map, Value, json};
+
+use crate::ai_gateway::{
+    adapters::http_stream::{self, HttpRequestConfig},
+    embeddings::{BackendEmbeddingsResponse, EmbeddingsRequest},
+    error::{GatewayError, GatewayErrorKind},
+    types::AdapterContext,
+};
+
+use super::chat::parse_usage;
+
+pub(super) async fn embed(
+    client: &reqwest::Client,
+    ctx: AdapterContext,
+    request: &EmbeddingsRequest,
+) -> Result<BackendEmbeddingsResponse, GatewayError> {
+    let endpoint = ctx.profile.endpoint.clone().ok_or_else(|| {
+        GatewayError::new(
+            GatewayErrorKind::InvalidRequest,
+            "openai-compatible backend requires endpoint",
+        )
+        .with_retryable(false)
+        .with_backend_id(ctx.backend_id.clone())
+    })?;
+
+    let json_response = http_stream::post_json(&HttpRequestConfig {
+        client: client.clone(),
+        url: format!("{}/embeddings", endpoint.trim_end_matches('/')),
+        body: build_body(&ctx.model, request),
+        backend_id: ctx.backend_id.clone(),
+        request_id: ctx.request_id.clone(),
+        credential: ctx.credential,
+        timeout: ctx.timeout,
+    })
+    .await?;
+
+    parse_response(&json_response, request.inputs.len(), &ctx.backend_id)
+}
+
+fn build_body(model: &str, request: &EmbeddingsRequest) -> Value {
+    let mut body = Map::new();
+    body.insert("model".to_string(), json!(model));
+    body.insert("input".to_string(), json!(request.inputs));
+    body.insert("encoding_format".to_string(), json!("float"));
+    if let Some(dimensions) = request.dimensions {
+        body.insert("dimensions".to_string(), json!(dimensions));
+    }
+    Value::Object(body)
+}
+
+/// Orders vectors by their `index`, which the API does not promise to return sorted.
+fn parse_response(
+    response: &Value,
+    input_count: usize,
+    backend_id: &str,
+) -> Result<BackendEmbeddingsResponse, GatewayError> {
+    let protocol_violation = |message: String| {
+        GatewayError::new(GatewayErrorKind::ProtocolViolation, message)
+            .with_retryable(false)
+            .with_backend_id(backend_id.to_string())
+    };
+
+    let data = response
+        .get("data")
+        .and_then(Value::as_array)
+        .ok_or_else(|| protocol_violation("embeddings response is missing `data`".to_string()))?;
+    let mut embeddings = vec![None; input_count];
+    for (position, item) in data.iter().enumerate() {
+        let index = item
+            .get("index")
+            .and_then(Value::as_u64)
+            .map_or(position, |index| index as usize);
+        let vector = item
+            .get("embedding")
+            .and_then(Value::as_array)
+            .and_then(|values| {
+                values
+                    .iter()
+                    .map(|value| value.as_f64().map(|value| value as f32))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .ok_or_else(|| {
+                protocol_violation(format!("embedding {index} is not an array of numbers"))
+            })?;
+        let Some(slot) = embeddings.get_mut(index) else {
+            return Err(protocol_violation(format!(
+                "embedding index {index} is out of range for {input_count} inputs"
+            )));
+        };
+        *slot = Some(vector);
+    }
+
+    let embeddings = embeddings
+        .into_iter()
+        .enumerate()
+        .map(|(index, vector)| {
+            vector.ok_or_else(|| protocol_violation(format!("embedding {index} is missing")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(BackendEmbeddingsResponse {
+        embeddings,
+        usage: response.get("usage").map(parse_usage),
+    })
+}
@@ -1,4 +1,5 @@
 pub mod chat;
+mod embeddings;
 pub(crate) mod wire;
 
 pub use chat::OpenAiCompatibleAdapter;
@@ -1,11 +1,9 @@
-use std::time::Duration;
-
 use async_trait::async_trait;
 use serde_json::Value;
 
 use crate::ai_gateway::{
     adapters::{
-        BackendAdapter,
+        BackendAdapter, build_http_client,
         http_stream::{self, HttpRequestConfig},
     },
     chat::types::{
@@ -13,7 +11,7 @@ use crate::ai_gateway::{
         ToolCallStatus, TurnPayload, UsageStats,
     },
     error::{GatewayError, GatewayErrorKind},
-    types::{AdapterContext, BackendCapabilities, BackendDialect},
+    types::{AdapterContext, BackendCapabilities, BackendDialect, HttpClientConfig},
 };
 
 use super::wire as responses_wire;
@@ -23,17 +21,20 @@ pub struct OpenAiResponsesAdapter {
     client: reqwest::Client,
 }
 
-impl Default for OpenAiResponsesAdapter {
-    fn default() -> Self {
+impl OpenAiResponsesAdapter {
+    pub fn new(http: &HttpClientConfig) -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .pool_idle_timeout(Duration::from_secs(30))
-                .build()
-                .expect("reqwest client must build"),
+            client: build_http_client(http),
         }
     }
 }
 
+impl Default for OpenAiResponsesAdapter {
+    fn default() -> Self {
+        Self::new(&HttpClientConfig::default())
+    }
+}
+
 fn default_responses_capabilities() -> BackendCapabilities {
     BackendCapabilities {
         streaming: false,
@@ -162,9 +163,11 @@ fn parse_complete_response(
             model: model.to_string(),
         },
         output_text,
+        reasoning_text: None,
         tool_calls,
         usage,
         finish_reason,
+        created_at_unix_s: payload.get("created_at").and_then(Value::as_i64),
     })
 }
 
@@ -60,6 +60,15 @@ pub(crate) fn build_request_body(
     if let Some(max_tokens) = payload.limits.max_output_tokens {
         body["max_output_tokens"] = Value::Number(max_tokens.into());
     }
+    if let Some(temperature) = payload.sampling.temperature {
+        body["temperature"] = json!(temperature);
+    }
+    if let Some(top_p) = payload.sampling.top_p {
+        body["top_p"] = json!(top_p);
+    }
+    if let Some(effort) = payload.sampling.reasoning_effort {
+        body["reasoning"] = json!({ "effort": effort.as_str() });
+    }
 
     Ok(body)
 }
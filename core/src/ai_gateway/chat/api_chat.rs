@@ -9,17 +9,20 @@ use std::{
 use tokio::sync::RwLock;
 
 use crate::ai_gateway::{
-    adapters::build_default_adapters,
+    adapters::{build_default_adapters, cassette},
     credentials::CredentialProvider,
+    embeddings::{EmbeddingsRequest, EmbeddingsResponse},
     error::{GatewayError, GatewayErrorKind},
-    resilience::ResilienceEngine,
+    resilience::{BackendBreakerStatus, ResilienceEngine},
     router::BackendRouter,
-    types::{AIGatewayConfig, ChatRouteRef},
+    slo::{BackendSloSnapshot, BackendSloTracker},
+    types::{AIGatewayConfig, BackendId, ChatRouteRef, DeclaredModelLimits},
 };
 use crate::observability::runtime as observability_runtime;
 
 use super::{
     capabilities::CapabilityGuard,
+    coalescing::RequestCoalescer,
     runtime::ChatRuntime,
     thread::{
         Thread, ThreadState, metadata_parent_span_id, metadata_tick, thread_messages_snapshot,
@@ -46,14 +49,23 @@ impl Chat {
         config: &AIGatewayConfig,
         credential_provider: Arc<dyn CredentialProvider>,
     ) -> Result<Self, GatewayError> {
+        let mut adapters = build_default_adapters(&config.http);
+        if let Some(cassette) = &config.chat.cassette {
+            adapters = cassette::wrap_adapters(adapters, cassette)?;
+        }
         let runtime = Arc::new(ChatRuntime {
             router: BackendRouter::new(config)?,
             credential_provider,
-            adapters: build_default_adapters(),
+            adapters,
             capability_guard: CapabilityGuard,
             resilience: ResilienceEngine::new(config.resilience.clone()),
+            slo: BackendSloTracker::new(),
             default_route_ref: config.chat.default_route.clone(),
             default_turn_timeout_ms: config.chat.default_turn_timeout_ms,
+            coalescer: config
+                .chat
+                .coalesce_identical_requests
+                .then(RequestCoalescer::default),
         });
 
         Ok(Self {
@@ -64,6 +76,34 @@ impl Chat {
         })
     }
 
+    /// Circuit breaker state for every backend this chat has dispatched to.
+    pub async fn backend_breaker_states(&self) -> BTreeMap<BackendId, BackendBreakerStatus> {
+        self.runtime.resilience.breaker_states().await
+    }
+
+    /// Attempt success rate, latency percentiles, and error kinds per backend.
+    pub fn backend_slo_snapshots(&self) -> BTreeMap<BackendId, BackendSloSnapshot> {
+        self.runtime.slo.snapshots()
+    }
+
+    /// Context window and output limits declared per backend in the gateway profile.
+    pub fn declared_model_limits(&self) -> BTreeMap<BackendId, DeclaredModelLimits> {
+        self.runtime.router.declared_limits()
+    }
+
+    /// Embeds `request.inputs` on the backend its route resolves to.
+    pub async fn embed(
+        &self,
+        request: EmbeddingsRequest,
+    ) -> Result<EmbeddingsResponse, GatewayError> {
+        let route_ref = request
+            .route_ref
+            .as_ref()
+            .or(self.runtime.default_route_ref.as_ref());
+        let backend = self.runtime.resolve_backend_route_ref(route_ref).await?;
+        self.runtime.dispatch_embed(&backend, &request).await
+    }
+
     pub async fn open_thread(&self, opts: ThreadOptions) -> Result<Thread, GatewayError> {
         let route_ref = opts
             .route_ref
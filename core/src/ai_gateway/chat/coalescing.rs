@@ -0,0 +1,88 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use sha2::{Digest, Sha256};
+use tokio::sync::OnceCell;
+
+use crate::ai_gateway::error::GatewayError;
+
+use super::{
+    runtime::{BoundBackend, turn_payload_json},
+    types::{TurnPayload, TurnResponse},
+};
+
+type SharedOutcome = Arc<OnceCell<Result<TurnResponse, GatewayError>>>;
+
+/// Single-flight table for identical in-flight dispatches.
+///
+/// Requests are keyed on backend, model, and payload content; per-thread metadata is left
+/// out so identical turns from different threads share one backend call. Entries live only
+/// while the call is in flight, so completed results are never replayed. Token usage is
+/// reported only to the caller whose dispatch ran, so shared calls are counted once.
+#[derive(Default)]
+pub(crate) struct RequestCoalescer {
+    in_flight: Mutex<HashMap<String, SharedOutcome>>,
+}
+
+impl RequestCoalescer {
+    pub(crate) fn key_for(backend: &BoundBackend, payload: &TurnPayload) -> String {
+        let mut content = turn_payload_json(payload);
+        if let Some(object) = content.as_object_mut() {
+            object.remove("metadata");
+        }
+        let mut hasher = Sha256::new();
+        hasher.update(backend.backend_id.as_bytes());
+        hasher.update([0]);
+        hasher.update(backend.model.as_bytes());
+        hasher.update([0]);
+        hasher.update(content.to_string().as_bytes());
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    /// Runs `dispatch` unless an identical request is in flight, in which case its outcome
+    /// is shared without usage. A cancelled leader hands the dispatch over to a waiting
+    /// follower. The flag reports whether the outcome came from another caller's dispatch.
+    pub(crate) async fn run<F>(
+        &self,
+        key: String,
+        dispatch: F,
+    ) -> (Result<TurnResponse, GatewayError>, bool)
+    where
+        F: Future<Output = Result<TurnResponse, GatewayError>>,
+    {
+        let cell = {
+            let mut in_flight = self.in_flight.lock().expect("lock poisoned");
+            Arc::clone(in_flight.entry(key.clone()).or_default())
+        };
+        let mut led = false;
+        let outcome = cell
+            .get_or_init(|| {
+                led = true;
+                dispatch
+            })
+            .await
+            .clone();
+        let mut in_flight = self.in_flight.lock().expect("lock poisoned");
+        if in_flight
+            .get(&key)
+            .is_some_and(|current| Arc::ptr_eq(current, &cell))
+        {
+            in_flight.remove(&key);
+        }
+        if led {
+            return (outcome, false);
+        }
+        let outcome = outcome.map(|response| TurnResponse {
+            usage: None,
+            ..response
+        });
+        (outcome, true)
+    }
+}
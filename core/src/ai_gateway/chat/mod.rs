@@ -1,8 +1,10 @@
 pub mod api_chat;
 pub mod capabilities;
+pub mod coalescing;
 pub mod executor;
 pub mod message;
 pub mod message_codec;
+pub(crate) mod output_schema;
 pub mod runtime;
 pub mod thread;
 pub mod thread_types;
@@ -27,5 +29,6 @@ pub use tool::{ChatToolDefinition, ToolOverride};
 pub use turn::Turn;
 pub use types::{
     ChatEvent, ChatEventStream, ChatMessage, ChatRole, ContentPart, FinishReason, MessageToolCall,
-    OutputMode, ToolCallResult, TurnLimits, TurnResponse, UsageStats,
+    OutputMode, ReasoningEffort, ToolCallResult, TurnLimits, TurnResponse, TurnSampling,
+    UsageStats,
 };
@@ -0,0 +1,128 @@
+use crate::ai_gateway::error::{GatewayError, GatewayErrorKind};
+
+use super::types::{BackendCompleteResponse, OutputMode};
+
+/// Rejects a final answer that breaks the schema a strict `JsonSchema` request asked for.
+///
+/// Backends are supposed to enforce strict schemas themselves; this catches the ones that
+/// don't before a caller's own parse fails without saying where. Tool-call responses carry no
+/// final answer and pass through.
+pub(crate) fn validate_strict_output(
+    output_mode: &OutputMode,
+    response: &BackendCompleteResponse,
+) -> Result<(), GatewayError> {
+    let OutputMode::JsonSchema {
+        name,
+        schema,
+        strict: true,
+    } = output_mode
+    else {
+        return Ok(());
+    };
+    if !response.tool_calls.is_empty() {
+        return Ok(());
+    }
+
+    let backend_id = response.backend_identity.backend_id.clone();
+    let violation = |message: String| {
+        GatewayError::new(GatewayErrorKind::ProtocolViolation, message)
+            .with_retryable(false)
+            .with_backend_id(backend_id.clone())
+    };
+    let compiled = jsonschema::JSONSchema::compile(schema)
+        .map_err(|err| violation(format!("output schema '{name}' is invalid: {err}")))?;
+    let output = serde_json::from_str::<serde_json::Value>(&response.output_text)
+        .map_err(|err| violation(format!("output for schema '{name}' is not JSON: {err}")))?;
+    if let Err(mut errors) = compiled.validate(&output)
+        && let Some(error) = errors.next()
+    {
+        return Err(violation(format!(
+            "output does not match schema '{name}' at '{}': {error}",
+            error.instance_path
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::ai_gateway::{
+        chat::types::{BackendIdentity, FinishReason},
+        types::BackendDialect,
+    };
+
+    fn strict_acts() -> OutputMode {
+        OutputMode::JsonSchema {
+            name: "acts".to_string(),
+            schema: json!({
+                "type": "object",
+                "required": ["acts"],
+                "properties": {
+                    "acts": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "required": ["endpoint_id"],
+                            "properties": { "endpoint_id": { "type": "string" } }
+                        }
+                    }
+                }
+            }),
+            strict: true,
+        }
+    }
+
+    fn response(output_text: &str) -> BackendCompleteResponse {
+        BackendCompleteResponse {
+            backend_identity: BackendIdentity {
+                backend_id: "openai".to_string(),
+                dialect: BackendDialect::OpenAiResponses,
+                model: "gpt-5".to_string(),
+            },
+            output_text: output_text.to_string(),
+            reasoning_text: None,
+            tool_calls: Vec::new(),
+            usage: None,
+            finish_reason: FinishReason::Stop,
+            created_at_unix_s: None,
+        }
+    }
+
+    #[test]
+    fn conforming_strict_output_passes() {
+        validate_strict_output(
+            &strict_acts(),
+            &response(r#"{"acts":[{"endpoint_id":"shell"}]}"#),
+        )
+        .expect("conforming output should pass");
+    }
+
+    #[test]
+    fn nonconforming_strict_output_reports_violation_path() {
+        let err =
+            validate_strict_output(&strict_acts(), &response(r#"{"acts":[{"endpoint_id":7}]}"#))
+                .expect_err("wrong field type should be rejected");
+
+        assert_eq!(err.kind, GatewayErrorKind::ProtocolViolation);
+        assert!(!err.retryable);
+        assert!(
+            err.message.contains("at '/acts/0/endpoint_id'"),
+            "{}",
+            err.message
+        );
+
+        let OutputMode::JsonSchema { name, schema, .. } = strict_acts() else {
+            unreachable!();
+        };
+        let lenient = OutputMode::JsonSchema {
+            name,
+            schema,
+            strict: false,
+        };
+        validate_strict_output(&lenient, &response(r#"{"acts":[{"endpoint_id":7}]}"#))
+            .expect("non-strict modes are not validated");
+    }
+}
@@ -1,5 +1,7 @@
 use std::{
+    borrow::Cow,
     collections::BTreeMap,
+    future::Future,
     sync::atomic::{AtomicU64, Ordering},
 };
 
@@ -9,10 +11,13 @@ use tokio::time::sleep;
 use crate::{
     ai_gateway::{
         adapters::BackendAdapter,
+        clock_skew::detect_response_clock_skew,
         credentials::CredentialProvider,
+        embeddings::{BackendEmbeddingsResponse, EmbeddingsRequest, EmbeddingsResponse},
         error::{GatewayError, GatewayErrorKind},
-        resilience::{ResilienceEngine, ResilienceLease},
-        router::BackendRouter,
+        resilience::{ResilienceEngine, ResilienceLease, RetryDecision},
+        router::{BackendRouter, SelectedBackend},
+        slo::BackendSloTracker,
         telemetry::{GatewayTelemetryEvent, emit_gateway_event},
         types::{
             AdapterContext, BackendCapabilities, BackendDialect, BackendId, BackendProfile,
@@ -24,7 +29,9 @@ use crate::{
 
 use super::{
     capabilities::CapabilityGuard,
-    types::{TurnPayload, TurnResponse, UsageStats},
+    coalescing::RequestCoalescer,
+    output_schema::validate_strict_output,
+    types::{BackendCompleteResponse, TurnLimits, TurnPayload, TurnResponse, UsageStats},
 };
 
 pub(crate) struct ChatRuntime {
@@ -33,8 +40,10 @@ pub(crate) struct ChatRuntime {
     pub adapters: std::collections::HashMap<BackendDialect, std::sync::Arc<dyn BackendAdapter>>,
     pub capability_guard: CapabilityGuard,
     pub resilience: ResilienceEngine,
+    pub slo: BackendSloTracker,
     pub default_route_ref: Option<ChatRouteRef>,
     pub default_turn_timeout_ms: u64,
+    pub coalescer: Option<RequestCoalescer>,
 }
 
 #[derive(Clone)]
@@ -45,6 +54,8 @@ pub(crate) struct BoundBackend {
     pub credential: ResolvedCredential,
     pub capabilities: BackendCapabilities,
     pub adapter: std::sync::Arc<dyn BackendAdapter>,
+    /// Backends tried in order when this one cannot serve, bound only once they are needed.
+    pub fallbacks: Vec<SelectedBackend>,
 }
 
 impl ChatRuntime {
@@ -53,50 +64,240 @@ impl ChatRuntime {
         backend: &BoundBackend,
         request_id: String,
         payload: &TurnPayload,
+    ) -> Result<TurnResponse, GatewayError> {
+        let Some(coalescer) = &self.coalescer else {
+            return self
+                .dispatch_with_fallbacks(backend, request_id, payload)
+                .await;
+        };
+        let key = RequestCoalescer::key_for(backend, payload);
+        let (outcome, coalesced) = coalescer
+            .run(
+                key,
+                self.dispatch_with_fallbacks(backend, request_id.clone(), payload),
+            )
+            .await;
+        if coalesced {
+            emit_gateway_event(GatewayTelemetryEvent::RequestCoalesced {
+                request_id,
+                backend_id: backend.backend_id.clone(),
+            });
+        }
+        outcome
+    }
+
+    /// Dispatches to `backend`, moving down its fallback chain while it cannot serve.
+    ///
+    /// A backend whose breaker is open is skipped without being started; one that fails with a
+    /// fallback-eligible error after its own retries hands the request to the next in line. Each
+    /// backend is checked against, and clamps, the payload under its own capabilities and limits,
+    /// and a fallback whose credentials cannot be resolved is passed over.
+    async fn dispatch_with_fallbacks(
+        &self,
+        backend: &BoundBackend,
+        request_id: String,
+        payload: &TurnPayload,
+    ) -> Result<TurnResponse, GatewayError> {
+        let mut current = Cow::Borrowed(backend);
+        let mut remaining = backend.fallbacks.iter();
+        let mut fallback_from = Vec::new();
+        loop {
+            let has_fallback = remaining.len() > 0;
+            let mut last_error =
+                if has_fallback && self.resilience.is_backend_open(&current.backend_id).await {
+                    GatewayError::new(
+                        GatewayErrorKind::CircuitOpen,
+                        "backend circuit is open; trying its fallback",
+                    )
+                    .with_backend_id(current.backend_id.clone())
+                } else {
+                    match self
+                        .dispatch_backend_complete(&current, request_id.clone(), payload)
+                        .await
+                    {
+                        Ok(mut response) => {
+                            if !fallback_from.is_empty() {
+                                response
+                                    .backend_metadata
+                                    .insert("fallback_from".to_string(), json!(fallback_from));
+                            }
+                            return Ok(response);
+                        }
+                        Err(err) if !has_fallback || !falls_back_on(&err) => return Err(err),
+                        Err(err) => err,
+                    }
+                };
+            let error_kind = last_error.kind;
+
+            let next = loop {
+                let Some(selected) = remaining.next() else {
+                    return Err(last_error);
+                };
+                match self.bound_backend_from_selected(selected.clone()).await {
+                    Ok(next) => break next,
+                    Err(err) => {
+                        tracing::warn!(
+                            target: "ai_gateway",
+                            request_id = %request_id,
+                            backend_id = %selected.backend_id,
+                            error = %err.message,
+                            "skipping_unbindable_fallback_backend"
+                        );
+                        last_error = err;
+                    }
+                }
+            };
+            emit_gateway_event(GatewayTelemetryEvent::BackendFallback {
+                request_id: request_id.clone(),
+                from_backend_id: current.backend_id.clone(),
+                to_backend_id: next.backend_id.clone(),
+                error_kind,
+            });
+            fallback_from.push(current.backend_id.clone());
+            current = Cow::Owned(next);
+        }
+    }
+
+    async fn dispatch_backend_complete(
+        &self,
+        backend: &BoundBackend,
+        request_id: String,
+        payload: &TurnPayload,
     ) -> Result<TurnResponse, GatewayError> {
         self.capability_guard
             .assert_supported(payload, &backend.capabilities)?;
+        let payload = clamp_output_tokens(payload, backend, &request_id);
+        let payload = payload.as_ref();
+
+        let trace = RequestTrace {
+            capability: "chat.complete",
+            request_id: &request_id,
+            tick: metadata_tick(&payload.metadata),
+            parent_span_id: payload
+                .metadata
+                .get("parent_span_id")
+                .cloned()
+                .or_else(|| payload.metadata.get("request_id").cloned()),
+            organ_id: payload.metadata.get("organ_id").cloned(),
+        };
+        let attempted = self
+            .run_attempts(
+                backend,
+                &payload.limits,
+                &trace,
+                backend.adapter.supports_tool_retry(),
+                |adapter_ctx| async move {
+                    backend
+                        .adapter
+                        .complete(adapter_ctx, payload)
+                        .await
+                        .and_then(|response| {
+                            validate_strict_output(&payload.output_mode, &response)?;
+                            Ok(response)
+                        })
+                },
+            )
+            .await?;
+        let complete_response = attempted.response;
 
+        if let (Some(threshold_ms), Some(created_at_unix_s)) = (
+            self.resilience.config().response_clock_skew_warn_ms,
+            complete_response.created_at_unix_s,
+        ) && let Some(skew_ms) = detect_response_clock_skew(
+            created_at_unix_s,
+            std::time::SystemTime::now(),
+            threshold_ms,
+        ) {
+            emit_gateway_event(GatewayTelemetryEvent::ResponseClockSkew {
+                request_id: request_id.clone(),
+                backend_id: backend.backend_id.clone(),
+                created_at_unix_s,
+                skew_ms,
+                threshold_ms,
+            });
+        }
+
+        let mut backend_metadata = response_metadata(backend, &request_id);
+        if self.resilience.config().include_retry_trace {
+            backend_metadata.insert("retry_trace".to_string(), json!(attempted.retry_trace));
+        }
+
+        Ok(TurnResponse {
+            output_text: complete_response.output_text,
+            reasoning_text: complete_response.reasoning_text,
+            tool_calls: complete_response.tool_calls,
+            pending_tool_call_continuation: false,
+            usage: complete_response.usage,
+            finish_reason: complete_response.finish_reason,
+            backend_metadata,
+        })
+    }
+
+    /// Embeds `request` on `backend` under the same breaker, concurrency and retry policy as chat.
+    pub(crate) async fn dispatch_embed(
+        &self,
+        backend: &BoundBackend,
+        request: &EmbeddingsRequest,
+    ) -> Result<EmbeddingsResponse, GatewayError> {
+        let request_id = next_request_id(&backend.backend_id, &backend.model);
+        let trace = RequestTrace {
+            capability: "embeddings",
+            request_id: &request_id,
+            tick: 0,
+            parent_span_id: None,
+            organ_id: None,
+        };
+        let attempted = self
+            .run_attempts(
+                backend,
+                &request.limits,
+                &trace,
+                false,
+                |adapter_ctx| async move { backend.adapter.embed(adapter_ctx, request).await },
+            )
+            .await?;
+
+        Ok(EmbeddingsResponse {
+            embeddings: attempted.response.embeddings,
+            usage: attempted.response.usage,
+            backend_metadata: response_metadata(backend, &request_id),
+        })
+    }
+
+    /// Runs `attempt` on `backend` until it succeeds or the retry policy gives up.
+    ///
+    /// Holds a resilience lease for the whole request, checks the breaker before every attempt,
+    /// records each outcome for the breaker and SLO tracker, and emits the request telemetry.
+    async fn run_attempts<T, F, Fut>(
+        &self,
+        backend: &BoundBackend,
+        limits: &TurnLimits,
+        trace: &RequestTrace<'_>,
+        tool_retry_safe: bool,
+        mut attempt_once: F,
+    ) -> Result<Attempted<T>, GatewayError>
+    where
+        T: AttemptResponse,
+        F: FnMut(AdapterContext) -> Fut,
+        Fut: Future<Output = Result<T, GatewayError>>,
+    {
         let lease = self
             .resilience
-            .pre_dispatch(&payload.limits, &backend.backend_id)
+            .pre_dispatch(limits, &backend.backend_id)
             .await?;
         let mut lease = Some(lease);
 
-        let tick = metadata_tick(&payload.metadata);
-        let parent_span_id = payload
-            .metadata
-            .get("parent_span_id")
-            .cloned()
-            .or_else(|| payload.metadata.get("request_id").cloned());
-        let organ_id = payload.metadata.get("organ_id").cloned();
+        let request_id = trace.request_id.to_string();
         emit_gateway_event(GatewayTelemetryEvent::RequestStarted {
             request_id: request_id.clone(),
             backend_id: backend.backend_id.clone(),
             model: backend.model.clone(),
             cost_attribution_id: None,
         });
-        observability_runtime::emit_ai_gateway_request(
-            observability_runtime::AiGatewayRequestArgs {
-                tick,
-                request_id: request_id.clone(),
-                span_id: request_id.clone(),
-                parent_span_id: parent_span_id.clone(),
-                organ_id: organ_id.clone(),
-                capability: "chat.complete".to_string(),
-                backend_id: backend.backend_id.clone(),
-                model: backend.model.clone(),
-                kind: "start".to_string(),
-                attempt: None,
-                retryable: None,
-                provider_request: None,
-                provider_response: None,
-                usage: None,
-                error: None,
-            },
-        );
+        trace.emit(backend, "start", None, None, None, None);
 
         let mut attempt = 0_u32;
+        let mut retry_trace = Vec::new();
         loop {
             emit_gateway_event(GatewayTelemetryEvent::AttemptStarted {
                 request_id: request_id.clone(),
@@ -110,24 +311,13 @@ impl ChatRuntime {
                 .await
             {
                 release_lease(&self.resilience, &mut lease);
-                observability_runtime::emit_ai_gateway_request(
-                    observability_runtime::AiGatewayRequestArgs {
-                        tick,
-                        request_id: request_id.clone(),
-                        span_id: request_id.clone(),
-                        parent_span_id: parent_span_id.clone(),
-                        organ_id: organ_id.clone(),
-                        capability: "chat.complete".to_string(),
-                        backend_id: backend.backend_id.clone(),
-                        model: backend.model.clone(),
-                        kind: "failed".to_string(),
-                        attempt: Some(attempt.saturating_add(1)),
-                        retryable: Some(err.retryable),
-                        provider_request: None,
-                        provider_response: None,
-                        usage: None,
-                        error: Some(json!(err)),
-                    },
+                trace.emit(
+                    backend,
+                    "failed",
+                    Some(attempt.saturating_add(1)),
+                    Some(err.retryable),
+                    None,
+                    Some(json!(err)),
                 );
                 emit_gateway_event(GatewayTelemetryEvent::RequestFailed {
                     request_id: request_id.clone(),
@@ -154,69 +344,46 @@ impl ChatRuntime {
                 request_id: request_id.clone(),
             };
 
-            match backend.adapter.complete(adapter_ctx, payload).await {
-                Ok(complete_response) => {
+            let attempt_started_at = std::time::Instant::now();
+            let attempt_result = attempt_once(adapter_ctx).await;
+            self.slo.record_attempt(
+                &backend.backend_id,
+                attempt_started_at.elapsed(),
+                attempt_result.as_ref().map(|_| ()).map_err(|err| err.kind),
+            );
+            match attempt_result {
+                Ok(response) => {
                     self.resilience.record_success(&backend.backend_id).await;
                     release_lease(&self.resilience, &mut lease);
-
                     emit_gateway_event(GatewayTelemetryEvent::RequestCompleted {
                         request_id: request_id.clone(),
                         attempts: attempt + 1,
-                        usage: complete_response.usage.clone(),
+                        usage: response.usage().cloned(),
                         cost_attribution_id: None,
                     });
-                    observability_runtime::emit_ai_gateway_request(
-                        observability_runtime::AiGatewayRequestArgs {
-                            tick,
-                            request_id: request_id.clone(),
-                            span_id: request_id.clone(),
-                            parent_span_id: parent_span_id.clone(),
-                            organ_id: organ_id.clone(),
-                            capability: "chat.complete".to_string(),
-                            backend_id: backend.backend_id.clone(),
-                            model: backend.model.clone(),
-                            kind: "succeeded".to_string(),
-                            attempt: Some(attempt.saturating_add(1)),
-                            retryable: None,
-                            provider_request: None,
-                            provider_response: None,
-                            usage: usage_json(complete_response.usage.as_ref()),
-                            error: None,
-                        },
-                    );
-
-                    let mut backend_metadata = BTreeMap::new();
-                    backend_metadata.insert(
-                        "backend_id".to_string(),
-                        serde_json::Value::String(backend.backend_id.clone()),
-                    );
-                    backend_metadata.insert(
-                        "model".to_string(),
-                        serde_json::Value::String(backend.model.clone()),
+                    trace.emit(
+                        backend,
+                        "succeeded",
+                        Some(attempt.saturating_add(1)),
+                        None,
+                        usage_json(response.usage()),
+                        None,
                     );
-                    backend_metadata.insert(
-                        "request_id".to_string(),
-                        serde_json::Value::String(request_id.clone()),
-                    );
-
-                    return Ok(TurnResponse {
-                        output_text: complete_response.output_text,
-                        tool_calls: complete_response.tool_calls,
-                        pending_tool_call_continuation: false,
-                        usage: complete_response.usage,
-                        finish_reason: complete_response.finish_reason,
-                        backend_metadata,
+                    return Ok(Attempted {
+                        response,
+                        retry_trace,
                     });
                 }
                 Err(err) => {
-                    let can_retry = self.resilience.can_retry(
+                    let decision = self.resilience.retry_decision(
                         &err,
                         attempt,
                         false,
                         false,
                         &backend.capabilities,
-                        backend.adapter.supports_tool_retry(),
+                        tool_retry_safe,
                     );
+                    let can_retry = decision.can_retry;
                     self.resilience
                         .record_failure(
                             &backend.backend_id,
@@ -229,26 +396,18 @@ impl ChatRuntime {
                         attempt,
                         kind: err.kind,
                         retryable: err.retryable,
+                        can_retry,
+                        retry_reason: decision.reason,
                         cost_attribution_id: None,
                     });
-                    observability_runtime::emit_ai_gateway_request(
-                        observability_runtime::AiGatewayRequestArgs {
-                            tick,
-                            request_id: request_id.clone(),
-                            span_id: request_id.clone(),
-                            parent_span_id: parent_span_id.clone(),
-                            organ_id: organ_id.clone(),
-                            capability: "chat.complete".to_string(),
-                            backend_id: backend.backend_id.clone(),
-                            model: backend.model.clone(),
-                            kind: "attempt_failed".to_string(),
-                            attempt: Some(attempt.saturating_add(1)),
-                            retryable: Some(err.retryable),
-                            provider_request: None,
-                            provider_response: None,
-                            usage: None,
-                            error: Some(json!(err.clone())),
-                        },
+                    retry_trace.push(decision);
+                    trace.emit(
+                        backend,
+                        "attempt_failed",
+                        Some(attempt.saturating_add(1)),
+                        Some(err.retryable),
+                        None,
+                        Some(json!(err.clone())),
                     );
 
                     if can_retry {
@@ -258,29 +417,18 @@ impl ChatRuntime {
                             1,
                         );
                         attempt = attempt.saturating_add(1);
-                        sleep(self.resilience.backoff_delay(attempt)).await;
+                        sleep(self.resilience.retry_delay(attempt, &err)).await;
                         continue;
                     }
 
                     release_lease(&self.resilience, &mut lease);
-                    observability_runtime::emit_ai_gateway_request(
-                        observability_runtime::AiGatewayRequestArgs {
-                            tick,
-                            request_id: request_id.clone(),
-                            span_id: request_id.clone(),
-                            parent_span_id: parent_span_id.clone(),
-                            organ_id: organ_id.clone(),
-                            capability: "chat.complete".to_string(),
-                            backend_id: backend.backend_id.clone(),
-                            model: backend.model.clone(),
-                            kind: "failed".to_string(),
-                            attempt: Some(attempt.saturating_add(1)),
-                            retryable: Some(err.retryable),
-                            provider_request: None,
-                            provider_response: None,
-                            usage: None,
-                            error: Some(json!(err.clone())),
-                        },
+                    trace.emit(
+                        backend,
+                        "failed",
+                        Some(attempt.saturating_add(1)),
+                        Some(err.retryable),
+                        None,
+                        Some(json!(err.clone())),
                     );
                     emit_gateway_event(GatewayTelemetryEvent::RequestFailed {
                         request_id: request_id.clone(),
@@ -299,12 +447,14 @@ impl ChatRuntime {
         route_ref: Option<&ChatRouteRef>,
     ) -> Result<BoundBackend, GatewayError> {
         let selected = self.router.select_route_ref(route_ref)?;
-        self.bound_backend_from_selected(selected).await
+        let mut backend = self.bound_backend_from_selected(selected).await?;
+        backend.fallbacks = self.router.fallbacks_for_route_ref(route_ref);
+        Ok(backend)
     }
 
     async fn bound_backend_from_selected(
         &self,
-        selected: crate::ai_gateway::router::SelectedBackend,
+        selected: SelectedBackend,
     ) -> Result<BoundBackend, GatewayError> {
         let credential = self
             .credential_provider
@@ -340,6 +490,7 @@ impl ChatRuntime {
             credential,
             capabilities,
             adapter,
+            fallbacks: Vec::new(),
         })
     }
 }
@@ -350,12 +501,125 @@ pub(crate) fn next_request_id(backend_id: &str, model: &str) -> String {
     format!("{}-{}-{}", backend_id, model, seq)
 }
 
+/// Identifies one gateway request in the observability events its attempts emit.
+struct RequestTrace<'a> {
+    capability: &'static str,
+    request_id: &'a str,
+    tick: u64,
+    parent_span_id: Option<String>,
+    organ_id: Option<String>,
+}
+
+impl RequestTrace<'_> {
+    fn emit(
+        &self,
+        backend: &BoundBackend,
+        kind: &str,
+        attempt: Option<u32>,
+        retryable: Option<bool>,
+        usage: Option<Value>,
+        error: Option<Value>,
+    ) {
+        observability_runtime::emit_ai_gateway_request(
+            observability_runtime::AiGatewayRequestArgs {
+                tick: self.tick,
+                request_id: self.request_id.to_string(),
+                span_id: self.request_id.to_string(),
+                parent_span_id: self.parent_span_id.clone(),
+                organ_id: self.organ_id.clone(),
+                capability: self.capability.to_string(),
+                backend_id: backend.backend_id.clone(),
+                model: backend.model.clone(),
+                kind: kind.to_string(),
+                attempt,
+                retryable,
+                provider_request: None,
+                provider_response: None,
+                usage,
+                error,
+            },
+        );
+    }
+}
+
+/// A backend response the shared attempt loop can report usage for.
+trait AttemptResponse {
+    fn usage(&self) -> Option<&UsageStats>;
+}
+
+impl AttemptResponse for BackendCompleteResponse {
+    fn usage(&self) -> Option<&UsageStats> {
+        self.usage.as_ref()
+    }
+}
+
+impl AttemptResponse for BackendEmbeddingsResponse {
+    fn usage(&self) -> Option<&UsageStats> {
+        self.usage.as_ref()
+    }
+}
+
+struct Attempted<T> {
+    response: T,
+    retry_trace: Vec<RetryDecision>,
+}
+
+fn response_metadata(backend: &BoundBackend, request_id: &str) -> BTreeMap<String, Value> {
+    BTreeMap::from([
+        ("backend_id".to_string(), json!(backend.backend_id)),
+        ("model".to_string(), json!(backend.model)),
+        ("request_id".to_string(), json!(request_id)),
+    ])
+}
+
+/// Errors that say this backend, rather than the request, is the problem.
+fn falls_back_on(err: &GatewayError) -> bool {
+    matches!(
+        err.kind,
+        GatewayErrorKind::CircuitOpen
+            | GatewayErrorKind::UnsupportedCapability
+            | GatewayErrorKind::Authentication
+            | GatewayErrorKind::Authorization
+            | GatewayErrorKind::RateLimited
+            | GatewayErrorKind::Timeout
+            | GatewayErrorKind::BackendTransient
+            | GatewayErrorKind::BackendPermanent
+    )
+}
+
 fn release_lease(resilience: &ResilienceEngine, lease: &mut Option<ResilienceLease>) {
     if let Some(current) = lease.take() {
         resilience.release(current);
     }
 }
 
+/// Clamps the requested output token limit to the backend's cap and declared limits, if any.
+fn clamp_output_tokens<'a>(
+    payload: &'a TurnPayload,
+    backend: &BoundBackend,
+    request_id: &str,
+) -> Cow<'a, TurnPayload> {
+    let (Some(requested), Some(cap)) = (
+        payload.limits.max_output_tokens,
+        backend.profile.output_tokens_cap(),
+    ) else {
+        return Cow::Borrowed(payload);
+    };
+    if requested <= cap {
+        return Cow::Borrowed(payload);
+    }
+
+    emit_gateway_event(GatewayTelemetryEvent::OutputTokensClamped {
+        request_id: request_id.to_string(),
+        backend_id: backend.backend_id.clone(),
+        requested,
+        cap,
+    });
+    let mut clamped = payload.clone();
+    clamped.limits.max_output_tokens = Some(cap);
+    Cow::Owned(clamped)
+}
+
 fn metadata_tick(metadata: &BTreeMap<String, String>) -> u64 {
     metadata
         .get("tick")
@@ -369,6 +633,7 @@ pub(crate) fn turn_payload_json(payload: &TurnPayload) -> Value {
         "tools": payload.tools,
         "output_mode": payload.output_mode,
         "limits": payload.limits,
+        "sampling": payload.sampling,
         "enable_thinking": payload.enable_thinking,
         "metadata": payload.metadata,
     })
@@ -132,6 +132,7 @@ impl Thread {
             tools: effective_tools,
             output_mode,
             limits,
+            sampling: input.sampling.clone().unwrap_or_default(),
             enable_thinking,
             metadata: metadata.clone(),
         };
@@ -8,7 +8,7 @@ use super::{
     executor::ToolExecutor,
     tool::{ChatToolDefinition, ToolOverride},
     turn::Turn,
-    types::{ChatMessage, OutputMode, TurnLimits, TurnResponse},
+    types::{ChatMessage, OutputMode, TurnLimits, TurnResponse, TurnSampling},
 };
 
 #[derive(Debug, Clone, Default)]
@@ -128,6 +128,7 @@ pub struct TurnInput {
     pub tool_overrides: Vec<ToolOverride>,
     pub output_mode: Option<OutputMode>,
     pub limits: Option<TurnLimits>,
+    pub sampling: Option<TurnSampling>,
     pub enable_thinking: Option<bool>,
     pub tool_executor: Option<Arc<dyn ToolExecutor>>,
     pub metadata: BTreeMap<String, String>,
@@ -140,6 +141,7 @@ impl Default for TurnInput {
             tool_overrides: Vec::new(),
             output_mode: None,
             limits: None,
+            sampling: None,
             enable_thinking: None,
             tool_executor: None,
             metadata: BTreeMap::new(),
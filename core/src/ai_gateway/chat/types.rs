@@ -1,6 +1,7 @@
 use std::{collections::BTreeMap, pin::Pin, sync::Arc};
 
 use futures_core::Stream;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::ai_gateway::{
@@ -90,6 +91,42 @@ pub struct TurnLimits {
     pub max_request_time_ms: Option<u64>,
 }
 
+// ---------------------------------------------------------------------------
+// Sampling
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReasoningEffort {
+    Low,
+    Medium,
+    High,
+}
+
+impl ReasoningEffort {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+}
+
+/// Sampling overrides for one turn; unset fields keep the backend defaults.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TurnSampling {
+    #[serde(default)]
+    pub temperature: Option<f64>,
+    #[serde(default)]
+    pub top_p: Option<f64>,
+    #[serde(default)]
+    pub reasoning_effort: Option<ReasoningEffort>,
+    /// Sampling seed for reproducible output on backends that honor one.
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
 // ---------------------------------------------------------------------------
 // Tool-call results returned by backends
 // ---------------------------------------------------------------------------
@@ -146,6 +183,7 @@ pub(crate) struct TurnPayload {
     pub tools: Vec<ChatToolDefinition>,
     pub output_mode: OutputMode,
     pub limits: TurnLimits,
+    pub sampling: TurnSampling,
     pub enable_thinking: bool,
     pub metadata: BTreeMap<String, String>,
 }
@@ -157,6 +195,9 @@ pub(crate) struct TurnPayload {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TurnResponse {
     pub output_text: String,
+    /// Reasoning the model emitted apart from its answer; `None` for non-reasoning backends.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reasoning_text: Option<String>,
     pub tool_calls: Vec<ToolCallResult>,
     pub pending_tool_call_continuation: bool,
     pub usage: Option<UsageStats>,
@@ -175,11 +216,15 @@ pub struct BackendIdentity {
     pub model: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum BackendRawEvent {
     OutputTextDelta {
         delta: String,
     },
+    ReasoningDelta {
+        delta: String,
+    },
     ToolCallDelta {
         call_id: String,
         name: Option<String>,
@@ -213,9 +258,12 @@ pub struct AdapterInvocation {
 pub struct BackendCompleteResponse {
     pub backend_identity: BackendIdentity,
     pub output_text: String,
+    pub reasoning_text: Option<String>,
     pub tool_calls: Vec<ToolCallResult>,
     pub usage: Option<UsageStats>,
     pub finish_reason: FinishReason,
+    /// Backend-reported creation time (unix seconds), when the dialect provides one.
+    pub created_at_unix_s: Option<i64>,
 }
 
 // ---------------------------------------------------------------------------
@@ -231,6 +279,9 @@ pub enum ChatEvent {
     TextDelta {
         delta: String,
     },
+    ReasoningDelta {
+        delta: String,
+    },
     ToolCallDelta {
         call_id: String,
         name: Option<String>,
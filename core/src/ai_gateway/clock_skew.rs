@@ -0,0 +1,23 @@
+//! Advisory detection of backend response timestamps that drift from local time.
+//!
+//! A large skew usually means a caching proxy served a stale completion or the
+//! request was misrouted. Detection never fails a request; it only reports.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Returns the signed skew in milliseconds (`reported - now`) when its magnitude exceeds
+/// `threshold_ms`, otherwise `None`.
+pub fn detect_response_clock_skew(
+    created_at_unix_s: i64,
+    now: SystemTime,
+    threshold_ms: u64,
+) -> Option<i64> {
+    let now_ms = match now.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => i64::try_from(elapsed.as_millis()).unwrap_or(i64::MAX),
+        Err(err) => -i64::try_from(err.duration().as_millis()).unwrap_or(i64::MAX),
+    };
+    let skew_ms = created_at_unix_s
+        .saturating_mul(1_000)
+        .saturating_sub(now_ms);
+    (skew_ms.unsigned_abs() > threshold_ms).then_some(skew_ms)
+}
@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ai_gateway::{
+    chat::types::{TurnLimits, UsageStats},
+    types::ChatRouteRef,
+};
+
+/// Texts to embed, routed like a chat turn through the gateway's configured backends.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EmbeddingsRequest {
+    /// Route to the embedding model; the chat default route when unset.
+    #[serde(default)]
+    pub route_ref: Option<ChatRouteRef>,
+    pub inputs: Vec<String>,
+    /// Output dimensionality, for models that can shorten their vectors.
+    #[serde(default)]
+    pub dimensions: Option<u32>,
+    #[serde(default)]
+    pub limits: TurnLimits,
+}
+
+/// One vector per request input, in input order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddingsResponse {
+    pub embeddings: Vec<Vec<f32>>,
+    pub usage: Option<UsageStats>,
+    pub backend_metadata: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BackendEmbeddingsResponse {
+    pub embeddings: Vec<Vec<f32>>,
+    pub usage: Option<UsageStats>,
+}
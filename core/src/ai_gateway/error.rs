@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::ai_gateway::types::BackendId;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum GatewayErrorKind {
     InvalidRequest,
@@ -29,6 +29,9 @@ pub struct GatewayError {
     pub backend_id: Option<BackendId>,
     pub provider_code: Option<String>,
     pub provider_http_status: Option<u16>,
+    /// Delay the backend asked for before retrying, from a `Retry-After` header.
+    #[serde(default)]
+    pub retry_after_ms: Option<u64>,
 }
 
 impl GatewayError {
@@ -45,6 +48,7 @@ impl GatewayError {
             backend_id: None,
             provider_code: None,
             provider_http_status: None,
+            retry_after_ms: None,
         }
     }
 
@@ -67,6 +71,11 @@ impl GatewayError {
         self.provider_http_status = Some(status);
         self
     }
+
+    pub fn with_retry_after_ms(mut self, retry_after_ms: u64) -> Self {
+        self.retry_after_ms = Some(retry_after_ms);
+        self
+    }
 }
 
 impl fmt::Display for GatewayError {
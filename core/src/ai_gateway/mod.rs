@@ -2,9 +2,12 @@
 
 pub mod adapters;
 pub mod chat;
+pub mod clock_skew;
 pub mod credentials;
+pub mod embeddings;
 pub mod error;
 pub mod resilience;
 pub mod router;
+pub mod slo;
 pub mod telemetry;
 pub mod types;
@@ -1,18 +1,23 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     sync::Arc,
     time::{Duration, Instant},
 };
 
+use serde::Serialize;
 use tokio::{
     sync::{Mutex, OwnedSemaphorePermit, Semaphore},
     time::sleep,
 };
 
-use crate::ai_gateway::{
-    chat::types::TurnLimits,
-    error::{GatewayError, GatewayErrorKind},
-    types::{BackendCapabilities, BackendId, ResilienceConfig, RetryPolicy},
+use crate::{
+    ai_gateway::{
+        chat::types::TurnLimits,
+        error::{GatewayError, GatewayErrorKind},
+        telemetry::{GatewayTelemetryEvent, emit_gateway_event},
+        types::{BackendCapabilities, BackendId, ResilienceConfig, RetryPolicy},
+    },
+    observability::metrics as observability_metrics,
 };
 
 #[derive(Clone)]
@@ -28,6 +33,8 @@ struct BreakerState {
     failure_streak: u32,
     open_until: Option<Instant>,
     probe_in_flight: bool,
+    /// Phase last announced through `BreakerStateChanged`.
+    reported_phase: BreakerPhase,
 }
 
 impl Default for BreakerState {
@@ -36,10 +43,79 @@ impl Default for BreakerState {
             failure_streak: 0,
             open_until: None,
             probe_in_flight: false,
+            reported_phase: BreakerPhase::Closed,
         }
     }
 }
 
+impl BreakerState {
+    fn phase(&self, now: Instant) -> BreakerPhase {
+        match self.open_until {
+            None => BreakerPhase::Closed,
+            Some(open_until) if now < open_until => BreakerPhase::Open,
+            Some(_) => BreakerPhase::HalfOpen,
+        }
+    }
+
+    /// Announces a phase change since the last announcement; callers hold the breaker lock.
+    fn report_phase(&mut self, backend_id: &BackendId, now: Instant) {
+        let phase = self.phase(now);
+        if phase == self.reported_phase {
+            return;
+        }
+        emit_gateway_event(GatewayTelemetryEvent::BreakerStateChanged {
+            backend_id: backend_id.clone(),
+            from: self.reported_phase,
+            to: phase,
+        });
+        self.reported_phase = phase;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakerPhase {
+    Closed,
+    Open,
+    /// The open window has elapsed; the next request is let through as a probe.
+    HalfOpen,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BackendBreakerStatus {
+    pub phase: BreakerPhase,
+    /// Consecutive breaker-counted failures since the last success.
+    pub failure_streak: u32,
+    pub probe_in_flight: bool,
+    /// Time left in the open window before a probe is let through; `None` unless open.
+    pub half_open_in_ms: Option<u64>,
+}
+
+/// Why [`ResilienceEngine::retry_decision`] allowed or refused a retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetryDecisionReason {
+    Retryable,
+    ErrorNotRetryable,
+    MaxRetriesReached,
+    /// Output was already emitted and the retry policy does not allow resuming.
+    OutputEmittedPolicyForbidsResume,
+    /// Output was already emitted and the backend cannot resume a stream.
+    OutputEmittedBackendNotResumable,
+    /// A tool call was already emitted and the adapter cannot replay it safely.
+    ToolCallNotRetrySafe,
+}
+
+/// One failed attempt's retry decision, accumulated per request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RetryDecision {
+    pub attempt: u32,
+    pub error_kind: GatewayErrorKind,
+    pub retryable: bool,
+    pub can_retry: bool,
+    pub reason: RetryDecisionReason,
+}
+
 #[derive(Debug, Clone)]
 struct TokenBucket {
     tokens: f64,
@@ -111,6 +187,41 @@ impl ResilienceEngine {
         let _ = lease.permit.take();
     }
 
+    /// Snapshot of breaker state for every backend that has been dispatched to.
+    ///
+    /// Read-only: a phase reached only by elapsed time is reported here but announced through
+    /// `BreakerStateChanged` by the next dispatch that observes it.
+    pub async fn breaker_states(&self) -> BTreeMap<BackendId, BackendBreakerStatus> {
+        let now = Instant::now();
+        let guard = self.breakers.lock().await;
+        guard
+            .iter()
+            .map(|(backend_id, state)| {
+                (
+                    backend_id.clone(),
+                    BackendBreakerStatus {
+                        phase: state.phase(now),
+                        failure_streak: state.failure_streak,
+                        probe_in_flight: state.probe_in_flight,
+                        half_open_in_ms: state
+                            .open_until
+                            .filter(|open_until| now < *open_until)
+                            .map(|open_until| (open_until - now).as_millis() as u64),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Whether the backend's breaker is inside its open window, without claiming a probe.
+    pub async fn is_backend_open(&self, backend_id: &BackendId) -> bool {
+        let now = Instant::now();
+        let guard = self.breakers.lock().await;
+        guard
+            .get(backend_id)
+            .is_some_and(|state| state.phase(now) == BreakerPhase::Open)
+    }
+
     pub async fn ensure_backend_allowed(&self, backend_id: &BackendId) -> Result<(), GatewayError> {
         let now = Instant::now();
         let mut guard = self.breakers.lock().await;
@@ -139,6 +250,7 @@ impl ResilienceEngine {
             }
 
             state.probe_in_flight = true;
+            state.report_phase(backend_id, now);
         }
 
         Ok(())
@@ -150,6 +262,8 @@ impl ResilienceEngine {
         state.failure_streak = 0;
         state.open_until = None;
         state.probe_in_flight = false;
+        state.report_phase(backend_id, Instant::now());
+        observability_metrics::record_ai_gateway_breaker_state(backend_id, BreakerPhase::Closed, 0);
     }
 
     pub async fn record_failure(&self, backend_id: &BackendId, count_toward_breaker: bool) {
@@ -167,6 +281,13 @@ impl ResilienceEngine {
             state.open_until =
                 Some(Instant::now() + Duration::from_millis(self.config.breaker_open_ms.max(1)));
         }
+        let now = Instant::now();
+        state.report_phase(backend_id, now);
+        observability_metrics::record_ai_gateway_breaker_state(
+            backend_id,
+            state.phase(now),
+            state.failure_streak,
+        );
     }
 
     pub fn backoff_delay(&self, attempt: u32) -> Duration {
@@ -178,6 +299,18 @@ impl ResilienceEngine {
         Duration::from_millis((without_jitter * jitter_factor) as u64)
     }
 
+    /// Backoff delay for `attempt`, stretched to honor the error's `Retry-After` up to
+    /// `max_retry_after_ms`.
+    pub fn retry_delay(&self, attempt: u32, err: &GatewayError) -> Duration {
+        let backoff = self.backoff_delay(attempt);
+        match err.retry_after_ms {
+            Some(retry_after_ms) => backoff.max(Duration::from_millis(
+                retry_after_ms.min(self.config.max_retry_after_ms),
+            )),
+            None => backoff,
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn can_retry(
         &self,
@@ -188,23 +321,51 @@ impl ResilienceEngine {
         capabilities: &BackendCapabilities,
         adapter_tool_retry_safe: bool,
     ) -> bool {
-        if !err.retryable || attempt >= self.config.max_retries {
-            return false;
-        }
-
-        if emitted_output && !matches!(self.config.retry_policy, RetryPolicy::AdapterResumable) {
-            return false;
-        }
+        self.retry_decision(
+            err,
+            attempt,
+            emitted_output,
+            emitted_tool,
+            capabilities,
+            adapter_tool_retry_safe,
+        )
+        .can_retry
+    }
 
-        if emitted_output && !capabilities.resumable_streaming {
-            return false;
-        }
+    /// Same rules as [`Self::can_retry`], reporting which rule decided.
+    #[allow(clippy::too_many_arguments)]
+    pub fn retry_decision(
+        &self,
+        err: &GatewayError,
+        attempt: u32,
+        emitted_output: bool,
+        emitted_tool: bool,
+        capabilities: &BackendCapabilities,
+        adapter_tool_retry_safe: bool,
+    ) -> RetryDecision {
+        let reason = if !err.retryable {
+            RetryDecisionReason::ErrorNotRetryable
+        } else if attempt >= self.config.max_retries {
+            RetryDecisionReason::MaxRetriesReached
+        } else if emitted_output
+            && !matches!(self.config.retry_policy, RetryPolicy::AdapterResumable)
+        {
+            RetryDecisionReason::OutputEmittedPolicyForbidsResume
+        } else if emitted_output && !capabilities.resumable_streaming {
+            RetryDecisionReason::OutputEmittedBackendNotResumable
+        } else if emitted_tool && !adapter_tool_retry_safe {
+            RetryDecisionReason::ToolCallNotRetrySafe
+        } else {
+            RetryDecisionReason::Retryable
+        };
 
-        if emitted_tool && !adapter_tool_retry_safe {
-            return false;
+        RetryDecision {
+            attempt,
+            error_kind: err.kind,
+            retryable: err.retryable,
+            can_retry: reason == RetryDecisionReason::Retryable,
+            reason,
         }
-
-        true
     }
 
     pub fn counts_toward_breaker(err: &GatewayError) -> bool {
@@ -261,3 +422,103 @@ impl ResilienceEngine {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn breaker_states_report_open_after_threshold_failures() {
+        let engine = ResilienceEngine::new(ResilienceConfig {
+            breaker_failure_threshold: 2,
+            breaker_open_ms: 60_000,
+            ..ResilienceConfig::default()
+        });
+        let backend_id: BackendId = "primary".to_string();
+
+        engine.record_failure(&backend_id, true).await;
+        let states = engine.breaker_states().await;
+        assert_eq!(states[&backend_id].phase, BreakerPhase::Closed);
+        assert_eq!(states[&backend_id].failure_streak, 1);
+
+        engine.record_failure(&backend_id, true).await;
+        let states = engine.breaker_states().await;
+        assert_eq!(states[&backend_id].phase, BreakerPhase::Open);
+        assert_eq!(states[&backend_id].failure_streak, 2);
+        assert!(engine.ensure_backend_allowed(&backend_id).await.is_err());
+
+        engine.record_success(&backend_id).await;
+        let states = engine.breaker_states().await;
+        assert_eq!(states[&backend_id].phase, BreakerPhase::Closed);
+        assert_eq!(states[&backend_id].failure_streak, 0);
+    }
+
+    #[tokio::test]
+    async fn breaker_reports_open_then_half_open_then_closed() {
+        let engine = ResilienceEngine::new(ResilienceConfig {
+            breaker_failure_threshold: 2,
+            breaker_open_ms: 20,
+            ..ResilienceConfig::default()
+        });
+        let backend_id: BackendId = "primary".to_string();
+        let reported = |engine: &ResilienceEngine| {
+            let engine = engine.clone();
+            let backend_id = backend_id.clone();
+            async move { engine.breakers.lock().await[&backend_id].reported_phase }
+        };
+
+        engine.record_failure(&backend_id, true).await;
+        assert_eq!(reported(&engine).await, BreakerPhase::Closed);
+        engine.record_failure(&backend_id, true).await;
+        assert_eq!(reported(&engine).await, BreakerPhase::Open);
+        let status = engine.breaker_states().await[&backend_id].clone();
+        assert_eq!(status.phase, BreakerPhase::Open);
+        assert!(status.half_open_in_ms.is_some_and(|ms| ms <= 20));
+
+        sleep(Duration::from_millis(30)).await;
+        assert_eq!(
+            engine.breaker_states().await[&backend_id].phase,
+            BreakerPhase::HalfOpen
+        );
+        assert_eq!(reported(&engine).await, BreakerPhase::Open);
+        engine
+            .ensure_backend_allowed(&backend_id)
+            .await
+            .expect("elapsed open window should admit a probe");
+        assert_eq!(reported(&engine).await, BreakerPhase::HalfOpen);
+        let status = engine.breaker_states().await[&backend_id].clone();
+        assert_eq!(status.phase, BreakerPhase::HalfOpen);
+        assert!(status.probe_in_flight);
+        assert_eq!(status.half_open_in_ms, None);
+
+        engine.record_success(&backend_id).await;
+        assert_eq!(reported(&engine).await, BreakerPhase::Closed);
+        assert_eq!(
+            engine.breaker_states().await[&backend_id].phase,
+            BreakerPhase::Closed
+        );
+    }
+
+    #[tokio::test]
+    async fn concurrent_failures_are_all_counted() {
+        let engine = ResilienceEngine::new(ResilienceConfig {
+            breaker_failure_threshold: 4,
+            breaker_open_ms: 60_000,
+            ..ResilienceConfig::default()
+        });
+        let backend_id: BackendId = "primary".to_string();
+
+        let tasks = (0..16).map(|_| {
+            let engine = engine.clone();
+            let backend_id = backend_id.clone();
+            tokio::spawn(async move { engine.record_failure(&backend_id, true).await })
+        });
+        for task in tasks.collect::<Vec<_>>() {
+            task.await.expect("record failure task");
+        }
+
+        let status = engine.breaker_states().await[&backend_id].clone();
+        assert_eq!(status.failure_streak, 16);
+        assert_eq!(status.phase, BreakerPhase::Open);
+    }
+}
@@ -1,10 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::ai_gateway::{
     error::{GatewayError, invalid_request},
     types::{
         AIGatewayConfig, BackendId, BackendProfile, CHAT_CAPABILITY_ID, ChatRouteAlias,
-        ChatRouteKey, ChatRouteRef, DEFAULT_ROUTE_ALIAS, ModelTarget,
+        ChatRouteKey, ChatRouteRef, DEFAULT_ROUTE_ALIAS, DeclaredModelLimits, ModelTarget,
     },
 };
 
@@ -12,6 +12,7 @@ use crate::ai_gateway::{
 pub struct BackendRouter {
     backends: HashMap<BackendId, BackendProfile>,
     route_aliases: HashMap<String, ModelTarget>,
+    route_fallbacks: HashMap<String, Vec<ModelTarget>>,
 }
 
 #[derive(Debug, Clone)]
@@ -96,12 +97,55 @@ impl BackendRouter {
             )));
         }
 
+        let mut route_fallbacks = HashMap::new();
+        for (alias, fallback_aliases) in &config.chat.route_fallbacks {
+            let alias = alias.trim();
+            if !route_aliases.contains_key(alias) {
+                return Err(invalid_request(format!(
+                    "ai_gateway.chat.route_fallbacks references unknown route alias '{}'",
+                    alias
+                )));
+            }
+            let mut chain = Vec::with_capacity(fallback_aliases.len());
+            for fallback_alias in fallback_aliases {
+                let fallback_alias = fallback_alias.trim();
+                if fallback_alias == alias {
+                    return Err(invalid_request(format!(
+                        "route alias '{}' cannot fall back to itself",
+                        alias
+                    )));
+                }
+                let target = route_aliases.get(fallback_alias).cloned().ok_or_else(|| {
+                    invalid_request(format!(
+                        "route alias '{}' falls back to unknown alias '{}'",
+                        alias, fallback_alias
+                    ))
+                })?;
+                chain.push(target);
+            }
+            route_fallbacks.insert(alias.to_string(), chain);
+        }
+
         Ok(Self {
             backends,
             route_aliases,
+            route_fallbacks,
         })
     }
 
+    /// Declared model limits of every backend that has any.
+    pub fn declared_limits(&self) -> BTreeMap<BackendId, DeclaredModelLimits> {
+        self.backends
+            .iter()
+            .filter_map(|(backend_id, profile)| {
+                profile
+                    .limits
+                    .clone()
+                    .map(|limits| (backend_id.clone(), limits))
+            })
+            .collect()
+    }
+
     pub fn select_route_ref(
         &self,
         route_ref: Option<&ChatRouteRef>,
@@ -114,6 +158,25 @@ impl BackendRouter {
         self.selected_from_target(target)
     }
 
+    /// Backends to try, in order, when the backend selected for `route_ref` cannot serve.
+    pub fn fallbacks_for_route_ref(
+        &self,
+        route_ref: Option<&ChatRouteRef>,
+    ) -> Vec<SelectedBackend> {
+        let alias = match route_ref {
+            Some(ChatRouteRef::Alias(alias)) => alias.alias.trim(),
+            // A key pins one binding; fallback chains are configured per route alias.
+            Some(ChatRouteRef::Key(_)) => return Vec::new(),
+            None => DEFAULT_ROUTE_ALIAS,
+        };
+        self.route_fallbacks
+            .get(alias)
+            .into_iter()
+            .flatten()
+            .filter_map(|target| self.selected_from_target(target.clone()).ok())
+            .collect()
+    }
+
     fn selected_from_target(&self, target: ModelTarget) -> Result<SelectedBackend, GatewayError> {
         let profile = self
             .backends
@@ -0,0 +1,160 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::Mutex,
+    time::Duration,
+};
+
+use serde::Serialize;
+
+use crate::{
+    ai_gateway::{error::GatewayErrorKind, types::BackendId},
+    observability::metrics as observability_metrics,
+};
+
+/// Latency samples kept per backend for the percentile window.
+const LATENCY_WINDOW: usize = 256;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BackendSloSnapshot {
+    pub attempts: u64,
+    pub successes: u64,
+    pub success_rate: f64,
+    /// Over the most recent attempts, successful or not.
+    pub p50_latency_ms: u64,
+    pub p95_latency_ms: u64,
+    pub error_kinds: BTreeMap<GatewayErrorKind, u64>,
+}
+
+#[derive(Debug, Default)]
+struct BackendSloState {
+    attempts: u64,
+    successes: u64,
+    error_kinds: BTreeMap<GatewayErrorKind, u64>,
+    latencies_ms: VecDeque<u64>,
+}
+
+impl BackendSloState {
+    fn snapshot(&self) -> BackendSloSnapshot {
+        let mut latencies = self.latencies_ms.iter().copied().collect::<Vec<_>>();
+        latencies.sort_unstable();
+        BackendSloSnapshot {
+            attempts: self.attempts,
+            successes: self.successes,
+            success_rate: if self.attempts == 0 {
+                1.0
+            } else {
+                self.successes as f64 / self.attempts as f64
+            },
+            p50_latency_ms: percentile(&latencies, 0.50),
+            p95_latency_ms: percentile(&latencies, 0.95),
+            error_kinds: self.error_kinds.clone(),
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[u64], quantile: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (quantile * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Per-backend attempt outcomes aggregated for success-rate and latency SLOs.
+#[derive(Debug, Default)]
+pub struct BackendSloTracker {
+    backends: Mutex<BTreeMap<BackendId, BackendSloState>>,
+}
+
+impl BackendSloTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_attempt(
+        &self,
+        backend_id: &str,
+        latency: Duration,
+        outcome: Result<(), GatewayErrorKind>,
+    ) {
+        let snapshot = {
+            let mut backends = self.backends.lock().expect("lock poisoned");
+            let state = backends.entry(backend_id.to_string()).or_default();
+            state.attempts = state.attempts.saturating_add(1);
+            match outcome {
+                Ok(()) => state.successes = state.successes.saturating_add(1),
+                Err(kind) => *state.error_kinds.entry(kind).or_default() += 1,
+            }
+            if state.latencies_ms.len() == LATENCY_WINDOW {
+                state.latencies_ms.pop_front();
+            }
+            state
+                .latencies_ms
+                .push_back(latency.as_millis().min(u64::MAX as u128) as u64);
+            state.snapshot()
+        };
+        observability_metrics::record_ai_gateway_backend_slo(backend_id, &snapshot);
+    }
+
+    pub fn snapshots(&self) -> BTreeMap<BackendId, BackendSloSnapshot> {
+        self.backends
+            .lock()
+            .expect("lock poisoned")
+            .iter()
+            .map(|(backend_id, state)| (backend_id.clone(), state.snapshot()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed_outcomes_yield_success_rate_and_latency_percentiles() {
+        let tracker = BackendSloTracker::new();
+        for latency_ms in 1..=16 {
+            tracker.record_attempt("primary", Duration::from_millis(latency_ms * 10), Ok(()));
+        }
+        tracker.record_attempt(
+            "primary",
+            Duration::from_millis(1_000),
+            Err(GatewayErrorKind::Timeout),
+        );
+        tracker.record_attempt(
+            "primary",
+            Duration::from_millis(20),
+            Err(GatewayErrorKind::RateLimited),
+        );
+        tracker.record_attempt(
+            "primary",
+            Duration::from_millis(900),
+            Err(GatewayErrorKind::Timeout),
+        );
+        tracker.record_attempt(
+            "primary",
+            Duration::from_millis(5),
+            Err(GatewayErrorKind::BackendTransient),
+        );
+        tracker.record_attempt("fallback", Duration::from_millis(40), Ok(()));
+
+        let snapshots = tracker.snapshots();
+        let primary = &snapshots["primary"];
+        assert_eq!(primary.attempts, 20);
+        assert_eq!(primary.successes, 16);
+        assert_eq!(primary.success_rate, 0.8);
+        assert_eq!(primary.p50_latency_ms, 80);
+        assert_eq!(primary.p95_latency_ms, 900);
+        assert_eq!(
+            primary.error_kinds,
+            BTreeMap::from([
+                (GatewayErrorKind::RateLimited, 1),
+                (GatewayErrorKind::Timeout, 2),
+                (GatewayErrorKind::BackendTransient, 1),
+            ])
+        );
+        assert_eq!(snapshots["fallback"].success_rate, 1.0);
+        assert_eq!(snapshots["fallback"].p95_latency_ms, 40);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::ai_gateway::{
     chat::types::UsageStats,
     error::GatewayErrorKind,
+    resilience::{BreakerPhase, RetryDecisionReason},
     types::{BackendId, RequestId},
 };
 
@@ -22,6 +23,8 @@ pub enum GatewayTelemetryEvent {
         attempt: u32,
         kind: GatewayErrorKind,
         retryable: bool,
+        can_retry: bool,
+        retry_reason: RetryDecisionReason,
         cost_attribution_id: Option<String>,
     },
     StreamFirstEvent {
@@ -43,6 +46,34 @@ pub enum GatewayTelemetryEvent {
         request_id: RequestId,
         cost_attribution_id: Option<String>,
     },
+    ResponseClockSkew {
+        request_id: RequestId,
+        backend_id: BackendId,
+        created_at_unix_s: i64,
+        skew_ms: i64,
+        threshold_ms: u64,
+    },
+    OutputTokensClamped {
+        request_id: RequestId,
+        backend_id: BackendId,
+        requested: u64,
+        cap: u64,
+    },
+    RequestCoalesced {
+        request_id: RequestId,
+        backend_id: BackendId,
+    },
+    BackendFallback {
+        request_id: RequestId,
+        from_backend_id: BackendId,
+        to_backend_id: BackendId,
+        error_kind: GatewayErrorKind,
+    },
+    BreakerStateChanged {
+        backend_id: BackendId,
+        from: BreakerPhase,
+        to: BreakerPhase,
+    },
 }
 
 pub fn emit_gateway_event(event: GatewayTelemetryEvent) {
@@ -82,6 +113,8 @@ pub fn emit_gateway_event(event: GatewayTelemetryEvent) {
             attempt,
             kind,
             retryable,
+            can_retry,
+            retry_reason,
             cost_attribution_id,
         } => {
             tracing::warn!(
@@ -91,6 +124,8 @@ pub fn emit_gateway_event(event: GatewayTelemetryEvent) {
                 attempt = attempt,
                 kind = ?kind,
                 retryable = retryable,
+                can_retry = can_retry,
+                retry_reason = ?retry_reason,
                 cost_attribution_id = cost_attribution_id.as_deref().unwrap_or("-"),
                 "attempt_failed"
             );
@@ -153,5 +188,81 @@ pub fn emit_gateway_event(event: GatewayTelemetryEvent) {
                 "request_cancelled"
             );
         }
+        GatewayTelemetryEvent::ResponseClockSkew {
+            request_id,
+            backend_id,
+            created_at_unix_s,
+            skew_ms,
+            threshold_ms,
+        } => {
+            tracing::warn!(
+                target: "ai_gateway",
+                event = "response_clock_skew",
+                request_id = %request_id,
+                backend_id = %backend_id,
+                created_at_unix_s = created_at_unix_s,
+                skew_ms = skew_ms,
+                threshold_ms = threshold_ms,
+                "response_clock_skew"
+            );
+        }
+        GatewayTelemetryEvent::OutputTokensClamped {
+            request_id,
+            backend_id,
+            requested,
+            cap,
+        } => {
+            tracing::info!(
+                target: "ai_gateway",
+                event = "output_tokens_clamped",
+                request_id = %request_id,
+                backend_id = %backend_id,
+                requested = requested,
+                cap = cap,
+                "output_tokens_clamped"
+            );
+        }
+        GatewayTelemetryEvent::RequestCoalesced {
+            request_id,
+            backend_id,
+        } => {
+            tracing::debug!(
+                target: "ai_gateway",
+                event = "request_coalesced",
+                request_id = %request_id,
+                backend_id = %backend_id,
+                "request_coalesced"
+            );
+        }
+        GatewayTelemetryEvent::BackendFallback {
+            request_id,
+            from_backend_id,
+            to_backend_id,
+            error_kind,
+        } => {
+            tracing::warn!(
+                target: "ai_gateway",
+                event = "backend_fallback",
+                request_id = %request_id,
+                from_backend_id = %from_backend_id,
+                to_backend_id = %to_backend_id,
+                error_kind = ?error_kind,
+                "backend_fallback"
+            );
+        }
+        GatewayTelemetryEvent::BreakerStateChanged {
+            backend_id,
+            from,
+            to,
+        } => {
+            tracing::warn!(
+                target: "ai_gateway",
+                event = "breaker_state_changed",
+                backend_id = %backend_id,
+                from = ?from,
+                to = ?to,
+                "breaker_state_changed"
+            );
+        }
     }
 }
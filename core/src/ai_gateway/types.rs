@@ -1,4 +1,4 @@
-use std::{collections::BTreeMap, time::Duration};
+use std::{collections::BTreeMap, path::PathBuf, time::Duration};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -59,6 +59,8 @@ pub enum BackendDialect {
     OpenAiResponses,
     #[serde(rename = "ollama")]
     Ollama,
+    #[serde(rename = "gemini")]
+    Gemini,
     #[serde(rename = "github_copilot_sdk")]
     GitHubCopilotSdk,
 }
@@ -142,12 +144,63 @@ pub struct BackendProfile {
     #[validate(length(min = 1))]
     #[validate(nested)]
     pub models: Vec<ModelProfile>,
+    /// Hard limit on `max_output_tokens` accepted by this backend; larger requests are clamped.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub max_output_tokens_cap: Option<u64>,
+    #[serde(default)]
+    #[validate(nested)]
+    pub limits: Option<DeclaredModelLimits>,
     #[serde(default)]
     #[validate(nested)]
     pub capabilities: Option<BackendCapabilities>,
     #[serde(default)]
     #[validate(nested)]
     pub copilot: Option<CopilotConfig>,
+    #[serde(default)]
+    pub post_finish_content: PostFinishContentPolicy,
+}
+
+/// How a streaming backend that keeps sending content after its finish reason is treated.
+///
+/// Applied by the openai-compatible adapter, the only one that streams tool-call deltas.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PostFinishContentPolicy {
+    /// Drop the trailing content and log one warning per stream.
+    #[default]
+    Lenient,
+    /// Fail the stream with a protocol violation.
+    Strict,
+}
+
+impl BackendProfile {
+    /// Tightest output token bound implied by the cap and the declared output limit.
+    ///
+    /// The context window bounds input and output together, so it does not cap output alone.
+    pub fn output_tokens_cap(&self) -> Option<u64> {
+        [
+            self.max_output_tokens_cap,
+            self.limits
+                .as_ref()
+                .and_then(|limits| limits.max_output_tokens),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+    }
+}
+
+/// Model limits the operator declares for a backend, as reported by its provider.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DeclaredModelLimits {
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub context_window_tokens: Option<u64>,
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub max_output_tokens: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Validate, JsonSchema)]
@@ -183,6 +236,11 @@ pub struct ResilienceConfig {
     #[serde(default = "default_backoff_max_ms")]
     #[validate(range(min = 1))]
     pub backoff_max_ms: u64,
+    /// Longest backend-requested `Retry-After` delay waited before a retry; longer requests
+    /// are shortened to this bound.
+    #[serde(default = "default_max_retry_after_ms")]
+    #[validate(range(min = 1))]
+    pub max_retry_after_ms: u64,
     #[serde(default)]
     pub retry_policy: RetryPolicy,
     #[serde(default = "default_breaker_failure_threshold")]
@@ -197,6 +255,15 @@ pub struct ResilienceConfig {
     #[serde(default)]
     #[validate(range(min = 1))]
     pub rate_smoothing_per_second: Option<u32>,
+    /// Advisory: warn when a backend-reported response timestamp deviates from local time
+    /// by more than this many milliseconds. Disabled when unset.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub response_clock_skew_warn_ms: Option<u64>,
+    /// Attach the per-attempt retry decisions to `backend_metadata.retry_trace` of completed
+    /// turns.
+    #[serde(default)]
+    pub include_retry_trace: bool,
 }
 
 impl Default for ResilienceConfig {
@@ -207,11 +274,53 @@ impl Default for ResilienceConfig {
             max_retries: default_max_retries(),
             backoff_base_ms: default_backoff_base_ms(),
             backoff_max_ms: default_backoff_max_ms(),
+            max_retry_after_ms: default_max_retry_after_ms(),
             retry_policy: RetryPolicy::BeforeFirstEventOnly,
             breaker_failure_threshold: default_breaker_failure_threshold(),
             breaker_open_ms: default_breaker_open_ms(),
             max_concurrency_per_backend: default_max_concurrency_per_backend(),
             rate_smoothing_per_second: None,
+            response_clock_skew_warn_ms: None,
+            include_retry_trace: false,
+        }
+    }
+}
+
+fn default_http_pool_idle_timeout_ms() -> u64 {
+    30_000
+}
+
+fn default_http_tcp_keepalive_ms() -> Option<u64> {
+    Some(30_000)
+}
+
+fn default_http_tcp_keepalive_interval_ms() -> Option<u64> {
+    Some(15_000)
+}
+
+/// Connection settings shared by the HTTP backend adapter clients.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct HttpClientConfig {
+    #[serde(default = "default_http_pool_idle_timeout_ms")]
+    #[validate(range(min = 1))]
+    pub pool_idle_timeout_ms: u64,
+    /// Idle time before the first TCP keepalive probe; `null` disables keepalive.
+    #[serde(default = "default_http_tcp_keepalive_ms")]
+    #[validate(range(min = 1))]
+    pub tcp_keepalive_ms: Option<u64>,
+    /// Time between unanswered TCP keepalive probes; `null` keeps the OS default.
+    #[serde(default = "default_http_tcp_keepalive_interval_ms")]
+    #[validate(range(min = 1))]
+    pub tcp_keepalive_interval_ms: Option<u64>,
+}
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        Self {
+            pool_idle_timeout_ms: default_http_pool_idle_timeout_ms(),
+            tcp_keepalive_ms: default_http_tcp_keepalive_ms(),
+            tcp_keepalive_interval_ms: default_http_tcp_keepalive_interval_ms(),
         }
     }
 }
@@ -253,6 +362,16 @@ pub struct ChatConfig {
     #[serde(default = "default_chat_default_turn_timeout_ms")]
     #[validate(range(min = 1))]
     pub default_turn_timeout_ms: u64,
+    /// Share one backend call between identical in-flight requests from different threads.
+    #[serde(default)]
+    pub coalesce_identical_requests: bool,
+    /// Record backend interactions to, or replay them from, a cassette file.
+    #[serde(default)]
+    #[validate(nested)]
+    pub cassette: Option<CassetteConfig>,
+    /// Ordered route aliases tried, per route alias, when the route's backend cannot serve a request.
+    #[serde(default)]
+    pub route_fallbacks: BTreeMap<String, Vec<String>>,
 }
 
 impl Default for ChatConfig {
@@ -263,10 +382,31 @@ impl Default for ChatConfig {
             default_max_turn_context_messages: default_chat_default_max_turn_context_messages(),
             default_session_ttl_seconds: default_chat_default_session_ttl_seconds(),
             default_turn_timeout_ms: default_chat_default_turn_timeout_ms(),
+            coalesce_identical_requests: false,
+            cassette: None,
+            route_fallbacks: BTreeMap::new(),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CassetteMode {
+    /// Forward to live backends and append each request/response pair to the cassette.
+    #[default]
+    Record,
+    /// Serve matching recorded responses without contacting any backend.
+    Replay,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CassetteConfig {
+    pub path: PathBuf,
+    #[serde(default)]
+    pub mode: CassetteMode,
+}
+
 fn default_request_timeout_ms() -> u64 {
     30_000
 }
@@ -283,6 +423,10 @@ fn default_backoff_max_ms() -> u64 {
     2_000
 }
 
+fn default_max_retry_after_ms() -> u64 {
+    30_000
+}
+
 fn default_breaker_failure_threshold() -> u32 {
     5
 }
@@ -324,6 +468,9 @@ pub struct AIGatewayConfig {
     #[serde(default)]
     #[validate(nested)]
     pub resilience: ResilienceConfig,
+    #[serde(default)]
+    #[validate(nested)]
+    pub http: HttpClientConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,128 @@
+use crate::{
+    body::{COGNITION_ACT_DIFF_ID, COGNITION_SENSE_DIFF_RESULT_ID},
+    cortex::{CognitionState, Cortex},
+    spine::{adapters::inline::InlineSenseDatum, types::EndpointExecutionOutcome},
+    types::Act,
+};
+
+pub struct CognitionHandlerOutput {
+    pub outcome: EndpointExecutionOutcome,
+    pub sense: Option<InlineSenseDatum>,
+}
+
+/// Answers `cognition.diff` with how the persisted cognition state changed since `last_seen`,
+/// then advances `last_seen` to the current state.
+pub async fn handle_cognition_invoke(
+    act: &Act,
+    cortex: &Cortex,
+    last_seen: &mut CognitionState,
+) -> CognitionHandlerOutput {
+    if act.neural_signal_descriptor_id != COGNITION_ACT_DIFF_ID {
+        return rejected(act, "unsupported_act");
+    }
+    let Ok(current) = cortex.load_cognition_state().await else {
+        return rejected(act, "cognition_state_unavailable");
+    };
+
+    let diff = last_seen.diff(&current);
+    *last_seen = current;
+    CognitionHandlerOutput {
+        outcome: EndpointExecutionOutcome::Applied {
+            actual_cost_micro: 0,
+            reference_id: format!("body.cognition:applied:{}", act.act_instance_id),
+        },
+        sense: Some(InlineSenseDatum {
+            sense_instance_id: uuid::Uuid::new_v4().to_string(),
+            neural_signal_descriptor_id: COGNITION_SENSE_DIFF_RESULT_ID.to_string(),
+            payload: serde_json::to_string(&diff).unwrap_or_default(),
+            weight: 0.0,
+            act_instance_id: Some(act.act_instance_id.clone()),
+        }),
+    }
+}
+
+fn rejected(act: &Act, reason_code: &str) -> CognitionHandlerOutput {
+    CognitionHandlerOutput {
+        outcome: EndpointExecutionOutcome::Rejected {
+            reason_code: reason_code.to_string(),
+            reference_id: format!("body.cognition:{reason_code}:{}", act.act_instance_id),
+        },
+        sense: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use tokio::sync::Mutex;
+
+    use super::*;
+    use crate::{
+        continuity::ContinuityEngine,
+        cortex::{GoalForest, GoalNode, ReactionLimits, testing::TestHooks},
+    };
+
+    fn diff_act() -> Act {
+        Act {
+            act_instance_id: "act-1".to_string(),
+            endpoint_id: "cognition.1".to_string(),
+            neural_signal_descriptor_id: COGNITION_ACT_DIFF_ID.to_string(),
+            might_emit_sense_ids: Vec::new(),
+            payload: serde_json::json!({}),
+            goal_id: None,
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn diff_act_reports_changes_since_the_previous_query() {
+        let path = std::env::temp_dir()
+            .join(format!("beluna-body-cognition-{}", uuid::Uuid::new_v4()))
+            .join("state.json");
+        let continuity = Arc::new(Mutex::new(
+            ContinuityEngine::with_defaults_at(path.clone()).expect("continuity"),
+        ));
+        let cortex = Cortex::for_test_with_hooks(
+            TestHooks::new(
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(Vec::new()) })),
+            ),
+            ReactionLimits::default(),
+        )
+        .with_continuity_for_test(continuity);
+        cortex
+            .save_cognition_state(CognitionState {
+                revision: 1,
+                goal_forest: GoalForest {
+                    nodes: vec![GoalNode {
+                        status: "open".to_string(),
+                        weight: 0.5,
+                        id: "explore".to_string(),
+                        summary: "explore the workspace".to_string(),
+                        children: Vec::new(),
+                    }],
+                },
+            })
+            .await
+            .expect("save state");
+        let mut last_seen = CognitionState::default();
+
+        let first = handle_cognition_invoke(&diff_act(), &cortex, &mut last_seen).await;
+        let payload: serde_json::Value =
+            serde_json::from_str(&first.sense.expect("diff sense").payload).expect("json diff");
+        assert_eq!(payload["to_revision"], 1);
+        assert_eq!(payload["added_goals"][0]["id"], "explore");
+
+        let second = handle_cognition_invoke(&diff_act(), &cortex, &mut last_seen).await;
+        let payload: serde_json::Value =
+            serde_json::from_str(&second.sense.expect("diff sense").payload).expect("json diff");
+        assert_eq!(payload["added_goals"], serde_json::json!([]));
+
+        let _ = std::fs::remove_file(&path);
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::remove_dir(parent);
+        }
+    }
+}
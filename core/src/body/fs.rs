@@ -0,0 +1,358 @@
+use std::{
+    io::ErrorKind,
+    path::{Component, Path, PathBuf},
+};
+
+use serde::de::DeserializeOwned;
+use tokio::{
+    fs::OpenOptions,
+    io::{AsyncReadExt, AsyncWriteExt},
+};
+
+use crate::{
+    body::{
+        FS_ACT_LIST_ID, FS_ACT_READ_ID, FS_ACT_WRITE_ID, FS_SENSE_LIST_RESULT_ID,
+        FS_SENSE_READ_RESULT_ID, FS_SENSE_WRITE_RESULT_ID,
+        payloads::{FsLimits, FsListRequest, FsReadRequest, FsWriteRequest},
+    },
+    spine::{adapters::inline::InlineSenseDatum, types::EndpointExecutionOutcome},
+    types::Act,
+};
+
+pub struct FsHandlerOutput {
+    pub outcome: EndpointExecutionOutcome,
+    pub sense: Option<InlineSenseDatum>,
+}
+
+pub async fn handle_fs_invoke(_request_id: &str, act: &Act, limits: &FsLimits) -> FsHandlerOutput {
+    let result = match act.neural_signal_descriptor_id.as_str() {
+        FS_ACT_READ_ID => read_file(act, limits)
+            .await
+            .map(|result| (FS_SENSE_READ_RESULT_ID, result)),
+        FS_ACT_WRITE_ID => write_file(act, limits)
+            .await
+            .map(|result| (FS_SENSE_WRITE_RESULT_ID, result)),
+        FS_ACT_LIST_ID => list_dir(act, limits)
+            .await
+            .map(|result| (FS_SENSE_LIST_RESULT_ID, result)),
+        _ => Err("unsupported_act"),
+    };
+    let (sense_id, result) = match result {
+        Ok(applied) => applied,
+        Err(reason_code) => return rejected(act, reason_code),
+    };
+
+    FsHandlerOutput {
+        outcome: EndpointExecutionOutcome::Applied {
+            actual_cost_micro: 0,
+            reference_id: format!("body.std.fs:applied:{}", act.act_instance_id),
+        },
+        sense: Some(InlineSenseDatum {
+            sense_instance_id: uuid::Uuid::new_v4().to_string(),
+            neural_signal_descriptor_id: sense_id.to_string(),
+            payload: result.to_string(),
+            weight: 0.0,
+            act_instance_id: Some(act.act_instance_id.clone()),
+        }),
+    }
+}
+
+async fn read_file(act: &Act, limits: &FsLimits) -> Result<serde_json::Value, &'static str> {
+    let request: FsReadRequest = decode_payload(act)?;
+    let path = resolve_existing(&limits.root, &request.path)?;
+    let cap = request.max_bytes(limits);
+
+    let file = tokio::fs::File::open(&path).await.map_err(io_reason)?;
+    let mut bytes = Vec::new();
+    file.take(cap as u64 + 1)
+        .read_to_end(&mut bytes)
+        .await
+        .map_err(io_reason)?;
+    let truncated = bytes.len() > cap;
+    bytes.truncate(cap);
+
+    Ok(serde_json::json!({
+        "path": request.path,
+        "content": String::from_utf8_lossy(&bytes),
+        "truncated": truncated,
+    }))
+}
+
+async fn write_file(act: &Act, limits: &FsLimits) -> Result<serde_json::Value, &'static str> {
+    let request: FsWriteRequest = decode_payload(act)?;
+    if request.content.len() > limits.max_write_bytes {
+        return Err("too_large");
+    }
+    let path = resolve_writable(&limits.root, &request.path)?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(request.append)
+        .truncate(!request.append)
+        .open(&path)
+        .await
+        .map_err(io_reason)?;
+    file.write_all(request.content.as_bytes())
+        .await
+        .map_err(io_reason)?;
+    file.flush().await.map_err(io_reason)?;
+
+    Ok(serde_json::json!({
+        "path": request.path,
+        "bytes_written": request.content.len(),
+        "append": request.append,
+    }))
+}
+
+async fn list_dir(act: &Act, limits: &FsLimits) -> Result<serde_json::Value, &'static str> {
+    let request: FsListRequest = decode_payload(act)?;
+    let relative = request.path.as_deref().unwrap_or(".");
+    let dir = resolve_existing(&limits.root, relative)?;
+
+    let mut read_dir = tokio::fs::read_dir(&dir).await.map_err(io_reason)?;
+    let mut entries = Vec::new();
+    while let Some(entry) = read_dir.next_entry().await.map_err(io_reason)? {
+        let file_type = entry.file_type().await.map_err(io_reason)?;
+        let kind = if file_type.is_dir() {
+            "dir"
+        } else if file_type.is_symlink() {
+            "symlink"
+        } else {
+            "file"
+        };
+        entries.push((entry.file_name().to_string_lossy().into_owned(), kind));
+    }
+    entries.sort();
+    let truncated = entries.len() > limits.max_list_entries;
+    entries.truncate(limits.max_list_entries);
+
+    Ok(serde_json::json!({
+        "path": relative,
+        "entries": entries
+            .into_iter()
+            .map(|(name, kind)| serde_json::json!({ "name": name, "kind": kind }))
+            .collect::<Vec<_>>(),
+        "truncated": truncated,
+    }))
+}
+
+fn decode_payload<T: DeserializeOwned>(act: &Act) -> Result<T, &'static str> {
+    serde_json::from_value(act.payload.clone()).map_err(|_| "invalid_payload")
+}
+
+/// Resolves an existing path under `root`; symlinks are followed and must stay inside it.
+fn resolve_existing(root: &Path, relative: &str) -> Result<PathBuf, &'static str> {
+    let root = canonical_root(root)?;
+    let relative = lexically_contained(relative)?;
+    let resolved = std::fs::canonicalize(root.join(relative)).map_err(io_reason)?;
+    ensure_within(&root, resolved)
+}
+
+/// Resolves a possibly missing file under `root` whose parent directory must already exist.
+fn resolve_writable(root: &Path, relative: &str) -> Result<PathBuf, &'static str> {
+    let root = canonical_root(root)?;
+    let relative = lexically_contained(relative)?;
+    let Some(file_name) = relative.file_name() else {
+        return Err("invalid_payload");
+    };
+    let parent = root.join(relative.parent().unwrap_or_else(|| Path::new("")));
+    let parent = ensure_within(&root, std::fs::canonicalize(parent).map_err(io_reason)?)?;
+    let target = parent.join(file_name);
+
+    // An existing symlink would redirect the write, so its destination must also stay inside.
+    match std::fs::symlink_metadata(&target) {
+        Ok(metadata) if metadata.file_type().is_symlink() => {
+            let destination = std::fs::canonicalize(&target).map_err(|_| "path_outside_root")?;
+            ensure_within(&root, destination)
+        }
+        _ => Ok(target),
+    }
+}
+
+fn canonical_root(root: &Path) -> Result<PathBuf, &'static str> {
+    std::fs::canonicalize(root).map_err(|_| "root_unavailable")
+}
+
+/// Rejects absolute paths and `..` segments before touching the filesystem.
+fn lexically_contained(relative: &str) -> Result<&Path, &'static str> {
+    let relative = Path::new(relative);
+    if relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+    {
+        Ok(relative)
+    } else {
+        Err("path_outside_root")
+    }
+}
+
+fn ensure_within(root: &Path, resolved: PathBuf) -> Result<PathBuf, &'static str> {
+    if resolved.starts_with(root) {
+        Ok(resolved)
+    } else {
+        Err("path_outside_root")
+    }
+}
+
+fn io_reason(err: std::io::Error) -> &'static str {
+    match err.kind() {
+        ErrorKind::NotFound => "not_found",
+        ErrorKind::PermissionDenied => "permission_denied",
+        _ => "io_error",
+    }
+}
+
+fn rejected(act: &Act, reason_code: &str) -> FsHandlerOutput {
+    FsHandlerOutput {
+        outcome: EndpointExecutionOutcome::Rejected {
+            reason_code: reason_code.to_string(),
+            reference_id: format!("body.std.fs:{reason_code}:{}", act.act_instance_id),
+        },
+        sense: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn fs_root() -> PathBuf {
+        let root = std::env::temp_dir().join(format!("beluna-fs-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(root.join("notes")).expect("fs root should be created");
+        std::fs::write(root.join("notes/today.md"), "water the plants").expect("seed file");
+        root
+    }
+
+    fn fs_act(neural_signal_descriptor_id: &str, payload: serde_json::Value) -> Act {
+        Act {
+            act_instance_id: "act-1".to_string(),
+            endpoint_id: "fs.1".to_string(),
+            neural_signal_descriptor_id: neural_signal_descriptor_id.to_string(),
+            might_emit_sense_ids: Vec::new(),
+            payload,
+            goal_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn file_under_root_is_read_into_result_sense() {
+        let limits = FsLimits {
+            root: fs_root(),
+            ..FsLimits::default()
+        };
+
+        let output = handle_fs_invoke(
+            "test",
+            &fs_act(FS_ACT_READ_ID, json!({ "path": "notes/today.md" })),
+            &limits,
+        )
+        .await;
+
+        let sense = output.sense.expect("read result sense");
+        assert_eq!(sense.neural_signal_descriptor_id, FS_SENSE_READ_RESULT_ID);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&sense.payload).expect("json payload"),
+            json!({
+                "path": "notes/today.md",
+                "content": "water the plants",
+                "truncated": false,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn file_under_root_is_written_then_appended() {
+        let root = fs_root();
+        let limits = FsLimits {
+            root: root.clone(),
+            ..FsLimits::default()
+        };
+
+        let output = handle_fs_invoke(
+            "test",
+            &fs_act(
+                FS_ACT_WRITE_ID,
+                json!({ "path": "notes/tomorrow.md", "content": "buy seeds" }),
+            ),
+            &limits,
+        )
+        .await;
+        let sense = output.sense.expect("write result sense");
+        assert_eq!(sense.neural_signal_descriptor_id, FS_SENSE_WRITE_RESULT_ID);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&sense.payload).expect("json payload"),
+            json!({ "path": "notes/tomorrow.md", "bytes_written": 9, "append": false })
+        );
+
+        handle_fs_invoke(
+            "test",
+            &fs_act(
+                FS_ACT_WRITE_ID,
+                json!({ "path": "notes/tomorrow.md", "content": ", rake", "append": true }),
+            ),
+            &limits,
+        )
+        .await;
+        assert_eq!(
+            std::fs::read_to_string(root.join("notes/tomorrow.md")).expect("written file"),
+            "buy seeds, rake"
+        );
+    }
+
+    #[tokio::test]
+    async fn directory_under_root_is_listed_sorted() {
+        let root = fs_root();
+        std::fs::write(root.join("readme.md"), "hello").expect("seed file");
+        let limits = FsLimits {
+            root,
+            ..FsLimits::default()
+        };
+
+        let output = handle_fs_invoke("test", &fs_act(FS_ACT_LIST_ID, json!({})), &limits).await;
+
+        let sense = output.sense.expect("list result sense");
+        assert_eq!(sense.neural_signal_descriptor_id, FS_SENSE_LIST_RESULT_ID);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&sense.payload).expect("json payload"),
+            json!({
+                "path": ".",
+                "entries": [
+                    { "name": "notes", "kind": "dir" },
+                    { "name": "readme.md", "kind": "file" },
+                ],
+                "truncated": false,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn traversal_outside_root_is_rejected() {
+        let root = fs_root();
+        let limits = FsLimits {
+            root: root.join("notes"),
+            ..FsLimits::default()
+        };
+
+        for (descriptor_id, payload) in [
+            (FS_ACT_READ_ID, json!({ "path": "../today.md" })),
+            (FS_ACT_READ_ID, json!({ "path": "/etc/hostname" })),
+            (
+                FS_ACT_WRITE_ID,
+                json!({ "path": "../../beluna-fs-outside.txt", "content": "overwritten" }),
+            ),
+            (FS_ACT_LIST_ID, json!({ "path": ".." })),
+        ] {
+            let output = handle_fs_invoke("test", &fs_act(descriptor_id, payload), &limits).await;
+
+            assert!(output.sense.is_none());
+            assert!(matches!(
+                output.outcome,
+                EndpointExecutionOutcome::Rejected { ref reason_code, .. }
+                    if reason_code == "path_outside_root"
+            ));
+        }
+    }
+}
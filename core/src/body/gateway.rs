@@ -0,0 +1,112 @@
+use crate::{
+    ai_gateway::chat::Chat,
+    body::{GATEWAY_ACT_BREAKER_STATES_ID, GATEWAY_SENSE_BREAKER_STATES_RESULT_ID},
+    spine::{adapters::inline::InlineSenseDatum, types::EndpointExecutionOutcome},
+    types::Act,
+};
+
+pub struct GatewayHandlerOutput {
+    pub outcome: EndpointExecutionOutcome,
+    pub sense: Option<InlineSenseDatum>,
+}
+
+/// Answers `gateway.breaker_states` with the circuit breaker state of every backend that has one.
+pub async fn handle_gateway_invoke(act: &Act, chat: &Chat) -> GatewayHandlerOutput {
+    if act.neural_signal_descriptor_id != GATEWAY_ACT_BREAKER_STATES_ID {
+        return GatewayHandlerOutput {
+            outcome: EndpointExecutionOutcome::Rejected {
+                reason_code: "unsupported_act".to_string(),
+                reference_id: format!("body.gateway:unsupported_act:{}", act.act_instance_id),
+            },
+            sense: None,
+        };
+    }
+
+    let payload = serde_json::json!({ "backends": chat.backend_breaker_states().await });
+    GatewayHandlerOutput {
+        outcome: EndpointExecutionOutcome::Applied {
+            actual_cost_micro: 0,
+            reference_id: format!("body.gateway:applied:{}", act.act_instance_id),
+        },
+        sense: Some(InlineSenseDatum {
+            sense_instance_id: uuid::Uuid::new_v4().to_string(),
+            neural_signal_descriptor_id: GATEWAY_SENSE_BREAKER_STATES_RESULT_ID.to_string(),
+            payload: payload.to_string(),
+            weight: 0.0,
+            act_instance_id: Some(act.act_instance_id.clone()),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::ai_gateway::{
+        credentials::EnvCredentialProvider,
+        types::{
+            AIGatewayConfig, BackendDialect, BackendProfile, ChatConfig, CredentialRef,
+            HttpClientConfig, ModelProfile, ResilienceConfig,
+        },
+    };
+
+    fn act(neural_signal_descriptor_id: &str) -> Act {
+        Act {
+            act_instance_id: "act-1".to_string(),
+            endpoint_id: "gateway.1".to_string(),
+            neural_signal_descriptor_id: neural_signal_descriptor_id.to_string(),
+            might_emit_sense_ids: Vec::new(),
+            payload: serde_json::json!({}),
+            goal_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn breaker_states_act_reports_backends_and_other_acts_are_rejected() {
+        let chat = Chat::new(
+            &AIGatewayConfig {
+                backends: vec![BackendProfile {
+                    id: "primary".to_string(),
+                    dialect: BackendDialect::OpenAiResponses,
+                    endpoint: Some("http://127.0.0.1:9/v1".to_string()),
+                    credential: CredentialRef::None,
+                    models: vec![ModelProfile {
+                        id: "gpt-5".to_string(),
+                        aliases: vec!["default".to_string()],
+                    }],
+                    max_output_tokens_cap: None,
+                    limits: None,
+                    capabilities: None,
+                    copilot: None,
+                    post_finish_content: Default::default(),
+                }],
+                chat: ChatConfig::default(),
+                resilience: ResilienceConfig::default(),
+                http: HttpClientConfig::default(),
+            },
+            Arc::new(EnvCredentialProvider),
+        )
+        .expect("chat");
+
+        let output = handle_gateway_invoke(&act(GATEWAY_ACT_BREAKER_STATES_ID), &chat).await;
+        assert!(matches!(
+            output.outcome,
+            EndpointExecutionOutcome::Applied { .. }
+        ));
+        let sense = output.sense.expect("result sense");
+        assert_eq!(
+            sense.neural_signal_descriptor_id,
+            GATEWAY_SENSE_BREAKER_STATES_RESULT_ID
+        );
+        assert_eq!(sense.payload, r#"{"backends":{}}"#);
+
+        let output = handle_gateway_invoke(&act("gateway.reset"), &chat).await;
+        assert!(matches!(
+            output.outcome,
+            EndpointExecutionOutcome::Rejected { ref reason_code, .. }
+                if reason_code == "unsupported_act"
+        ));
+        assert!(output.sense.is_none());
+    }
+}
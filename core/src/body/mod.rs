@@ -3,7 +3,13 @@ use std::sync::Arc;
 use anyhow::{Result, anyhow};
 
 use crate::{
-    body::payloads::{ShellLimits, WebLimits},
+    ai_gateway::chat::Chat,
+    body::{
+        cognition::handle_cognition_invoke,
+        gateway::handle_gateway_invoke,
+        payloads::{FsLimits, ShellLimits, WebLimits},
+    },
+    cortex::Cortex,
     spine::{
         NeuralSignalDescriptor,
         adapters::inline::{InlineEndpointRuntimeHandles, SpineInlineAdapter},
@@ -17,16 +23,36 @@ use crate::body::shell::handle_shell_invoke;
 #[cfg(feature = "std-web")]
 use crate::body::web::handle_web_invoke;
 
+#[cfg(feature = "std-fs")]
+use crate::body::fs::handle_fs_invoke;
+
+pub mod cognition;
+pub mod fs;
+pub mod gateway;
 pub mod payloads;
 pub mod shell;
 pub mod web;
 
 pub const SHELL_ENDPOINT_NAME: &str = "shell";
 pub const WEB_ENDPOINT_NAME: &str = "web";
+pub const FS_ENDPOINT_NAME: &str = "fs";
+pub const GATEWAY_ENDPOINT_NAME: &str = "gateway";
+pub const COGNITION_ENDPOINT_NAME: &str = "cognition";
 pub const SHELL_ACT_EXEC_ID: &str = "shell.exec";
 pub const WEB_ACT_FETCH_ID: &str = "web.fetch";
 pub const SHELL_SENSE_EXEC_RESULT_ID: &str = "shell.exec.result";
+pub const SHELL_SENSE_EXEC_OUTPUT_ID: &str = "shell.exec.output";
 pub const WEB_SENSE_FETCH_RESULT_ID: &str = "web.fetch.result";
+pub const FS_ACT_READ_ID: &str = "fs.read";
+pub const FS_ACT_WRITE_ID: &str = "fs.write";
+pub const FS_ACT_LIST_ID: &str = "fs.list";
+pub const FS_SENSE_READ_RESULT_ID: &str = "fs.read.result";
+pub const FS_SENSE_WRITE_RESULT_ID: &str = "fs.write.result";
+pub const FS_SENSE_LIST_RESULT_ID: &str = "fs.list.result";
+pub const GATEWAY_ACT_BREAKER_STATES_ID: &str = "gateway.breaker_states";
+pub const GATEWAY_SENSE_BREAKER_STATES_RESULT_ID: &str = "gateway.breaker_states.result";
+pub const COGNITION_ACT_DIFF_ID: &str = "cognition.diff";
+pub const COGNITION_SENSE_DIFF_RESULT_ID: &str = "cognition.diff.result";
 
 /// Inline Body Endpoints are built into Core, started by `main`, and attached through Spine inline adapter.
 pub fn start_inline_body_endpoints(
@@ -35,16 +61,60 @@ pub fn start_inline_body_endpoints(
     shell_limits: ShellLimits,
     web_enabled: bool,
     web_limits: WebLimits,
+    fs_enabled: bool,
+    fs_limits: FsLimits,
 ) -> Result<()> {
     if shell_enabled {
         start_std_shell_inline_endpoint(Arc::clone(&inline_adapter), shell_limits)?;
     }
     if web_enabled {
-        start_std_web_inline_endpoint(inline_adapter, web_limits)?;
+        start_std_web_inline_endpoint(Arc::clone(&inline_adapter), web_limits)?;
+    }
+    if fs_enabled {
+        start_std_fs_inline_endpoint(inline_adapter, fs_limits)?;
     }
     Ok(())
 }
 
+/// Attaches the `gateway` endpoint, which reports AI gateway backend breaker states.
+///
+/// It only reads in-memory gateway state, so its worker runs on the caller's runtime.
+pub async fn start_gateway_inline_endpoint(
+    inline_adapter: Arc<SpineInlineAdapter>,
+    chat: Arc<Chat>,
+) -> Result<()> {
+    let handles = inline_adapter
+        .attach_inline_endpoint(
+            GATEWAY_ENDPOINT_NAME.to_string(),
+            vec![
+                gateway_breaker_states_registration_descriptor(),
+                gateway_breaker_states_result_registration_descriptor(),
+            ],
+        )
+        .await?;
+    tokio::spawn(run_gateway_worker(handles, chat));
+    Ok(())
+}
+
+/// Attaches the `cognition` endpoint, whose `cognition.diff` reports how the persisted cognition
+/// state changed since the previous query, or since the endpoint started.
+pub async fn start_cognition_inline_endpoint(
+    inline_adapter: Arc<SpineInlineAdapter>,
+    cortex: Arc<Cortex>,
+) -> Result<()> {
+    let handles = inline_adapter
+        .attach_inline_endpoint(
+            COGNITION_ENDPOINT_NAME.to_string(),
+            vec![
+                cognition_diff_registration_descriptor(),
+                cognition_diff_result_registration_descriptor(),
+            ],
+        )
+        .await?;
+    tokio::spawn(run_cognition_worker(handles, cortex));
+    Ok(())
+}
+
 fn start_std_shell_inline_endpoint(
     inline_adapter: Arc<SpineInlineAdapter>,
     limits: ShellLimits,
@@ -74,6 +144,7 @@ fn start_std_shell_inline_endpoint(
                             vec![
                                 shell_registration_descriptor(),
                                 shell_result_registration_descriptor(),
+                                shell_output_registration_descriptor(),
                             ],
                         )
                         .await;
@@ -167,6 +238,71 @@ fn start_std_web_inline_endpoint(
     }
 }
 
+fn start_std_fs_inline_endpoint(
+    inline_adapter: Arc<SpineInlineAdapter>,
+    limits: FsLimits,
+) -> Result<()> {
+    #[cfg(feature = "std-fs")]
+    {
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel::<Result<()>>();
+        let parent_span = tracing::Span::current();
+        std::thread::Builder::new()
+            .name("beluna-inline-std-fs".to_string())
+            .spawn(move || {
+                let _entered = parent_span.enter();
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build();
+                let Ok(runtime) = runtime else {
+                    let _ = ready_tx.send(Err(anyhow!(
+                        "failed to build tokio runtime for inline std-fs worker"
+                    )));
+                    return;
+                };
+
+                runtime.block_on(async move {
+                    let handles = inline_adapter
+                        .attach_inline_endpoint(
+                            FS_ENDPOINT_NAME.to_string(),
+                            vec![
+                                fs_read_registration_descriptor(),
+                                fs_write_registration_descriptor(),
+                                fs_list_registration_descriptor(),
+                                fs_result_registration_descriptor(FS_SENSE_READ_RESULT_ID),
+                                fs_result_registration_descriptor(FS_SENSE_WRITE_RESULT_ID),
+                                fs_result_registration_descriptor(FS_SENSE_LIST_RESULT_ID),
+                            ],
+                        )
+                        .await;
+
+                    match handles {
+                        Ok(handles) => {
+                            let _ = ready_tx.send(Ok(()));
+                            run_fs_worker(handles, limits).await;
+                        }
+                        Err(err) => {
+                            let _ = ready_tx.send(Err(err));
+                        }
+                    }
+                });
+            })
+            .map_err(|err| anyhow!("failed to spawn inline std-fs worker thread: {err}"))?;
+
+        ready_rx
+            .recv()
+            .map_err(|_| anyhow!("inline std-fs worker startup channel closed"))??;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "std-fs"))]
+    {
+        let _ = (inline_adapter, limits);
+        Err(anyhow!(
+            "body.std_fs.enabled=true but core is built without feature std-fs"
+        ))
+    }
+}
+
 #[cfg(feature = "std-shell")]
 #[tracing::instrument(
     name = "std_shell_worker",
@@ -174,29 +310,102 @@ fn start_std_web_inline_endpoint(
     skip(handles, limits)
 )]
 async fn run_shell_worker(mut handles: InlineEndpointRuntimeHandles, limits: ShellLimits) {
-    while let Some(act) = handles.act_rx.recv().await {
+    let reason = loop {
+        let Some(act) = handles.act_rx.recv().await else {
+            break "act_channel_closed";
+        };
         let request_id = format!("builtin-shell:{}", act.act_instance_id);
-        let output = handle_shell_invoke(&request_id, act.as_ref(), &limits).await;
+        let output =
+            handle_shell_invoke(&request_id, act.as_ref(), &limits, &handles.sense_tx).await;
         if let Some(sense) = output.sense
             && handles.sense_tx.send(Arc::new(sense)).await.is_err()
         {
-            break;
+            break "sense_channel_closed";
         }
-    }
+    };
+    log_inline_worker_exited(SHELL_ENDPOINT_NAME, reason);
 }
 
 #[cfg(feature = "std-web")]
 #[tracing::instrument(name = "std_web_worker", target = "body.inline", skip(handles, limits))]
 async fn run_web_worker(mut handles: InlineEndpointRuntimeHandles, limits: WebLimits) {
-    while let Some(act) = handles.act_rx.recv().await {
+    let reason = loop {
+        let Some(act) = handles.act_rx.recv().await else {
+            break "act_channel_closed";
+        };
         let request_id = format!("builtin-web:{}", act.act_instance_id);
         let output = handle_web_invoke(&request_id, act.as_ref(), &limits).await;
         if let Some(sense) = output.sense
             && handles.sense_tx.send(Arc::new(sense)).await.is_err()
         {
-            break;
+            break "sense_channel_closed";
         }
-    }
+    };
+    log_inline_worker_exited(WEB_ENDPOINT_NAME, reason);
+}
+
+#[cfg(feature = "std-fs")]
+#[tracing::instrument(name = "std_fs_worker", target = "body.inline", skip(handles, limits))]
+async fn run_fs_worker(mut handles: InlineEndpointRuntimeHandles, limits: FsLimits) {
+    let reason = loop {
+        let Some(act) = handles.act_rx.recv().await else {
+            break "act_channel_closed";
+        };
+        let request_id = format!("builtin-fs:{}", act.act_instance_id);
+        let output = handle_fs_invoke(&request_id, act.as_ref(), &limits).await;
+        if let Some(sense) = output.sense
+            && handles.sense_tx.send(Arc::new(sense)).await.is_err()
+        {
+            break "sense_channel_closed";
+        }
+    };
+    log_inline_worker_exited(FS_ENDPOINT_NAME, reason);
+}
+
+#[tracing::instrument(name = "gateway_worker", target = "body.inline", skip(handles, chat))]
+async fn run_gateway_worker(mut handles: InlineEndpointRuntimeHandles, chat: Arc<Chat>) {
+    let reason = loop {
+        let Some(act) = handles.act_rx.recv().await else {
+            break "act_channel_closed";
+        };
+        let output = handle_gateway_invoke(act.as_ref(), &chat).await;
+        if let Some(sense) = output.sense
+            && handles.sense_tx.send(Arc::new(sense)).await.is_err()
+        {
+            break "sense_channel_closed";
+        }
+    };
+    log_inline_worker_exited(GATEWAY_ENDPOINT_NAME, reason);
+}
+
+#[tracing::instrument(
+    name = "cognition_worker",
+    target = "body.inline",
+    skip(handles, cortex)
+)]
+async fn run_cognition_worker(mut handles: InlineEndpointRuntimeHandles, cortex: Arc<Cortex>) {
+    let mut last_seen = cortex.load_cognition_state().await.unwrap_or_default();
+    let reason = loop {
+        let Some(act) = handles.act_rx.recv().await else {
+            break "act_channel_closed";
+        };
+        let output = handle_cognition_invoke(act.as_ref(), &cortex, &mut last_seen).await;
+        if let Some(sense) = output.sense
+            && handles.sense_tx.send(Arc::new(sense)).await.is_err()
+        {
+            break "sense_channel_closed";
+        }
+    };
+    log_inline_worker_exited(COGNITION_ENDPOINT_NAME, reason);
+}
+
+fn log_inline_worker_exited(endpoint_name: &str, reason: &str) {
+    tracing::warn!(
+        target: "body.inline",
+        endpoint_name = endpoint_name,
+        reason = reason,
+        "inline_worker_exited"
+    );
 }
 
 #[cfg(feature = "std-shell")]
@@ -214,9 +423,12 @@ fn shell_registration_descriptor() -> NeuralSignalDescriptor {
                 "env": {"type": "object", "additionalProperties": {"type": "string"}},
                 "timeout_ms": {"type": "integer", "minimum": 1},
                 "stdout_max_bytes": {"type": "integer", "minimum": 1},
-                "stderr_max_bytes": {"type": "integer", "minimum": 1}
+                "stderr_max_bytes": {"type": "integer", "minimum": 1},
+                "stdin": {"type": ["string", "null"]},
+                "stream": {"type": "boolean", "default": false}
             }
         }),
+        priority: None,
     }
 }
 
@@ -227,6 +439,18 @@ fn shell_result_registration_descriptor() -> NeuralSignalDescriptor {
         endpoint_id: SHELL_ENDPOINT_NAME.to_string(),
         neural_signal_descriptor_id: SHELL_SENSE_EXEC_RESULT_ID.to_string(),
         payload_schema: serde_json::json!({ "type": "string" }),
+        priority: None,
+    }
+}
+
+#[cfg(feature = "std-shell")]
+fn shell_output_registration_descriptor() -> NeuralSignalDescriptor {
+    NeuralSignalDescriptor {
+        r#type: NeuralSignalType::Sense,
+        endpoint_id: SHELL_ENDPOINT_NAME.to_string(),
+        neural_signal_descriptor_id: SHELL_SENSE_EXEC_OUTPUT_ID.to_string(),
+        payload_schema: serde_json::json!({ "type": "string" }),
+        priority: None,
     }
 }
 
@@ -248,6 +472,7 @@ fn web_registration_descriptor() -> NeuralSignalDescriptor {
                 "response_max_bytes": {"type": "integer", "minimum": 1}
             }
         }),
+        priority: None,
     }
 }
 
@@ -258,5 +483,110 @@ fn web_result_registration_descriptor() -> NeuralSignalDescriptor {
         endpoint_id: WEB_ENDPOINT_NAME.to_string(),
         neural_signal_descriptor_id: WEB_SENSE_FETCH_RESULT_ID.to_string(),
         payload_schema: serde_json::json!({ "type": "string" }),
+        priority: None,
+    }
+}
+
+#[cfg(feature = "std-fs")]
+fn fs_read_registration_descriptor() -> NeuralSignalDescriptor {
+    NeuralSignalDescriptor {
+        r#type: NeuralSignalType::Act,
+        endpoint_id: FS_ENDPOINT_NAME.to_string(),
+        neural_signal_descriptor_id: FS_ACT_READ_ID.to_string(),
+        payload_schema: serde_json::json!({
+            "type": "object",
+            "required": ["path"],
+            "properties": {
+                "path": {"type": "string"},
+                "max_bytes": {"type": "integer", "minimum": 1}
+            }
+        }),
+        priority: None,
+    }
+}
+
+#[cfg(feature = "std-fs")]
+fn fs_write_registration_descriptor() -> NeuralSignalDescriptor {
+    NeuralSignalDescriptor {
+        r#type: NeuralSignalType::Act,
+        endpoint_id: FS_ENDPOINT_NAME.to_string(),
+        neural_signal_descriptor_id: FS_ACT_WRITE_ID.to_string(),
+        payload_schema: serde_json::json!({
+            "type": "object",
+            "required": ["path", "content"],
+            "properties": {
+                "path": {"type": "string"},
+                "content": {"type": "string"},
+                "append": {"type": "boolean", "default": false}
+            }
+        }),
+        priority: None,
+    }
+}
+
+#[cfg(feature = "std-fs")]
+fn fs_list_registration_descriptor() -> NeuralSignalDescriptor {
+    NeuralSignalDescriptor {
+        r#type: NeuralSignalType::Act,
+        endpoint_id: FS_ENDPOINT_NAME.to_string(),
+        neural_signal_descriptor_id: FS_ACT_LIST_ID.to_string(),
+        payload_schema: serde_json::json!({
+            "type": "object",
+            "properties": {
+                "path": {"type": ["string", "null"]}
+            }
+        }),
+        priority: None,
+    }
+}
+
+#[cfg(feature = "std-fs")]
+fn fs_result_registration_descriptor(neural_signal_descriptor_id: &str) -> NeuralSignalDescriptor {
+    NeuralSignalDescriptor {
+        r#type: NeuralSignalType::Sense,
+        endpoint_id: FS_ENDPOINT_NAME.to_string(),
+        neural_signal_descriptor_id: neural_signal_descriptor_id.to_string(),
+        payload_schema: serde_json::json!({ "type": "string" }),
+        priority: None,
+    }
+}
+
+fn gateway_breaker_states_registration_descriptor() -> NeuralSignalDescriptor {
+    NeuralSignalDescriptor {
+        r#type: NeuralSignalType::Act,
+        endpoint_id: GATEWAY_ENDPOINT_NAME.to_string(),
+        neural_signal_descriptor_id: GATEWAY_ACT_BREAKER_STATES_ID.to_string(),
+        payload_schema: serde_json::json!({ "type": "object", "properties": {} }),
+        priority: None,
+    }
+}
+
+fn gateway_breaker_states_result_registration_descriptor() -> NeuralSignalDescriptor {
+    NeuralSignalDescriptor {
+        r#type: NeuralSignalType::Sense,
+        endpoint_id: GATEWAY_ENDPOINT_NAME.to_string(),
+        neural_signal_descriptor_id: GATEWAY_SENSE_BREAKER_STATES_RESULT_ID.to_string(),
+        payload_schema: serde_json::json!({ "type": "string" }),
+        priority: None,
+    }
+}
+
+fn cognition_diff_registration_descriptor() -> NeuralSignalDescriptor {
+    NeuralSignalDescriptor {
+        r#type: NeuralSignalType::Act,
+        endpoint_id: COGNITION_ENDPOINT_NAME.to_string(),
+        neural_signal_descriptor_id: COGNITION_ACT_DIFF_ID.to_string(),
+        payload_schema: serde_json::json!({ "type": "object", "properties": {} }),
+        priority: None,
+    }
+}
+
+fn cognition_diff_result_registration_descriptor() -> NeuralSignalDescriptor {
+    NeuralSignalDescriptor {
+        r#type: NeuralSignalType::Sense,
+        endpoint_id: COGNITION_ENDPOINT_NAME.to_string(),
+        neural_signal_descriptor_id: COGNITION_SENSE_DIFF_RESULT_ID.to_string(),
+        payload_schema: serde_json::json!({ "type": "string" }),
+        priority: None,
     }
 }
@@ -1,8 +1,8 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, path::PathBuf};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ShellExecRequest {
@@ -17,6 +17,12 @@ pub struct ShellExecRequest {
     pub stdout_max_bytes: Option<usize>,
     #[serde(default)]
     pub stderr_max_bytes: Option<usize>,
+    /// Written to the child's stdin, which is then closed.
+    #[serde(default)]
+    pub stdin: Option<String>,
+    /// Emit each output line as its own sense while the process runs.
+    #[serde(default)]
+    pub stream: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
@@ -34,6 +40,26 @@ pub struct ShellLimits {
     pub default_stderr_max_bytes: usize,
     #[validate(range(min = 1))]
     pub max_stderr_max_bytes: usize,
+    /// Largest `stdin` a payload may carry; bigger payloads are rejected.
+    #[serde(default = "default_shell_stdin_max_bytes")]
+    #[validate(range(min = 1))]
+    pub stdin_max_bytes: usize,
+    /// Executables the endpoint may run; empty allows any.
+    ///
+    /// Bare names resolve through core's `PATH`, paths are taken as given, and `argv[0]` must
+    /// resolve to the same file, so a same-named binary elsewhere is denied.
+    #[serde(default)]
+    pub allowed_commands: Vec<String>,
+    /// Directories a command may run in, subdirectories included; empty allows any.
+    ///
+    /// Relative prefixes resolve against core's working directory, which is also the `cwd` checked
+    /// when a payload omits one.
+    #[serde(default)]
+    pub allowed_cwd_prefixes: Vec<PathBuf>,
+}
+
+fn default_shell_stdin_max_bytes() -> usize {
+    64 * 1024
 }
 
 impl Default for ShellLimits {
@@ -45,6 +71,9 @@ impl Default for ShellLimits {
             max_stdout_max_bytes: 256 * 1024,
             default_stderr_max_bytes: 16 * 1024,
             max_stderr_max_bytes: 256 * 1024,
+            stdin_max_bytes: default_shell_stdin_max_bytes(),
+            allowed_commands: Vec::new(),
+            allowed_cwd_prefixes: Vec::new(),
         }
     }
 }
@@ -95,6 +124,27 @@ pub struct WebLimits {
     pub default_response_max_bytes: usize,
     #[validate(range(min = 1))]
     pub max_response_max_bytes: usize,
+    /// Proxy for every fetch, e.g. `http://proxy.corp:3128`; fetches go direct when unset.
+    #[serde(default)]
+    #[validate(custom(function = "validate_proxy_url"))]
+    pub proxy_url: Option<String>,
+    /// Redirects followed per fetch; `0` surfaces the redirect response itself.
+    #[serde(default = "default_web_max_redirects")]
+    pub max_redirects: u32,
+    /// Allow fetches and redirects to loopback, private, link-local, and metadata addresses.
+    #[serde(default)]
+    pub allow_private_network: bool,
+}
+
+fn default_web_max_redirects() -> u32 {
+    10
+}
+
+fn validate_proxy_url(proxy_url: &str) -> Result<(), ValidationError> {
+    if reqwest::Url::parse(proxy_url).is_err() {
+        return Err(ValidationError::new("proxy_url"));
+    }
+    Ok(())
 }
 
 impl Default for WebLimits {
@@ -104,6 +154,9 @@ impl Default for WebLimits {
             max_timeout_ms: 60_000,
             default_response_max_bytes: 64 * 1024,
             max_response_max_bytes: 512 * 1024,
+            proxy_url: None,
+            max_redirects: default_web_max_redirects(),
+            allow_private_network: false,
         }
     }
 }
@@ -125,3 +178,67 @@ impl WebFetchRequest {
         self.method.as_deref().unwrap_or("GET")
     }
 }
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FsReadRequest {
+    pub path: String,
+    #[serde(default)]
+    pub max_bytes: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FsWriteRequest {
+    pub path: String,
+    pub content: String,
+    #[serde(default)]
+    pub append: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FsListRequest {
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FsLimits {
+    /// Directory every fs path resolves under; nothing outside it can be read, written, or listed.
+    #[serde(default = "default_fs_root")]
+    pub root: PathBuf,
+    #[validate(range(min = 1))]
+    pub default_read_max_bytes: usize,
+    #[validate(range(min = 1))]
+    pub max_read_max_bytes: usize,
+    #[validate(range(min = 1))]
+    pub max_write_bytes: usize,
+    #[validate(range(min = 1))]
+    pub max_list_entries: usize,
+}
+
+fn default_fs_root() -> PathBuf {
+    PathBuf::from("workspace")
+}
+
+impl Default for FsLimits {
+    fn default() -> Self {
+        Self {
+            root: default_fs_root(),
+            default_read_max_bytes: 64 * 1024,
+            max_read_max_bytes: 1024 * 1024,
+            max_write_bytes: 1024 * 1024,
+            max_list_entries: 1_000,
+        }
+    }
+}
+
+impl FsReadRequest {
+    pub fn max_bytes(&self, limits: &FsLimits) -> usize {
+        self.max_bytes
+            .unwrap_or(limits.default_read_max_bytes)
+            .min(limits.max_read_max_bytes)
+    }
+}
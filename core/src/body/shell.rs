@@ -1,13 +1,22 @@
-use std::process::Stdio;
+use std::{
+    ffi::{OsStr, OsString},
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+    process::{ExitStatus, Stdio},
+    sync::Arc,
+};
 
 use tokio::{
-    process::Command,
+    io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader},
+    process::{Child, ChildStdin, Command},
+    sync::mpsc,
+    task::JoinSet,
     time::{Duration, timeout},
 };
 
 use crate::{
     body::{
-        SHELL_SENSE_EXEC_RESULT_ID,
+        SHELL_SENSE_EXEC_OUTPUT_ID, SHELL_SENSE_EXEC_RESULT_ID,
         payloads::{ShellExecRequest, ShellLimits},
     },
     spine::adapters::inline::InlineSenseDatum,
@@ -20,10 +29,25 @@ pub struct ShellHandlerOutput {
     pub sense: Option<InlineSenseDatum>,
 }
 
+struct ShellRunOutput {
+    status: ExitStatus,
+    stdout_text: String,
+    stderr_text: String,
+    stdout_truncated: bool,
+    stderr_truncated: bool,
+}
+
+/// Per-stream byte cap shared by every chunk of a streamed command.
+struct StreamByteBudget {
+    remaining: usize,
+    truncated: bool,
+}
+
 pub async fn handle_shell_invoke(
     _request_id: &str,
     act: &Act,
     limits: &ShellLimits,
+    sense_tx: &mpsc::Sender<Arc<InlineSenseDatum>>,
 ) -> ShellHandlerOutput {
     let parse_result: Result<ShellExecRequest, _> = serde_json::from_value(act.payload.clone());
     let command_request = match parse_result {
@@ -49,11 +73,43 @@ pub async fn handle_shell_invoke(
         };
     }
 
+    let Some(program) = resolve_allowed_command(&command_request, limits) else {
+        return ShellHandlerOutput {
+            outcome: EndpointExecutionOutcome::Rejected {
+                reason_code: "denied".to_string(),
+                reference_id: format!("body.std.shell:command_not_allowed:{}", act.act_instance_id),
+            },
+            sense: None,
+        };
+    };
+    if !cwd_is_allowed(command_request.cwd.as_deref(), limits) {
+        return ShellHandlerOutput {
+            outcome: EndpointExecutionOutcome::Rejected {
+                reason_code: "denied".to_string(),
+                reference_id: format!("body.std.shell:cwd_not_allowed:{}", act.act_instance_id),
+            },
+            sense: None,
+        };
+    }
+
+    if let Some(stdin) = &command_request.stdin
+        && stdin.len() > limits.stdin_max_bytes
+    {
+        return ShellHandlerOutput {
+            outcome: EndpointExecutionOutcome::Rejected {
+                reason_code: "invalid_payload".to_string(),
+                reference_id: format!("body.std.shell:stdin_too_large:{}", act.act_instance_id),
+            },
+            sense: None,
+        };
+    }
+
     let timeout_ms = command_request.timeout_ms(limits);
     let stdout_cap = command_request.stdout_max_bytes(limits);
     let stderr_cap = command_request.stderr_max_bytes(limits);
 
-    let mut command = Command::new(&command_request.argv[0]);
+    let mut command = Command::new(&program);
+    command.arg0(&command_request.argv[0]);
     if command_request.argv.len() > 1 {
         command.args(&command_request.argv[1..]);
     }
@@ -64,35 +120,32 @@ pub async fn handle_shell_invoke(
         command.envs(&command_request.env);
     }
 
+    if command_request.stdin.is_some() {
+        command.stdin(Stdio::piped());
+    }
     command.stdout(Stdio::piped());
     command.stderr(Stdio::piped());
     command.kill_on_drop(true);
 
-    let output = match command.spawn() {
-        Ok(child) => {
-            match timeout(Duration::from_millis(timeout_ms), child.wait_with_output()).await {
-                Ok(Ok(output)) => output,
-                Ok(Err(_)) => {
-                    return ShellHandlerOutput {
-                        outcome: EndpointExecutionOutcome::Rejected {
-                            reason_code: "exec_failure".to_string(),
-                            reference_id: format!(
-                                "body.std.shell:wait_failure:{}",
-                                act.act_instance_id
-                            ),
-                        },
-                        sense: None,
-                    };
-                }
-                Err(_) => {
-                    return ShellHandlerOutput {
-                        outcome: EndpointExecutionOutcome::Rejected {
-                            reason_code: "timeout".to_string(),
-                            reference_id: format!("body.std.shell:timeout:{}", act.act_instance_id),
-                        },
-                        sense: None,
-                    };
-                }
+    let run = match command.spawn() {
+        Ok(mut child) => {
+            if let (Some(input), Some(child_stdin)) =
+                (command_request.stdin.clone(), child.stdin.take())
+            {
+                tokio::spawn(feed_stdin(child_stdin, input));
+            }
+            if command_request.stream {
+                timeout(
+                    Duration::from_millis(timeout_ms),
+                    stream_child_output(act, child, stdout_cap, stderr_cap, sense_tx),
+                )
+                .await
+            } else {
+                timeout(
+                    Duration::from_millis(timeout_ms),
+                    buffer_child_output(child, stdout_cap, stderr_cap),
+                )
+                .await
             }
         }
         Err(_) => {
@@ -105,12 +158,37 @@ pub async fn handle_shell_invoke(
             };
         }
     };
+    let ShellRunOutput {
+        status,
+        stdout_text,
+        stderr_text,
+        stdout_truncated,
+        stderr_truncated,
+    } = match run {
+        Ok(Ok(output)) => output,
+        Ok(Err(_)) => {
+            return ShellHandlerOutput {
+                outcome: EndpointExecutionOutcome::Rejected {
+                    reason_code: "exec_failure".to_string(),
+                    reference_id: format!("body.std.shell:wait_failure:{}", act.act_instance_id),
+                },
+                sense: None,
+            };
+        }
+        Err(_) => {
+            return ShellHandlerOutput {
+                outcome: EndpointExecutionOutcome::Rejected {
+                    reason_code: "timeout".to_string(),
+                    reference_id: format!("body.std.shell:timeout:{}", act.act_instance_id),
+                },
+                sense: None,
+            };
+        }
+    };
 
-    let exit_code = output.status.code().unwrap_or(-1);
-    let (stdout_text, stdout_truncated) = truncate_to_text(&output.stdout, stdout_cap);
-    let (stderr_text, stderr_truncated) = truncate_to_text(&output.stderr, stderr_cap);
+    let exit_code = status.code().unwrap_or(-1);
 
-    if !output.status.success() {
+    if !status.success() {
         return ShellHandlerOutput {
             outcome: EndpointExecutionOutcome::Rejected {
                 reason_code: "non_zero_exit".to_string(),
@@ -160,6 +238,194 @@ pub async fn handle_shell_invoke(
     }
 }
 
+/// Resolves `argv[0]` the way the spawn would and returns the executable to run.
+///
+/// With `allowed_commands` set, the resolved path must match one of them resolved the same way
+/// against core's own `PATH`, so `./echo` or `/tmp/evil/echo` cannot pass for an allowed `echo`.
+/// Only parent directories are canonicalized: multi-call binaries share one target per name.
+fn resolve_allowed_command(request: &ShellExecRequest, limits: &ShellLimits) -> Option<PathBuf> {
+    let program = &request.argv[0];
+    if limits.allowed_commands.is_empty() {
+        return Some(PathBuf::from(program));
+    }
+    let search_path = request
+        .env
+        .get("PATH")
+        .map(OsString::from)
+        .or_else(|| std::env::var_os("PATH"));
+    let resolved = resolve_program(program, request.cwd.as_deref(), search_path.as_deref())?;
+    let core_path = std::env::var_os("PATH");
+    limits
+        .allowed_commands
+        .iter()
+        .filter_map(|allowed| resolve_program(allowed, None, core_path.as_deref()))
+        .any(|allowed| allowed == resolved)
+        .then_some(resolved)
+}
+
+fn resolve_program(
+    program: &str,
+    cwd: Option<&str>,
+    search_path: Option<&OsStr>,
+) -> Option<PathBuf> {
+    let candidate = if program.contains('/') {
+        let base = match cwd {
+            Some(cwd) => std::env::current_dir().ok()?.join(cwd),
+            None => std::env::current_dir().ok()?,
+        };
+        base.join(program)
+    } else {
+        std::env::split_paths(search_path?)
+            .map(|dir| dir.join(program))
+            .find(|candidate| is_executable_file(candidate))?
+    };
+    if !is_executable_file(&candidate) {
+        return None;
+    }
+    let parent = std::fs::canonicalize(candidate.parent()?).ok()?;
+    Some(parent.join(candidate.file_name()?))
+}
+
+fn is_executable_file(path: &Path) -> bool {
+    std::fs::metadata(path)
+        .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+/// Checks the canonical `cwd` so `..` segments and symlinks cannot escape an allowed prefix.
+fn cwd_is_allowed(cwd: Option<&str>, limits: &ShellLimits) -> bool {
+    if limits.allowed_cwd_prefixes.is_empty() {
+        return true;
+    }
+    let resolved = match cwd {
+        Some(cwd) => std::fs::canonicalize(cwd),
+        None => std::env::current_dir().and_then(std::fs::canonicalize),
+    };
+    let Ok(resolved) = resolved else {
+        return false;
+    };
+    limits.allowed_cwd_prefixes.iter().any(|prefix| {
+        std::fs::canonicalize(prefix).is_ok_and(|prefix| resolved.starts_with(prefix))
+    })
+}
+
+/// Writes the payload's stdin alongside output collection so a full pipe cannot deadlock the child.
+async fn feed_stdin(mut child_stdin: ChildStdin, input: String) {
+    // A child that exits without reading its input closes the pipe; that is not an act failure.
+    let _ = child_stdin.write_all(input.as_bytes()).await;
+}
+
+async fn buffer_child_output(
+    child: Child,
+    stdout_cap: usize,
+    stderr_cap: usize,
+) -> std::io::Result<ShellRunOutput> {
+    let output = child.wait_with_output().await?;
+    let (stdout_text, stdout_truncated) = truncate_to_text(&output.stdout, stdout_cap);
+    let (stderr_text, stderr_truncated) = truncate_to_text(&output.stderr, stderr_cap);
+    Ok(ShellRunOutput {
+        status: output.status,
+        stdout_text,
+        stderr_text,
+        stdout_truncated,
+        stderr_truncated,
+    })
+}
+
+/// Emits each stdout/stderr line as a `shell.exec.output` sense while the process runs.
+///
+/// The terminal result sense then carries only the exit code and truncation flags.
+async fn stream_child_output(
+    act: &Act,
+    mut child: Child,
+    stdout_cap: usize,
+    stderr_cap: usize,
+    sense_tx: &mpsc::Sender<Arc<InlineSenseDatum>>,
+) -> std::io::Result<ShellRunOutput> {
+    let (chunk_tx, mut chunk_rx) = mpsc::unbounded_channel();
+    let mut readers = JoinSet::new();
+    if let Some(stdout) = child.stdout.take() {
+        readers.spawn(forward_lines("stdout", stdout, chunk_tx.clone()));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        readers.spawn(forward_lines("stderr", stderr, chunk_tx.clone()));
+    }
+    drop(chunk_tx);
+
+    let mut stdout_budget = StreamByteBudget::new(stdout_cap);
+    let mut stderr_budget = StreamByteBudget::new(stderr_cap);
+    let mut sequence = 0_u64;
+    while let Some((stream, line)) = chunk_rx.recv().await {
+        let budget = if stream == "stdout" {
+            &mut stdout_budget
+        } else {
+            &mut stderr_budget
+        };
+        let Some(admitted) = budget.admit(&line) else {
+            continue;
+        };
+        sequence += 1;
+        let sense = InlineSenseDatum {
+            sense_instance_id: uuid::Uuid::new_v4().to_string(),
+            neural_signal_descriptor_id: SHELL_SENSE_EXEC_OUTPUT_ID.to_string(),
+            payload: format!(
+                "shell_output act_instance_id={}; stream={}; sequence={}\n{}",
+                act.act_instance_id,
+                stream,
+                sequence,
+                String::from_utf8_lossy(admitted).trim_end_matches('\n')
+            ),
+            weight: 0.0,
+            act_instance_id: Some(act.act_instance_id.clone()),
+        };
+        let _ = sense_tx.send(Arc::new(sense)).await;
+    }
+    while let Some(reader) = readers.join_next().await {
+        reader.map_err(std::io::Error::other)??;
+    }
+
+    Ok(ShellRunOutput {
+        status: child.wait().await?,
+        stdout_text: String::new(),
+        stderr_text: String::new(),
+        stdout_truncated: stdout_budget.truncated,
+        stderr_truncated: stderr_budget.truncated,
+    })
+}
+
+async fn forward_lines(
+    stream: &'static str,
+    pipe: impl AsyncRead + Unpin,
+    chunk_tx: mpsc::UnboundedSender<(&'static str, Vec<u8>)>,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(pipe);
+    loop {
+        let mut line = Vec::new();
+        if reader.read_until(b'\n', &mut line).await? == 0 || chunk_tx.send((stream, line)).is_err()
+        {
+            return Ok(());
+        }
+    }
+}
+
+impl StreamByteBudget {
+    fn new(cap: usize) -> Self {
+        Self {
+            remaining: cap,
+            truncated: false,
+        }
+    }
+
+    /// Returns the part of `chunk` that still fits under the cap, or `None` once it is spent.
+    fn admit<'a>(&mut self, chunk: &'a [u8]) -> Option<&'a [u8]> {
+        let admitted = &chunk[..chunk.len().min(self.remaining)];
+        if admitted.len() < chunk.len() {
+            self.truncated = true;
+        }
+        self.remaining -= admitted.len();
+        (!admitted.is_empty()).then_some(admitted)
+    }
+}
+
 fn build_shell_result_payload(
     act: &Act,
     exit_code: i32,
@@ -194,3 +460,258 @@ fn truncate_to_text(bytes: &[u8], cap: usize) -> (String, bool) {
 
     (String::from_utf8_lossy(&bytes[..cap]).to_string(), true)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn streaming_shell_act(script: &str, stdout_max_bytes: usize) -> Act {
+        Act {
+            act_instance_id: "act-1".to_string(),
+            endpoint_id: "shell.1".to_string(),
+            neural_signal_descriptor_id: "shell.exec".to_string(),
+            might_emit_sense_ids: Vec::new(),
+            payload: serde_json::json!({
+                "argv": ["sh", "-c", script],
+                "stdout_max_bytes": stdout_max_bytes,
+                "stream": true
+            }),
+            goal_id: None,
+        }
+    }
+
+    fn drain_output_lines(sense_rx: &mut mpsc::Receiver<Arc<InlineSenseDatum>>) -> Vec<String> {
+        let mut lines = Vec::new();
+        while let Ok(sense) = sense_rx.try_recv() {
+            assert_eq!(
+                sense.neural_signal_descriptor_id,
+                SHELL_SENSE_EXEC_OUTPUT_ID
+            );
+            lines.push(sense.payload.clone());
+        }
+        lines
+    }
+
+    fn shell_act(payload: serde_json::Value) -> Act {
+        Act {
+            payload,
+            ..streaming_shell_act("", 1024)
+        }
+    }
+
+    /// Temp directory removed again when the test finishes.
+    struct SandboxDir(PathBuf);
+
+    impl std::ops::Deref for SandboxDir {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for SandboxDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn sandbox_dir() -> SandboxDir {
+        let dir = std::env::temp_dir().join(format!("beluna-shell-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).expect("sandbox dir should be created");
+        SandboxDir(dir)
+    }
+
+    fn sandboxed_limits(allowed_cwd_prefix: &Path) -> ShellLimits {
+        ShellLimits {
+            allowed_commands: vec!["echo".to_string()],
+            allowed_cwd_prefixes: vec![allowed_cwd_prefix.to_path_buf()],
+            ..ShellLimits::default()
+        }
+    }
+
+    fn rejection_reference(output: ShellHandlerOutput) -> Option<String> {
+        match output.outcome {
+            EndpointExecutionOutcome::Rejected {
+                reason_code,
+                reference_id,
+            } => {
+                assert_eq!(reason_code, "denied");
+                Some(reference_id)
+            }
+            _ => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn allowlisted_command_runs_inside_allowed_cwd() {
+        let sandbox = sandbox_dir();
+        let workdir = sandbox.join("work");
+        std::fs::create_dir(&workdir).expect("workdir should be created");
+        let (sense_tx, _sense_rx) = mpsc::channel(1);
+
+        let output = handle_shell_invoke(
+            "test",
+            &shell_act(serde_json::json!({
+                "argv": ["/bin/echo", "ok"],
+                "cwd": workdir.to_str().expect("utf-8 path")
+            })),
+            &sandboxed_limits(&sandbox),
+            &sense_tx,
+        )
+        .await;
+
+        assert!(matches!(
+            output.outcome,
+            EndpointExecutionOutcome::Applied { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn command_outside_allowlist_is_denied() {
+        let sandbox = sandbox_dir();
+        let (sense_tx, _sense_rx) = mpsc::channel(1);
+
+        let output = handle_shell_invoke(
+            "test",
+            &shell_act(serde_json::json!({
+                "argv": ["rm", "-rf", "scratch"],
+                "cwd": sandbox.to_str().expect("utf-8 path")
+            })),
+            &sandboxed_limits(&sandbox),
+            &sense_tx,
+        )
+        .await;
+
+        assert_eq!(
+            rejection_reference(output).as_deref(),
+            Some("body.std.shell:command_not_allowed:act-1")
+        );
+    }
+
+    #[tokio::test]
+    async fn path_qualified_impostor_of_allowed_command_is_denied() {
+        let sandbox = sandbox_dir();
+        let impostor = sandbox.join("echo");
+        std::fs::write(&impostor, "#!/bin/sh\ntouch pwned\n").expect("impostor should be written");
+        std::fs::set_permissions(&impostor, std::fs::Permissions::from_mode(0o755))
+            .expect("impostor should be executable");
+        let (sense_tx, _sense_rx) = mpsc::channel(1);
+
+        for program in ["./echo", impostor.to_str().expect("utf-8 path")] {
+            let output = handle_shell_invoke(
+                "test",
+                &shell_act(serde_json::json!({
+                    "argv": [program, "hi"],
+                    "cwd": sandbox.to_str().expect("utf-8 path")
+                })),
+                &sandboxed_limits(&sandbox),
+                &sense_tx,
+            )
+            .await;
+
+            assert_eq!(
+                rejection_reference(output).as_deref(),
+                Some("body.std.shell:command_not_allowed:act-1")
+            );
+        }
+        assert!(!sandbox.join("pwned").exists());
+    }
+
+    #[tokio::test]
+    async fn cwd_escaping_allowed_prefix_is_denied() {
+        let sandbox = sandbox_dir();
+        let escape = sandbox.join("..");
+        let (sense_tx, _sense_rx) = mpsc::channel(1);
+
+        let output = handle_shell_invoke(
+            "test",
+            &shell_act(serde_json::json!({
+                "argv": ["echo", "escaped"],
+                "cwd": escape.to_str().expect("utf-8 path")
+            })),
+            &sandboxed_limits(&sandbox),
+            &sense_tx,
+        )
+        .await;
+
+        assert_eq!(
+            rejection_reference(output).as_deref(),
+            Some("body.std.shell:cwd_not_allowed:act-1")
+        );
+    }
+
+    #[tokio::test]
+    async fn stdin_is_piped_into_the_child() {
+        let act = shell_act(serde_json::json!({
+            "argv": ["cat"],
+            "stdin": "hello from stdin"
+        }));
+        let (sense_tx, _sense_rx) = mpsc::channel(1);
+
+        let output = handle_shell_invoke("test", &act, &ShellLimits::default(), &sense_tx).await;
+
+        let result = output.sense.expect("result sense");
+        assert!(result.payload.contains("stdout:\nhello from stdin\n"));
+    }
+
+    #[tokio::test]
+    async fn streamed_lines_arrive_before_the_process_exits() {
+        let act = streaming_shell_act("echo one; sleep 0.3; echo two; echo three", 1024);
+        let (sense_tx, mut sense_rx) = mpsc::channel(16);
+        let invoke = tokio::spawn(async move {
+            handle_shell_invoke("test", &act, &ShellLimits::default(), &sense_tx).await
+        });
+
+        let first = timeout(Duration::from_secs(2), sense_rx.recv())
+            .await
+            .expect("first line should stream")
+            .expect("sense channel open");
+        assert!(!invoke.is_finished());
+        assert_eq!(
+            first.payload,
+            "shell_output act_instance_id=act-1; stream=stdout; sequence=1\none"
+        );
+
+        let output = invoke.await.expect("invoke should join");
+        assert_eq!(
+            drain_output_lines(&mut sense_rx),
+            vec![
+                "shell_output act_instance_id=act-1; stream=stdout; sequence=2\ntwo".to_string(),
+                "shell_output act_instance_id=act-1; stream=stdout; sequence=3\nthree".to_string(),
+            ]
+        );
+        assert!(matches!(
+            output.outcome,
+            EndpointExecutionOutcome::Applied { .. }
+        ));
+        let result = output.sense.expect("terminal result sense");
+        assert_eq!(
+            result.neural_signal_descriptor_id,
+            SHELL_SENSE_EXEC_RESULT_ID
+        );
+        assert!(
+            result
+                .payload
+                .contains("exit_code=0; stdout_truncated=false")
+        );
+    }
+
+    #[tokio::test]
+    async fn streamed_output_byte_cap_spans_chunks() {
+        let act = streaming_shell_act("echo one; echo two; echo three", 6);
+        let (sense_tx, mut sense_rx) = mpsc::channel(16);
+
+        let output = handle_shell_invoke("test", &act, &ShellLimits::default(), &sense_tx).await;
+
+        assert_eq!(
+            drain_output_lines(&mut sense_rx),
+            vec![
+                "shell_output act_instance_id=act-1; stream=stdout; sequence=1\none".to_string(),
+                "shell_output act_instance_id=act-1; stream=stdout; sequence=2\ntw".to_string(),
+            ]
+        );
+        let result = output.sense.expect("terminal result sense");
+        assert!(result.payload.contains("stdout_truncated=true"));
+    }
+}
@@ -1,6 +1,13 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
 use reqwest::{
-    Client, Method, Url,
-    header::{HeaderMap, HeaderName, HeaderValue},
+    Client, Method, Proxy, Url,
+    dns::{Addrs, Name, Resolve, Resolving},
+    header::{HeaderMap, HeaderName, HeaderValue, LOCATION},
+    redirect,
 };
 use tokio::time::{Duration, timeout};
 
@@ -88,7 +95,11 @@ pub async fn handle_web_invoke(
         }
     };
 
-    let client = match Client::builder().no_proxy().build() {
+    if !limits.allow_private_network && targets_private_network(&url).await {
+        return private_network_blocked(act, &url);
+    }
+
+    let client = match build_client(limits) {
         Ok(client) => client,
         Err(_) => {
             return WebHandlerOutput {
@@ -113,6 +124,10 @@ pub async fn handle_web_invoke(
 
     let response = match timeout(Duration::from_millis(timeout_ms), builder.send()).await {
         Ok(Ok(response)) => response,
+        // A redirect or DNS answer that lands on a private address fails the send.
+        Ok(Err(err)) if is_private_network_block(&err) => {
+            return private_network_blocked(act, &url);
+        }
         Ok(Err(_)) => {
             return WebHandlerOutput {
                 outcome: EndpointExecutionOutcome::Rejected {
@@ -135,6 +150,14 @@ pub async fn handle_web_invoke(
 
     let status_code = response.status().as_u16();
     let final_url = response.url().to_string();
+    let redirect_location = response
+        .status()
+        .is_redirection()
+        .then(|| response.headers().get(LOCATION))
+        .flatten()
+        .and_then(|location| location.to_str().ok())
+        .map(|location| format!("; location={location}"))
+        .unwrap_or_default();
 
     let body_bytes = match timeout(Duration::from_millis(timeout_ms), response.bytes()).await {
         Ok(Ok(bytes)) => bytes,
@@ -171,13 +194,14 @@ pub async fn handle_web_invoke(
             payload: format!(
                 concat!(
                     "web_fetch_result act_instance_id={}; neural_signal_descriptor_id={}; ",
-                    "url={}; status_code={}; body_truncated={}; success=true\n",
+                    "url={}; status_code={}{}; body_truncated={}; success=true\n",
                     "body:\n{}"
                 ),
                 act.act_instance_id,
                 act.neural_signal_descriptor_id,
                 final_url,
                 status_code,
+                redirect_location,
                 body_truncated,
                 body_text
             ),
@@ -187,6 +211,160 @@ pub async fn handle_web_invoke(
     }
 }
 
+fn private_network_blocked(act: &Act, url: &Url) -> WebHandlerOutput {
+    WebHandlerOutput {
+        outcome: EndpointExecutionOutcome::Rejected {
+            reason_code: "private_network_blocked".to_string(),
+            reference_id: format!(
+                "body.std.web:private_network_blocked:{}",
+                act.act_instance_id
+            ),
+        },
+        sense: Some(InlineSenseDatum {
+            sense_instance_id: uuid::Uuid::new_v4().to_string(),
+            neural_signal_descriptor_id: WEB_SENSE_FETCH_RESULT_ID.to_string(),
+            payload: format!(
+                concat!(
+                    "web_fetch_result act_instance_id={}; neural_signal_descriptor_id={}; ",
+                    "url={}; reason_code=private_network_blocked; success=false"
+                ),
+                act.act_instance_id, act.neural_signal_descriptor_id, url
+            ),
+            weight: 1.0,
+            act_instance_id: Some(act.act_instance_id.clone()),
+        }),
+    }
+}
+
+/// Raised by the redirect policy and resolver when a hop would reach a private address.
+#[derive(Debug)]
+struct PrivateNetworkBlocked(String);
+
+impl std::fmt::Display for PrivateNetworkBlocked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is a private network address", self.0)
+    }
+}
+
+impl std::error::Error for PrivateNetworkBlocked {}
+
+fn is_private_network_block(err: &reqwest::Error) -> bool {
+    let mut source: Option<&(dyn std::error::Error + 'static)> = Some(err);
+    while let Some(current) = source {
+        if current.is::<PrivateNetworkBlocked>() {
+            return true;
+        }
+        source = current.source();
+    }
+    false
+}
+
+fn build_client(limits: &WebLimits) -> reqwest::Result<Client> {
+    let max_redirects = limits.max_redirects as usize;
+    let allow_private_network = limits.allow_private_network;
+    let redirect_policy = redirect::Policy::custom(move |attempt| {
+        if max_redirects == 0 {
+            attempt.stop()
+        } else if attempt.previous().len() > max_redirects {
+            attempt.error("too many redirects")
+        } else if !allow_private_network && host_is_private_ip(attempt.url()) {
+            let host = attempt.url().host_str().unwrap_or_default().to_string();
+            attempt.error(PrivateNetworkBlocked(host))
+        } else {
+            attempt.follow()
+        }
+    });
+    let builder = Client::builder().redirect(redirect_policy);
+    match &limits.proxy_url {
+        // The proxy resolves targets itself and may legitimately live on a private address.
+        Some(proxy_url) => builder.proxy(Proxy::all(proxy_url)?).build(),
+        None if allow_private_network => builder.no_proxy().build(),
+        None => builder
+            .no_proxy()
+            .dns_resolver(Arc::new(PublicNetworkResolver))
+            .build(),
+    }
+}
+
+/// Drops private addresses from DNS answers so redirects and rebinding cannot reach them.
+struct PublicNetworkResolver;
+
+impl Resolve for PublicNetworkResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let public = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|address| !is_private_network_ip(address.ip()))
+                .collect::<Vec<SocketAddr>>();
+            if public.is_empty() {
+                return Err(PrivateNetworkBlocked(name.as_str().to_string()).into());
+            }
+            Ok(Box::new(public.into_iter()) as Addrs)
+        })
+    }
+}
+
+async fn targets_private_network(url: &Url) -> bool {
+    if let Some(ip) = host_ip(url) {
+        return is_private_network_ip(ip);
+    }
+    let (Some(domain), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+        return false;
+    };
+    match tokio::net::lookup_host((domain, port)).await {
+        Ok(mut addresses) => addresses.any(|address| is_private_network_ip(address.ip())),
+        // Unresolvable hosts fail later as ordinary network errors.
+        Err(_) => false,
+    }
+}
+
+fn host_is_private_ip(url: &Url) -> bool {
+    host_ip(url).is_some_and(is_private_network_ip)
+}
+
+fn host_ip(url: &Url) -> Option<IpAddr> {
+    url.host_str()?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse()
+        .ok()
+}
+
+/// Anything not routable on the public internet: loopback, RFC 1918, shared, link-local (cloud
+/// metadata), benchmarking, "this network", reserved, multicast, and unique-local ranges.
+///
+/// IPv6 forms that embed an IPv4 address (mapped, compatible, NAT64) are judged by that address.
+fn is_private_network_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_multicast()
+                || first == 0
+                || first >= 240
+                || (first == 100 && (64..128).contains(&second))
+                || (first == 198 && (18..20).contains(&second))
+        }
+        IpAddr::V6(ip) => {
+            let segments = ip.segments();
+            if let Some(embedded) = ip.to_ipv4() {
+                return is_private_network_ip(IpAddr::V4(embedded));
+            }
+            if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+                let [.., high, low] = segments;
+                let embedded = (u32::from(high) << 16) | u32::from(low);
+                return is_private_network_ip(IpAddr::V4(embedded.into()));
+            }
+            ip.is_multicast()
+                || ip.is_unique_local()
+                || ip.is_unicast_link_local()
+                || segments[..3] == [0x64, 0xff9b, 1]
+        }
+    }
+}
+
 fn build_headers(
     values: &std::collections::BTreeMap<String, String>,
 ) -> Result<HeaderMap, &'static str> {
@@ -207,3 +385,186 @@ fn truncate_to_text(bytes: &[u8], cap: usize) -> (String, bool) {
 
     (String::from_utf8_lossy(&bytes[..cap]).to_string(), true)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::local_http::{LocalJsonServer, ScriptedResponse};
+
+    /// Scripts `/start` as a 302 to `/final`.
+    fn moved() -> ScriptedResponse {
+        ScriptedResponse::text(302, "moved").with_header("location", "/final")
+    }
+
+    fn landed() -> ScriptedResponse {
+        ScriptedResponse::text(200, "landed")
+    }
+
+    fn fetch_act(url: String) -> Act {
+        Act {
+            act_instance_id: "act-1".to_string(),
+            endpoint_id: "web.1".to_string(),
+            neural_signal_descriptor_id: "web.fetch".to_string(),
+            might_emit_sense_ids: Vec::new(),
+            payload: serde_json::json!({ "url": url }),
+            goal_id: None,
+        }
+    }
+
+    /// The test server listens on loopback, which the default limits block.
+    fn loopback_limits() -> WebLimits {
+        WebLimits {
+            allow_private_network: true,
+            ..WebLimits::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn loopback_fetch_is_blocked_by_default() {
+        let server = LocalJsonServer::start_scripted(vec![landed()]).await;
+        let base_url = server.base_url();
+
+        let output = handle_web_invoke(
+            "test",
+            &fetch_act(format!("{base_url}/final")),
+            &WebLimits::default(),
+        )
+        .await;
+
+        assert!(matches!(
+            output.outcome,
+            EndpointExecutionOutcome::Rejected { ref reason_code, .. }
+                if reason_code == "private_network_blocked"
+        ));
+        let sense = output.sense.expect("failure sense");
+        assert!(
+            sense
+                .payload
+                .ends_with("reason_code=private_network_blocked; success=false")
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_is_applied_when_private_network_is_allowed() {
+        let server = LocalJsonServer::start_scripted(vec![landed()]).await;
+        let base_url = server.base_url();
+
+        let output = handle_web_invoke(
+            "test",
+            &fetch_act(format!("{base_url}/final")),
+            &loopback_limits(),
+        )
+        .await;
+
+        assert!(matches!(
+            output.outcome,
+            EndpointExecutionOutcome::Applied { .. }
+        ));
+    }
+
+    #[test]
+    fn metadata_and_private_ranges_are_classified_private() {
+        for private in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "::1",
+            "fd00:ec2::254",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "0.1.2.3",
+            "198.18.0.1",
+            "198.19.255.255",
+            "224.0.0.1",
+            "ff02::1",
+            "64:ff9b::a9fe:a9fe",
+            "64:ff9b:1::1",
+            "::127.0.0.1",
+            "::",
+        ] {
+            assert!(
+                is_private_network_ip(private.parse().expect("ip")),
+                "{private} should be private"
+            );
+        }
+        for public in [
+            "93.184.216.34",
+            "198.20.0.1",
+            "2606:4700::1111",
+            "64:ff9b::5db8:d822",
+        ] {
+            assert!(
+                !is_private_network_ip(public.parse().expect("ip")),
+                "{public} should be public"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn redirect_and_dns_answers_reaching_private_addresses_are_classified_blocked() {
+        let server = LocalJsonServer::start_scripted(vec![moved()]).await;
+        let base_url = server.base_url();
+        let client = build_client(&WebLimits::default()).expect("client should build");
+
+        // The literal loopback start skips the resolver, so only the redirect hop is checked.
+        let redirected = client
+            .get(format!("{base_url}/start"))
+            .send()
+            .await
+            .expect_err("redirect to loopback should fail");
+        assert!(is_private_network_block(&redirected));
+
+        let port = Url::parse(&base_url)
+            .expect("base url")
+            .port()
+            .expect("port");
+        let resolved = client
+            .get(format!("http://localhost:{port}/final"))
+            .send()
+            .await
+            .expect_err("localhost should not resolve publicly");
+        assert!(is_private_network_block(&resolved));
+    }
+
+    #[tokio::test]
+    async fn redirect_is_followed_within_limit() {
+        let server = LocalJsonServer::start_scripted(vec![moved(), landed()]).await;
+        let base_url = server.base_url();
+
+        let output = handle_web_invoke(
+            "test",
+            &fetch_act(format!("{base_url}/start")),
+            &loopback_limits(),
+        )
+        .await;
+
+        let payload = output.sense.expect("result sense").payload;
+        assert!(payload.contains(&format!("url={base_url}/final; status_code=200;")));
+        assert!(payload.ends_with("body:\nlanded"));
+    }
+
+    #[tokio::test]
+    async fn redirect_is_surfaced_when_redirects_are_disabled() {
+        let server = LocalJsonServer::start_scripted(vec![moved()]).await;
+        let base_url = server.base_url();
+
+        let output = handle_web_invoke(
+            "test",
+            &fetch_act(format!("{base_url}/start")),
+            &WebLimits {
+                max_redirects: 0,
+                ..loopback_limits()
+            },
+        )
+        .await;
+
+        let payload = output.sense.expect("result sense").payload;
+        assert!(payload.contains(&format!(
+            "url={base_url}/start; status_code=302; location=/final;"
+        )));
+        assert!(payload.ends_with("body:\nmoved"));
+    }
+}
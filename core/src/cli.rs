@@ -2,9 +2,37 @@ use std::{env, path::PathBuf};
 
 use anyhow::{Result, anyhow};
 
+use crate::stem::SenseReplayPacing;
+
+const RUN_USAGE: &str =
+    "usage: beluna [--config <path>] [--replay-senses <path> [--replay-as-fast-as-possible]]";
+const LEDGER_EXPORT_USAGE: &str =
+    "usage: beluna ledger export [--config <path>] [--after <seq_no>] [--limit <count>]";
+const DEFAULT_LEDGER_EXPORT_LIMIT: usize = 1_000;
+
 pub enum CliCommand {
-    Run { config_path: PathBuf },
-    ConfigSchema { output: Option<PathBuf> },
+    Run {
+        config_path: PathBuf,
+        sense_replay: Option<SenseReplayArgs>,
+    },
+    ConfigSchema {
+        output: Option<PathBuf>,
+    },
+    ConfigShow {
+        config_path: PathBuf,
+    },
+    /// Dumps one page of the persisted survival ledger's entries as NDJSON.
+    LedgerExport {
+        config_path: PathBuf,
+        after_seq_no: u64,
+        limit: usize,
+    },
+}
+
+/// Recorded sense stream to feed through the afferent pathway once the agent is running.
+pub struct SenseReplayArgs {
+    pub recording_path: PathBuf,
+    pub pacing: SenseReplayPacing,
 }
 
 pub fn command_from_args() -> Result<CliCommand> {
@@ -12,37 +40,89 @@ pub fn command_from_args() -> Result<CliCommand> {
     if args.is_empty() {
         return Ok(CliCommand::Run {
             config_path: PathBuf::from("./beluna.jsonc"),
+            sense_replay: None,
         });
     }
 
     match args[0].as_str() {
-        "--config" => parse_run_command(&args),
+        "--config" | "--replay-senses" | "--replay-as-fast-as-possible" => parse_run_command(&args),
         "config" => parse_config_command(&args),
+        "ledger" => parse_ledger_command(&args),
         other => Err(anyhow!(
-            "unknown argument: {other}. usage: beluna [--config <path>] | beluna config schema [--output <path>]"
+            "unknown argument: {other}. {RUN_USAGE} | beluna config schema [--output <path>] | beluna config show [--config <path>] | {}",
+            LEDGER_EXPORT_USAGE.trim_start_matches("usage: ")
         )),
     }
 }
 
 fn parse_run_command(args: &[String]) -> Result<CliCommand> {
-    if args.len() != 2 {
+    let mut config_path = PathBuf::from("./beluna.jsonc");
+    let mut recording_path: Option<PathBuf> = None;
+    let mut pacing = SenseReplayPacing::Recorded;
+    let mut index = 0;
+    while index < args.len() {
+        match args[index].as_str() {
+            flag @ ("--config" | "--replay-senses") => {
+                let value = args
+                    .get(index + 1)
+                    .ok_or_else(|| anyhow!("missing value for {flag}. {RUN_USAGE}"))?;
+                if flag == "--config" {
+                    config_path = PathBuf::from(value);
+                } else {
+                    recording_path = Some(PathBuf::from(value));
+                }
+                index += 2;
+            }
+            "--replay-as-fast-as-possible" => {
+                pacing = SenseReplayPacing::AsFastAsPossible;
+                index += 1;
+            }
+            other => {
+                return Err(anyhow!("invalid run argument: {other}. {RUN_USAGE}"));
+            }
+        }
+    }
+
+    if recording_path.is_none() && pacing == SenseReplayPacing::AsFastAsPossible {
         return Err(anyhow!(
-            "invalid run arguments. usage: beluna [--config <path>]"
+            "--replay-as-fast-as-possible requires --replay-senses. {RUN_USAGE}"
         ));
     }
 
     Ok(CliCommand::Run {
-        config_path: PathBuf::from(&args[1]),
+        config_path,
+        sense_replay: recording_path.map(|recording_path| SenseReplayArgs {
+            recording_path,
+            pacing,
+        }),
     })
 }
 
 fn parse_config_command(args: &[String]) -> Result<CliCommand> {
-    if args.get(1).map(String::as_str) != Some("schema") {
-        return Err(anyhow!(
-            "unknown config subcommand. usage: beluna config schema [--output <path>]"
-        ));
+    match args.get(1).map(String::as_str) {
+        Some("schema") => parse_config_schema_command(args),
+        Some("show") => parse_config_show_command(args),
+        _ => Err(anyhow!(
+            "unknown config subcommand. usage: beluna config schema [--output <path>] | beluna config show [--config <path>]"
+        )),
+    }
+}
+
+fn parse_config_show_command(args: &[String]) -> Result<CliCommand> {
+    match &args[2..] {
+        [] => Ok(CliCommand::ConfigShow {
+            config_path: PathBuf::from("./beluna.jsonc"),
+        }),
+        [flag, value] if flag == "--config" => Ok(CliCommand::ConfigShow {
+            config_path: PathBuf::from(value),
+        }),
+        _ => Err(anyhow!(
+            "invalid show arguments. usage: beluna config show [--config <path>]"
+        )),
     }
+}
 
+fn parse_config_schema_command(args: &[String]) -> Result<CliCommand> {
     let mut output: Option<PathBuf> = None;
     let mut index = 2;
     while index < args.len() {
@@ -66,3 +146,49 @@ fn parse_config_command(args: &[String]) -> Result<CliCommand> {
 
     Ok(CliCommand::ConfigSchema { output })
 }
+
+fn parse_ledger_command(args: &[String]) -> Result<CliCommand> {
+    if args.get(1).map(String::as_str) != Some("export") {
+        return Err(anyhow!("unknown ledger subcommand. {LEDGER_EXPORT_USAGE}"));
+    }
+
+    let mut config_path = PathBuf::from("./beluna.jsonc");
+    let mut after_seq_no = 0;
+    let mut limit = DEFAULT_LEDGER_EXPORT_LIMIT;
+    let mut index = 2;
+    while index < args.len() {
+        let flag = args[index].as_str();
+        let value = args
+            .get(index + 1)
+            .ok_or_else(|| anyhow!("missing value for {flag}. {LEDGER_EXPORT_USAGE}"))?;
+        match flag {
+            "--config" => config_path = PathBuf::from(value),
+            "--after" => {
+                after_seq_no = value.parse().map_err(|_| {
+                    anyhow!("invalid --after value: {value}. {LEDGER_EXPORT_USAGE}")
+                })?;
+            }
+            "--limit" => {
+                limit = value
+                    .parse()
+                    .ok()
+                    .filter(|limit| *limit > 0)
+                    .ok_or_else(|| {
+                        anyhow!("invalid --limit value: {value}. {LEDGER_EXPORT_USAGE}")
+                    })?;
+            }
+            other => {
+                return Err(anyhow!(
+                    "unknown argument for ledger export: {other}. {LEDGER_EXPORT_USAGE}"
+                ));
+            }
+        }
+        index += 2;
+    }
+
+    Ok(CliCommand::LedgerExport {
+        config_path,
+        after_seq_no,
+        limit,
+    })
+}
@@ -10,6 +10,10 @@ use validator::Validate;
 
 use crate::ai_gateway::types::AIGatewayConfig;
 
+/// Object keys whose string values are replaced by [`REDACTED_VALUE`] in [`Config::redacted_json`].
+const SECRET_KEYS: &[&str] = &["token", "api_key", "password", "secret", "authorization"];
+pub const REDACTED_VALUE: &str = "[redacted]";
+
 mod body;
 mod continuity;
 mod cortex;
@@ -20,18 +24,31 @@ mod schema;
 mod spine;
 mod validation;
 
-pub use body::{BodyRuntimeConfig, StdShellRuntimeConfig, StdWebRuntimeConfig};
+pub use body::{
+    BodyRuntimeConfig, IntrospectionEndpointConfig, StdFsRuntimeConfig, StdShellRuntimeConfig,
+    StdWebRuntimeConfig,
+};
 pub use continuity::ContinuityRuntimeConfig;
-pub use cortex::{CortexRoutesConfig, CortexRuntimeConfig};
-pub use logging::LoggingConfig;
+pub use cortex::{
+    ActCatalogMode, CognitionStateSizeLimitConfig, CortexOrganPresetsConfig, CortexRoutesConfig,
+    CortexRuntimeConfig, CycleRateLimitConfig, EndpointSensePolicyRule, IrSectionEscaping,
+    IrSectionLimits, LatencyDowngradeConfig, ModelParameterPreset, PrimaryOutputCacheConfig,
+    SenseBatchOrdering, SenseLane, SenseLaneRule, SensePayloadLogging, SenseProcessingPolicy,
+    StrictSchemaFallbackConfig, UnknownCapabilityActs,
+};
+pub use logging::{LoggingConfig, StderrLogFormat};
 pub use observability::{
     ObservabilityConfig, OtlpConfig, OtlpDefaultsConfig, OtlpLogsConfig, OtlpMetricsConfig,
     OtlpSignalProtocol, OtlpSignalsConfig, OtlpTracesConfig,
 };
-pub use runtime_loop::{CoreLoopConfig, TickMissedBehavior};
+pub use runtime_loop::{
+    ActConfirmationConfig, CoreLoopConfig, SenseQueueWatermarksConfig, TickMissedBehavior,
+};
 pub use schema::{generate_schema_json_pretty, generate_schema_value, write_schema_to_path};
 pub use spine::{
-    InlineAdapterConfig, SpineAdapterConfig, SpineRuntimeConfig, UnixSocketNdjsonAdapterConfig,
+    BodyEndpointHeartbeatConfig, DuplicateAuthPolicy, EndpointErrorBudgetConfig,
+    EndpointNameNormalization, EndpointNameRulesConfig, InlineAdapterConfig, SpineAdapterConfig,
+    SpineRuntimeConfig, TcpNdjsonAdapterConfig, UnixSocketNdjsonAdapterConfig,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
@@ -78,14 +95,27 @@ impl Config {
         config
             .validate()
             .map_err(|err| anyhow!("config validation failed: {err}"))?;
+        config
+            .cortex
+            .routes
+            .validate_against_gateway(&config.ai_gateway)
+            .map_err(|err| anyhow!("config validation failed: {err}"))?;
 
         Ok(config)
     }
 
+    /// The effective config as JSON, with credential and token values replaced by `[redacted]`.
+    pub fn redacted_json(&self) -> Result<serde_json::Value> {
+        let mut value = serde_json::to_value(self).context("failed to serialize config")?;
+        redact_secret_values(&mut value);
+        Ok(value)
+    }
+
     fn normalize_paths(&mut self, config_base: &Path) {
         normalize_path_against_base(&mut self.logging.dir, config_base);
         self.spine.normalize_paths(config_base);
         normalize_path_against_base(&mut self.continuity.state_path, config_base);
+        normalize_path_against_base(&mut self.body.std_fs.limits.root, config_base);
     }
 }
 
@@ -105,3 +135,59 @@ fn normalize_path_against_base(path: &mut PathBuf, config_base: &Path) {
         *path = config_base.join(&*path);
     }
 }
+
+fn redact_secret_values(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(entries) => {
+            for (key, entry) in entries.iter_mut() {
+                if entry.is_string() && SECRET_KEYS.contains(&key.to_ascii_lowercase().as_str()) {
+                    *entry = serde_json::Value::String(REDACTED_VALUE.to_string());
+                } else {
+                    redact_secret_values(entry);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_secret_values),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacted_json_matches_loaded_config_except_credentials() {
+        let dir = std::env::temp_dir().join(format!("beluna-config-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).expect("temp dir should be created");
+        let config_path = dir.join("beluna.jsonc");
+        fs::write(
+            &config_path,
+            r#"{
+                // inline credentials must never surface in the effective config
+                ai_gateway: {
+                    backends: [{
+                        id: "openai",
+                        dialect: "openai_responses",
+                        credential: { type: "inline_token", token: "sk-live-secret" },
+                        models: [{ id: "gpt-4.1-mini" }],
+                    }],
+                },
+            }"#,
+        )
+        .expect("config should be written");
+        let config = Config::load(&config_path).expect("config should load");
+
+        let redacted = config.redacted_json().expect("config should serialize");
+
+        let credential = &redacted["ai_gateway"]["backends"][0]["credential"];
+        assert_eq!(credential["type"], "inline_token");
+        assert_eq!(credential["token"], REDACTED_VALUE);
+        assert!(!redacted.to_string().contains("sk-live-secret"));
+        let mut expected = serde_json::to_value(&config).expect("config should serialize");
+        expected["ai_gateway"]["backends"][0]["credential"]["token"] =
+            serde_json::Value::String(REDACTED_VALUE.to_string());
+        assert_eq!(redacted, expected);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
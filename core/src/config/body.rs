@@ -2,7 +2,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-use crate::body::payloads::{ShellLimits, WebLimits};
+use crate::body::payloads::{FsLimits, ShellLimits, WebLimits};
 
 fn default_enabled_true() -> bool {
     true
@@ -17,6 +17,15 @@ pub struct BodyRuntimeConfig {
     #[serde(default)]
     #[validate(nested)]
     pub std_web: StdWebRuntimeConfig,
+    #[serde(default)]
+    #[validate(nested)]
+    pub std_fs: StdFsRuntimeConfig,
+    /// The `gateway` endpoint, which lets the model query backend circuit breaker states.
+    #[serde(default)]
+    pub gateway_health: IntrospectionEndpointConfig,
+    /// The `cognition` endpoint, which lets the model diff its cognition state between queries.
+    #[serde(default)]
+    pub cognition_diff: IntrospectionEndpointConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
@@ -56,3 +65,22 @@ impl Default for StdWebRuntimeConfig {
         }
     }
 }
+
+/// Off by default: unlike shell and web, it needs a deliberately chosen `limits.root`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StdFsRuntimeConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    #[validate(nested)]
+    pub limits: FsLimits,
+}
+
+/// Off by default: introspection endpoints expose Core internals to the model.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct IntrospectionEndpointConfig {
+    #[serde(default)]
+    pub enabled: bool,
+}
@@ -16,12 +16,19 @@ pub struct ContinuityRuntimeConfig {
     #[serde(default = "default_continuity_state_path")]
     #[validate(custom(function = "validate_non_empty_path"))]
     pub state_path: PathBuf,
+    /// Persist continuity state this often while running, bounding what a crash can lose.
+    ///
+    /// Unset keeps flushing to record writes and shutdown only.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub flush_interval_ms: Option<u64>,
 }
 
 impl Default for ContinuityRuntimeConfig {
     fn default() -> Self {
         Self {
             state_path: default_continuity_state_path(),
+            flush_interval_ms: None,
         }
     }
 }
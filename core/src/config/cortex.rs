@@ -1,8 +1,13 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
-use crate::cortex::ReactionLimits;
+use crate::{
+    ai_gateway::{chat::ReasoningEffort, types::AIGatewayConfig},
+    cortex::{ReactionLimitBounds, ReactionLimits},
+};
 
 use super::validation::validate_non_blank;
 
@@ -14,6 +19,190 @@ fn default_cortex_outbox_capacity() -> usize {
     32
 }
 
+fn default_strict_schema_failure_threshold() -> u32 {
+    3
+}
+
+fn default_primary_output_cache_ttl_ms() -> u64 {
+    5_000
+}
+
+fn default_primary_output_cache_capacity() -> usize {
+    64
+}
+
+fn default_cycle_rate_burst() -> u32 {
+    1
+}
+
+fn default_trimmable_goal_statuses() -> Vec<String> {
+    vec!["done".to_string(), "completed".to_string()]
+}
+
+fn default_strict_schema_retry_after_cycles() -> u64 {
+    20
+}
+
+fn default_latency_downgrade_window() -> usize {
+    20
+}
+
+fn default_latency_downgrade_min_samples() -> usize {
+    5
+}
+
+fn default_latency_downgrade_probe_every_calls() -> u32 {
+    10
+}
+
+fn default_act_catalog_mode() -> ActCatalogMode {
+    ActCatalogMode::FullSchema
+}
+
+/// How act descriptors are rendered into Primary dynamic act tools.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ActCatalogMode {
+    /// Expose the full descriptor payload schema on every act tool.
+    FullSchema,
+    /// Expose a deterministic summary (required fields, types) derived from the payload schema.
+    /// Cheaper for large catalogs; full schema validation still applies on dispatch.
+    SchemaSummary,
+}
+
+fn default_ir_section_escaping() -> IrSectionEscaping {
+    IrSectionEscaping::Cdata
+}
+
+/// How section bodies are kept from closing the Input IR tags they are embedded in.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IrSectionEscaping {
+    /// Wrap each non-empty section body in a CDATA block; body text stays verbatim.
+    Cdata,
+    /// Replace `&`, `<` and `>` in section bodies with XML entities.
+    Entities,
+}
+
+/// Per-section byte caps on Input IR bodies; unset sections are not capped.
+///
+/// Oversized sections keep whole leading entries (lines) within the cap and end with a
+/// `[truncated N entries]` marker.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct IrSectionLimits {
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub senses_max_bytes: Option<usize>,
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub proprioception_max_bytes: Option<usize>,
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub goal_forest_max_bytes: Option<usize>,
+}
+
+/// How sense payload text appears in `cortex_organ_input` logs.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SensePayloadLogging {
+    /// Log payloads verbatim.
+    #[default]
+    Full,
+    /// Replace payload text with its byte length.
+    Redact,
+    /// Replace payload text with a short SHA-256 digest, so repeated payloads stay correlatable.
+    Hash,
+}
+
+/// Where the cortex runtime handles senses tagged by a lane rule.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SenseLane {
+    /// Queue the sense for the next primary cycle.
+    #[default]
+    Primary,
+    /// Only record the latest sense per endpoint and descriptor into the situation view, which
+    /// Primary sees in its proprioception section.
+    SituationView,
+}
+
+/// Order of the senses handed to one cycle.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SenseBatchOrdering {
+    /// Arrival order.
+    #[default]
+    Fifo,
+    /// Higher sense weight first; equal weights keep arrival order.
+    Priority,
+}
+
+/// How the cortex runtime treats senses from a matching endpoint as they arrive.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SenseProcessingPolicy {
+    /// Queue the sense for the next cycle.
+    #[default]
+    Batch,
+    /// Start a cycle as soon as the sense arrives, carrying everything already queued.
+    ImmediateFlush,
+    /// Keep only the latest queued sense per endpoint and descriptor.
+    Compact,
+    /// Drop a sense whose payload repeats the previous one from the same endpoint and descriptor.
+    DropDuplicates,
+}
+
+/// Applies a sense-processing policy to senses from matching endpoints.
+///
+/// `endpoint_name` matches the name an endpoint authenticated with, regardless of the
+/// runtime suffix Spine assigns. When both selectors are set, a sense must match both.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+#[validate(schema(function = "validate_endpoint_sense_policy_selector"))]
+pub struct EndpointSensePolicyRule {
+    #[serde(default)]
+    #[validate(custom(function = "validate_non_blank"))]
+    pub endpoint_name: Option<String>,
+    #[serde(default)]
+    pub neural_signal_descriptor_ids: Vec<String>,
+    pub policy: SenseProcessingPolicy,
+}
+
+fn validate_endpoint_sense_policy_selector(
+    rule: &EndpointSensePolicyRule,
+) -> Result<(), ValidationError> {
+    if rule.endpoint_name.is_none() && rule.neural_signal_descriptor_ids.is_empty() {
+        return Err(ValidationError::new(
+            "endpoint_sense_policy_requires_endpoint_name_or_descriptor_ids",
+        ));
+    }
+    Ok(())
+}
+
+/// What the acts helper does with acts naming a capability missing from the live catalog.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UnknownCapabilityActs {
+    /// Drop the act before dispatch and emit `ActDroppedUnknownCapability`.
+    #[default]
+    Drop,
+    /// Dispatch it anyway and let Spine report `endpoint_not_found`.
+    Dispatch,
+}
+
+/// Tags senses with matching descriptor ids and routes them to one lane.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SenseLaneRule {
+    #[validate(length(min = 1))]
+    pub tag: String,
+    #[validate(length(min = 1))]
+    pub neural_signal_descriptor_ids: Vec<String>,
+    #[serde(default)]
+    pub lane: SenseLane,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct CortexRoutesConfig {
@@ -46,8 +235,111 @@ impl Default for CortexRoutesConfig {
     }
 }
 
+impl CortexRoutesConfig {
+    /// Rejects stage routes naming an alias that no `ai_gateway` model declares.
+    ///
+    /// Unset stages use the gateway default route on purpose; a set but unknown alias
+    /// would otherwise only surface as a failing organ call on every cycle.
+    pub fn validate_against_gateway(&self, gateway: &AIGatewayConfig) -> Result<(), String> {
+        let known_aliases = gateway
+            .backends
+            .iter()
+            .flat_map(|backend| backend.models.iter())
+            .flat_map(|model| model.aliases.iter())
+            .map(|alias| alias.trim())
+            .collect::<BTreeSet<_>>();
+        let stage_routes = [
+            ("primary", &self.primary),
+            ("sense_helper", &self.sense_helper),
+            ("acts_helper", &self.acts_helper),
+            ("attention", &self.attention),
+            ("cleanup", &self.cleanup),
+        ];
+        for (stage, route) in stage_routes {
+            let Some(alias) = route.as_deref().map(str::trim) else {
+                continue;
+            };
+            if !known_aliases.contains(alias) {
+                return Err(format!(
+                    "cortex.routes.{stage} references unknown ai_gateway alias '{alias}'"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Named model parameters an organ stage can be assigned; unset fields keep the request defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ModelParameterPreset {
+    #[serde(default)]
+    #[validate(range(min = 0.0, max = 2.0))]
+    pub temperature: Option<f64>,
+    #[serde(default)]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub top_p: Option<f64>,
+    /// Replaces the stage's configured output-token cap.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub max_output_tokens: Option<u64>,
+    #[serde(default)]
+    pub reasoning_effort: Option<ReasoningEffort>,
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+/// `model_presets` names assigned to each organ stage; unassigned stages use no preset.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CortexOrganPresetsConfig {
+    #[serde(default)]
+    pub primary: Option<String>,
+    #[serde(default)]
+    pub attention: Option<String>,
+    #[serde(default)]
+    pub cleanup: Option<String>,
+    #[serde(default)]
+    pub sense_helper: Option<String>,
+    #[serde(default)]
+    pub goal_forest_helper: Option<String>,
+    #[serde(default)]
+    pub acts_helper: Option<String>,
+}
+
+impl CortexOrganPresetsConfig {
+    pub(crate) fn stage_presets(&self) -> [(&'static str, &Option<String>); 6] {
+        [
+            ("primary", &self.primary),
+            ("attention", &self.attention),
+            ("cleanup", &self.cleanup),
+            ("sense_helper", &self.sense_helper),
+            ("goal_forest_helper", &self.goal_forest_helper),
+            ("acts_helper", &self.acts_helper),
+        ]
+    }
+}
+
+fn validate_organ_preset_names(config: &CortexRuntimeConfig) -> Result<(), ValidationError> {
+    for (stage, preset) in config.organ_presets.stage_presets() {
+        let Some(preset) = preset else {
+            continue;
+        };
+        if !config.model_presets.contains_key(preset) {
+            let mut err = ValidationError::new("unknown_model_preset");
+            err.message = Some(
+                format!("cortex.organ_presets.{stage} references unknown model preset '{preset}'")
+                    .into(),
+            );
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
 #[serde(deny_unknown_fields)]
+#[validate(schema(function = "validate_organ_preset_names"))]
 pub struct CortexRuntimeConfig {
     #[serde(default = "default_cortex_inbox_capacity")]
     #[validate(range(min = 1))]
@@ -61,6 +353,191 @@ pub struct CortexRuntimeConfig {
     #[serde(default)]
     #[validate(nested)]
     pub routes: CortexRoutesConfig,
+    #[serde(default = "default_act_catalog_mode")]
+    pub act_catalog_mode: ActCatalogMode,
+    #[serde(default = "default_ir_section_escaping")]
+    pub ir_section_escaping: IrSectionEscaping,
+    #[serde(default)]
+    #[validate(nested)]
+    pub ir_section_limits: IrSectionLimits,
+    /// Hard bounds for limits the cortex may tune itself; self-tuning is disabled when unset.
+    #[serde(default)]
+    #[validate(nested)]
+    pub reaction_limit_bounds: Option<ReactionLimitBounds>,
+    /// When set, every this many consecutive empty ticks carry a synthetic idle sense.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub idle_sense_interval_ticks: Option<u64>,
+    /// Lets a `core.control`/`cycle.trigger` sense force one immediate cycle outside the tick
+    /// cadence.
+    #[serde(default)]
+    pub on_demand_cycle_trigger: bool,
+    #[serde(default)]
+    pub sense_payload_logging: SensePayloadLogging,
+    /// With redaction on, additionally log the unredacted organ input at debug level.
+    #[serde(default)]
+    pub debug_log_full_sense_payloads: bool,
+    /// Fraction of cycles whose organ inputs and outputs are logged in full; other cycles log a
+    /// size and hash summary, plus the full payloads of any stage that fails. Unset logs all.
+    #[serde(default)]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub organ_log_full_payload_rate: Option<f64>,
+    /// Downgrades stages whose strict JSON-schema calls keep failing; disabled when unset.
+    #[serde(default)]
+    #[validate(nested)]
+    pub strict_schema_fallback: Option<StrictSchemaFallbackConfig>,
+    /// Sense tagging rules, first match wins; untagged senses go to the primary lane.
+    #[serde(default)]
+    #[validate(nested)]
+    pub sense_lanes: Vec<SenseLaneRule>,
+    /// Order of the senses handed to each cycle; arrival order by default.
+    #[serde(default)]
+    pub sense_batch_ordering: SenseBatchOrdering,
+    /// Per-endpoint sense-processing policies, first match wins; unmatched senses are batched.
+    #[serde(default)]
+    #[validate(nested)]
+    pub endpoint_sense_policies: Vec<EndpointSensePolicyRule>,
+    /// Dispatch priority of acts within a cycle by descriptor id, higher first.
+    ///
+    /// Unlisted acts have priority 0; acts of equal priority keep the acts helper's order.
+    #[serde(default)]
+    pub act_priorities: BTreeMap<String, i32>,
+    /// Whether acts naming a capability missing from the live catalog are dropped before
+    /// dispatch or sent on for Spine to reject.
+    #[serde(default)]
+    pub unknown_capability_acts: UnknownCapabilityActs,
+    /// Drop repeated acts within a cycle whose endpoint, descriptor, and canonical payload match.
+    #[serde(default)]
+    pub dedup_acts_within_cycle: bool,
+    /// Offer Primary the `control-sleep` tool to request a graceful shutdown of the runtime.
+    ///
+    /// Off by default: when on, the model alone decides when the process stops.
+    #[serde(default)]
+    pub self_sleep_enabled: bool,
+    /// Reuses the Primary output for an identical input IR within the TTL; disabled when unset.
+    ///
+    /// Outputs that dispatched acts or committed a thread are never reused.
+    #[serde(default)]
+    #[validate(nested)]
+    pub primary_output_cache: Option<PrimaryOutputCacheConfig>,
+    /// Hard ceiling on how often cycles may start, independent of ticks and sense batching.
+    #[serde(default)]
+    #[validate(nested)]
+    pub cycle_rate_limit: Option<CycleRateLimitConfig>,
+    /// Named model parameter sets that `organ_presets` can assign to stages.
+    #[serde(default)]
+    #[validate(nested)]
+    pub model_presets: BTreeMap<String, ModelParameterPreset>,
+    /// Preset name per organ stage; each must be a key of `model_presets`.
+    #[serde(default)]
+    pub organ_presets: CortexOrganPresetsConfig,
+    /// Bounds the persisted cognition state by trimming completed goals; unbounded when unset.
+    #[serde(default)]
+    #[validate(nested)]
+    pub cognition_state_size_limit: Option<CognitionStateSizeLimitConfig>,
+    /// Routes organ calls to a faster model while a route's recent latency is too high;
+    /// disabled when unset.
+    #[serde(default)]
+    #[validate(nested)]
+    pub latency_downgrade: Option<LatencyDowngradeConfig>,
+}
+
+/// Latency-based downgrade of organ routes to faster fallback routes.
+///
+/// A route is downgraded once the p95 of its last `window` call latencies exceeds
+/// `p95_threshold_ms`. While downgraded, every `probe_every_calls`-th call still goes to the
+/// route as a probe, and the route is restored once the probes' p95 is back under the threshold.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LatencyDowngradeConfig {
+    /// Faster route alias to use for each monitored route alias.
+    #[validate(length(min = 1))]
+    pub fallback_routes: BTreeMap<String, String>,
+    #[validate(range(min = 1))]
+    pub p95_threshold_ms: u64,
+    #[serde(default = "default_latency_downgrade_window")]
+    #[validate(range(min = 1))]
+    pub window: usize,
+    /// Samples required before the p95 is trusted, both to downgrade and to restore.
+    #[serde(default = "default_latency_downgrade_min_samples")]
+    #[validate(range(min = 1))]
+    pub min_samples: usize,
+    #[serde(default = "default_latency_downgrade_probe_every_calls")]
+    #[validate(range(min = 1))]
+    pub probe_every_calls: u32,
+}
+
+/// Cap on the serialized cognition state, enforced before each save.
+///
+/// Goals whose status is listed in `trimmable_goal_statuses` are removed oldest first, in
+/// forest order, and only when no goal beneath them is still active.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CognitionStateSizeLimitConfig {
+    #[validate(range(min = 1))]
+    pub max_serialized_bytes: usize,
+    #[serde(default = "default_trimmable_goal_statuses")]
+    pub trimmable_goal_statuses: Vec<String>,
+}
+
+/// Token bucket governing cycle starts; triggers beyond the rate wait for the next slot and
+/// share one cycle with everything that arrived meanwhile.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CycleRateLimitConfig {
+    #[validate(range(min = 0.001))]
+    pub max_cycles_per_second: f64,
+    /// Cycles that may start back to back before the rate applies.
+    #[serde(default = "default_cycle_rate_burst")]
+    #[validate(range(min = 1))]
+    pub burst: u32,
+}
+
+/// Opt-in cache of Primary outputs keyed on a hash of the input IR.
+///
+/// Primary is nondeterministic, so a hit replays an earlier decision instead of a fresh one.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PrimaryOutputCacheConfig {
+    #[serde(default = "default_primary_output_cache_ttl_ms")]
+    #[validate(range(min = 1))]
+    pub ttl_ms: u64,
+    /// Entries kept at most; the least recently used one is evicted beyond this.
+    #[serde(default = "default_primary_output_cache_capacity")]
+    #[validate(range(min = 1))]
+    pub capacity: usize,
+}
+
+impl Default for PrimaryOutputCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl_ms: default_primary_output_cache_ttl_ms(),
+            capacity: default_primary_output_cache_capacity(),
+        }
+    }
+}
+
+/// Per-stage fallback from strict JSON-schema output to JSON-object mode with local validation.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StrictSchemaFallbackConfig {
+    /// Consecutive strict-mode failures of one stage before it is downgraded.
+    #[serde(default = "default_strict_schema_failure_threshold")]
+    #[validate(range(min = 1))]
+    pub failure_threshold: u32,
+    /// Cycles a downgraded stage stays in JSON-object mode before strict mode is re-attempted.
+    #[serde(default = "default_strict_schema_retry_after_cycles")]
+    #[validate(range(min = 1))]
+    pub retry_strict_after_cycles: u64,
+}
+
+impl Default for StrictSchemaFallbackConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: default_strict_schema_failure_threshold(),
+            retry_strict_after_cycles: default_strict_schema_retry_after_cycles(),
+        }
+    }
 }
 
 impl Default for CortexRuntimeConfig {
@@ -70,6 +547,92 @@ impl Default for CortexRuntimeConfig {
             outbox_capacity: default_cortex_outbox_capacity(),
             default_limits: ReactionLimits::default(),
             routes: CortexRoutesConfig::default(),
+            act_catalog_mode: default_act_catalog_mode(),
+            ir_section_escaping: default_ir_section_escaping(),
+            ir_section_limits: IrSectionLimits::default(),
+            reaction_limit_bounds: None,
+            idle_sense_interval_ticks: None,
+            on_demand_cycle_trigger: false,
+            sense_payload_logging: SensePayloadLogging::default(),
+            debug_log_full_sense_payloads: false,
+            organ_log_full_payload_rate: None,
+            strict_schema_fallback: None,
+            sense_lanes: Vec::new(),
+            sense_batch_ordering: SenseBatchOrdering::default(),
+            endpoint_sense_policies: Vec::new(),
+            act_priorities: BTreeMap::new(),
+            unknown_capability_acts: UnknownCapabilityActs::default(),
+            dedup_acts_within_cycle: false,
+            self_sleep_enabled: false,
+            primary_output_cache: None,
+            cycle_rate_limit: None,
+            model_presets: BTreeMap::new(),
+            organ_presets: CortexOrganPresetsConfig::default(),
+            cognition_state_size_limit: None,
+            latency_downgrade: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ai_gateway::types::{
+        BackendDialect, BackendProfile, ChatConfig, CredentialRef, HttpClientConfig, ModelProfile,
+        ResilienceConfig,
+    };
+
+    use super::*;
+
+    #[test]
+    fn stage_route_with_unknown_alias_is_rejected() {
+        let gateway = AIGatewayConfig {
+            backends: vec![BackendProfile {
+                id: "openai".to_string(),
+                dialect: BackendDialect::OpenAiResponses,
+                endpoint: None,
+                credential: CredentialRef::None,
+                models: vec![ModelProfile {
+                    id: "gpt-5".to_string(),
+                    aliases: vec!["default".to_string(), "acts".to_string()],
+                }],
+                max_output_tokens_cap: None,
+                limits: None,
+                capabilities: None,
+                copilot: None,
+                post_finish_content: Default::default(),
+            }],
+            chat: ChatConfig::default(),
+            resilience: ResilienceConfig::default(),
+            http: HttpClientConfig::default(),
+        };
+        let mut routes = CortexRoutesConfig {
+            acts_helper: Some("acts".to_string()),
+            ..CortexRoutesConfig::default()
+        };
+        assert!(routes.validate_against_gateway(&gateway).is_ok());
+
+        routes.sense_helper = Some("snese".to_string());
+        let err = routes
+            .validate_against_gateway(&gateway)
+            .expect_err("unknown alias should be rejected");
+        assert!(err.contains("cortex.routes.sense_helper"));
+        assert!(err.contains("'snese'"));
+    }
+
+    #[test]
+    fn organ_preset_with_unknown_name_is_rejected() {
+        let mut config = CortexRuntimeConfig::default();
+        config
+            .model_presets
+            .insert("deterministic".to_string(), ModelParameterPreset::default());
+        config.organ_presets.acts_helper = Some("deterministic".to_string());
+        assert!(config.validate().is_ok());
+
+        config.organ_presets.primary = Some("creative".to_string());
+        let err = config
+            .validate()
+            .expect_err("unknown preset should be rejected")
+            .to_string();
+        assert!(err.contains("cortex.organ_presets.primary"));
+    }
+}
@@ -22,6 +22,16 @@ fn default_logging_retention_days() -> usize {
     14
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StderrLogFormat {
+    /// Human-readable lines for interactive runs.
+    #[default]
+    Pretty,
+    /// One JSON object per line with span context, for log aggregators.
+    Json,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct LoggingConfig {
@@ -36,6 +46,8 @@ pub struct LoggingConfig {
     pub retention_days: usize,
     #[serde(default = "default_enabled_true")]
     pub stderr_warn_enabled: bool,
+    #[serde(default)]
+    pub stderr_format: StderrLogFormat,
 }
 
 impl Default for LoggingConfig {
@@ -45,6 +57,7 @@ impl Default for LoggingConfig {
             filter: default_logging_filter(),
             retention_days: default_logging_retention_days(),
             stderr_warn_enabled: default_enabled_true(),
+            stderr_format: StderrLogFormat::default(),
         }
     }
 }
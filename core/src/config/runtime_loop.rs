@@ -1,6 +1,6 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
 fn default_tick_interval_ms() -> u64 {
     10_000
@@ -22,6 +22,10 @@ fn default_efferent_shutdown_drain_timeout_ms() -> u64 {
     5_000
 }
 
+fn default_shutdown_deadline_ms() -> u64 {
+    30_000
+}
+
 fn default_sense_queue_capacity() -> usize {
     32
 }
@@ -38,6 +42,10 @@ pub struct CoreLoopConfig {
     #[serde(default = "default_sense_queue_capacity")]
     #[validate(range(min = 1))]
     pub sense_queue_capacity: usize,
+    /// Telemetry and a pressure signal when the sense queue crosses these depths; off when unset.
+    #[serde(default)]
+    #[validate(nested)]
+    pub sense_queue_watermarks: Option<SenseQueueWatermarksConfig>,
     #[serde(default = "default_max_deferring_nums")]
     #[validate(range(min = 1))]
     pub max_deferring_nums: usize,
@@ -47,22 +55,97 @@ pub struct CoreLoopConfig {
     #[serde(default = "default_efferent_shutdown_drain_timeout_ms")]
     #[validate(range(min = 1))]
     pub efferent_shutdown_drain_timeout_ms: u64,
+    /// Upper bound on the whole shutdown drain; the process force-exits once it passes.
+    #[serde(default = "default_shutdown_deadline_ms")]
+    #[validate(range(min = 1))]
+    pub shutdown_deadline_ms: u64,
     #[serde(default = "default_tick_interval_ms")]
     #[validate(range(min = 1))]
     pub tick_interval_ms: u64,
     #[serde(default = "default_tick_missed_behavior")]
     pub tick_missed_behavior: TickMissedBehavior,
+    /// Human confirmation gate for selected acts; disabled when unset.
+    #[serde(default)]
+    #[validate(nested)]
+    pub act_confirmation: Option<ActConfirmationConfig>,
+    /// Feed every terminal act dispatch result back to cortex as a `core.stem`/`act.result` sense.
+    #[serde(default)]
+    pub act_result_feedback: bool,
+    /// Dev/test only: turn `act.result.inject` senses from any endpoint into synthetic
+    /// `act.result` feedback.
+    #[serde(default)]
+    pub act_result_injection: bool,
+}
+
+/// Depths of the sense queue feeding cortex at which pressure is raised and cleared again.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+#[validate(schema(function = "validate_low_below_high_watermark"))]
+pub struct SenseQueueWatermarksConfig {
+    /// Pressure is raised once the queue holds at least this many senses.
+    #[validate(range(min = 1))]
+    pub high: usize,
+    /// Pressure is cleared once the queue drains to this many senses or fewer.
+    pub low: usize,
+}
+
+fn validate_low_below_high_watermark(
+    watermarks: &SenseQueueWatermarksConfig,
+) -> Result<(), ValidationError> {
+    if watermarks.low >= watermarks.high {
+        return Err(ValidationError::new("low_watermark_must_be_below_high"));
+    }
+    Ok(())
+}
+
+fn default_confirmation_timeout_ms() -> u64 {
+    60_000
+}
+
+fn default_confirm_request_descriptor_id() -> String {
+    "confirm.request".to_string()
+}
+
+fn default_confirm_response_descriptor_id() -> String {
+    "confirm.response".to_string()
+}
+
+/// Acts whose descriptor id is listed here are held until the confirming endpoint answers.
+///
+/// The held act is described to the endpoint as a `confirm.request` act; it is dispatched only
+/// after a `confirm.response` sense with `{"act_instance_id": ..., "confirmed": true}` arrives,
+/// and rejected on denial or timeout. Efferent dispatch is serial, so later acts wait behind it.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ActConfirmationConfig {
+    /// Name the confirming endpoint registers with, without the spine-assigned suffix.
+    #[validate(length(min = 1))]
+    pub endpoint_name: String,
+    #[validate(length(min = 1))]
+    pub neural_signal_descriptor_ids: Vec<String>,
+    #[serde(default = "default_confirmation_timeout_ms")]
+    #[validate(range(min = 1))]
+    pub timeout_ms: u64,
+    #[serde(default = "default_confirm_request_descriptor_id")]
+    pub request_descriptor_id: String,
+    #[serde(default = "default_confirm_response_descriptor_id")]
+    pub response_descriptor_id: String,
 }
 
 impl Default for CoreLoopConfig {
     fn default() -> Self {
         Self {
             sense_queue_capacity: default_sense_queue_capacity(),
+            sense_queue_watermarks: None,
             max_deferring_nums: default_max_deferring_nums(),
             afferent_sidecar_capacity: default_afferent_sidecar_capacity(),
             efferent_shutdown_drain_timeout_ms: default_efferent_shutdown_drain_timeout_ms(),
+            shutdown_deadline_ms: default_shutdown_deadline_ms(),
             tick_interval_ms: default_tick_interval_ms(),
             tick_missed_behavior: default_tick_missed_behavior(),
+            act_confirmation: None,
+            act_result_feedback: false,
+            act_result_injection: false,
         }
     }
 }
@@ -1,11 +1,18 @@
-use std::path::Path;
+use std::{collections::BTreeMap, path::Path};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationError};
 
-pub use crate::spine::adapters::{
-    inline::InlineAdapterConfig, unix_socket::UnixSocketNdjsonAdapterConfig,
+pub use crate::spine::{
+    SpineExecutionMode,
+    adapters::{
+        inline::InlineAdapterConfig,
+        tcp_ndjson::TcpNdjsonAdapterConfig,
+        unix_socket::{
+            BodyEndpointHeartbeatConfig, DuplicateAuthPolicy, UnixSocketNdjsonAdapterConfig,
+        },
+    },
 };
 
 fn default_spine_adapters() -> Vec<SpineAdapterConfig> {
@@ -25,12 +32,142 @@ pub struct SpineRuntimeConfig {
     #[serde(default = "default_spine_adapters")]
     #[validate(custom(function = "validate_adapters"))]
     pub adapters: Vec<SpineAdapterConfig>,
+    /// Whether acts are handed to endpoints serialized in dispatch order or concurrently.
+    #[serde(default)]
+    pub execution_mode: SpineExecutionMode,
+    /// Upper bound on acts dispatched to adapters but not yet delivered or acknowledged.
+    ///
+    /// Dispatch waits for a free slot once the bound is reached; unset means unbounded.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub max_in_flight_acts: Option<usize>,
+    /// Operator cap, in bytes, on act payloads sent to endpoints.
+    ///
+    /// Acts whose serialized payload is larger are rejected at dispatch, and any larger
+    /// `maxLength` an endpoint declares in its payload schemas is lowered to this value at
+    /// registration. Unset disables both.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub max_descriptor_payload_bytes: Option<u64>,
+    /// Per-endpoint delivery error budget; endpoints over it are marked degraded, not removed.
+    ///
+    /// Unset disables tracking.
+    #[serde(default)]
+    #[validate(nested)]
+    pub endpoint_error_budget: Option<EndpointErrorBudgetConfig>,
+    /// Minimum spacing between acts dispatched to one body endpoint, keyed by endpoint name.
+    ///
+    /// Acts to a paced endpoint wait in dispatch order instead of arriving back-to-back.
+    #[serde(default)]
+    pub endpoint_min_inter_act_ms: BTreeMap<String, u64>,
+    /// Acts held for each paused body endpoint; acts beyond it are rejected until resume.
+    #[serde(default = "default_max_queued_acts_per_paused_endpoint")]
+    pub max_queued_acts_per_paused_endpoint: usize,
+    /// Charset, length, and normalization rules for endpoint names at auth; unset only trims.
+    #[serde(default)]
+    #[validate(nested)]
+    pub endpoint_names: Option<EndpointNameRulesConfig>,
 }
 
 impl Default for SpineRuntimeConfig {
     fn default() -> Self {
         Self {
             adapters: default_spine_adapters(),
+            execution_mode: SpineExecutionMode::default(),
+            max_in_flight_acts: None,
+            max_descriptor_payload_bytes: None,
+            endpoint_error_budget: None,
+            endpoint_min_inter_act_ms: BTreeMap::new(),
+            max_queued_acts_per_paused_endpoint: default_max_queued_acts_per_paused_endpoint(),
+            endpoint_names: None,
+        }
+    }
+}
+
+fn default_max_queued_acts_per_paused_endpoint() -> usize {
+    32
+}
+
+fn default_endpoint_name_max_length() -> usize {
+    64
+}
+
+fn default_endpoint_name_allowed_punctuation() -> String {
+    "-".to_string()
+}
+
+fn default_error_budget_window_size() -> usize {
+    20
+}
+
+fn default_error_budget_min_samples() -> usize {
+    10
+}
+
+fn default_error_budget_max_failure_ratio() -> f64 {
+    0.2
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EndpointErrorBudgetConfig {
+    /// Number of most recent delivery attempts considered per endpoint.
+    #[serde(default = "default_error_budget_window_size")]
+    #[validate(range(min = 1))]
+    pub window_size: usize,
+    /// Attempts required in the window before an endpoint's standing is judged.
+    #[serde(default = "default_error_budget_min_samples")]
+    #[validate(range(min = 1))]
+    pub min_samples: usize,
+    /// Failure ratio above which the endpoint is degraded; at or below it standing is restored.
+    #[serde(default = "default_error_budget_max_failure_ratio")]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub max_failure_ratio: f64,
+}
+
+impl Default for EndpointErrorBudgetConfig {
+    fn default() -> Self {
+        Self {
+            window_size: default_error_budget_window_size(),
+            min_samples: default_error_budget_min_samples(),
+            max_failure_ratio: default_error_budget_max_failure_ratio(),
+        }
+    }
+}
+
+/// How an endpoint name is rewritten before it is validated.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EndpointNameNormalization {
+    /// Keep the trimmed name as sent.
+    #[default]
+    None,
+    /// Lowercase ASCII letters, so `Chat` and `chat` name the same endpoint.
+    AsciiLowercase,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EndpointNameRulesConfig {
+    /// Maximum name length in characters, after normalization.
+    #[serde(default = "default_endpoint_name_max_length")]
+    #[validate(range(min = 1))]
+    pub max_length: usize,
+    /// Characters allowed besides ASCII letters and digits.
+    ///
+    /// Anything else, including non-ASCII look-alikes and whitespace, is rejected.
+    #[serde(default = "default_endpoint_name_allowed_punctuation")]
+    pub allowed_punctuation: String,
+    #[serde(default)]
+    pub normalization: EndpointNameNormalization,
+}
+
+impl Default for EndpointNameRulesConfig {
+    fn default() -> Self {
+        Self {
+            max_length: default_endpoint_name_max_length(),
+            allowed_punctuation: default_endpoint_name_allowed_punctuation(),
+            normalization: EndpointNameNormalization::default(),
         }
     }
 }
@@ -52,6 +189,9 @@ pub enum SpineAdapterConfig {
     UnixSocketNdjson {
         config: UnixSocketNdjsonAdapterConfig,
     },
+    TcpNdjson {
+        config: TcpNdjsonAdapterConfig,
+    },
 }
 
 fn validate_adapters(adapters: &[SpineAdapterConfig]) -> Result<(), ValidationError> {
@@ -71,6 +211,11 @@ fn validate_adapters(adapters: &[SpineAdapterConfig]) -> Result<(), ValidationEr
                     .validate()
                     .map_err(|_| ValidationError::new("unix_socket_adapter_invalid"))?;
             }
+            SpineAdapterConfig::TcpNdjson { config } => {
+                config
+                    .validate()
+                    .map_err(|_| ValidationError::new("tcp_ndjson_adapter_invalid"))?;
+            }
         }
     }
 
@@ -80,7 +225,7 @@ fn validate_adapters(adapters: &[SpineAdapterConfig]) -> Result<(), ValidationEr
 impl SpineAdapterConfig {
     fn normalize_paths(&mut self, config_base: &Path) {
         match self {
-            SpineAdapterConfig::Inline { .. } => {}
+            SpineAdapterConfig::Inline { .. } | SpineAdapterConfig::TcpNdjson { .. } => {}
             SpineAdapterConfig::UnixSocketNdjson { config } => config.normalize_paths(config_base),
         }
     }
@@ -5,7 +5,10 @@ use crate::{
         error::ContinuityError,
         persistence::ContinuityPersistence,
         state::ContinuityState,
-        types::{ContinuityRecord, ContinuityRecordBody, ContinuityRecordKey, DispatchContext},
+        types::{
+            ContinuityRecord, ContinuityRecordBody, ContinuityRecordKey, ContinuityStore,
+            DispatchContext,
+        },
     },
     types::{Act, DispatchDecision},
 };
@@ -78,6 +81,11 @@ impl ContinuityEngine {
     pub fn flush(&mut self) -> Result<(), ContinuityError> {
         self.persistence.save(&self.state.store)
     }
+
+    /// Copies what [`Self::flush`] would write, so it can be saved without holding the engine.
+    pub fn flush_snapshot(&self) -> (ContinuityPersistence, ContinuityStore) {
+        (self.persistence.clone(), self.state.store.clone())
+    }
 }
 
 #[cfg(test)]
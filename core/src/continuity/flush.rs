@@ -0,0 +1,91 @@
+use std::{future::Future, sync::Arc, time::Duration};
+
+use tokio::{sync::Mutex, task::JoinHandle, time::MissedTickBehavior};
+use tokio_util::sync::CancellationToken;
+
+use crate::continuity::{
+    engine::ContinuityEngine,
+    error::{ContinuityError, internal_error},
+};
+
+/// Flushes `continuity` every `interval` until `shutdown` is cancelled.
+///
+/// The engine is locked only to copy its store; the write itself runs on the blocking pool, so
+/// a slow disk never holds up cortex or the efferent pathway waiting on the lock.
+pub fn spawn_periodic_flush(
+    continuity: Arc<Mutex<ContinuityEngine>>,
+    interval: Duration,
+    shutdown: CancellationToken,
+) -> JoinHandle<()> {
+    tokio::spawn(run_periodic_flush(interval, shutdown, move || {
+        let continuity = Arc::clone(&continuity);
+        async move { flush_on_blocking_pool(&continuity).await }
+    }))
+}
+
+/// Flushes `continuity` once, locking it only to copy its store and writing on the blocking pool.
+pub async fn flush_on_blocking_pool(
+    continuity: &Mutex<ContinuityEngine>,
+) -> Result<(), ContinuityError> {
+    let (persistence, store) = continuity.lock().await.flush_snapshot();
+    tokio::task::spawn_blocking(move || persistence.save(&store))
+        .await
+        .map_err(|err| internal_error(format!("continuity flush task failed: {err}")))?
+}
+
+async fn run_periodic_flush<F, Fut>(interval: Duration, shutdown: CancellationToken, mut flush: F)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), ContinuityError>>,
+{
+    let mut ticker = tokio::time::interval(interval.max(Duration::from_millis(1)));
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    ticker.tick().await;
+    loop {
+        tokio::select! {
+            _ = shutdown.cancelled() => break,
+            _ = ticker.tick() => {
+                match flush().await {
+                    Ok(()) => tracing::debug!(target: "continuity", "continuity_periodic_flush"),
+                    Err(err) => tracing::warn!(
+                        target: "continuity",
+                        error = %err,
+                        "continuity_periodic_flush_failed"
+                    ),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn flushes_run_at_configured_cadence() {
+        let flushes = Arc::new(AtomicUsize::new(0));
+        let shutdown = CancellationToken::new();
+        let task = tokio::spawn(run_periodic_flush(
+            Duration::from_millis(40),
+            shutdown.clone(),
+            {
+                let flushes = Arc::clone(&flushes);
+                move || {
+                    flushes.fetch_add(1, Ordering::SeqCst);
+                    async { Ok(()) }
+                }
+            },
+        ));
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(flushes.load(Ordering::SeqCst), 0);
+        tokio::time::sleep(Duration::from_millis(110)).await;
+        shutdown.cancel();
+        task.await.expect("flush task should stop on shutdown");
+
+        assert!((2..=4).contains(&flushes.load(Ordering::SeqCst)));
+    }
+}
@@ -2,12 +2,14 @@
 
 pub mod engine;
 pub mod error;
+pub mod flush;
 pub mod persistence;
 pub mod state;
 pub mod types;
 
 pub use engine::ContinuityEngine;
 pub use error::{ContinuityError, ContinuityErrorKind};
+pub use flush::{flush_on_blocking_pool, spawn_periodic_flush};
 pub use persistence::ContinuityPersistence;
 pub use state::ContinuityState;
 pub use types::{
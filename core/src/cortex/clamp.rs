@@ -1,6 +1,7 @@
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
-use crate::types::SenseInstanceId;
+use crate::types::{SenseInstanceId, canonical_json};
 
 pub fn derive_act_instance_id(
     cycle_id: u64,
@@ -18,3 +19,54 @@ pub fn derive_act_instance_id(
     );
     Uuid::now_v7().to_string()
 }
+
+/// Key identifying the same act within one cycle, independent of payload key order.
+pub fn derive_act_dedup_key(
+    cycle_id: u64,
+    endpoint_id: &str,
+    neural_signal_descriptor_id: &str,
+    payload: &serde_json::Value,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(
+        canonical_json(&serde_json::json!({
+            "cycle_id": cycle_id,
+            "endpoint_id": endpoint_id,
+            "neural_signal_descriptor_id": neural_signal_descriptor_id,
+            "payload": payload,
+        }))
+        .as_bytes(),
+    );
+    format!("act:{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payloads_differing_only_in_key_order_share_a_dedup_key() {
+        let ordered = serde_json::from_str::<serde_json::Value>(
+            r#"{"a":1,"b":{"x":[1,{"k":"v","j":null}],"y":true}}"#,
+        )
+        .expect("payload should parse");
+        let reordered = serde_json::from_str::<serde_json::Value>(
+            r#"{"b":{"y":true,"x":[1,{"j":null,"k":"v"}]},"a":1}"#,
+        )
+        .expect("payload should parse");
+
+        assert_eq!(canonical_json(&ordered), canonical_json(&reordered));
+        assert_eq!(
+            derive_act_dedup_key(3, "tool.1", "say", &ordered),
+            derive_act_dedup_key(3, "tool.1", "say", &reordered)
+        );
+        assert_ne!(
+            derive_act_dedup_key(3, "tool.1", "say", &ordered),
+            derive_act_dedup_key(4, "tool.1", "say", &ordered)
+        );
+        assert_ne!(
+            derive_act_dedup_key(3, "tool.1", "say", &ordered),
+            derive_act_dedup_key(3, "tool.1", "say", &serde_json::json!({ "a": 2 }))
+        );
+    }
+}
@@ -1,10 +1,14 @@
-use std::collections::HashMap;
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
+};
 
 use serde::{Deserialize, Serialize};
 use tokio::time::{Duration, timeout};
 
 use crate::{
     ai_gateway::chat::OutputMode,
+    config::UnknownCapabilityActs,
     cortex::{
         clamp::derive_act_instance_id,
         error::{CortexError, extractor_failed},
@@ -26,9 +30,22 @@ struct ActDraft {
 type ActsHelperOutput = Vec<ActDraft>;
 
 #[derive(Clone, Default)]
-pub(crate) struct ActsOutputHelper;
+pub(crate) struct ActsOutputHelper {
+    act_priorities: BTreeMap<String, i32>,
+    unknown_capability_acts: UnknownCapabilityActs,
+}
 
 impl ActsOutputHelper {
+    pub(crate) fn new(
+        act_priorities: BTreeMap<String, i32>,
+        unknown_capability_acts: UnknownCapabilityActs,
+    ) -> Self {
+        Self {
+            act_priorities,
+            unknown_capability_acts,
+        }
+    }
+
     pub(crate) async fn to_structured_output(
         &self,
         runtime: &impl HelperRuntime,
@@ -39,16 +56,46 @@ impl ActsOutputHelper {
         sense_descriptors: &[NeuralSignalDescriptor],
     ) -> Vec<Act> {
         let stage = CognitionOrgan::Acts.stage();
+        if acts_section.trim().is_empty() {
+            // An empty acts section is the primary deciding not to act, not a helper failure.
+            helpers::log_organ_input(
+                runtime.payload_log_sampler(),
+                cycle_id,
+                stage,
+                "{\"acts_section\":\"\"}",
+            );
+            runtime.emit_intentional_noop(cycle_id, stage);
+            helpers::log_organ_output(runtime.payload_log_sampler(), cycle_id, stage, "[]");
+            return Vec::new();
+        }
+
         let input_payload = helpers::pretty_json(&serde_json::json!({
             "acts_section": acts_section,
             "act_descriptor_catalog": act_descriptors,
             "sense_descriptor_catalog": sense_descriptors,
         }));
-        helpers::log_organ_input(cycle_id, stage, &input_payload);
+        helpers::log_organ_input(
+            runtime.payload_log_sampler(),
+            cycle_id,
+            stage,
+            &input_payload,
+        );
 
         if let Some(drafts) = parse_direct_json_acts_if_valid(acts_section, act_descriptors) {
-            let acts = materialize_acts(cycle_id, drafts, act_descriptors, sense_descriptors);
-            helpers::log_organ_output(cycle_id, stage, &helpers::pretty_json(&acts));
+            let mut acts = self.materialize_acts(
+                runtime,
+                cycle_id,
+                drafts,
+                act_descriptors,
+                sense_descriptors,
+            );
+            self.order_by_priority(&mut acts);
+            helpers::log_organ_output(
+                runtime.payload_log_sampler(),
+                cycle_id,
+                stage,
+                &helpers::pretty_json(&acts),
+            );
             return acts;
         }
 
@@ -92,9 +139,20 @@ impl ActsOutputHelper {
 
         match act_drafts_result {
             Ok(Ok(act_drafts)) => {
-                let acts =
-                    materialize_acts(cycle_id, act_drafts, act_descriptors, sense_descriptors);
-                helpers::log_organ_output(cycle_id, stage, &helpers::pretty_json(&acts));
+                let mut acts = self.materialize_acts(
+                    runtime,
+                    cycle_id,
+                    act_drafts,
+                    act_descriptors,
+                    sense_descriptors,
+                );
+                self.order_by_priority(&mut acts);
+                helpers::log_organ_output(
+                    runtime.payload_log_sampler(),
+                    cycle_id,
+                    stage,
+                    &helpers::pretty_json(&acts),
+                );
                 acts
             }
             Ok(Err(err)) => {
@@ -107,7 +165,12 @@ impl ActsOutputHelper {
                     "acts_helper_failed_fallback_empty"
                 );
                 let fallback = Vec::new();
-                helpers::log_organ_output(cycle_id, stage, &helpers::pretty_json(&fallback));
+                helpers::log_organ_output(
+                    runtime.payload_log_sampler(),
+                    cycle_id,
+                    stage,
+                    &helpers::pretty_json(&fallback),
+                );
                 fallback
             }
             Err(_) => {
@@ -119,11 +182,92 @@ impl ActsOutputHelper {
                     "acts_helper_timeout_fallback_empty"
                 );
                 let fallback = Vec::new();
-                helpers::log_organ_output(cycle_id, stage, &helpers::pretty_json(&fallback));
+                helpers::log_organ_output(
+                    runtime.payload_log_sampler(),
+                    cycle_id,
+                    stage,
+                    &helpers::pretty_json(&fallback),
+                );
                 fallback
             }
         }
     }
+
+    /// Turns drafts into acts against the live catalog; drafts naming an unknown capability
+    /// are handled per `unknown_capability_acts`.
+    fn materialize_acts(
+        &self,
+        runtime: &impl HelperRuntime,
+        cycle_id: u64,
+        drafts: ActsHelperOutput,
+        act_descriptors: &[NeuralSignalDescriptor],
+        sense_descriptors: &[NeuralSignalDescriptor],
+    ) -> Vec<Act> {
+        let endpoint_emitted_sense_catalog =
+            build_endpoint_emitted_sense_catalog(sense_descriptors);
+        let mut acts = Vec::with_capacity(drafts.len());
+        for draft in drafts {
+            let matched_descriptor = act_descriptors.iter().find(|descriptor| {
+                descriptor.r#type == NeuralSignalType::Act
+                    && descriptor.endpoint_id == draft.endpoint_id
+                    && descriptor_fq_act_id(descriptor) == draft.fq_act_id
+            });
+            let neural_signal_descriptor_id = match matched_descriptor {
+                Some(descriptor) => descriptor.neural_signal_descriptor_id.clone(),
+                None => match self.unknown_capability_acts {
+                    UnknownCapabilityActs::Drop => {
+                        runtime.emit_act_dropped_unknown_capability(
+                            cycle_id,
+                            &draft.endpoint_id,
+                            &draft.fq_act_id,
+                        );
+                        continue;
+                    }
+                    UnknownCapabilityActs::Dispatch => draft
+                        .fq_act_id
+                        .strip_prefix(&format!("{}/", draft.endpoint_id))
+                        .unwrap_or(&draft.fq_act_id)
+                        .to_string(),
+                },
+            };
+
+            let payload = draft.payload;
+            let might_emit_sense_ids = endpoint_emitted_sense_catalog
+                .get(&draft.endpoint_id)
+                .cloned()
+                .unwrap_or_default();
+            acts.push(Act {
+                act_instance_id: derive_act_instance_id(
+                    cycle_id,
+                    &[],
+                    &draft.endpoint_id,
+                    &neural_signal_descriptor_id,
+                    &payload,
+                ),
+                endpoint_id: draft.endpoint_id,
+                neural_signal_descriptor_id,
+                might_emit_sense_ids,
+                payload,
+                goal_id: None,
+            });
+        }
+        acts
+    }
+
+    /// Higher-priority acts first; unlisted acts have priority 0 and ties keep helper order.
+    fn order_by_priority(&self, acts: &mut [Act]) {
+        if self.act_priorities.is_empty() {
+            return;
+        }
+        acts.sort_by_key(|act| {
+            Reverse(
+                self.act_priorities
+                    .get(&act.neural_signal_descriptor_id)
+                    .copied()
+                    .unwrap_or(0),
+            )
+        });
+    }
 }
 
 pub(crate) fn acts_json_schema() -> serde_json::Value {
@@ -179,48 +323,6 @@ fn payload_matches_schema(payload: &serde_json::Value, schema: &serde_json::Valu
     compiled.validate(payload).is_ok()
 }
 
-fn materialize_acts(
-    cycle_id: u64,
-    drafts: ActsHelperOutput,
-    act_descriptors: &[NeuralSignalDescriptor],
-    sense_descriptors: &[NeuralSignalDescriptor],
-) -> Vec<Act> {
-    let endpoint_emitted_sense_catalog = build_endpoint_emitted_sense_catalog(sense_descriptors);
-    let mut acts = Vec::with_capacity(drafts.len());
-    for draft in drafts {
-        let Some(matched_descriptor) = act_descriptors.iter().find(|descriptor| {
-            descriptor.r#type == NeuralSignalType::Act
-                && descriptor_fq_act_id(descriptor) == draft.fq_act_id
-        }) else {
-            continue;
-        };
-
-        if matched_descriptor.endpoint_id != draft.endpoint_id {
-            continue;
-        }
-
-        let payload = draft.payload;
-        let might_emit_sense_ids = endpoint_emitted_sense_catalog
-            .get(&draft.endpoint_id)
-            .cloned()
-            .unwrap_or_default();
-        acts.push(Act {
-            act_instance_id: derive_act_instance_id(
-                cycle_id,
-                &[],
-                &draft.endpoint_id,
-                &matched_descriptor.neural_signal_descriptor_id,
-                &payload,
-            ),
-            endpoint_id: draft.endpoint_id,
-            neural_signal_descriptor_id: matched_descriptor.neural_signal_descriptor_id.clone(),
-            might_emit_sense_ids,
-            payload,
-        });
-    }
-    acts
-}
-
 fn build_endpoint_emitted_sense_catalog(
     sense_descriptors: &[NeuralSignalDescriptor],
 ) -> HashMap<String, Vec<String>> {
@@ -248,3 +350,248 @@ fn descriptor_fq_act_id(descriptor: &NeuralSignalDescriptor) -> String {
         &descriptor.neural_signal_descriptor_id,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::{
+        ai_gateway::chat::TurnResponse,
+        cortex::{
+            helpers::log_sampling::PayloadLogSampler, testing::TestHooks, types::ReactionLimits,
+        },
+    };
+
+    #[derive(Default)]
+    struct RecordingRuntime {
+        limits: ReactionLimits,
+        payload_log_sampler: PayloadLogSampler,
+        organ_calls: AtomicUsize,
+        intentional_noops: Mutex<Vec<(u64, &'static str)>>,
+        failed_stages: Mutex<Vec<&'static str>>,
+        dropped_unknown_acts: Mutex<Vec<(u64, String, String)>>,
+    }
+
+    #[async_trait]
+    impl HelperRuntime for RecordingRuntime {
+        fn limits(&self) -> &ReactionLimits {
+            &self.limits
+        }
+
+        fn hooks(&self) -> Option<&TestHooks> {
+            None
+        }
+
+        fn payload_log_sampler(&self) -> &PayloadLogSampler {
+            &self.payload_log_sampler
+        }
+
+        fn emit_stage_failed(&self, _cycle_id: u64, stage: &'static str) {
+            self.failed_stages
+                .lock()
+                .expect("lock poisoned")
+                .push(stage);
+        }
+
+        fn emit_intentional_noop(&self, cycle_id: u64, stage: &'static str) {
+            self.intentional_noops
+                .lock()
+                .expect("lock poisoned")
+                .push((cycle_id, stage));
+        }
+
+        fn emit_act_dropped_unknown_capability(
+            &self,
+            cycle_id: u64,
+            endpoint_id: &str,
+            fq_act_id: &str,
+        ) {
+            self.dropped_unknown_acts
+                .lock()
+                .expect("lock poisoned")
+                .push((cycle_id, endpoint_id.to_string(), fq_act_id.to_string()));
+        }
+
+        async fn run_text_organ_with_system(
+            &self,
+            _cycle_id: u64,
+            _organ: CognitionOrgan,
+            _max_output_tokens: u64,
+            _system_prompt: String,
+            _user_prompt: String,
+        ) -> Result<String, CortexError> {
+            unreachable!("acts helper uses structured organ calls")
+        }
+
+        async fn run_organ(
+            &self,
+            _cycle_id: u64,
+            _organ: CognitionOrgan,
+            _max_output_tokens: u64,
+            _system_prompt: String,
+            _user_prompt: String,
+            _output_mode: OutputMode,
+        ) -> Result<TurnResponse, CortexError> {
+            self.organ_calls.fetch_add(1, Ordering::SeqCst);
+            Err(extractor_failed("acts helper should not be called"))
+        }
+    }
+
+    #[tokio::test]
+    async fn empty_acts_section_skips_helper_as_intentional_noop() {
+        let runtime = RecordingRuntime::default();
+
+        let acts = ActsOutputHelper::default()
+            .to_structured_output(&runtime, 9, Duration::from_secs(1), " \n ", &[], &[])
+            .await;
+
+        assert!(acts.is_empty());
+        assert_eq!(runtime.organ_calls.load(Ordering::SeqCst), 0);
+        assert_eq!(
+            *runtime.intentional_noops.lock().expect("lock poisoned"),
+            vec![(9, "acts_helper")]
+        );
+        assert!(
+            runtime
+                .failed_stages
+                .lock()
+                .expect("lock poisoned")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn acts_are_ordered_by_configured_priority_with_stable_ties() {
+        let runtime = RecordingRuntime::default();
+        let act_descriptors = ["chat.present", "log.append", "fs.sync"]
+            .into_iter()
+            .map(|neural_signal_descriptor_id| NeuralSignalDescriptor {
+                r#type: NeuralSignalType::Act,
+                endpoint_id: "tool.1".to_string(),
+                neural_signal_descriptor_id: neural_signal_descriptor_id.to_string(),
+                payload_schema: serde_json::json!({ "type": "object" }),
+                priority: None,
+            })
+            .collect::<Vec<_>>();
+        let acts_section = serde_json::json!([
+            { "endpoint_id": "tool.1", "fq_act_id": "tool.1/log.append", "payload": { "n": 1 } },
+            { "endpoint_id": "tool.1", "fq_act_id": "tool.1/fs.sync", "payload": {} },
+            { "endpoint_id": "tool.1", "fq_act_id": "tool.1/chat.present", "payload": {} },
+            { "endpoint_id": "tool.1", "fq_act_id": "tool.1/log.append", "payload": { "n": 2 } },
+        ])
+        .to_string();
+        let helper = ActsOutputHelper::new(
+            BTreeMap::from([
+                ("chat.present".to_string(), 10),
+                ("fs.sync".to_string(), -1),
+            ]),
+            UnknownCapabilityActs::default(),
+        );
+
+        let acts = helper
+            .to_structured_output(
+                &runtime,
+                3,
+                Duration::from_secs(1),
+                &acts_section,
+                &act_descriptors,
+                &[],
+            )
+            .await;
+
+        let order = acts
+            .iter()
+            .map(|act| {
+                (
+                    act.neural_signal_descriptor_id.as_str(),
+                    act.payload.get("n").cloned(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            order,
+            vec![
+                ("chat.present", None),
+                ("log.append", Some(serde_json::json!(1))),
+                ("log.append", Some(serde_json::json!(2))),
+                ("fs.sync", None),
+            ]
+        );
+        assert_eq!(runtime.organ_calls.load(Ordering::SeqCst), 0);
+    }
+
+    fn act_descriptor(
+        endpoint_id: &str,
+        neural_signal_descriptor_id: &str,
+    ) -> NeuralSignalDescriptor {
+        NeuralSignalDescriptor {
+            r#type: NeuralSignalType::Act,
+            endpoint_id: endpoint_id.to_string(),
+            neural_signal_descriptor_id: neural_signal_descriptor_id.to_string(),
+            payload_schema: serde_json::json!({ "type": "object" }),
+            priority: None,
+        }
+    }
+
+    #[test]
+    fn act_for_unknown_capability_is_dropped_with_event() {
+        let runtime = RecordingRuntime::default();
+        let drafts = vec![
+            ActDraft {
+                endpoint_id: "tool.1".to_string(),
+                fq_act_id: "tool.1/run".to_string(),
+                payload: serde_json::json!({}),
+            },
+            ActDraft {
+                endpoint_id: "tool.1".to_string(),
+                fq_act_id: "tool.1/missing".to_string(),
+                payload: serde_json::json!({}),
+            },
+        ];
+
+        let acts = ActsOutputHelper::default().materialize_acts(
+            &runtime,
+            4,
+            drafts,
+            &[act_descriptor("tool.1", "run")],
+            &[],
+        );
+
+        assert_eq!(acts.len(), 1);
+        assert_eq!(acts[0].neural_signal_descriptor_id, "run");
+        assert_eq!(
+            *runtime.dropped_unknown_acts.lock().expect("lock poisoned"),
+            vec![(4, "tool.1".to_string(), "tool.1/missing".to_string())]
+        );
+    }
+
+    #[test]
+    fn dispatch_policy_keeps_act_for_unknown_capability() {
+        let runtime = RecordingRuntime::default();
+        let drafts = vec![ActDraft {
+            endpoint_id: "tool.2".to_string(),
+            fq_act_id: "tool.2/run".to_string(),
+            payload: serde_json::json!({}),
+        }];
+
+        let acts = ActsOutputHelper::new(BTreeMap::new(), UnknownCapabilityActs::Dispatch)
+            .materialize_acts(&runtime, 4, drafts, &[act_descriptor("tool.1", "run")], &[]);
+
+        assert_eq!(acts.len(), 1);
+        assert_eq!(acts[0].endpoint_id, "tool.2");
+        assert_eq!(acts[0].neural_signal_descriptor_id, "run");
+        assert!(
+            runtime
+                .dropped_unknown_acts
+                .lock()
+                .expect("lock poisoned")
+                .is_empty()
+        );
+    }
+}
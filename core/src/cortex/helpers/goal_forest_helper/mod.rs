@@ -24,7 +24,7 @@ pub(crate) struct GoalForestHelper;
 impl GoalForestHelper {
     pub(crate) async fn to_input_ir_section(
         &self,
-        _runtime: &impl HelperRuntime,
+        runtime: &impl HelperRuntime,
         cycle_id: u64,
         _deadline: Duration,
         goal_forest: &GoalForest,
@@ -33,7 +33,12 @@ impl GoalForestHelper {
         let input_payload = helpers::pretty_json(&serde_json::json!({
             "goal_forest": goal_forest,
         }));
-        helpers::log_organ_input(cycle_id, stage, &input_payload);
+        helpers::log_organ_input(
+            runtime.payload_log_sampler(),
+            cycle_id,
+            stage,
+            &input_payload,
+        );
 
         let output = if goal_forest.nodes.is_empty() {
             goal_forest_empty_one_shot().to_string()
@@ -41,7 +46,7 @@ impl GoalForestHelper {
             goal_forest_ascii(&goal_forest.nodes)
         };
 
-        helpers::log_organ_output(cycle_id, stage, &output);
+        helpers::log_organ_output(runtime.payload_log_sampler(), cycle_id, stage, &output);
         output
     }
 
@@ -70,7 +75,12 @@ impl GoalForestHelper {
             "current_goal_forest_json": current_goal_forest_json,
             "patch_instructions": instructions,
         }));
-        helpers::log_organ_input(cycle_id, stage, &input_payload);
+        helpers::log_organ_input(
+            runtime.payload_log_sampler(),
+            cycle_id,
+            stage,
+            &input_payload,
+        );
 
         let prompt = prompts::build_goal_forest_patch_sub_agent_prompt(
             &current_goal_forest_ascii,
@@ -132,7 +142,12 @@ impl GoalForestHelper {
         });
 
         let output_payload = helpers::pretty_json(&output);
-        helpers::log_organ_output(cycle_id, stage, &output_payload);
+        helpers::log_organ_output(
+            runtime.payload_log_sampler(),
+            cycle_id,
+            stage,
+            &output_payload,
+        );
         Ok(output)
     }
 }
@@ -0,0 +1,145 @@
+use std::{collections::BTreeMap, sync::Mutex};
+
+use sha2::{Digest, Sha256};
+
+/// `(cycle_id, payload)` by `(stage, kind)`.
+type RetainedPayloads = BTreeMap<(String, &'static str), (u64, String)>;
+
+/// Per-runtime organ payload sampling; the default logs every cycle in full.
+#[derive(Debug, Default)]
+pub(crate) struct PayloadLogSampler {
+    /// Fraction of cycles whose organ payloads are logged in full; `None` logs every cycle.
+    full_payload_rate: Option<f64>,
+    /// Latest sampled-out payload per `(stage, kind)`, logged in full if that stage then fails.
+    retained: Mutex<RetainedPayloads>,
+}
+
+/// Whether `cycle_id` falls into the sampled fraction of cycles.
+///
+/// Sampled cycles are spread evenly, so every run of `n` consecutive cycles logs
+/// `round(n * rate)` of them in full, give or take one.
+pub(crate) fn full_payload_sampled(cycle_id: u64, rate: f64) -> bool {
+    if rate >= 1.0 {
+        return true;
+    }
+    if rate <= 0.0 {
+        return false;
+    }
+    let current = (cycle_id as f64 * rate).floor();
+    let previous = (cycle_id.saturating_sub(1) as f64 * rate).floor();
+    cycle_id == 0 || current > previous
+}
+
+impl PayloadLogSampler {
+    pub(crate) fn new(full_payload_rate: Option<f64>) -> Self {
+        Self {
+            full_payload_rate: full_payload_rate.map(|rate| rate.clamp(0.0, 1.0)),
+            retained: Mutex::default(),
+        }
+    }
+
+    fn logs_full_payload(&self, cycle_id: u64) -> bool {
+        match self.full_payload_rate {
+            Some(rate) => full_payload_sampled(cycle_id, rate),
+            None => true,
+        }
+    }
+
+    /// Logs `payload` in full on sampled cycles, otherwise a compact summary.
+    ///
+    /// Returns `false` when only the summary was logged.
+    pub(crate) fn log_sampled_payload(
+        &self,
+        cycle_id: u64,
+        stage: &str,
+        kind: &'static str,
+        payload: &str,
+    ) -> bool {
+        if self.logs_full_payload(cycle_id) {
+            return true;
+        }
+        let digest = Sha256::digest(payload.as_bytes());
+        let hash = digest[..8]
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+        tracing::info!(
+            target: "cortex",
+            cycle_id = cycle_id,
+            stage = stage,
+            kind = kind,
+            payload_bytes = payload.len(),
+            payload_sha256 = %hash,
+            "cortex_organ_payload_summary"
+        );
+        self.retained
+            .lock()
+            .expect("lock poisoned")
+            .insert((stage.to_string(), kind), (cycle_id, payload.to_string()));
+        false
+    }
+
+    /// Logs the retained payloads of a failed stage (e.g. `primary` for `primary_timeout`).
+    pub(crate) fn log_retained_payloads_on_failure(&self, cycle_id: u64, failed_stage: &str) {
+        let retained = {
+            let mut retained = self.retained.lock().expect("lock poisoned");
+            let keys = retained
+                .iter()
+                .filter(|((stage, _), (retained_cycle_id, _))| {
+                    *retained_cycle_id == cycle_id && failed_stage.starts_with(stage.as_str())
+                })
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<_>>();
+            keys.into_iter()
+                .filter_map(|key| retained.remove(&key).map(|(_, payload)| (key, payload)))
+                .collect::<Vec<_>>()
+        };
+        for ((stage, kind), payload) in retained {
+            tracing::info!(
+                target: "cortex",
+                cycle_id = cycle_id,
+                stage = %stage,
+                kind = kind,
+                failed_stage = failed_stage,
+                payload = %payload,
+                "cortex_organ_payload_on_failure"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sampling_logs_the_configured_fraction_of_cycles() {
+        let sampled = |rate: f64| {
+            (1..=1_000)
+                .filter(|cycle_id| full_payload_sampled(*cycle_id, rate))
+                .count()
+        };
+        assert_eq!(sampled(0.25), 250);
+        assert_eq!(sampled(0.1), 100);
+        assert_eq!(sampled(0.0), 0);
+        assert_eq!(sampled(1.0), 1_000);
+        assert_eq!(
+            (1..=4)
+                .filter(|cycle_id| full_payload_sampled(*cycle_id, 0.25))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn retained_payloads_belong_to_their_own_sampler() {
+        let sampled_out = PayloadLogSampler::new(Some(0.0));
+        let full = PayloadLogSampler::default();
+
+        assert!(!sampled_out.log_sampled_payload(1, "primary", "input", "{}"));
+        assert!(full.log_sampled_payload(1, "primary", "input", "{}"));
+
+        assert_eq!(sampled_out.retained.lock().expect("lock poisoned").len(), 1);
+        assert!(full.retained.lock().expect("lock poisoned").is_empty());
+    }
+}
@@ -1,14 +1,21 @@
+use std::collections::BTreeMap;
+
 use async_trait::async_trait;
 use serde::Serialize;
+use sha2::{Digest, Sha256};
 
 use crate::{
     ai_gateway::chat::{OutputMode, TurnResponse},
+    config::{SensePayloadLogging, UnknownCapabilityActs},
     cortex::{error::CortexError, testing::TestHooks, types::ReactionLimits},
     types::{NeuralSignalDescriptor, NeuralSignalType},
 };
 
+use log_sampling::PayloadLogSampler;
+
 pub(crate) mod acts_output_helper;
 pub(crate) mod goal_forest_helper;
+pub(crate) mod log_sampling;
 pub(crate) mod proprioception_input_helper;
 pub(crate) mod sense_input_helper;
 
@@ -23,6 +30,15 @@ pub(crate) enum CognitionOrgan {
 }
 
 impl CognitionOrgan {
+    pub(crate) const ALL: [Self; 6] = [
+        Self::Primary,
+        Self::Attention,
+        Self::Cleanup,
+        Self::Sense,
+        Self::GoalForest,
+        Self::Acts,
+    ];
+
     pub(crate) fn stage(self) -> &'static str {
         match self {
             Self::Primary => "primary",
@@ -40,6 +56,19 @@ pub(crate) trait HelperRuntime: Send + Sync {
     fn limits(&self) -> &ReactionLimits;
     fn hooks(&self) -> Option<&TestHooks>;
     fn emit_stage_failed(&self, cycle_id: u64, stage: &'static str);
+    fn emit_intentional_noop(&self, cycle_id: u64, stage: &'static str);
+    fn emit_act_dropped_unknown_capability(
+        &self,
+        cycle_id: u64,
+        endpoint_id: &str,
+        fq_act_id: &str,
+    );
+
+    fn organ_input_log_policy(&self) -> OrganInputLogPolicy {
+        OrganInputLogPolicy::default()
+    }
+
+    fn payload_log_sampler(&self) -> &PayloadLogSampler;
 
     async fn run_text_organ_with_system(
         &self,
@@ -79,7 +108,32 @@ pub(crate) struct CortexHelper {
     pub output: OutputHelper,
 }
 
-pub(crate) fn log_organ_input(cycle_id: u64, stage: &str, input_payload: &str) {
+impl CortexHelper {
+    pub(crate) fn with_act_policies(
+        act_priorities: BTreeMap<String, i32>,
+        unknown_capability_acts: UnknownCapabilityActs,
+    ) -> Self {
+        Self {
+            input: InputHelper::default(),
+            output: OutputHelper {
+                acts: acts_output_helper::ActsOutputHelper::new(
+                    act_priorities,
+                    unknown_capability_acts,
+                ),
+            },
+        }
+    }
+}
+
+pub(crate) fn log_organ_input(
+    sampler: &PayloadLogSampler,
+    cycle_id: u64,
+    stage: &str,
+    input_payload: &str,
+) {
+    if !sampler.log_sampled_payload(cycle_id, stage, "input", input_payload) {
+        return;
+    }
     tracing::info!(
         target: "cortex",
         cycle_id = cycle_id,
@@ -89,7 +143,95 @@ pub(crate) fn log_organ_input(cycle_id: u64, stage: &str, input_payload: &str) {
     );
 }
 
-pub(crate) fn log_organ_output(cycle_id: u64, stage: &str, output_payload: &str) {
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct OrganInputLogPolicy {
+    pub sense_payloads: SensePayloadLogging,
+    pub debug_full_payloads: bool,
+}
+
+/// Logs an organ input whose string values under `sensitive_keys` carry sense payload text.
+pub(crate) fn log_organ_input_with_policy(
+    sampler: &PayloadLogSampler,
+    cycle_id: u64,
+    stage: &str,
+    policy: OrganInputLogPolicy,
+    input: &serde_json::Value,
+    sensitive_keys: &[&str],
+) {
+    if policy.sense_payloads == SensePayloadLogging::Full {
+        log_organ_input(sampler, cycle_id, stage, &pretty_json(input));
+        return;
+    }
+    let redacted = redact_sensitive_values(input, policy.sense_payloads, sensitive_keys);
+    log_organ_input(sampler, cycle_id, stage, &pretty_json(&redacted));
+    if policy.debug_full_payloads {
+        tracing::debug!(
+            target: "cortex",
+            cycle_id = cycle_id,
+            stage = stage,
+            input_payload = %pretty_json(input),
+            "cortex_organ_input_unredacted"
+        );
+    }
+}
+
+/// Replaces string values under `sensitive_keys` at any depth; everything else is kept.
+pub(crate) fn redact_sensitive_values(
+    value: &serde_json::Value,
+    mode: SensePayloadLogging,
+    sensitive_keys: &[&str],
+) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(object) => serde_json::Value::Object(
+            object
+                .iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        serde_json::Value::String(text)
+                            if sensitive_keys.contains(&key.as_str()) =>
+                        {
+                            serde_json::Value::String(redact_text(text, mode))
+                        }
+                        _ => redact_sensitive_values(value, mode, sensitive_keys),
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        serde_json::Value::Array(items) => serde_json::Value::Array(
+            items
+                .iter()
+                .map(|item| redact_sensitive_values(item, mode, sensitive_keys))
+                .collect(),
+        ),
+        _ => value.clone(),
+    }
+}
+
+fn redact_text(text: &str, mode: SensePayloadLogging) -> String {
+    match mode {
+        SensePayloadLogging::Full => text.to_string(),
+        SensePayloadLogging::Redact => format!("[redacted {} bytes]", text.len()),
+        SensePayloadLogging::Hash => {
+            let digest = Sha256::digest(text.as_bytes());
+            let hex = digest[..8]
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>();
+            format!("[sha256:{hex} {} bytes]", text.len())
+        }
+    }
+}
+
+pub(crate) fn log_organ_output(
+    sampler: &PayloadLogSampler,
+    cycle_id: u64,
+    stage: &str,
+    output_payload: &str,
+) {
+    if !sampler.log_sampled_payload(cycle_id, stage, "output", output_payload) {
+        return;
+    }
     tracing::info!(
         target: "cortex",
         cycle_id = cycle_id,
@@ -147,3 +289,38 @@ pub(crate) fn act_descriptors(
     });
     entries
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacted_sense_context_omits_payload_text_but_keeps_structure() {
+        let input = serde_json::json!({
+            "sense_context": [{
+                "sense_ref_id": "s1",
+                "payload": "my card number is 4111-1111",
+                "payload_schema": { "type": "string" },
+            }],
+            "sense_passthrough_max_bytes": 2048,
+        });
+
+        for mode in [SensePayloadLogging::Redact, SensePayloadLogging::Hash] {
+            let redacted = redact_sensitive_values(&input, mode, &["payload"]);
+            let logged = pretty_json(&redacted);
+
+            assert!(!logged.contains("4111"), "{logged}");
+            assert_eq!(redacted["sense_context"][0]["sense_ref_id"], "s1");
+            assert_eq!(
+                redacted["sense_context"][0]["payload_schema"],
+                input["sense_context"][0]["payload_schema"]
+            );
+            assert_eq!(redacted["sense_passthrough_max_bytes"], 2048);
+        }
+        assert_eq!(
+            redact_sensitive_values(&input, SensePayloadLogging::Redact, &["payload"])["sense_context"]
+                [0]["payload"],
+            "[redacted 27 bytes]"
+        );
+    }
+}
@@ -1,13 +1,14 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
-use tokio::time::Duration;
+use tokio::{sync::Semaphore, time::Duration};
 
 use crate::{
     ai_gateway::chat::OutputMode,
     cortex::{
-        error::{CortexError, extractor_failed},
-        helpers::{self, CognitionOrgan, HelperRuntime},
+        error::{CortexError, extractor_failed, internal_error},
+        helpers::{self, CognitionOrgan, HelperRuntime, log_sampling::PayloadLogSampler},
         prompts,
     },
     types::{NeuralSignalDescriptor, Sense, build_fq_neural_signal_id},
@@ -120,6 +121,8 @@ impl SenseInputHelper {
             cycle_id,
             &context,
             runtime.limits().sense_passthrough_max_bytes,
+            runtime.organ_input_log_policy(),
+            runtime.payload_log_sampler(),
         )
         .await
     }
@@ -129,6 +132,8 @@ impl SenseInputHelper {
         cycle_id: u64,
         context: &SenseToolContext,
         sense_passthrough_max_bytes: usize,
+        log_policy: helpers::OrganInputLogPolicy,
+        sampler: &PayloadLogSampler,
     ) -> String {
         let stage = CognitionOrgan::Sense.stage();
         if context.entries().is_empty() {
@@ -136,19 +141,25 @@ impl SenseInputHelper {
                 "sense_context": [],
                 "sense_passthrough_max_bytes": sense_passthrough_max_bytes,
             }));
-            helpers::log_organ_input(cycle_id, stage, &input_payload);
+            helpers::log_organ_input(sampler, cycle_id, stage, &input_payload);
             let output = "[]".to_string();
-            helpers::log_organ_output(cycle_id, stage, &output);
+            helpers::log_organ_output(sampler, cycle_id, stage, &output);
             return output;
         }
 
-        let input_payload = helpers::pretty_json(&serde_json::json!({
-            "sense_context": context.entries(),
-            "sense_passthrough_max_bytes": sense_passthrough_max_bytes,
-        }));
-        helpers::log_organ_input(cycle_id, stage, &input_payload);
+        helpers::log_organ_input_with_policy(
+            sampler,
+            cycle_id,
+            stage,
+            log_policy,
+            &serde_json::json!({
+                "sense_context": context.entries(),
+                "sense_passthrough_max_bytes": sense_passthrough_max_bytes,
+            }),
+            &["payload"],
+        );
         let output = render_sense_lines(context, sense_passthrough_max_bytes);
-        helpers::log_organ_output(cycle_id, stage, &output);
+        helpers::log_organ_output(sampler, cycle_id, stage, &output);
         output
     }
 }
@@ -201,7 +212,7 @@ pub(crate) async fn expand_sense_with_sub_agent(
     context: &SenseToolContext,
     tasks: &[SenseSubAgentTask],
 ) -> Result<serde_json::Value, CortexError> {
-    let mut results = Vec::new();
+    let mut found_entries = Vec::new();
     let mut not_found_sense_ids = Vec::new();
 
     for task in tasks {
@@ -209,44 +220,24 @@ pub(crate) async fn expand_sense_with_sub_agent(
             not_found_sense_ids.push(task.sense_id.clone());
             continue;
         };
+        found_entries.push((entry, task.instruction.clone().unwrap_or_default()));
+    }
 
-        let instruction = task.instruction.clone().unwrap_or_default();
-        let payload_text = entry.payload.clone();
-        let payload_schema_json = serde_json::to_string_pretty(&entry.payload_schema)
-            .unwrap_or_else(|_| "{}".to_string());
-        let prompt = prompts::build_sense_sub_agent_prompt(
-            &payload_text,
-            &payload_schema_json,
-            &instruction,
-        );
-        let response = runtime
-            .run_organ(
-                cycle_id,
-                CognitionOrgan::Sense,
-                runtime.limits().max_sub_output_tokens,
-                prompts::sense_sub_agent_system_prompt(),
-                prompt,
-                OutputMode::JsonSchema {
-                    name: "sense_sub_agent_output".to_string(),
-                    schema: sense_sub_agent_output_json_schema(),
-                    strict: true,
-                },
-            )
-            .await?;
-        let mut envelope: SenseSubAgentEnvelope = serde_json::from_str(&response.output_text)
-            .map_err(|err| extractor_failed(err.to_string()))?;
-        envelope.confidence_score = envelope.confidence_score.clamp(0.0, 1.0);
-
-        results.push(serde_json::json!({
-            "sense_id": entry.sense_ref_id,
-            "endpoint_id": entry.endpoint_id,
-            "neural_signal_descriptor_id": entry.sense_id,
-            "fq_sense_id": entry.fq_sense_id,
-            "instruction": instruction,
-            "result": envelope.result,
-            "confidence_score": envelope.confidence_score,
-        }));
+    let concurrency = runtime.limits().max_sense_sub_agent_concurrency.max(1);
+    let permits = Semaphore::new(concurrency);
+    let mut pending = Vec::with_capacity(found_entries.len());
+    for (entry, instruction) in found_entries {
+        let permits = &permits;
+        pending.push(async move {
+            let _permit = permits
+                .acquire()
+                .await
+                .map_err(|err| internal_error(err.to_string()))?;
+            run_sense_sub_agent(runtime, cycle_id, entry, instruction).await
+        });
     }
+    let outcomes = join_all(pending).await;
+    let results = outcomes.into_iter().collect::<Result<Vec<_>, _>>()?;
 
     Ok(serde_json::json!({
         "results": results,
@@ -254,6 +245,45 @@ pub(crate) async fn expand_sense_with_sub_agent(
     }))
 }
 
+async fn run_sense_sub_agent(
+    runtime: &impl HelperRuntime,
+    cycle_id: u64,
+    entry: &SenseToolContextEntry,
+    instruction: String,
+) -> Result<serde_json::Value, CortexError> {
+    let payload_schema_json =
+        serde_json::to_string_pretty(&entry.payload_schema).unwrap_or_else(|_| "{}".to_string());
+    let prompt =
+        prompts::build_sense_sub_agent_prompt(&entry.payload, &payload_schema_json, &instruction);
+    let response = runtime
+        .run_organ(
+            cycle_id,
+            CognitionOrgan::Sense,
+            runtime.limits().max_sub_output_tokens,
+            prompts::sense_sub_agent_system_prompt(),
+            prompt,
+            OutputMode::JsonSchema {
+                name: "sense_sub_agent_output".to_string(),
+                schema: sense_sub_agent_output_json_schema(),
+                strict: true,
+            },
+        )
+        .await?;
+    let mut envelope: SenseSubAgentEnvelope = serde_json::from_str(&response.output_text)
+        .map_err(|err| extractor_failed(err.to_string()))?;
+    envelope.confidence_score = envelope.confidence_score.clamp(0.0, 1.0);
+
+    Ok(serde_json::json!({
+        "sense_id": entry.sense_ref_id,
+        "endpoint_id": entry.endpoint_id,
+        "neural_signal_descriptor_id": entry.sense_id,
+        "fq_sense_id": entry.fq_sense_id,
+        "instruction": instruction,
+        "result": envelope.result,
+        "confidence_score": envelope.confidence_score,
+    }))
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct SenseInputEvent {
     sense_instance_id: u64,
@@ -411,3 +441,127 @@ fn sense_sub_agent_output_json_schema() -> serde_json::Value {
         "additionalProperties": false
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::{
+        ai_gateway::chat::{FinishReason, TurnResponse},
+        cortex::{testing::TestHooks, types::ReactionLimits},
+    };
+
+    struct CountingRuntime {
+        limits: ReactionLimits,
+        payload_log_sampler: PayloadLogSampler,
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl HelperRuntime for CountingRuntime {
+        fn limits(&self) -> &ReactionLimits {
+            &self.limits
+        }
+
+        fn hooks(&self) -> Option<&TestHooks> {
+            None
+        }
+
+        fn payload_log_sampler(&self) -> &PayloadLogSampler {
+            &self.payload_log_sampler
+        }
+
+        fn emit_stage_failed(&self, _cycle_id: u64, _stage: &'static str) {}
+
+        fn emit_intentional_noop(&self, _cycle_id: u64, _stage: &'static str) {}
+
+        fn emit_act_dropped_unknown_capability(
+            &self,
+            _cycle_id: u64,
+            _endpoint_id: &str,
+            _fq_act_id: &str,
+        ) {
+        }
+
+        async fn run_text_organ_with_system(
+            &self,
+            _cycle_id: u64,
+            _organ: CognitionOrgan,
+            _max_output_tokens: u64,
+            _system_prompt: String,
+            _user_prompt: String,
+        ) -> Result<String, CortexError> {
+            unreachable!("sense sub-agent uses structured organ calls")
+        }
+
+        async fn run_organ(
+            &self,
+            _cycle_id: u64,
+            _organ: CognitionOrgan,
+            _max_output_tokens: u64,
+            _system_prompt: String,
+            _user_prompt: String,
+            _output_mode: OutputMode,
+        ) -> Result<TurnResponse, CortexError> {
+            let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(TurnResponse {
+                output_text: r#"{"result":"ok","confidence_score":0.5}"#.to_string(),
+                reasoning_text: None,
+                tool_calls: vec![],
+                pending_tool_call_continuation: false,
+                usage: None,
+                finish_reason: FinishReason::Stop,
+                backend_metadata: BTreeMap::new(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn sense_sub_agents_run_concurrently_up_to_limit() {
+        let runtime = CountingRuntime {
+            limits: ReactionLimits {
+                max_sense_sub_agent_concurrency: 2,
+                ..ReactionLimits::default()
+            },
+            payload_log_sampler: PayloadLogSampler::default(),
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        };
+        let senses: Vec<Sense> = (0..5)
+            .map(|index| Sense {
+                sense_instance_id: format!("sense-{index}"),
+                endpoint_id: "chat.1".to_string(),
+                neural_signal_descriptor_id: "user_message".to_string(),
+                payload: format!("message {index}"),
+                weight: 1.0,
+                act_instance_id: None,
+            })
+            .collect();
+        let context = SenseToolContext::from_inputs(&senses, &[]);
+        let tasks: Vec<_> = context
+            .entries()
+            .iter()
+            .map(|entry| SenseSubAgentTask {
+                sense_id: entry.sense_ref_id.clone(),
+                instruction: Some("summarize".to_string()),
+            })
+            .collect();
+
+        let expanded = expand_sense_with_sub_agent(&runtime, 1, &context, &tasks)
+            .await
+            .expect("sub-agent expansion should succeed");
+
+        assert_eq!(expanded["results"].as_array().map(Vec::len), Some(5));
+        assert_eq!(runtime.max_in_flight.load(Ordering::SeqCst), 2);
+    }
+}
@@ -13,9 +13,12 @@ pub use error::{CortexError, CortexErrorKind};
 pub use helpers::goal_forest_helper::{GoalForest, GoalNode};
 pub use runtime::{
     AfferentRuleControlPort, Cortex, CortexAfferentAdmission, CortexDeps, CortexRuntime,
-    CortexTelemetryEvent, CortexTelemetryHook, PhysicalStateReadPort,
+    CortexTelemetryBus, CortexTelemetryEvent, CortexTelemetryHook, CortexTelemetryPort,
+    CycleThroughput, CycleThroughputSnapshot, NoopCortexTelemetry, PhysicalStateReadPort,
+    RouteLatencySwitch, SelfSleepRequest, SituationEntry, SituationView,
 };
 pub use types::{
-    CognitionState, CortexControlDirective, CortexOutput, ReactionLimits,
-    new_default_cognition_state,
+    CognitionState, CognitionStateDiff, CognitionStateTrim, CortexControlDirective, CortexOutput,
+    CycleOutputTokenBudget, GoalGraph, GoalGraphEdge, GoalGraphNode, OutputTokenBudgetPolicy,
+    ReactionLimitBounds, ReactionLimits, new_default_cognition_state,
 };
@@ -44,7 +44,8 @@ pub fn attention_system_prompt() -> String {
         "Own only runtime attention controls:\n",
         "1) Call replace-afferent-gating when you want to replace the complete afferent gating ruleset.\n",
         "2) Call sleep when the runtime should skip future admitted ticks for a bounded number of ticks.\n",
-        "3) If the current gating rules and sleep state should remain as-is, return without calling tools.\n",
+        "3) Call tune-reaction-limits, when offered, to propose bounded reaction limits for future cycles.\n",
+        "4) If the current gating rules, sleep state, and limits should remain as-is, return without calling tools.\n",
         "Use at most one call for each singleton control tool."
     )
     .to_string()
@@ -207,6 +208,23 @@ pub fn classify_sleep_act(act: &crate::types::Act) -> Option<u64> {
     act.payload.get("ticks")?.as_u64()
 }
 
+/// On-demand trigger sense; it forces a cycle and never reaches Primary itself.
+pub fn is_cycle_trigger_sense(sense: &Sense) -> bool {
+    sense.endpoint_id == "core.control" && sense.neural_signal_descriptor_id == "cycle.trigger"
+}
+
+/// Synthetic sense injected on idle ticks so Primary can act on elapsed time alone.
+pub fn idle_sense(idle_ticks: u64) -> Sense {
+    Sense {
+        sense_instance_id: uuid::Uuid::now_v7().to_string(),
+        endpoint_id: "core.control".to_string(),
+        neural_signal_descriptor_id: "idle".to_string(),
+        payload: serde_json::json!({ "idle_ticks": idle_ticks }).to_string(),
+        weight: 1.0,
+        act_instance_id: None,
+    }
+}
+
 pub fn references_sleep_descriptor(act_descriptors: &[NeuralSignalDescriptor]) -> bool {
     act_descriptors.iter().any(|descriptor| {
         descriptor.endpoint_id == "core.control"
@@ -1,4 +1,7 @@
-use std::collections::{BTreeMap, VecDeque};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::Arc,
+};
 
 use async_trait::async_trait;
 use regex::Regex;
@@ -8,7 +11,7 @@ use tokio::sync::{Mutex, mpsc};
 use crate::{
     stem::{
         AfferentMiddleware, AfferentMiddlewareDecision, AfferentPathwayError,
-        PathwayMiddlewareDecision, SenseConsumerHandle,
+        PathwayMiddlewareDecision, SenseConsumerHandle, SenseQueueWatermarks,
     },
     types::{Sense, build_fq_neural_signal_id},
 };
@@ -171,21 +174,38 @@ pub struct CortexAfferentAdmission {
     state: Mutex<DeferralState>,
     egress_tx: mpsc::Sender<Sense>,
     max_deferred_senses: usize,
+    watermarks: Option<Arc<SenseQueueWatermarks>>,
 }
 
 impl CortexAfferentAdmission {
     pub fn new(queue_capacity: usize, max_deferred_senses: usize) -> (Self, SenseConsumerHandle) {
+        Self::with_watermarks(queue_capacity, max_deferred_senses, None)
+    }
+
+    /// Same as `new`, observing the egress queue depth against `watermarks` on both ends.
+    pub fn with_watermarks(
+        queue_capacity: usize,
+        max_deferred_senses: usize,
+        watermarks: Option<Arc<SenseQueueWatermarks>>,
+    ) -> (Self, SenseConsumerHandle) {
         let (egress_tx, egress_rx) = mpsc::channel(queue_capacity.max(1));
         (
             Self {
                 state: Mutex::new(DeferralState::default()),
                 egress_tx,
                 max_deferred_senses: max_deferred_senses.max(1),
+                watermarks: watermarks.clone(),
             },
-            SenseConsumerHandle::new(egress_rx),
+            SenseConsumerHandle::with_watermarks(egress_rx, watermarks),
         )
     }
 
+    fn observe_egress_depth(&self) {
+        if let Some(watermarks) = &self.watermarks {
+            watermarks.observe_depth(self.egress_tx.max_capacity() - self.egress_tx.capacity());
+        }
+    }
+
     async fn admit_or_defer(&self, sense: Sense) -> Result<(), AfferentPathwayError> {
         let mut state = self.state.lock().await;
         if matching_rule_ids(&state, &sense).is_empty() {
@@ -197,6 +217,7 @@ impl CortexAfferentAdmission {
                     kind: crate::stem::AfferentPathwayErrorKind::QueueClosed,
                     message: "cortex afferent admission queue is closed".to_string(),
                 })?;
+            self.observe_egress_depth();
             return Ok(());
         }
 
@@ -224,7 +245,7 @@ impl CortexAfferentAdmission {
         let mut not_released = VecDeque::new();
         for sense in released {
             match self.egress_tx.try_send(sense) {
-                Ok(()) => {}
+                Ok(()) => self.observe_egress_depth(),
                 Err(mpsc::error::TrySendError::Full(sense)) => {
                     not_released.push_back(sense);
                 }
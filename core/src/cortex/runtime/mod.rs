@@ -1,4 +1,4 @@
-use std::{collections::VecDeque, sync::Arc};
+use std::{collections::VecDeque, sync::Arc, time::Instant};
 
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
@@ -6,19 +6,39 @@ use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
 use crate::{
+    config::{
+        CycleRateLimitConfig, EndpointSensePolicyRule, SenseBatchOrdering, SenseLaneRule,
+        SenseProcessingPolicy,
+    },
+    cortex::prompts,
     stem::{SenseConsumerHandle, TickGrant},
     types::{PhysicalState, Sense},
 };
 
 mod afferent_admission;
 mod primary;
+mod rate_governor;
+mod self_sleep;
+mod sense_lanes;
+mod sense_policies;
+mod telemetry_bus;
+mod telemetry_port;
+mod throughput;
 
 pub use afferent_admission::{
     AfferentRuleControlPort, CortexAfferentAdmission, DeferralRuleAddInput,
     DeferralRuleSetSnapshot, DeferralRuleSnapshot, RuleControlError, RuleControlErrorKind,
     RuleRevision,
 };
-pub use primary::{Cortex, CortexTelemetryEvent, CortexTelemetryHook};
+pub use primary::{Cortex, CortexTelemetryEvent, CortexTelemetryHook, RouteLatencySwitch};
+use rate_governor::CycleRateGovernor;
+pub use self_sleep::SelfSleepRequest;
+use sense_lanes::SenseLaneRouter;
+pub use sense_lanes::{SituationEntry, SituationView};
+use sense_policies::EndpointSensePolicyRouter;
+pub use telemetry_bus::CortexTelemetryBus;
+pub use telemetry_port::{CortexTelemetryPort, NoopCortexTelemetry};
+pub use throughput::{CycleThroughput, CycleThroughputSnapshot};
 
 #[async_trait]
 pub trait PhysicalStateReadPort: Send + Sync {
@@ -30,6 +50,12 @@ pub struct CortexDeps {
     pub afferent_consumer: SenseConsumerHandle,
     pub physical_state_reader: Arc<dyn PhysicalStateReadPort>,
     pub cortex_core: Arc<Cortex>,
+    pub idle_sense_interval_ticks: Option<u64>,
+    pub on_demand_cycle_trigger: bool,
+    pub sense_lanes: Vec<SenseLaneRule>,
+    pub endpoint_sense_policies: Vec<EndpointSensePolicyRule>,
+    pub sense_batch_ordering: SenseBatchOrdering,
+    pub cycle_rate_limit: Option<CycleRateLimitConfig>,
 }
 
 pub struct CortexRuntime {
@@ -38,19 +64,45 @@ pub struct CortexRuntime {
     cycle_id: u64,
     pending_senses: VecDeque<Sense>,
     ignore_all_triggers_for_ticks_remaining: u64,
+    consecutive_idle_ticks: u64,
+    throughput: Arc<CycleThroughput>,
+    sense_lanes: SenseLaneRouter,
+    sense_policies: EndpointSensePolicyRouter,
+    sense_batch_ordering: SenseBatchOrdering,
+    situation_view: Arc<SituationView>,
+    cycle_rate_governor: Option<CycleRateGovernor>,
 }
 
 impl CortexRuntime {
-    pub fn new(deps: CortexDeps, shutdown: CancellationToken) -> Self {
+    pub fn new(mut deps: CortexDeps, shutdown: CancellationToken) -> Self {
         Self {
+            sense_lanes: SenseLaneRouter::new(std::mem::take(&mut deps.sense_lanes)),
+            sense_policies: EndpointSensePolicyRouter::new(std::mem::take(
+                &mut deps.endpoint_sense_policies,
+            )),
+            situation_view: Arc::new(SituationView::new()),
+            sense_batch_ordering: deps.sense_batch_ordering,
+            cycle_rate_governor: deps.cycle_rate_limit.as_ref().map(CycleRateGovernor::new),
             cycle_id: 0,
             deps,
             shutdown,
             pending_senses: VecDeque::new(),
             ignore_all_triggers_for_ticks_remaining: 0,
+            consecutive_idle_ticks: 0,
+            throughput: Arc::new(CycleThroughput::new()),
         }
     }
 
+    /// Shared handle for reading cycle throughput while the runtime runs.
+    pub fn throughput(&self) -> Arc<CycleThroughput> {
+        Arc::clone(&self.throughput)
+    }
+
+    /// Shared handle for reading senses routed to the situation-view lane.
+    pub fn situation_view(&self) -> Arc<SituationView> {
+        Arc::clone(&self.situation_view)
+    }
+
     #[tracing::instrument(name = "cortex_runtime", target = "cortex", skip(self))]
     pub async fn run(mut self) {
         loop {
@@ -74,37 +126,138 @@ impl CortexRuntime {
                         tracing::info!(target = "cortex", "afferent_consumer_closed");
                         break;
                     };
-                    self.pending_senses.push_back(sense);
+                    if let Err(err) = self.on_sense(sense).await {
+                        tracing::warn!(target = "cortex", error = %err, "cycle_failed_on_trigger");
+                    }
                 }
             }
         }
     }
 
-    async fn on_tick(&mut self, tick: TickGrant) -> Result<()> {
+    async fn on_sense(&mut self, sense: Sense) -> Result<()> {
+        if !(self.deps.on_demand_cycle_trigger && prompts::is_cycle_trigger_sense(&sense)) {
+            return self.enqueue_sense_by_policy(sense).await;
+        }
+
+        if !self.wait_for_cycle_slot().await {
+            return Ok(());
+        }
+        self.drain_pending_senses_nonblocking();
+        self.pending_senses
+            .retain(|pending| !prompts::is_cycle_trigger_sense(pending));
+        tracing::debug!(
+            target = "cortex",
+            endpoint_id = %sense.endpoint_id,
+            pending_sense_count = self.pending_senses.len(),
+            "on_demand_cycle_triggered"
+        );
+        let senses = self.take_sense_batch();
+        self.run_cycle(senses).await
+    }
+
+    /// Queues the sense, then starts a cycle right away when its endpoint is `ImmediateFlush`.
+    async fn enqueue_sense_by_policy(&mut self, sense: Sense) -> Result<()> {
+        let endpoint_id = sense.endpoint_id.clone();
+        if !self.enqueue_sense(sense) {
+            return Ok(());
+        }
+        if !self.wait_for_cycle_slot().await {
+            return Ok(());
+        }
         self.drain_pending_senses_nonblocking();
+        tracing::debug!(
+            target = "cortex",
+            endpoint_id = %endpoint_id,
+            pending_sense_count = self.pending_senses.len(),
+            "immediate_flush_cycle_triggered"
+        );
+        let senses = self.take_sense_batch();
+        self.run_cycle(senses).await
+    }
+
+    async fn on_tick(&mut self, tick: TickGrant) -> Result<()> {
+        let flush_requested = self.drain_pending_senses_nonblocking();
 
         if self.ignore_all_triggers_for_ticks_remaining > 0 {
             self.ignore_all_triggers_for_ticks_remaining = self
                 .ignore_all_triggers_for_ticks_remaining
                 .saturating_sub(1);
+            if !flush_requested {
+                tracing::debug!(
+                    target = "cortex",
+                    tick_seq = tick.tick_seq,
+                    remaining_ticks = self.ignore_all_triggers_for_ticks_remaining,
+                    "tick_ignored_by_sleep_gate"
+                );
+                return Ok(());
+            }
             tracing::debug!(
                 target = "cortex",
                 tick_seq = tick.tick_seq,
-                remaining_ticks = self.ignore_all_triggers_for_ticks_remaining,
-                "tick_ignored_by_sleep_gate"
+                pending_sense_count = self.pending_senses.len(),
+                "immediate_flush_cycle_triggered"
             );
+        }
+        if !self.wait_for_cycle_slot().await {
             return Ok(());
         }
 
-        let senses = self.pending_senses.drain(..).collect::<Vec<_>>();
+        let mut senses = self.take_sense_batch();
+        if senses.is_empty() {
+            self.consecutive_idle_ticks = self.consecutive_idle_ticks.saturating_add(1);
+            if let Some(interval) = self.deps.idle_sense_interval_ticks
+                && self.consecutive_idle_ticks.is_multiple_of(interval.max(1))
+            {
+                tracing::debug!(
+                    target = "cortex",
+                    tick_seq = tick.tick_seq,
+                    idle_ticks = self.consecutive_idle_ticks,
+                    "idle_sense_injected"
+                );
+                senses.push(prompts::idle_sense(self.consecutive_idle_ticks));
+            }
+        } else {
+            self.consecutive_idle_ticks = 0;
+        }
+
+        self.run_cycle(senses).await
+    }
 
+    /// Holds the trigger until the rate governor admits another cycle start, collecting senses
+    /// that arrive meanwhile into the same batch; false when shutdown comes first.
+    async fn wait_for_cycle_slot(&mut self) -> bool {
+        let Some(governor) = &mut self.cycle_rate_governor else {
+            return true;
+        };
+        let delay = governor.reserve_start();
+        if delay.is_zero() {
+            return true;
+        }
+        tracing::debug!(
+            target = "cortex",
+            delay_ms = delay.as_millis() as u64,
+            "cycle_start_delayed_by_rate_governor"
+        );
+        tokio::select! {
+            _ = self.shutdown.cancelled() => false,
+            _ = tokio::time::sleep(delay) => {
+                self.drain_pending_senses_nonblocking();
+                true
+            }
+        }
+    }
+
+    async fn run_cycle(&mut self, senses: Vec<Sense>) -> Result<()> {
         self.cycle_id = self.cycle_id.saturating_add(1);
-        let physical_state = self
+        let started_at = Instant::now();
+        let mut physical_state = self
             .deps
             .physical_state_reader
             .snapshot(self.cycle_id)
             .await
             .map_err(|err| anyhow!("physical_state_snapshot_failed: {err}"))?;
+        self.situation_view
+            .project_into(&mut physical_state.proprioception);
 
         let output = self
             .deps
@@ -116,13 +269,503 @@ impl CortexRuntime {
         if let Some(ticks) = output.control.ignore_all_trigger_for_ticks {
             self.ignore_all_triggers_for_ticks_remaining = ticks.max(1);
         }
+        self.throughput.record_cycle(started_at, Instant::now());
 
         Ok(())
     }
 
-    fn drain_pending_senses_nonblocking(&mut self) {
+    /// Queues every sense already waiting; true when any of them asked for an immediate flush.
+    fn drain_pending_senses_nonblocking(&mut self) -> bool {
+        let mut flush_requested = false;
         while let Ok(sense) = self.deps.afferent_consumer.try_recv() {
-            self.pending_senses.push_back(sense);
+            flush_requested |= self.enqueue_sense(sense);
+        }
+        flush_requested
+    }
+
+    fn take_sense_batch(&mut self) -> Vec<Sense> {
+        let mut senses = self.pending_senses.drain(..).collect::<Vec<_>>();
+        if self.sense_batch_ordering == SenseBatchOrdering::Priority {
+            senses.sort_by(|lhs, rhs| rhs.weight.total_cmp(&lhs.weight));
+        }
+        senses
+    }
+
+    /// Routes and queues one sense under its endpoint policy; true when it was queued with
+    /// `ImmediateFlush`.
+    fn enqueue_sense(&mut self, sense: Sense) -> bool {
+        let Some(sense) = self.sense_lanes.route(sense, &self.situation_view) else {
+            return false;
+        };
+        let policy = self.sense_policies.policy_for(&sense);
+        match policy {
+            SenseProcessingPolicy::DropDuplicates if self.sense_policies.is_duplicate(&sense) => {
+                tracing::debug!(
+                    target = "cortex",
+                    endpoint_id = %sense.endpoint_id,
+                    neural_signal_descriptor_id = %sense.neural_signal_descriptor_id,
+                    "duplicate_sense_dropped"
+                );
+                return false;
+            }
+            SenseProcessingPolicy::Compact => {
+                self.pending_senses.retain(|pending| {
+                    pending.endpoint_id != sense.endpoint_id
+                        || pending.neural_signal_descriptor_id != sense.neural_signal_descriptor_id
+                });
+            }
+            _ => {}
+        }
+        self.pending_senses.push_back(sense);
+        policy == SenseProcessingPolicy::ImmediateFlush
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use tokio::{sync::Mutex, time::Instant};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        continuity::ContinuityEngine,
+        cortex::{ReactionLimits, testing::TestHooks},
+    };
+
+    #[derive(Default)]
+    struct CountingPhysicalState {
+        snapshots: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl PhysicalStateReadPort for CountingPhysicalState {
+        async fn snapshot(&self, cycle_id: u64) -> Result<PhysicalState> {
+            self.snapshots.fetch_add(1, Ordering::SeqCst);
+            Ok(PhysicalState {
+                cycle_id,
+                ledger: Default::default(),
+                ns_descriptor: Default::default(),
+                proprioception: Default::default(),
+            })
+        }
+    }
+
+    struct Harness {
+        runtime: CortexRuntime,
+        physical_state: Arc<CountingPhysicalState>,
+        primary_inputs: Arc<std::sync::Mutex<Vec<String>>>,
+        sense_tx: mpsc::Sender<Sense>,
+        _tick_tx: mpsc::Sender<TickGrant>,
+        path: std::path::PathBuf,
+    }
+
+    impl Harness {
+        fn new(idle_sense_interval_ticks: Option<u64>, on_demand_cycle_trigger: bool) -> Self {
+            Self::with_sense_lanes(
+                idle_sense_interval_ticks,
+                on_demand_cycle_trigger,
+                Vec::new(),
+            )
+        }
+
+        fn with_sense_lanes(
+            idle_sense_interval_ticks: Option<u64>,
+            on_demand_cycle_trigger: bool,
+            sense_lanes: Vec<SenseLaneRule>,
+        ) -> Self {
+            let path = std::env::temp_dir()
+                .join(format!("beluna-cortex-runtime-{}", Uuid::new_v4()))
+                .join("state.json");
+            let continuity = Arc::new(Mutex::new(
+                ContinuityEngine::with_defaults_at(path.clone()).expect("continuity"),
+            ));
+            let primary_inputs = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+            let captured_for_hook = Arc::clone(&primary_inputs);
+            let hooks = TestHooks::new(
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+                Arc::new(move |request: crate::cortex::testing::PrimaryRequest| {
+                    captured_for_hook
+                        .lock()
+                        .expect("capture lock")
+                        .push(request.input_ir);
+                    crate::cortex::testing::boxed(async { Ok("acknowledged".to_string()) })
+                }),
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(Vec::new()) })),
+            );
+            let cortex = Cortex::for_test_with_hooks(hooks, ReactionLimits::default())
+                .with_continuity_for_test(continuity);
+            let physical_state = Arc::new(CountingPhysicalState::default());
+            let (tick_tx, tick_grant_rx) = mpsc::channel(1);
+            let (sense_tx, sense_rx) = mpsc::channel(4);
+            let runtime = CortexRuntime::new(
+                CortexDeps {
+                    tick_grant_rx,
+                    afferent_consumer: SenseConsumerHandle::new(sense_rx),
+                    physical_state_reader: physical_state.clone(),
+                    cortex_core: Arc::new(cortex),
+                    idle_sense_interval_ticks,
+                    on_demand_cycle_trigger,
+                    sense_lanes,
+                    endpoint_sense_policies: Vec::new(),
+                    sense_batch_ordering: SenseBatchOrdering::default(),
+                    cycle_rate_limit: None,
+                },
+                CancellationToken::new(),
+            );
+            Self {
+                runtime,
+                physical_state,
+                primary_inputs,
+                sense_tx,
+                _tick_tx: tick_tx,
+                path,
+            }
+        }
+
+        fn primary_inputs(&self) -> Vec<String> {
+            self.primary_inputs.lock().expect("capture lock").clone()
+        }
+    }
+
+    impl Drop for Harness {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.path);
+            if let Some(parent) = self.path.parent() {
+                let _ = std::fs::remove_dir(parent);
+            }
+        }
+    }
+
+    fn sense(neural_signal_descriptor_id: &str, payload: &str) -> Sense {
+        Sense {
+            sense_instance_id: Uuid::now_v7().to_string(),
+            endpoint_id: "tool.1".to_string(),
+            neural_signal_descriptor_id: neural_signal_descriptor_id.to_string(),
+            payload: payload.to_string(),
+            weight: 1.0,
+            act_instance_id: None,
         }
     }
+
+    fn cycle_trigger(payload: &str) -> Sense {
+        Sense {
+            endpoint_id: "core.control".to_string(),
+            ..sense("cycle.trigger", payload)
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn empty_queue_tick_invokes_reactor_with_idle_sense_when_enabled() -> Result<()> {
+        let mut harness = Harness::new(Some(2), false);
+
+        for tick_seq in 1..=2 {
+            harness
+                .runtime
+                .on_tick(TickGrant {
+                    tick_seq,
+                    emitted_at: Instant::now(),
+                })
+                .await?;
+        }
+
+        let inputs = harness.primary_inputs();
+        assert_eq!(inputs.len(), 2);
+        assert!(!inputs[0].contains("idle_ticks"));
+        assert!(inputs[1].contains("idle_ticks"));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn completed_cycles_are_reflected_in_throughput() -> Result<()> {
+        let mut harness = Harness::new(None, false);
+        let throughput = harness.runtime.throughput();
+
+        for tick_seq in 1..=3 {
+            harness
+                .runtime
+                .on_tick(TickGrant {
+                    tick_seq,
+                    emitted_at: Instant::now(),
+                })
+                .await?;
+        }
+
+        let snapshot = throughput.snapshot();
+        assert_eq!(snapshot.cycles_total, 3);
+        assert!(snapshot.cycles_per_second > 0.0);
+        assert!(snapshot.average_latency > std::time::Duration::ZERO);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn bursting_ticks_start_cycles_no_faster_than_rate_limit() -> Result<()> {
+        let mut harness = Harness::new(None, false);
+        harness.runtime.cycle_rate_governor = Some(CycleRateGovernor::new(&CycleRateLimitConfig {
+            max_cycles_per_second: 20.0,
+            burst: 1,
+        }));
+        let throughput = harness.runtime.throughput();
+
+        let started_at = Instant::now();
+        for tick_seq in 1..=4 {
+            harness
+                .runtime
+                .on_tick(TickGrant {
+                    tick_seq,
+                    emitted_at: Instant::now(),
+                })
+                .await?;
+        }
+
+        assert_eq!(throughput.snapshot().cycles_total, 4);
+        assert!(started_at.elapsed() >= std::time::Duration::from_millis(150));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cycle_trigger_sense_runs_exactly_one_cycle_when_enabled() -> Result<()> {
+        let mut harness = Harness::new(None, true);
+        harness
+            .sense_tx
+            .send(sense("user_message", "hello before trigger"))
+            .await?;
+        harness
+            .sense_tx
+            .send(cycle_trigger("coalesced trigger"))
+            .await?;
+
+        harness.runtime.on_sense(cycle_trigger("forced")).await?;
+
+        assert_eq!(harness.physical_state.snapshots.load(Ordering::SeqCst), 1);
+        let inputs = harness.primary_inputs();
+        assert_eq!(inputs.len(), 1);
+        assert!(inputs[0].contains("hello before trigger"));
+        assert!(!inputs[0].contains("cycle.trigger"));
+        assert!(harness.runtime.pending_senses.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn telemetry_tagged_sense_reaches_primary_only_through_situation_view() -> Result<()> {
+        let mut harness = Harness::with_sense_lanes(
+            None,
+            false,
+            vec![SenseLaneRule {
+                tag: "telemetry".to_string(),
+                neural_signal_descriptor_ids: vec!["battery.level".to_string()],
+                lane: crate::config::SenseLane::SituationView,
+            }],
+        );
+        let situation_view = harness.runtime.situation_view();
+
+        harness
+            .runtime
+            .on_sense(sense("battery.level", r#"{"percent":81}"#))
+            .await?;
+        harness
+            .runtime
+            .on_sense(sense("battery.level", r#"{"percent":80}"#))
+            .await?;
+        harness
+            .runtime
+            .on_tick(TickGrant {
+                tick_seq: 1,
+                emitted_at: Instant::now(),
+            })
+            .await?;
+
+        let inputs = harness.primary_inputs();
+        assert_eq!(inputs.len(), 1);
+        assert!(
+            inputs[0].contains(r#"- situation.telemetry.tool.1.battery.level: {"percent":80}"#)
+        );
+        assert!(!inputs[0].contains(r#"{"percent":81}"#));
+        let situation = situation_view.snapshot();
+        let entry = situation
+            .get(&("tool.1".to_string(), "battery.level".to_string()))
+            .expect("telemetry sense should be recorded");
+        assert_eq!(entry.tag, "telemetry");
+        assert_eq!(entry.sense.payload, r#"{"percent":80}"#);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn injected_denial_feedback_appears_in_next_reaction_input() -> Result<()> {
+        use crate::stem::{
+            ACT_RESULT_INJECT_DESCRIPTOR_ID, ActResultInjection, AfferentMiddleware,
+            ContinueOutput, PathwayMiddlewareDecision,
+        };
+
+        let mut harness = Harness::new(None, false);
+        let injected = sense(
+            ACT_RESULT_INJECT_DESCRIPTOR_ID,
+            &serde_json::json!({
+                "act_instance_id": "act-7",
+                "endpoint_id": "render.1",
+                "neural_signal_descriptor_id": "image.generate",
+                "result": {
+                    "type": "rejected",
+                    "reason_code": "budget_exhausted",
+                    "reference_id": "act-7",
+                },
+            })
+            .to_string(),
+        );
+        let PathwayMiddlewareDecision::Continue(ContinueOutput::Replace(feedback)) =
+            ActResultInjection.handle_sense(&injected).await?
+        else {
+            panic!("injected act result should be replaced with feedback");
+        };
+        for sense in feedback {
+            harness.sense_tx.send(sense).await?;
+        }
+
+        harness
+            .runtime
+            .on_tick(TickGrant {
+                tick_seq: 1,
+                emitted_at: Instant::now(),
+            })
+            .await?;
+
+        let inputs = harness.primary_inputs();
+        assert_eq!(inputs.len(), 1);
+        assert!(inputs[0].contains("act.result"));
+        assert!(inputs[0].contains("budget_exhausted"));
+        assert!(!inputs[0].contains(ACT_RESULT_INJECT_DESCRIPTOR_ID));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn priority_ordering_pulls_later_high_weight_sense_first() -> Result<()> {
+        let mut harness = Harness::new(None, false);
+        harness.runtime.sense_batch_ordering = SenseBatchOrdering::Priority;
+        for (payload, weight) in [("early low", 0.1), ("later low", 0.1), ("urgent", 0.9)] {
+            harness
+                .sense_tx
+                .send(Sense {
+                    weight,
+                    ..sense("user_message", payload)
+                })
+                .await?;
+        }
+
+        harness
+            .runtime
+            .on_tick(TickGrant {
+                tick_seq: 1,
+                emitted_at: Instant::now(),
+            })
+            .await?;
+
+        let inputs = harness.primary_inputs();
+        assert_eq!(inputs.len(), 1);
+        let position = |payload: &str| inputs[0].find(payload).expect("sense in input");
+        assert!(position("urgent") < position("early low"));
+        assert!(position("early low") < position("later low"));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn endpoint_policies_compact_sensor_and_flush_on_control() -> Result<()> {
+        let mut harness = Harness::new(None, false);
+        harness.runtime.sense_policies = EndpointSensePolicyRouter::new(vec![
+            EndpointSensePolicyRule {
+                endpoint_name: Some("sensor".to_string()),
+                neural_signal_descriptor_ids: Vec::new(),
+                policy: SenseProcessingPolicy::Compact,
+            },
+            EndpointSensePolicyRule {
+                endpoint_name: Some("control".to_string()),
+                neural_signal_descriptor_ids: Vec::new(),
+                policy: SenseProcessingPolicy::ImmediateFlush,
+            },
+        ]);
+        let from = |endpoint_id: &str, payload: &str| Sense {
+            endpoint_id: endpoint_id.to_string(),
+            ..sense("reading", payload)
+        };
+
+        for reading in ["reading 1", "reading 2", "reading 3"] {
+            harness.runtime.on_sense(from("sensor.4", reading)).await?;
+        }
+        assert_eq!(harness.physical_state.snapshots.load(Ordering::SeqCst), 0);
+        assert_eq!(harness.runtime.pending_senses.len(), 1);
+
+        harness
+            .runtime
+            .on_sense(from("control.2", "halt now"))
+            .await?;
+
+        assert_eq!(harness.physical_state.snapshots.load(Ordering::SeqCst), 1);
+        let inputs = harness.primary_inputs();
+        assert_eq!(inputs.len(), 1);
+        assert!(inputs[0].contains("halt now"));
+        assert!(inputs[0].contains("reading 3"));
+        assert!(!inputs[0].contains("reading 1"));
+        assert!(!inputs[0].contains("reading 2"));
+        assert!(harness.runtime.pending_senses.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn immediate_flush_sense_drained_on_tick_runs_through_the_sleep_gate() -> Result<()> {
+        let mut harness = Harness::new(None, false);
+        harness.runtime.sense_policies =
+            EndpointSensePolicyRouter::new(vec![EndpointSensePolicyRule {
+                endpoint_name: Some("control".to_string()),
+                neural_signal_descriptor_ids: Vec::new(),
+                policy: SenseProcessingPolicy::ImmediateFlush,
+            }]);
+        harness.runtime.ignore_all_triggers_for_ticks_remaining = 5;
+        harness
+            .sense_tx
+            .send(Sense {
+                endpoint_id: "control.2".to_string(),
+                ..sense("command", "halt now")
+            })
+            .await?;
+
+        harness
+            .runtime
+            .on_tick(TickGrant {
+                tick_seq: 1,
+                emitted_at: Instant::now(),
+            })
+            .await?;
+
+        assert_eq!(harness.physical_state.snapshots.load(Ordering::SeqCst), 1);
+        let inputs = harness.primary_inputs();
+        assert_eq!(inputs.len(), 1);
+        assert!(inputs[0].contains("halt now"));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cycle_trigger_from_a_body_endpoint_is_queued() -> Result<()> {
+        let mut harness = Harness::new(None, true);
+
+        harness
+            .runtime
+            .on_sense(sense("cycle.trigger", "spoofed"))
+            .await?;
+
+        assert_eq!(harness.physical_state.snapshots.load(Ordering::SeqCst), 0);
+        assert_eq!(harness.runtime.pending_senses.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cycle_trigger_sense_is_queued_when_disabled() -> Result<()> {
+        let mut harness = Harness::new(None, false);
+
+        harness.runtime.on_sense(cycle_trigger("forced")).await?;
+
+        assert_eq!(harness.physical_state.snapshots.load(Ordering::SeqCst), 0);
+        assert_eq!(harness.runtime.pending_senses.len(), 1);
+        Ok(())
+    }
 }
@@ -16,9 +16,14 @@ use crate::{
         Chat, ChatMessage, ChatRole, ContentPart, ContextControlReason, DeriveContextOptions,
         FinishReason, OutputMode, SystemPromptAction, Thread, ThreadContextRequest, ThreadOptions,
         ToolExecutor, ToolOverride, TurnInput, TurnLimits, TurnResponse, TurnRetentionPolicy,
+        TurnSampling,
     },
+    ai_gateway::error::{GatewayError, GatewayErrorKind},
     ai_gateway::types::{CHAT_CAPABILITY_ID, ChatRouteAlias, ChatRouteRef},
-    config::CortexRoutesConfig,
+    config::{
+        ActCatalogMode, CognitionStateSizeLimitConfig, CortexRoutesConfig, IrSectionEscaping,
+        IrSectionLimits, ModelParameterPreset,
+    },
     continuity::{
         ContinuityEngine,
         types::{ContinuityRecordBody, ContinuityRecordKey},
@@ -27,25 +32,25 @@ use crate::{
         error::{CortexError, extractor_failed, internal_error, primary_failed},
         helpers::{
             self, CognitionOrgan, CortexHelper, HelperRuntime, goal_forest_helper::GoalNode,
-            sense_input_helper,
+            log_sampling::PayloadLogSampler, sense_input_helper,
         },
         ir, prompts,
         testing::{PrimaryRequest as TestPrimaryRequest, TestHooks},
         types::{
-            CognitionState, CortexControlDirective, CortexOutput, ReactionLimits,
-            validate_cognition_state,
+            CognitionState, CognitionStateTrim, CortexControlDirective, CortexOutput, GoalGraph,
+            ReactionLimits, validate_cognition_state,
         },
     },
     observability::{
         metrics as observability_metrics,
         runtime::{self as observability_runtime, OrganResponseStatus},
     },
-    spine::ActDispatchResult,
+    spine::{ActDispatchResult, error_budget::degraded_endpoint_ids},
     stem::ActProducerHandle,
-    types::{Act, NeuralSignalDescriptor, PhysicalState, Sense},
+    types::{Act, PhysicalState, Sense},
 };
 
-use super::AfferentRuleControlPort;
+use super::{AfferentRuleControlPort, CortexTelemetryPort, SelfSleepRequest};
 
 const COGNITION_STATE_NAMESPACE: &str = "continuity.cognition";
 const COGNITION_STATE_RECORD_ID: &str = "state";
@@ -58,23 +63,78 @@ fn cognition_state_record_key() -> ContinuityRecordKey {
 
 mod apply;
 mod attention;
+mod budget;
 mod cleanup;
 mod executor;
+mod latency_downgrade;
+mod model_presets;
+mod output_cache;
 mod session;
+mod strict_mode;
 mod tools;
+mod tuning;
 
+use budget::CycleOutputTokenLedger;
 use executor::PrimaryToolExecutor;
+use latency_downgrade::LatencyDowngrade;
+pub use latency_downgrade::RouteLatencySwitch;
+use model_presets::OrganModelPresets;
+use output_cache::PrimaryOutputCache;
 use session::PrimarySession;
+use strict_mode::StrictSchemaFallback;
 use tools::{
     ActToolBinding, build_act_tool_bindings, dynamic_act_tool_overrides, primary_internal_tools,
 };
+use tuning::ReactionLimitTuner;
 
 #[derive(Debug, Clone)]
 pub enum CortexTelemetryEvent {
-    ReactionStarted { cycle_id: u64 },
-    StageFailed { cycle_id: u64, stage: &'static str },
-    ReactionCompleted { cycle_id: u64, act_count: usize },
-    NoopFallback { cycle_id: u64, reason: &'static str },
+    ReactionStarted {
+        cycle_id: u64,
+    },
+    StageFailed {
+        cycle_id: u64,
+        stage: &'static str,
+    },
+    ReactionCompleted {
+        cycle_id: u64,
+        act_count: usize,
+    },
+    NoopFallback {
+        cycle_id: u64,
+        reason: &'static str,
+    },
+    /// A stage had nothing to do by decision of an earlier stage, e.g. an empty acts section.
+    IntentionalNoop {
+        cycle_id: u64,
+        stage: &'static str,
+    },
+    ReactionLimitsTuned {
+        cycle_id: u64,
+        limits: ReactionLimits,
+        clamped_fields: Vec<&'static str>,
+    },
+    /// Primary was skipped because an identical input IR has a cached output.
+    PrimaryOutputCacheHit {
+        cycle_id: u64,
+    },
+    /// The acts helper produced an act for a capability missing from the live catalog.
+    ActDroppedUnknownCapability {
+        cycle_id: u64,
+        endpoint_id: String,
+        fq_act_id: String,
+    },
+    /// Goals were trimmed to keep the persisted cognition state under its size cap.
+    CognitionStateTrimmed {
+        cycle_id: u64,
+        trim: CognitionStateTrim,
+        max_serialized_bytes: usize,
+    },
+    /// A route was switched to or back from its fallback because of its recent p95 latency.
+    RouteLatencySwitched {
+        cycle_id: u64,
+        switch: RouteLatencySwitch,
+    },
 }
 
 pub type CortexTelemetryHook = Arc<dyn Fn(CortexTelemetryEvent) + Send + Sync>;
@@ -84,13 +144,27 @@ pub struct Cortex {
     chat: Option<Arc<Chat>>,
     tick_interval_ms: u64,
     routes: CortexRoutesConfig,
+    model_presets: OrganModelPresets,
     hooks: Option<TestHooks>,
     helper: CortexHelper,
-    telemetry_hook: Option<CortexTelemetryHook>,
+    telemetry_ports: Vec<Arc<dyn CortexTelemetryPort>>,
     limits: ReactionLimits,
+    act_catalog_mode: ActCatalogMode,
+    ir_section_escaping: IrSectionEscaping,
+    ir_section_limits: IrSectionLimits,
+    organ_input_log_policy: helpers::OrganInputLogPolicy,
+    payload_log_sampler: Arc<PayloadLogSampler>,
+    output_token_ledger: CycleOutputTokenLedger,
+    limit_tuner: ReactionLimitTuner,
+    strict_schema_fallback: StrictSchemaFallback,
+    latency_downgrade: LatencyDowngrade,
+    dedup_acts_within_cycle: bool,
+    primary_output_cache: PrimaryOutputCache,
+    cognition_state_size_limit: Option<CognitionStateSizeLimitConfig>,
     continuity: Option<Arc<Mutex<ContinuityEngine>>>,
     afferent_rule_control: Option<Arc<dyn AfferentRuleControlPort>>,
     efferent_producer: Option<ActProducerHandle>,
+    self_sleep: Option<Arc<SelfSleepRequest>>,
     primary_session: PrimarySession,
 }
 
@@ -98,6 +172,8 @@ pub struct Cortex {
 struct PrimaryTurnState {
     next_act_seq_no: u64,
     dispatched_act_count: usize,
+    /// Act instance ids by dedup key, tracked only with `dedup_acts_within_cycle`.
+    dispatched_act_keys: HashMap<String, String>,
     break_primary_phase_requested: bool,
     protocol_violation: Option<String>,
 }
@@ -143,13 +219,40 @@ impl Cortex {
             chat: Some(chat),
             tick_interval_ms: tick_interval_ms.max(1),
             routes: config.routes.clone(),
+            model_presets: OrganModelPresets::new(&config.model_presets, &config.organ_presets),
             hooks: None,
-            helper: CortexHelper::default(),
-            telemetry_hook,
+            helper: CortexHelper::with_act_policies(
+                config.act_priorities.clone(),
+                config.unknown_capability_acts,
+            ),
+            telemetry_ports: telemetry_hook
+                .map(|hook| Arc::new(hook) as Arc<dyn CortexTelemetryPort>)
+                .into_iter()
+                .collect(),
             limits,
+            act_catalog_mode: config.act_catalog_mode,
+            ir_section_escaping: config.ir_section_escaping,
+            ir_section_limits: config.ir_section_limits.clone(),
+            organ_input_log_policy: helpers::OrganInputLogPolicy {
+                sense_payloads: config.sense_payload_logging,
+                debug_full_payloads: config.debug_log_full_sense_payloads,
+            },
+            payload_log_sampler: Arc::new(PayloadLogSampler::new(
+                config.organ_log_full_payload_rate,
+            )),
+            output_token_ledger: CycleOutputTokenLedger::default(),
+            limit_tuner: ReactionLimitTuner::new(config.reaction_limit_bounds.clone()),
+            strict_schema_fallback: StrictSchemaFallback::new(
+                config.strict_schema_fallback.clone(),
+            ),
+            latency_downgrade: LatencyDowngrade::new(config.latency_downgrade.clone()),
+            dedup_acts_within_cycle: config.dedup_acts_within_cycle,
+            primary_output_cache: PrimaryOutputCache::new(config.primary_output_cache.as_ref()),
+            cognition_state_size_limit: config.cognition_state_size_limit.clone(),
             continuity,
             afferent_rule_control,
             efferent_producer,
+            self_sleep: None,
             primary_session: PrimarySession::new(),
         }
     }
@@ -160,17 +263,54 @@ impl Cortex {
             chat: None,
             tick_interval_ms: 1_000,
             routes: CortexRoutesConfig::default(),
+            model_presets: OrganModelPresets::default(),
             hooks: Some(hooks),
             helper: CortexHelper::default(),
-            telemetry_hook: None,
+            telemetry_ports: Vec::new(),
             limits,
+            act_catalog_mode: ActCatalogMode::FullSchema,
+            ir_section_escaping: IrSectionEscaping::Cdata,
+            ir_section_limits: IrSectionLimits::default(),
+            organ_input_log_policy: helpers::OrganInputLogPolicy::default(),
+            payload_log_sampler: Arc::default(),
+            output_token_ledger: CycleOutputTokenLedger::default(),
+            limit_tuner: ReactionLimitTuner::default(),
+            strict_schema_fallback: StrictSchemaFallback::default(),
+            latency_downgrade: LatencyDowngrade::default(),
+            dedup_acts_within_cycle: false,
+            primary_output_cache: PrimaryOutputCache::default(),
+            cognition_state_size_limit: None,
             continuity: None,
             afferent_rule_control: None,
             efferent_producer: None,
+            self_sleep: None,
             primary_session: PrimarySession::new(),
         }
     }
 
+    /// Offers Primary the `control-sleep` tool, raising requests on `self_sleep`.
+    pub fn with_self_sleep(mut self, self_sleep: Arc<SelfSleepRequest>) -> Self {
+        self.self_sleep = Some(self_sleep);
+        self
+    }
+
+    /// Adds a port that observes every emitted telemetry event, after any already registered.
+    ///
+    /// This is the only telemetry fan-out; broadcast subscribers attach a `CortexTelemetryBus`.
+    pub fn with_telemetry_port(mut self, port: Arc<dyn CortexTelemetryPort>) -> Self {
+        self.telemetry_ports.push(port);
+        self
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_continuity_for_test(
+        mut self,
+        continuity: Arc<Mutex<ContinuityEngine>>,
+    ) -> Self {
+        self.continuity = Some(continuity);
+        self
+    }
+
     pub async fn load_cognition_state(&self) -> Result<CognitionState, CortexError> {
         let continuity = self.continuity.as_ref().ok_or_else(|| {
             CortexError::new(
@@ -209,6 +349,12 @@ impl Cortex {
         Ok(state)
     }
 
+    /// Current goal forest as a node/edge graph; render it with [`GoalGraph::to_dot`].
+    pub async fn goal_graph(&self) -> Result<GoalGraph, CortexError> {
+        let state = self.load_cognition_state().await?;
+        Ok(GoalGraph::from_forest(&state.goal_forest))
+    }
+
     pub async fn save_cognition_state(&self, state: CognitionState) -> Result<(), CortexError> {
         let continuity = self.continuity.as_ref().ok_or_else(|| {
             CortexError::new(
@@ -237,6 +383,17 @@ impl Cortex {
         &self,
         senses: &[Sense],
         physical_state: &PhysicalState,
+    ) -> Result<CortexOutput, CortexError> {
+        match self.with_tuned_limits() {
+            Some(tuned) => tuned.run_reaction(senses, physical_state).await,
+            None => self.run_reaction(senses, physical_state).await,
+        }
+    }
+
+    async fn run_reaction(
+        &self,
+        senses: &[Sense],
+        physical_state: &PhysicalState,
     ) -> Result<CortexOutput, CortexError> {
         self.emit(CortexTelemetryEvent::ReactionStarted {
             cycle_id: physical_state.cycle_id,
@@ -277,6 +434,8 @@ impl Cortex {
                 physical_state.cycle_id,
                 &sense_tool_context,
                 self.limits.sense_passthrough_max_bytes,
+                self.organ_input_log_policy,
+                &self.payload_log_sampler,
             ),
             async {
                 self.helper
@@ -310,30 +469,50 @@ impl Cortex {
             input_ir_goal_forest = %goal_forest_section,
             "input_ir_goal_forest"
         );
-        let input_ir = ir::build_input_ir(
+        let primary_input_payload = ir::build_primary_input_payload(
             &senses_section,
             &proprioception_section,
             &goal_forest_section,
+            self.ir_section_escaping,
+            &self.ir_section_limits,
         );
-        let primary_input_payload = ir::build_primary_input_payload(
-            &senses_section,
+        let input_ir = ir::input_ir_from_payload(&primary_input_payload);
+
+        let cache_key = self.primary_output_cache.key_for(
+            &sense_tool_context,
             &proprioception_section,
             &goal_forest_section,
         );
-
-        let primary_result = timeout(
-            deadline,
-            self.run_primary_engine(
-                physical_state.cycle_id,
-                primary_input_payload,
-                input_ir.text.clone(),
-                sense_tool_context,
-                act_descriptors.clone(),
-                sense_descriptors.clone(),
-                goal_forest.nodes.clone(),
-            ),
-        )
-        .await;
+        let cached = cache_key
+            .as_deref()
+            .and_then(|key| self.primary_output_cache.get(key));
+        let primary_result = if let Some(cached) = cached {
+            self.emit(CortexTelemetryEvent::PrimaryOutputCacheHit {
+                cycle_id: physical_state.cycle_id,
+            });
+            Ok(Ok(cached))
+        } else {
+            let result = timeout(
+                deadline,
+                self.run_primary_engine(
+                    physical_state.cycle_id,
+                    primary_input_payload,
+                    input_ir.text.clone(),
+                    sense_tool_context,
+                    build_act_tool_bindings(
+                        &act_descriptors,
+                        &sense_descriptors,
+                        &degraded_endpoint_ids(&physical_state.proprioception),
+                    ),
+                    goal_forest.nodes.clone(),
+                ),
+            )
+            .await;
+            if let (Some(key), Ok(Ok(output))) = (cache_key, &result) {
+                self.primary_output_cache.insert(key, output);
+            }
+            result
+        };
         let primary_output = match primary_result {
             Ok(Ok(output)) => output,
             Ok(Err(err)) => {
@@ -404,7 +583,10 @@ impl Cortex {
 
                 match attention_result {
                     Ok(attention_output) => {
-                        match self.apply_attention_result(attention_output).await {
+                        match self
+                            .apply_attention_result(physical_state.cycle_id, attention_output)
+                            .await
+                        {
                             Ok(attention_control) => {
                                 control.ignore_all_trigger_for_ticks =
                                     attention_control.ignore_all_trigger_for_ticks;
@@ -495,17 +677,18 @@ impl Cortex {
         primary_input: String,
         input_ir_internal: String,
         sense_tool_context: sense_input_helper::SenseToolContext,
-        act_descriptors: Vec<NeuralSignalDescriptor>,
-        sense_descriptors: Vec<NeuralSignalDescriptor>,
+        act_bindings: Vec<ActToolBinding>,
         initial_goal_forest_nodes: Vec<GoalNode>,
     ) -> Result<PrimaryEngineResult, CortexError> {
-        let act_bindings = build_act_tool_bindings(&act_descriptors, &sense_descriptors);
         let fresh_act_binding_map = act_bindings
             .iter()
             .map(|binding| (binding.alias.clone(), binding.clone()))
             .collect::<HashMap<_, _>>();
-        let fresh_dynamic_act_tool_overrides =
-            dynamic_act_tool_overrides(&act_bindings, self.limits.max_waiting_ticks);
+        let fresh_dynamic_act_tool_overrides = dynamic_act_tool_overrides(
+            &act_bindings,
+            self.limits.max_waiting_ticks,
+            self.act_catalog_mode,
+        );
 
         if let Some(hooks) = &self.hooks {
             let output = (hooks.primary)(TestPrimaryRequest {
@@ -514,7 +697,7 @@ impl Cortex {
             })
             .await?;
             let stage = CognitionOrgan::Primary.stage();
-            helpers::log_organ_output(cycle_id, stage, &output);
+            helpers::log_organ_output(&self.payload_log_sampler, cycle_id, stage, &output);
             return Ok(PrimaryEngineResult {
                 output_text: output,
                 dispatched_act_count: 0,
@@ -572,7 +755,7 @@ impl Cortex {
             act_tool_aliases.sort();
 
             let stage = CognitionOrgan::Primary.stage();
-            let input_payload = helpers::pretty_json(&serde_json::json!({
+            let input_payload = serde_json::json!({
                 "primary_input": &primary_input,
                 "input_ir_internal": &input_ir_internal,
                 "mode": mode,
@@ -585,8 +768,16 @@ impl Cortex {
                     .map(|entry| entry.sense_ref_id.clone())
                     .collect::<Vec<_>>(),
                 "act_tool_aliases": act_tool_aliases,
-            }));
-            helpers::log_organ_input(cycle_id, stage, &input_payload);
+            });
+            // The primary input embeds rendered senses, so it is redacted as a whole.
+            helpers::log_organ_input_with_policy(
+                &self.payload_log_sampler,
+                cycle_id,
+                stage,
+                self.organ_input_log_policy,
+                &input_payload,
+                &["primary_input", "input_ir_internal"],
+            );
 
             let tool_executor = Arc::new(PrimaryToolExecutor::new(
                 self.clone(),
@@ -629,7 +820,12 @@ impl Cortex {
 
             if turn_state.break_primary_phase_requested {
                 if !assistant_text.is_empty() {
-                    helpers::log_organ_output(cycle_id, stage, &assistant_text);
+                    helpers::log_organ_output(
+                        &self.payload_log_sampler,
+                        cycle_id,
+                        stage,
+                        &assistant_text,
+                    );
                 }
                 return Ok(PrimaryEngineResult {
                     output_text: assistant_text,
@@ -647,7 +843,12 @@ impl Cortex {
                 mode = "tool_continuation";
             } else {
                 if !assistant_text.is_empty() {
-                    helpers::log_organ_output(cycle_id, stage, &assistant_text);
+                    helpers::log_organ_output(
+                        &self.payload_log_sampler,
+                        cycle_id,
+                        stage,
+                        &assistant_text,
+                    );
                 }
                 input_messages = vec![build_primary_user_message(
                     &prompts::primary_break_reminder_prompt(),
@@ -677,7 +878,7 @@ impl Cortex {
         let options = ThreadOptions {
             thread_id: Some("cortex-primary-thread".to_string()),
             route_ref,
-            tools: primary_internal_tools(),
+            tools: primary_internal_tools(self.self_sleep.is_some()),
             system_prompt: Some(prompts::primary_system_prompt()),
             metadata: organ_thread_metadata(cycle_id, CognitionOrgan::Primary.stage()),
             ..ThreadOptions::default()
@@ -753,6 +954,11 @@ impl Cortex {
         let stage = CognitionOrgan::Primary.stage();
         let request_id = format!("cortex-{stage}-{cycle_id}-turn-{step}");
         let started_at = Instant::now();
+        let max_output_tokens = self.reserve_output_tokens(
+            cycle_id,
+            CognitionOrgan::Primary,
+            self.limits.max_primary_output_tokens,
+        )?;
         let route_or_organ = self
             .resolve_route(CognitionOrgan::Primary)
             .unwrap_or_else(|| stage.to_string());
@@ -764,7 +970,7 @@ impl Cortex {
             serde_json::json!({
                 "input_messages": input_messages,
                 "tool_overrides": tool_overrides_payload(&tool_overrides),
-                "max_output_tokens": self.limits.max_primary_output_tokens,
+                "max_output_tokens": max_output_tokens,
                 "max_request_time_ms": self.limits.max_cycle_time_ms,
                 "output_mode": "text",
             }),
@@ -772,11 +978,12 @@ impl Cortex {
         let mut input = build_turn_input(
             cycle_id,
             request_id.clone(),
-            self.limits.max_primary_output_tokens,
+            max_output_tokens,
             self.limits.max_cycle_time_ms,
             input_messages,
             tool_overrides,
             stage,
+            self.model_presets.for_organ(CognitionOrgan::Primary),
             OutputMode::Text,
         );
         input.tool_executor = tool_executor;
@@ -816,6 +1023,7 @@ impl Cortex {
             Some(output.thread_id.as_str()),
             Some(output.turn_id),
         );
+        self.settle_output_tokens(cycle_id, max_output_tokens, &output.response);
 
         Ok(output.response)
     }
@@ -860,9 +1068,14 @@ impl Cortex {
         let stage = organ.stage();
         let request_id = format!("cortex-{stage}-{cycle_id}");
         let started_at = Instant::now();
-        let route = self.resolve_route(organ);
+        let route = self.latency_downgrade.select(self.resolve_route(organ));
         let route_or_organ = route.clone().unwrap_or_else(|| stage.to_string());
-        let output_mode_label = output_mode_label(&output_mode);
+        let resolved_mode = self
+            .strict_schema_fallback
+            .resolve(organ, cycle_id, output_mode);
+        let output_mode_label = output_mode_label(&resolved_mode.output_mode);
+        let max_output_tokens = self.reserve_output_tokens(cycle_id, organ, max_output_tokens)?;
+        let organ_timeout = self.limits.organ_timeout(stage);
         observability_runtime::emit_cortex_organ_start(
             cycle_id,
             stage,
@@ -879,7 +1092,7 @@ impl Cortex {
             cycle_id,
             request_id.clone(),
             max_output_tokens,
-            self.limits.max_cycle_time_ms,
+            organ_timeout.as_millis() as u64,
             vec![ChatMessage {
                 role: ChatRole::User,
                 parts: vec![ContentPart::Text {
@@ -891,7 +1104,8 @@ impl Cortex {
             }],
             Vec::new(),
             stage,
-            output_mode,
+            self.model_presets.for_organ(organ),
+            resolved_mode.output_mode,
         );
 
         let chat = self.chat.as_ref().ok_or_else(|| {
@@ -903,7 +1117,7 @@ impl Cortex {
         let thread = match chat
             .open_thread(ThreadOptions {
                 thread_id: Some(format!("cortex-{stage}-{cycle_id}-thread")),
-                route_ref: alias_route_ref(route),
+                route_ref: alias_route_ref(route.clone()),
                 system_prompt: Some(system_prompt),
                 metadata: {
                     let mut metadata = organ_thread_metadata(cycle_id, stage);
@@ -931,7 +1145,23 @@ impl Cortex {
             }
         };
 
-        let result = thread.complete(input).await;
+        let result = match timeout(organ_timeout, thread.complete(input)).await {
+            Ok(result) => result,
+            Err(_) => Err(GatewayError::new(
+                GatewayErrorKind::Timeout,
+                format!(
+                    "{stage} exceeded its {}ms organ timeout",
+                    organ_timeout.as_millis()
+                ),
+            )
+            .with_retryable(false)),
+        };
+        if let Some(switch) = self
+            .latency_downgrade
+            .record(route.as_deref(), started_at.elapsed().as_millis() as u64)
+        {
+            self.emit(CortexTelemetryEvent::RouteLatencySwitched { cycle_id, switch });
+        }
         let output = result.map_err(|err| {
             observability_runtime::emit_cortex_organ_end(
                 cycle_id,
@@ -954,8 +1184,14 @@ impl Cortex {
                 error = %err.message,
                 "llm_call_failed"
             );
+            if resolved_mode.strict_attempt && strict_mode::is_strict_mode_failure(&err) {
+                self.strict_schema_fallback.record(organ, cycle_id, false);
+            }
             map_organ_gateway_error(organ, err.to_string())
         })?;
+        if resolved_mode.strict_attempt {
+            self.strict_schema_fallback.record(organ, cycle_id, true);
+        }
         observability_runtime::emit_cortex_organ_end(
             cycle_id,
             stage,
@@ -967,21 +1203,47 @@ impl Cortex {
             Some(output.thread_id.as_str()),
             Some(output.turn_id),
         );
+        self.settle_output_tokens(cycle_id, max_output_tokens, &output.response);
+        if let Some(schema) = &resolved_mode.validate_against {
+            self.validate_downgraded_output(organ, schema, &output.response.output_text)?;
+        }
 
         Ok(output.response)
     }
 
     async fn persist_goal_forest_nodes(
         &self,
+        cycle_id: u64,
         goal_forest_nodes: &[GoalNode],
     ) -> Result<u64, CortexError> {
-        let mut state = self.load_cognition_state().await?;
-        if state.goal_forest.nodes == goal_forest_nodes {
-            return Ok(state.revision);
-        }
+        let previous = self.load_cognition_state().await?;
+        let mut state = previous.clone();
         state.goal_forest.nodes = goal_forest_nodes.to_vec();
+        if let Some(limit) = &self.cognition_state_size_limit
+            && let Some(trim) = state
+                .trim_to_serialized_size(limit.max_serialized_bytes, &limit.trimmable_goal_statuses)
+        {
+            self.emit(CortexTelemetryEvent::CognitionStateTrimmed {
+                cycle_id,
+                trim,
+                max_serialized_bytes: limit.max_serialized_bytes,
+            });
+        }
+        if state.goal_forest == previous.goal_forest {
+            return Ok(previous.revision);
+        }
         state.revision = state.revision.saturating_add(1);
         self.save_cognition_state(state.clone()).await?;
+        let diff = previous.diff(&state);
+        if !diff.is_empty() {
+            tracing::debug!(
+                target: "cortex",
+                from_revision = diff.from_revision,
+                to_revision = diff.to_revision,
+                cognition_diff = %serde_json::to_string(&diff).unwrap_or_default(),
+                "cognition_state_changed"
+            );
+        }
         Ok(state.revision)
     }
 
@@ -1031,6 +1293,8 @@ impl Cortex {
                     stage = *stage,
                     "stage_failed"
                 );
+                self.payload_log_sampler
+                    .log_retained_payloads_on_failure(*cycle_id, stage);
             }
             CortexTelemetryEvent::ReactionCompleted {
                 cycle_id,
@@ -1051,10 +1315,99 @@ impl Cortex {
                     "noop_fallback"
                 );
             }
+            CortexTelemetryEvent::IntentionalNoop { cycle_id, stage } => {
+                tracing::debug!(
+                    target: "cortex",
+                    cycle_id = *cycle_id,
+                    stage = *stage,
+                    "intentional_noop"
+                );
+            }
+            CortexTelemetryEvent::ReactionLimitsTuned {
+                cycle_id,
+                limits,
+                clamped_fields,
+            } => {
+                tracing::info!(
+                    target: "cortex",
+                    cycle_id = *cycle_id,
+                    max_cycle_time_ms = limits.max_cycle_time_ms,
+                    max_primary_turns_per_tick = limits.max_primary_turns_per_tick,
+                    sense_passthrough_max_bytes = limits.sense_passthrough_max_bytes,
+                    max_waiting_ticks = limits.max_waiting_ticks,
+                    max_sense_sub_agent_concurrency = limits.max_sense_sub_agent_concurrency,
+                    clamped_fields = ?clamped_fields,
+                    "reaction_limits_tuned"
+                );
+            }
+            CortexTelemetryEvent::PrimaryOutputCacheHit { cycle_id } => {
+                tracing::info!(
+                    target: "cortex",
+                    cycle_id = *cycle_id,
+                    "primary_output_cache_hit"
+                );
+            }
+            CortexTelemetryEvent::ActDroppedUnknownCapability {
+                cycle_id,
+                endpoint_id,
+                fq_act_id,
+            } => {
+                tracing::warn!(
+                    target: "cortex",
+                    cycle_id = *cycle_id,
+                    endpoint_id = %endpoint_id,
+                    fq_act_id = %fq_act_id,
+                    "act_dropped_unknown_capability"
+                );
+            }
+            CortexTelemetryEvent::CognitionStateTrimmed {
+                cycle_id,
+                trim,
+                max_serialized_bytes,
+            } => {
+                tracing::warn!(
+                    target: "cortex",
+                    cycle_id = *cycle_id,
+                    trimmed_goal_ids = ?trim.trimmed_goal_ids,
+                    serialized_bytes_before = trim.serialized_bytes_before,
+                    serialized_bytes_after = trim.serialized_bytes_after,
+                    max_serialized_bytes = *max_serialized_bytes,
+                    still_over_cap = trim.serialized_bytes_after > *max_serialized_bytes,
+                    "cognition_state_trimmed"
+                );
+            }
+            CortexTelemetryEvent::RouteLatencySwitched { cycle_id, switch } => match switch {
+                RouteLatencySwitch::Downgraded {
+                    route,
+                    fallback_route,
+                    p95_latency_ms,
+                } => {
+                    tracing::warn!(
+                        target: "cortex",
+                        cycle_id = *cycle_id,
+                        route = %route,
+                        fallback_route = %fallback_route,
+                        p95_latency_ms = *p95_latency_ms,
+                        "route_latency_downgraded"
+                    );
+                }
+                RouteLatencySwitch::Restored {
+                    route,
+                    p95_latency_ms,
+                } => {
+                    tracing::info!(
+                        target: "cortex",
+                        cycle_id = *cycle_id,
+                        route = %route,
+                        p95_latency_ms = *p95_latency_ms,
+                        "route_latency_restored"
+                    );
+                }
+            },
         }
 
-        if let Some(hook) = &self.telemetry_hook {
-            hook(event);
+        for port in &self.telemetry_ports {
+            port.emit(event.clone());
         }
     }
 }
@@ -1073,6 +1426,31 @@ impl HelperRuntime for Cortex {
         self.emit(CortexTelemetryEvent::StageFailed { cycle_id, stage });
     }
 
+    fn emit_intentional_noop(&self, cycle_id: u64, stage: &'static str) {
+        self.emit(CortexTelemetryEvent::IntentionalNoop { cycle_id, stage });
+    }
+
+    fn emit_act_dropped_unknown_capability(
+        &self,
+        cycle_id: u64,
+        endpoint_id: &str,
+        fq_act_id: &str,
+    ) {
+        self.emit(CortexTelemetryEvent::ActDroppedUnknownCapability {
+            cycle_id,
+            endpoint_id: endpoint_id.to_string(),
+            fq_act_id: fq_act_id.to_string(),
+        });
+    }
+
+    fn organ_input_log_policy(&self) -> helpers::OrganInputLogPolicy {
+        self.organ_input_log_policy
+    }
+
+    fn payload_log_sampler(&self) -> &PayloadLogSampler {
+        &self.payload_log_sampler
+    }
+
     async fn run_text_organ_with_system(
         &self,
         cycle_id: u64,
@@ -1117,11 +1495,12 @@ impl HelperRuntime for Cortex {
 fn build_turn_input(
     cycle_id: u64,
     request_id: String,
-    _max_output_tokens: u64,
+    max_output_tokens: Option<u64>,
     max_request_time_ms: u64,
     messages: Vec<ChatMessage>,
     tool_overrides: Vec<ToolOverride>,
     stage: &'static str,
+    preset: Option<&ModelParameterPreset>,
     output_mode: OutputMode,
 ) -> TurnInput {
     let mut metadata = BTreeMap::new();
@@ -1134,10 +1513,17 @@ fn build_turn_input(
         tool_overrides,
         output_mode: Some(output_mode),
         limits: Some(TurnLimits {
-            // Paused: keep config contract for future resume, but do not enforce token caps now.
-            max_output_tokens: None,
+            // Paused unless a cycle output-token budget or a preset cap is configured.
+            max_output_tokens: max_output_tokens
+                .or_else(|| preset.and_then(|preset| preset.max_output_tokens)),
             max_request_time_ms: Some(max_request_time_ms),
         }),
+        sampling: preset.map(|preset| TurnSampling {
+            temperature: preset.temperature,
+            top_p: preset.top_p,
+            reasoning_effort: preset.reasoning_effort,
+            seed: preset.seed,
+        }),
         enable_thinking: Some(false),
         metadata,
         ..TurnInput::default()
@@ -1172,6 +1558,17 @@ fn map_organ_gateway_error(organ: CognitionOrgan, message: String) -> CortexErro
 }
 
 fn log_output_token_limits_paused(limits: &ReactionLimits) {
+    if let Some(budget) = &limits.cycle_output_token_budget {
+        tracing::info!(
+            target: "cortex",
+            max_primary_output_tokens = limits.max_primary_output_tokens,
+            max_sub_output_tokens = limits.max_sub_output_tokens,
+            total_output_tokens = budget.total_output_tokens,
+            policy = ?budget.policy,
+            "output_token_limits_budgeted"
+        );
+        return;
+    }
     tracing::info!(
         target: "cortex",
         max_primary_output_tokens = limits.max_primary_output_tokens,
@@ -1269,6 +1666,14 @@ mod tests {
     use uuid::Uuid;
 
     use super::*;
+    use crate::ai_gateway::{
+        credentials::EnvCredentialProvider,
+        types::{
+            AIGatewayConfig, BackendDialect, BackendProfile, ChatConfig, CredentialRef,
+            HttpClientConfig, ModelProfile, ResilienceConfig,
+        },
+    };
+    use crate::local_http::{LocalJsonServer, ScriptedResponse};
 
     #[tokio::test(flavor = "multi_thread")]
     async fn cognition_state_persists_through_generic_continuity_record()
@@ -1281,13 +1686,27 @@ mod tests {
             chat: None,
             tick_interval_ms: 1,
             routes: CortexRoutesConfig::default(),
+            model_presets: OrganModelPresets::default(),
             hooks: None,
             helper: CortexHelper::default(),
-            telemetry_hook: None,
+            telemetry_ports: Vec::new(),
             limits: ReactionLimits::default(),
+            act_catalog_mode: ActCatalogMode::FullSchema,
+            ir_section_escaping: IrSectionEscaping::Cdata,
+            ir_section_limits: IrSectionLimits::default(),
+            organ_input_log_policy: helpers::OrganInputLogPolicy::default(),
+            payload_log_sampler: Arc::default(),
+            output_token_ledger: CycleOutputTokenLedger::default(),
+            limit_tuner: ReactionLimitTuner::default(),
+            strict_schema_fallback: StrictSchemaFallback::default(),
+            latency_downgrade: LatencyDowngrade::default(),
+            dedup_acts_within_cycle: false,
+            primary_output_cache: PrimaryOutputCache::default(),
+            cognition_state_size_limit: None,
             continuity: Some(Arc::clone(&continuity)),
             afferent_rule_control: None,
             efferent_producer: None,
+            self_sleep: None,
             primary_session: PrimarySession::new(),
         };
 
@@ -1315,6 +1734,354 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn identical_input_ir_within_ttl_reuses_cached_primary_output()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let path = unique_state_path("primary-cache");
+        let continuity = Arc::new(Mutex::new(ContinuityEngine::with_defaults_at(
+            path.clone(),
+        )?));
+        let primary_calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let primary_calls_for_hook = Arc::clone(&primary_calls);
+        let hooks = TestHooks::new(
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+            Arc::new(move |_| {
+                primary_calls_for_hook.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                crate::cortex::testing::boxed(async { Ok("acknowledged".to_string()) })
+            }),
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(Vec::new()) })),
+        );
+        let cache_hits = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let cache_hits_for_hook = Arc::clone(&cache_hits);
+        let mut cortex = Cortex::for_test_with_hooks(hooks, ReactionLimits::default());
+        cortex.continuity = Some(Arc::clone(&continuity));
+        cortex.primary_output_cache =
+            PrimaryOutputCache::new(Some(&crate::config::PrimaryOutputCacheConfig {
+                ttl_ms: 60_000,
+                ..crate::config::PrimaryOutputCacheConfig::default()
+            }));
+        let hook: CortexTelemetryHook = Arc::new(move |event| {
+            if let CortexTelemetryEvent::PrimaryOutputCacheHit { cycle_id } = event {
+                cache_hits_for_hook
+                    .lock()
+                    .expect("capture lock")
+                    .push(cycle_id);
+            }
+        });
+        cortex.telemetry_ports.push(Arc::new(hook));
+
+        let senses = vec![test_sense("chat.1", "user_message", "hello again")];
+        for cycle_id in [1, 2] {
+            let physical_state = PhysicalState {
+                cycle_id,
+                ledger: Default::default(),
+                ns_descriptor: Default::default(),
+                proprioception: Default::default(),
+            };
+            cortex.cortex(&senses, &physical_state).await?;
+        }
+
+        assert_eq!(primary_calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(*cache_hits.lock().expect("capture lock"), vec![2]);
+
+        let _ = fs::remove_file(&path);
+        if let Some(parent) = path.parent() {
+            let _ = fs::remove_dir(parent);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn acts_helper_route_selects_dedicated_backend_while_other_stages_use_default() {
+        use crate::ai_gateway::{
+            router::BackendRouter,
+            types::{
+                AIGatewayConfig, BackendDialect, BackendProfile, ChatConfig, CredentialRef,
+                HttpClientConfig, ModelProfile, ResilienceConfig,
+            },
+        };
+
+        let backend = |id: &str, model: &str, alias: &str| BackendProfile {
+            id: id.to_string(),
+            dialect: BackendDialect::OpenAiResponses,
+            endpoint: None,
+            credential: CredentialRef::None,
+            models: vec![ModelProfile {
+                id: model.to_string(),
+                aliases: vec![alias.to_string()],
+            }],
+            max_output_tokens_cap: None,
+            limits: None,
+            capabilities: None,
+            copilot: None,
+            post_finish_content: Default::default(),
+        };
+        let router = BackendRouter::new(&AIGatewayConfig {
+            backends: vec![
+                backend("small", "small-model", "default"),
+                backend("strong", "strong-model", "acts"),
+            ],
+            chat: ChatConfig::default(),
+            resilience: ResilienceConfig::default(),
+            http: HttpClientConfig::default(),
+        })
+        .expect("router");
+        let hooks = TestHooks::new(
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(Vec::new()) })),
+        );
+        let mut cortex = Cortex::for_test_with_hooks(hooks, ReactionLimits::default());
+        cortex.routes.acts_helper = Some("acts".to_string());
+
+        let selected_backend = |organ: CognitionOrgan| {
+            router
+                .select_route_ref(alias_route_ref(cortex.resolve_route(organ)).as_ref())
+                .expect("route should resolve")
+                .backend_id
+        };
+        assert_eq!(selected_backend(CognitionOrgan::Acts), "strong");
+        for organ in [
+            CognitionOrgan::Primary,
+            CognitionOrgan::Sense,
+            CognitionOrgan::Attention,
+            CognitionOrgan::Cleanup,
+        ] {
+            assert_eq!(selected_backend(organ), "small");
+        }
+    }
+
+    #[test]
+    fn each_stage_turn_input_carries_its_preset_parameters() {
+        use crate::{
+            ai_gateway::chat::ReasoningEffort,
+            config::{CortexOrganPresetsConfig, ModelParameterPreset},
+        };
+
+        let organs = [
+            CognitionOrgan::Primary,
+            CognitionOrgan::Attention,
+            CognitionOrgan::Cleanup,
+            CognitionOrgan::Sense,
+            CognitionOrgan::GoalForest,
+            CognitionOrgan::Acts,
+        ];
+        let presets = organs
+            .iter()
+            .enumerate()
+            .map(|(index, organ)| {
+                let preset = ModelParameterPreset {
+                    temperature: Some(index as f64 / 10.0),
+                    top_p: Some(0.5 + index as f64 / 100.0),
+                    max_output_tokens: Some(100 + index as u64),
+                    reasoning_effort: Some(ReasoningEffort::Low),
+                    seed: Some(index as u64),
+                };
+                (organ.stage().to_string(), preset)
+            })
+            .collect::<BTreeMap<_, _>>();
+        let assignments = CortexOrganPresetsConfig {
+            primary: Some("primary".to_string()),
+            attention: Some("attention".to_string()),
+            cleanup: Some("cleanup".to_string()),
+            sense_helper: Some("sense_helper".to_string()),
+            goal_forest_helper: Some("goal_forest_helper".to_string()),
+            acts_helper: Some("acts_helper".to_string()),
+        };
+        let model_presets = OrganModelPresets::new(&presets, &assignments);
+
+        for organ in organs {
+            let expected = &presets[organ.stage()];
+            let input = build_turn_input(
+                1,
+                format!("cortex-{}-1", organ.stage()),
+                None,
+                1_000,
+                Vec::new(),
+                Vec::new(),
+                organ.stage(),
+                model_presets.for_organ(organ),
+                OutputMode::Text,
+            );
+
+            let sampling = input.sampling.expect("preset sampling should be set");
+            assert_eq!(sampling.temperature, expected.temperature);
+            assert_eq!(sampling.top_p, expected.top_p);
+            assert_eq!(sampling.reasoning_effort, expected.reasoning_effort);
+            assert_eq!(sampling.seed, expected.seed);
+            assert_eq!(
+                input.limits.and_then(|limits| limits.max_output_tokens),
+                expected.max_output_tokens
+            );
+        }
+
+        let unassigned = build_turn_input(
+            1,
+            "cortex-primary-1".to_string(),
+            None,
+            1_000,
+            Vec::new(),
+            Vec::new(),
+            "primary",
+            OrganModelPresets::default().for_organ(CognitionOrgan::Primary),
+            OutputMode::Text,
+        );
+        assert!(unassigned.sampling.is_none());
+    }
+
+    #[test]
+    fn every_registered_telemetry_port_observes_reaction_started() {
+        struct RecordingPort(std::sync::Mutex<Vec<u64>>);
+
+        impl CortexTelemetryPort for RecordingPort {
+            fn emit(&self, event: CortexTelemetryEvent) {
+                if let CortexTelemetryEvent::ReactionStarted { cycle_id } = event {
+                    self.0.lock().expect("capture lock").push(cycle_id);
+                }
+            }
+        }
+
+        let metrics = Arc::new(RecordingPort(std::sync::Mutex::default()));
+        let debugger = Arc::new(RecordingPort(std::sync::Mutex::default()));
+        let bus = crate::cortex::CortexTelemetryBus::new();
+        let mut live_ui = bus.subscribe();
+        let cortex = Cortex::for_test_with_hooks(
+            TestHooks::new(
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(Vec::new()) })),
+            ),
+            ReactionLimits::default(),
+        )
+        .with_telemetry_port(Arc::new(crate::cortex::NoopCortexTelemetry))
+        .with_telemetry_port(metrics.clone())
+        .with_telemetry_port(debugger.clone())
+        .with_telemetry_port(Arc::new(bus.clone()));
+
+        cortex.emit(CortexTelemetryEvent::ReactionStarted { cycle_id: 3 });
+
+        assert_eq!(*metrics.0.lock().expect("capture lock"), vec![3]);
+        assert_eq!(*debugger.0.lock().expect("capture lock"), vec![3]);
+        assert!(matches!(
+            live_ui
+                .try_recv()
+                .expect("bus subscriber should see the event"),
+            CortexTelemetryEvent::ReactionStarted { cycle_id: 3 }
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn slow_sense_helper_times_out_without_killing_primary() {
+        let fast = LocalJsonServer::start(vec![responses_reply("primary reply")]).await;
+        let slow = LocalJsonServer::start_scripted(vec![
+            ScriptedResponse::json(200, responses_reply("too late"))
+                .delayed(Duration::from_secs(30)),
+        ])
+        .await;
+        let backend = |id: &str, endpoint: String, alias: &str| BackendProfile {
+            id: id.to_string(),
+            dialect: BackendDialect::OpenAiResponses,
+            endpoint: Some(endpoint),
+            credential: CredentialRef::None,
+            models: vec![ModelProfile {
+                id: "gpt-5".to_string(),
+                aliases: vec![alias.to_string()],
+            }],
+            max_output_tokens_cap: None,
+            limits: None,
+            capabilities: None,
+            copilot: None,
+            post_finish_content: Default::default(),
+        };
+        let chat = Chat::new(
+            &AIGatewayConfig {
+                backends: vec![
+                    backend("primary", fast.endpoint(), "default"),
+                    backend("sense", slow.endpoint(), "sense"),
+                ],
+                chat: ChatConfig::default(),
+                resilience: ResilienceConfig {
+                    max_retries: 0,
+                    ..ResilienceConfig::default()
+                },
+                http: HttpClientConfig::default(),
+            },
+            Arc::new(EnvCredentialProvider),
+        )
+        .expect("chat");
+        let mut cortex = Cortex::for_test_with_hooks(
+            TestHooks::new(
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+                Arc::new(|_| crate::cortex::testing::boxed(async { Ok(Vec::new()) })),
+            ),
+            ReactionLimits {
+                organ_timeouts_ms: [("sense_helper".to_string(), 100)].into(),
+                ..ReactionLimits::default()
+            },
+        );
+        cortex.chat = Some(Arc::new(chat));
+        cortex.routes = CortexRoutesConfig {
+            sense_helper: Some("sense".to_string()),
+            ..CortexRoutesConfig::default()
+        };
+
+        let started_at = Instant::now();
+        let (sense, primary) = tokio::join!(
+            cortex.run_organ(
+                1,
+                CognitionOrgan::Sense,
+                64,
+                "sense system".to_string(),
+                "sense user".to_string(),
+                OutputMode::Text,
+            ),
+            cortex.run_organ(
+                1,
+                CognitionOrgan::Primary,
+                64,
+                "primary system".to_string(),
+                "primary user".to_string(),
+                OutputMode::Text,
+            ),
+        );
+
+        let sense_err = sense.expect_err("slow sense helper should time out");
+        assert_eq!(
+            sense_err.kind,
+            crate::cortex::error::CortexErrorKind::ExtractorInferenceFailed
+        );
+        let primary = primary.expect("primary should finish within the cycle deadline");
+        assert_eq!(primary.output_text, "primary reply");
+        assert!(started_at.elapsed() < Duration::from_secs(5));
+    }
+
+    fn responses_reply(text: &str) -> serde_json::Value {
+        serde_json::json!({
+            "status": "completed",
+            "output": [{
+                "type": "message",
+                "role": "assistant",
+                "content": [{ "type": "output_text", "text": text }]
+            }]
+        })
+    }
+
+    fn test_sense(endpoint_id: &str, neural_signal_descriptor_id: &str, payload: &str) -> Sense {
+        Sense {
+            sense_instance_id: Uuid::now_v7().to_string(),
+            endpoint_id: endpoint_id.to_string(),
+            neural_signal_descriptor_id: neural_signal_descriptor_id.to_string(),
+            payload: payload.to_string(),
+            weight: 1.0,
+            act_instance_id: None,
+        }
+    }
+
     fn unique_state_path(label: &str) -> PathBuf {
         std::env::temp_dir()
             .join(format!("beluna-cortex-{label}-{}", Uuid::new_v4()))
@@ -11,6 +11,7 @@ use super::{Cortex, attention::AttentionPhaseOutput, cleanup::CleanupPhaseOutput
 impl Cortex {
     pub(super) async fn apply_attention_result(
         &self,
+        cycle_id: u64,
         output: AttentionPhaseOutput,
     ) -> Result<CortexControlDirective, CortexError> {
         if let Some(rules) = output.gating_rules {
@@ -28,6 +29,10 @@ impl Cortex {
             })?;
         }
 
+        if let Some(proposal) = output.reaction_limits_proposal.as_ref() {
+            self.apply_reaction_limits_proposal(cycle_id, proposal);
+        }
+
         Ok(CortexControlDirective {
             ignore_all_trigger_for_ticks: output.sleep_ticks,
         })
@@ -40,7 +45,10 @@ impl Cortex {
     ) -> Result<(), CortexError> {
         let mut persisted_revision = None;
         if let Some(goal_forest_nodes) = output.patched_goal_forest.as_ref() {
-            persisted_revision = Some(self.persist_goal_forest_nodes(goal_forest_nodes).await?);
+            persisted_revision = Some(
+                self.persist_goal_forest_nodes(cycle_id, goal_forest_nodes)
+                    .await?,
+            );
         }
 
         if output.patched_goal_forest.is_some() || output.reset_context_requested {
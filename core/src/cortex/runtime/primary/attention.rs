@@ -13,6 +13,7 @@ use crate::{
         error::{CortexError, extractor_failed},
         helpers::{self, CognitionOrgan},
         prompts,
+        types::ReactionLimitBounds,
     },
     observability::runtime::{self as observability_runtime, OrganResponseStatus},
 };
@@ -23,16 +24,19 @@ use super::{
     Cortex, ai_gateway_request_id, alias_route_ref, build_turn_input, gateway_error_summary,
     map_organ_gateway_error, organ_thread_metadata, output_mode_label, tool_overrides_payload,
     tools::{PRIMARY_TOOL_BREAK_PRIMARY_PHASE, PRIMARY_TOOL_EXPAND_SENSES},
+    tuning::ReactionLimitsProposal,
     turn_response_payload,
 };
 
 const ATTENTION_TOOL_REPLACE_AFFERENT_GATING: &str = "replace-afferent-gating";
 const ATTENTION_TOOL_SLEEP: &str = "sleep";
+const ATTENTION_TOOL_TUNE_REACTION_LIMITS: &str = "tune-reaction-limits";
 
 #[derive(Debug, Clone, Default, serde::Serialize)]
 pub(super) struct AttentionPhaseOutput {
     pub(super) gating_rules: Option<Vec<DeferralRuleAddInput>>,
     pub(super) sleep_ticks: Option<u64>,
+    pub(super) reaction_limits_proposal: Option<ReactionLimitsProposal>,
 }
 
 #[derive(Debug, Default)]
@@ -130,6 +134,30 @@ impl AttentionToolExecutor {
                     }
                 }
             }
+            ATTENTION_TOOL_TUNE_REACTION_LIMITS if self.cortex.limit_tuner.bounds().is_some() => {
+                let parsed = serde_json::from_str::<ReactionLimitsProposal>(&call.arguments_json)
+                    .map_err(|err| err.to_string());
+                match parsed {
+                    Ok(proposal) => {
+                        let mut state = self.state.lock().await;
+                        if state.output.reaction_limits_proposal.is_some() {
+                            fail_closed(
+                                &mut state,
+                                "tune-reaction-limits was called more than once",
+                            )
+                        } else if proposal.is_empty() {
+                            fail_closed(&mut state, "at least one limit must be proposed")
+                        } else {
+                            state.output.reaction_limits_proposal = Some(proposal);
+                            Ok(serde_json::json!({ "reaction_limits": "tune_requested" }))
+                        }
+                    }
+                    Err(err) => {
+                        let mut state = self.state.lock().await;
+                        fail_closed(&mut state, err)
+                    }
+                }
+            }
             _ => Err(format!("unknown attention tool '{}'", call.name)),
         };
 
@@ -192,7 +220,8 @@ impl Cortex {
             .await?;
         let tool_executor = Arc::new(AttentionToolExecutor::new(self.clone(), cycle_id));
         let user_prompt = prompts::attention_user_prompt();
-        let tool_overrides = attention_tool_overrides(self.limits.max_waiting_ticks);
+        let tool_overrides =
+            attention_tool_overrides(self.limits.max_waiting_ticks, self.limit_tuner.bounds());
         let response = self
             .run_phase_tool_turn(
                 cycle_id,
@@ -206,6 +235,7 @@ impl Cortex {
             .await?;
         let output = tool_executor.output().await?;
         helpers::log_organ_output(
+            &self.payload_log_sampler,
             cycle_id,
             stage,
             &helpers::pretty_json(&serde_json::json!({
@@ -272,6 +302,8 @@ impl Cortex {
         let route_or_organ = route.clone().unwrap_or_else(|| stage.to_string());
         let output_mode = OutputMode::Text;
         let output_mode_label = output_mode_label(&output_mode);
+        let max_output_tokens =
+            self.reserve_output_tokens(cycle_id, organ, self.limits.max_sub_output_tokens)?;
         observability_runtime::emit_cortex_organ_start(
             cycle_id,
             stage,
@@ -280,7 +312,7 @@ impl Cortex {
             serde_json::json!({
                 "user_prompt": user_prompt,
                 "tool_overrides": tool_overrides_payload(&tool_overrides),
-                "max_output_tokens": self.limits.max_sub_output_tokens,
+                "max_output_tokens": max_output_tokens,
                 "max_request_time_ms": self.limits.max_cycle_time_ms,
                 "output_mode": output_mode_label,
             }),
@@ -288,7 +320,7 @@ impl Cortex {
         let mut input = build_turn_input(
             cycle_id,
             request_id.clone(),
-            self.limits.max_sub_output_tokens,
+            max_output_tokens,
             self.limits.max_cycle_time_ms,
             vec![ChatMessage {
                 role: ChatRole::User,
@@ -301,6 +333,7 @@ impl Cortex {
             }],
             tool_overrides,
             stage,
+            self.model_presets.for_organ(organ),
             output_mode,
         );
         input.tool_executor = tool_executor;
@@ -340,6 +373,7 @@ impl Cortex {
             Some(output.thread_id.as_str()),
             Some(output.turn_id),
         );
+        self.settle_output_tokens(cycle_id, max_output_tokens, &output.response);
         Ok(output.response)
     }
 }
@@ -350,8 +384,11 @@ fn fail_closed<T>(state: &mut AttentionToolState, message: impl Into<String>) ->
     Err(message)
 }
 
-fn attention_tool_overrides(max_waiting_ticks: u64) -> Vec<ToolOverride> {
-    vec![
+fn attention_tool_overrides(
+    max_waiting_ticks: u64,
+    reaction_limit_bounds: Option<&ReactionLimitBounds>,
+) -> Vec<ToolOverride> {
+    let mut overrides = vec![
         ToolOverride::Remove(PRIMARY_TOOL_EXPAND_SENSES.to_string()),
         ToolOverride::Remove(PRIMARY_TOOL_BREAK_PRIMARY_PHASE.to_string()),
         ToolOverride::Set(crate::ai_gateway::chat::ChatToolDefinition {
@@ -410,5 +447,49 @@ fn attention_tool_overrides(max_waiting_ticks: u64) -> Vec<ToolOverride> {
                 "additionalProperties": false
             }),
         }),
-    ]
+    ];
+    if let Some(bounds) = reaction_limit_bounds {
+        overrides.push(ToolOverride::Set(crate::ai_gateway::chat::ChatToolDefinition {
+            name: ATTENTION_TOOL_TUNE_REACTION_LIMITS.to_string(),
+            description: Some(
+                concat!(
+                    "Propose new reaction limits for the next cycles. ",
+                    "Values outside the operator bounds are clamped; omitted limits stay unchanged."
+                )
+                .to_string(),
+            ),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "max_cycle_time_ms": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": bounds.max_cycle_time_ms
+                    },
+                    "max_primary_turns_per_tick": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": bounds.max_primary_turns_per_tick
+                    },
+                    "sense_passthrough_max_bytes": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": bounds.sense_passthrough_max_bytes
+                    },
+                    "max_waiting_ticks": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": bounds.max_waiting_ticks
+                    },
+                    "max_sense_sub_agent_concurrency": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": bounds.max_sense_sub_agent_concurrency
+                    }
+                },
+                "additionalProperties": false
+            }),
+        }));
+    }
+    overrides
 }
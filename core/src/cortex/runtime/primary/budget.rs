@@ -0,0 +1,157 @@
+use std::sync::{Arc, Mutex};
+
+use super::Cortex;
+use crate::{
+    ai_gateway::chat::TurnResponse,
+    cortex::{
+        error::{CortexError, budget_exceeded},
+        helpers::CognitionOrgan,
+        types::{CycleOutputTokenBudget, OutputTokenBudgetPolicy},
+    },
+};
+
+const ORGAN_COUNT: u64 = CognitionOrgan::ALL.len() as u64;
+const PRIMARY_STAGE_WEIGHT: u64 = 3;
+
+/// Tracks the remaining output-token budget of the current cycle.
+///
+/// Each organ call reserves its weighted share of the remaining budget up front
+/// and settles the unused part once the response usage is known, so concurrent
+/// helper calls cannot overspend the cycle.
+#[derive(Clone, Default)]
+pub(super) struct CycleOutputTokenLedger {
+    state: Arc<Mutex<Option<LedgerState>>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LedgerState {
+    cycle_id: u64,
+    remaining: u64,
+}
+
+impl CycleOutputTokenLedger {
+    pub(super) fn reserve(
+        &self,
+        budget: Option<&CycleOutputTokenBudget>,
+        cycle_id: u64,
+        organ: CognitionOrgan,
+        configured_cap: u64,
+    ) -> Result<Option<u64>, CortexError> {
+        let Some(budget) = budget else {
+            return Ok(None);
+        };
+        let mut state = self.state.lock().expect("lock poisoned");
+        let ledger = match state.as_mut() {
+            Some(ledger) if ledger.cycle_id == cycle_id => ledger,
+            _ => state.insert(LedgerState {
+                cycle_id,
+                remaining: budget.total_output_tokens,
+            }),
+        };
+        if ledger.remaining == 0 {
+            return Err(budget_exceeded(format!(
+                "cycle {cycle_id} output-token budget exhausted before {}",
+                organ.stage()
+            )));
+        }
+
+        let (weight, total_weight) = policy_weight(budget.policy, organ);
+        let share = (ledger.remaining.saturating_mul(weight) / total_weight).max(1);
+        let cap = share.min(configured_cap.max(1));
+        ledger.remaining = ledger.remaining.saturating_sub(cap);
+        Ok(Some(cap))
+    }
+
+    pub(super) fn settle(&self, cycle_id: u64, reserved: Option<u64>, used: Option<u64>) {
+        let (Some(reserved), Some(used)) = (reserved, used) else {
+            return;
+        };
+        let mut state = self.state.lock().expect("lock poisoned");
+        if let Some(ledger) = state.as_mut()
+            && ledger.cycle_id == cycle_id
+        {
+            ledger.remaining = ledger
+                .remaining
+                .saturating_add(reserved.saturating_sub(used));
+        }
+    }
+}
+
+impl Cortex {
+    /// Returns the output-token cap for one organ call, or `None` when no cycle budget is set.
+    pub(super) fn reserve_output_tokens(
+        &self,
+        cycle_id: u64,
+        organ: CognitionOrgan,
+        configured_cap: u64,
+    ) -> Result<Option<u64>, CortexError> {
+        self.output_token_ledger.reserve(
+            self.limits.cycle_output_token_budget.as_ref(),
+            cycle_id,
+            organ,
+            self.model_presets.max_output_tokens(organ, configured_cap),
+        )
+    }
+
+    pub(super) fn settle_output_tokens(
+        &self,
+        cycle_id: u64,
+        reserved: Option<u64>,
+        response: &TurnResponse,
+    ) {
+        let used = response
+            .usage
+            .as_ref()
+            .and_then(|usage| usage.output_tokens)
+            .or(reserved);
+        self.output_token_ledger.settle(cycle_id, reserved, used);
+    }
+}
+
+fn policy_weight(policy: OutputTokenBudgetPolicy, organ: CognitionOrgan) -> (u64, u64) {
+    match policy {
+        OutputTokenBudgetPolicy::Equal => (1, ORGAN_COUNT),
+        OutputTokenBudgetPolicy::WeightedByStage => {
+            let total_weight = ORGAN_COUNT - 1 + PRIMARY_STAGE_WEIGHT;
+            match organ {
+                CognitionOrgan::Primary => (PRIMARY_STAGE_WEIGHT, total_weight),
+                _ => (1, total_weight),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn later_organs_receive_reduced_caps_under_tight_budget() {
+        let ledger = CycleOutputTokenLedger::default();
+        let budget = CycleOutputTokenBudget {
+            total_output_tokens: 600,
+            policy: OutputTokenBudgetPolicy::Equal,
+        };
+
+        let primary = ledger
+            .reserve(Some(&budget), 1, CognitionOrgan::Primary, 1_024)
+            .expect("budget should not be exhausted");
+        ledger.settle(1, primary, primary);
+        let sense = ledger
+            .reserve(Some(&budget), 1, CognitionOrgan::Sense, 768)
+            .expect("budget should not be exhausted");
+        ledger.settle(1, sense, sense);
+        let acts = ledger
+            .reserve(Some(&budget), 1, CognitionOrgan::Acts, 768)
+            .expect("budget should not be exhausted");
+
+        assert_eq!(primary, Some(100));
+        assert_eq!(sense, Some(83));
+        assert_eq!(acts, Some(69));
+
+        let next_cycle = ledger
+            .reserve(Some(&budget), 2, CognitionOrgan::Primary, 1_024)
+            .expect("budget resets on a new cycle");
+        assert_eq!(next_cycle, Some(100));
+    }
+}
@@ -234,6 +234,7 @@ impl Cortex {
             .await?;
         let output = tool_executor.output().await?;
         helpers::log_organ_output(
+            &self.payload_log_sampler,
             cycle_id,
             stage,
             &helpers::pretty_json(&serde_json::json!({
@@ -6,7 +6,7 @@ use tokio::sync::Mutex;
 use crate::{
     ai_gateway::chat::{ToolCallResult, ToolExecutionRequest, ToolExecutionResult, ToolExecutor},
     cortex::{
-        clamp::derive_act_instance_id,
+        clamp::{derive_act_dedup_key, derive_act_instance_id},
         helpers::{goal_forest_helper::GoalNode, sense_input_helper},
     },
     types::{Act, build_fq_neural_signal_id},
@@ -15,7 +15,8 @@ use crate::{
 use super::{
     ActToolBinding, Cortex, PrimaryTurnState,
     tools::{
-        ActToolArgs, ExpandSenseTask, PRIMARY_TOOL_BREAK_PRIMARY_PHASE, PRIMARY_TOOL_EXPAND_SENSES,
+        ActToolArgs, ControlSleepArgs, ExpandSenseTask, PRIMARY_TOOL_BREAK_PRIMARY_PHASE,
+        PRIMARY_TOOL_CONTROL_SLEEP, PRIMARY_TOOL_EXPAND_SENSES,
     },
 };
 
@@ -65,6 +66,22 @@ impl PrimaryToolExecutor {
         self.goal_forest_nodes.lock().await.clone()
     }
 
+    /// Keeps a goal id Primary attributed an act to only if the cycle's goal forest has it.
+    async fn known_goal_id(&self, goal_id: Option<String>) -> Option<String> {
+        let goal_id = goal_id?;
+        let goal_forest_nodes = self.goal_forest_nodes.lock().await;
+        if goal_forest_contains(&goal_forest_nodes, &goal_id) {
+            return Some(goal_id);
+        }
+        tracing::debug!(
+            target: "cortex",
+            cycle_id = self.cycle_id,
+            goal_id = %goal_id,
+            "act_goal_attribution_unknown_goal_dropped"
+        );
+        None
+    }
+
     async fn execute_internal_tool_call(&self, call: &ToolCallResult) -> PrimaryToolCallResult {
         let cycle_id = self.cycle_id;
         let step = self.step;
@@ -76,6 +93,7 @@ impl PrimaryToolExecutor {
                     if !payload_matches_schema(&args.payload, &binding.descriptor.payload_schema) {
                         Err("payload does not match act descriptor schema".to_string())
                     } else {
+                        let goal_id = self.known_goal_id(args.goal_id).await;
                         let act = Act {
                             act_instance_id: derive_act_instance_id(
                                 cycle_id,
@@ -91,38 +109,84 @@ impl PrimaryToolExecutor {
                                 .clone(),
                             might_emit_sense_ids: binding.might_emit_sense_ids.clone(),
                             payload: args.payload,
+                            goal_id,
                         };
                         let act_instance_id = act.act_instance_id.clone();
-                        let act_seq_no = {
+                        let act_fq_id = build_fq_neural_signal_id(
+                            &binding.descriptor.endpoint_id,
+                            &binding.descriptor.neural_signal_descriptor_id,
+                        );
+                        let dedup_key = self.cortex.dedup_acts_within_cycle.then(|| {
+                            derive_act_dedup_key(
+                                cycle_id,
+                                &act.endpoint_id,
+                                &act.neural_signal_descriptor_id,
+                                &act.payload,
+                            )
+                        });
+                        let duplicate_of = {
                             let mut state = self.turn_state.lock().await;
-                            let next = state.next_act_seq_no.saturating_add(1);
-                            state.next_act_seq_no = next;
-                            next
+                            match dedup_key {
+                                Some(dedup_key) => {
+                                    match state.dispatched_act_keys.get(&dedup_key) {
+                                        Some(existing) => Some(existing.clone()),
+                                        None => {
+                                            state
+                                                .dispatched_act_keys
+                                                .insert(dedup_key, act_instance_id.clone());
+                                            None
+                                        }
+                                    }
+                                }
+                                None => None,
+                            }
                         };
-                        match self
-                            .cortex
-                            .dispatch_act(cycle_id, act_seq_no, act.clone())
-                            .await
-                        {
-                            Ok(dispatch_result) => {
+                        if let Some(existing) = duplicate_of {
+                            tracing::debug!(
+                                target: "cortex",
+                                cycle_id = cycle_id,
+                                act_instance_id = %existing,
+                                act_fq_id = %act_fq_id,
+                                "duplicate_act_in_cycle_dropped"
+                            );
+                            Ok((
+                                serde_json::json!({
+                                    "act_instance_id": existing,
+                                    "act_tool_alias": binding.alias,
+                                    "act_fq_id": act_fq_id,
+                                    "deduplicated": true,
+                                }),
+                                false,
+                            ))
+                        } else {
+                            let act_seq_no = {
                                 let mut state = self.turn_state.lock().await;
-                                state.dispatched_act_count =
-                                    state.dispatched_act_count.saturating_add(1);
-                                Ok((
-                                    serde_json::json!({
-                                        "act_instance_id": act_instance_id,
-                                        "act_tool_alias": binding.alias,
-                                        "act_fq_id": build_fq_neural_signal_id(
-                                            &binding.descriptor.endpoint_id,
-                                            &binding.descriptor.neural_signal_descriptor_id,
-                                        ),
-                                        "might_emit_sense_ids": act.might_emit_sense_ids,
-                                        "dispatch_result": dispatch_result,
-                                    }),
-                                    false,
-                                ))
+                                let next = state.next_act_seq_no.saturating_add(1);
+                                state.next_act_seq_no = next;
+                                next
+                            };
+                            match self
+                                .cortex
+                                .dispatch_act(cycle_id, act_seq_no, act.clone())
+                                .await
+                            {
+                                Ok(dispatch_result) => {
+                                    let mut state = self.turn_state.lock().await;
+                                    state.dispatched_act_count =
+                                        state.dispatched_act_count.saturating_add(1);
+                                    Ok((
+                                        serde_json::json!({
+                                            "act_instance_id": act_instance_id,
+                                            "act_tool_alias": binding.alias,
+                                            "act_fq_id": act_fq_id,
+                                            "might_emit_sense_ids": act.might_emit_sense_ids,
+                                            "dispatch_result": dispatch_result,
+                                        }),
+                                        false,
+                                    ))
+                                }
+                                Err(err) => Err(err),
                             }
-                            Err(err) => Err(err),
                         }
                     }
                 }
@@ -252,6 +316,24 @@ impl PrimaryToolExecutor {
                         Err(err) => Err(err),
                     }
                 }
+                PRIMARY_TOOL_CONTROL_SLEEP => match &self.cortex.self_sleep {
+                    Some(self_sleep) => {
+                        match serde_json::from_str::<ControlSleepArgs>(&call.arguments_json) {
+                            Ok(args) => {
+                                tracing::info!(
+                                    target: "cortex",
+                                    cycle_id = cycle_id,
+                                    reason = ?args.reason,
+                                    "self_requested_sleep"
+                                );
+                                self_sleep.request(args.reason);
+                                Ok((serde_json::json!({ "sleep": "requested" }), false))
+                            }
+                            Err(err) => Err(err.to_string()),
+                        }
+                    }
+                    None => Err("control-sleep is disabled".to_string()),
+                },
                 _ => Err(format!(
                     "unknown internal cognitive action tool '{}'",
                     call.name
@@ -342,3 +424,122 @@ fn string_array_field(object: &serde_json::Value, key: &str) -> Vec<String> {
         })
         .unwrap_or_default()
 }
+
+fn goal_forest_contains(nodes: &[GoalNode], goal_id: &str) -> bool {
+    nodes
+        .iter()
+        .any(|node| node.id == goal_id || goal_forest_contains(&node.children, goal_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        ai_gateway::chat::types::ToolCallStatus,
+        cortex::{ReactionLimits, runtime::SelfSleepRequest, testing::TestHooks},
+        types::{NeuralSignalDescriptor, NeuralSignalType},
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn control_sleep_call_raises_self_sleep_request() {
+        let hooks = TestHooks::new(
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(Vec::new()) })),
+        );
+        let self_sleep = Arc::new(SelfSleepRequest::new());
+        let cortex = Cortex::for_test_with_hooks(hooks, ReactionLimits::default())
+            .with_self_sleep(Arc::clone(&self_sleep));
+        let executor = PrimaryToolExecutor::new(
+            cortex,
+            4,
+            1,
+            sense_input_helper::SenseToolContext::default(),
+            HashMap::new(),
+            Vec::new(),
+            PrimaryTurnState::default(),
+        );
+
+        let result = executor
+            .execute_internal_tool_call(&ToolCallResult {
+                id: "call-1".to_string(),
+                name: PRIMARY_TOOL_CONTROL_SLEEP.to_string(),
+                arguments_json: r#"{"reason":"no pending goals"}"#.to_string(),
+                status: ToolCallStatus::Ready,
+            })
+            .await;
+
+        assert_eq!(result.payload["ok"], true);
+        tokio::time::timeout(Duration::from_secs(1), self_sleep.requested())
+            .await
+            .expect("sleep should be requested");
+        assert_eq!(self_sleep.reason().as_deref(), Some("no pending goals"));
+    }
+
+    #[tokio::test]
+    async fn act_emitted_for_a_goal_carries_its_goal_id() {
+        let hooks = TestHooks::new(
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(String::new()) })),
+            Arc::new(|_| crate::cortex::testing::boxed(async { Ok(Vec::new()) })),
+        );
+        let (producer, mut efferent_rx) = crate::stem::new_efferent_pathway(None);
+        let mut cortex = Cortex::for_test_with_hooks(hooks, ReactionLimits::default());
+        cortex.efferent_producer = Some(producer);
+        let binding = ActToolBinding {
+            alias: "act_shell_exec".to_string(),
+            descriptor: NeuralSignalDescriptor {
+                r#type: NeuralSignalType::Act,
+                endpoint_id: "std.shell".to_string(),
+                neural_signal_descriptor_id: "exec".to_string(),
+                payload_schema: serde_json::json!({ "type": "object" }),
+                priority: None,
+            },
+            might_emit_sense_ids: Vec::new(),
+            degraded: false,
+        };
+        let goal = |id: &str, children| GoalNode {
+            status: "open".to_string(),
+            weight: 1.0,
+            id: id.to_string(),
+            summary: format!("goal {id}"),
+            children,
+        };
+        let executor = PrimaryToolExecutor::new(
+            cortex,
+            9,
+            1,
+            sense_input_helper::SenseToolContext::default(),
+            HashMap::from([(binding.alias.clone(), binding)]),
+            vec![goal("ship", vec![goal("build", Vec::new())])],
+            PrimaryTurnState::default(),
+        );
+        let act_call = |arguments_json: &str| ToolCallResult {
+            id: "call-1".to_string(),
+            name: "act_shell_exec".to_string(),
+            arguments_json: arguments_json.to_string(),
+            status: ToolCallStatus::Ready,
+        };
+
+        executor
+            .execute_internal_tool_call(&act_call(
+                r#"{"payload":{"argv":["make"]},"goal_id":"build"}"#,
+            ))
+            .await;
+        let envelope = efferent_rx.recv().await.expect("act should be dispatched");
+        assert_eq!(envelope.act.goal_id.as_deref(), Some("build"));
+
+        executor
+            .execute_internal_tool_call(&act_call(
+                r#"{"payload":{"argv":["ls"]},"goal_id":"unknown"}"#,
+            ))
+            .await;
+        let envelope = efferent_rx.recv().await.expect("act should be dispatched");
+        assert_eq!(envelope.act.goal_id, None);
+    }
+}
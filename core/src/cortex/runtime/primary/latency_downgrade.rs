@@ -0,0 +1,168 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use crate::config::LatencyDowngradeConfig;
+
+/// A monitored route changed between itself and its fallback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteLatencySwitch {
+    Downgraded {
+        route: String,
+        fallback_route: String,
+        p95_latency_ms: u64,
+    },
+    Restored {
+        route: String,
+        p95_latency_ms: u64,
+    },
+}
+
+#[derive(Debug, Default)]
+struct RouteLatencyState {
+    samples_ms: VecDeque<u64>,
+    downgraded: bool,
+    calls_since_probe: u32,
+}
+
+/// Sends organ calls of a slow route to its configured fallback until the route recovers.
+#[derive(Clone, Default)]
+pub(super) struct LatencyDowngrade {
+    config: Option<LatencyDowngradeConfig>,
+    routes: Arc<Mutex<BTreeMap<String, RouteLatencyState>>>,
+}
+
+impl LatencyDowngrade {
+    pub(super) fn new(config: Option<LatencyDowngradeConfig>) -> Self {
+        Self {
+            config,
+            routes: Arc::default(),
+        }
+    }
+
+    /// The route to call instead of `route`, which is `route` itself unless it is downgraded.
+    pub(super) fn select(&self, route: Option<String>) -> Option<String> {
+        let (Some(config), Some(route)) = (&self.config, route.as_deref()) else {
+            return route;
+        };
+        let Some(fallback_route) = config.fallback_routes.get(route) else {
+            return Some(route.to_string());
+        };
+        let mut routes = self.routes.lock().expect("lock poisoned");
+        let Some(state) = routes.get_mut(route).filter(|state| state.downgraded) else {
+            return Some(route.to_string());
+        };
+        state.calls_since_probe = state.calls_since_probe.saturating_add(1);
+        if state.calls_since_probe >= config.probe_every_calls {
+            state.calls_since_probe = 0;
+            return Some(route.to_string());
+        }
+        Some(fallback_route.clone())
+    }
+
+    /// Records the latency of a call made on `route`, reporting a switch it caused.
+    pub(super) fn record(
+        &self,
+        route: Option<&str>,
+        latency_ms: u64,
+    ) -> Option<RouteLatencySwitch> {
+        let (config, route) = (self.config.as_ref()?, route?);
+        let fallback_route = config.fallback_routes.get(route)?;
+        let mut routes = self.routes.lock().expect("lock poisoned");
+        let state = routes.entry(route.to_string()).or_default();
+        state.samples_ms.push_back(latency_ms);
+        while state.samples_ms.len() > config.window {
+            state.samples_ms.pop_front();
+        }
+        if state.samples_ms.len() < config.min_samples {
+            return None;
+        }
+
+        let p95_latency_ms = p95(&state.samples_ms);
+        match (state.downgraded, p95_latency_ms > config.p95_threshold_ms) {
+            (false, true) => {
+                state.downgraded = true;
+                state.calls_since_probe = 0;
+                state.samples_ms.clear();
+                Some(RouteLatencySwitch::Downgraded {
+                    route: route.to_string(),
+                    fallback_route: fallback_route.clone(),
+                    p95_latency_ms,
+                })
+            }
+            (true, false) => {
+                state.downgraded = false;
+                Some(RouteLatencySwitch::Restored {
+                    route: route.to_string(),
+                    p95_latency_ms,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Nearest-rank 95th percentile.
+fn p95(samples_ms: &VecDeque<u64>) -> u64 {
+    let mut sorted = samples_ms.iter().copied().collect::<Vec<_>>();
+    sorted.sort_unstable();
+    let rank = (sorted.len() * 95).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn downgrade() -> LatencyDowngrade {
+        LatencyDowngrade::new(Some(LatencyDowngradeConfig {
+            fallback_routes: BTreeMap::from([("smart".to_string(), "fast".to_string())]),
+            p95_threshold_ms: 1_000,
+            window: 4,
+            min_samples: 2,
+            probe_every_calls: 3,
+        }))
+    }
+
+    #[test]
+    fn sustained_latency_falls_back_and_fast_probes_restore_primary() {
+        let downgrade = downgrade();
+        let smart = Some("smart".to_string());
+
+        assert_eq!(downgrade.record(Some("smart"), 200), None);
+        assert_eq!(downgrade.record(Some("smart"), 300), None);
+        assert_eq!(downgrade.select(smart.clone()).as_deref(), Some("smart"));
+
+        assert_eq!(
+            downgrade.record(Some("smart"), 5_000),
+            Some(RouteLatencySwitch::Downgraded {
+                route: "smart".to_string(),
+                fallback_route: "fast".to_string(),
+                p95_latency_ms: 5_000,
+            })
+        );
+        assert_eq!(downgrade.select(smart.clone()).as_deref(), Some("fast"));
+        assert_eq!(downgrade.select(smart.clone()).as_deref(), Some("fast"));
+        assert_eq!(downgrade.select(None), None);
+        assert_eq!(
+            downgrade.select(Some("other".to_string())).as_deref(),
+            Some("other")
+        );
+        assert_eq!(downgrade.record(Some("fast"), 9_000), None);
+
+        assert_eq!(downgrade.select(smart.clone()).as_deref(), Some("smart"));
+        assert_eq!(downgrade.record(Some("smart"), 400), None);
+        assert_eq!(downgrade.select(smart.clone()).as_deref(), Some("fast"));
+        assert_eq!(downgrade.select(smart.clone()).as_deref(), Some("fast"));
+        assert_eq!(downgrade.select(smart.clone()).as_deref(), Some("smart"));
+        assert_eq!(
+            downgrade.record(Some("smart"), 500),
+            Some(RouteLatencySwitch::Restored {
+                route: "smart".to_string(),
+                p95_latency_ms: 500,
+            })
+        );
+        assert_eq!(downgrade.select(smart).as_deref(), Some("smart"));
+    }
+}
@@ -0,0 +1,42 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{
+    config::{CortexOrganPresetsConfig, ModelParameterPreset},
+    cortex::helpers::CognitionOrgan,
+};
+
+/// Model parameter presets resolved per organ stage from `cortex.organ_presets`.
+#[derive(Clone, Default)]
+pub(super) struct OrganModelPresets {
+    by_stage: Arc<BTreeMap<&'static str, ModelParameterPreset>>,
+}
+
+impl OrganModelPresets {
+    pub(super) fn new(
+        presets: &BTreeMap<String, ModelParameterPreset>,
+        assignments: &CortexOrganPresetsConfig,
+    ) -> Self {
+        let by_stage = assignments
+            .stage_presets()
+            .into_iter()
+            .filter_map(|(stage, name)| {
+                let preset = presets.get(name.as_deref()?)?;
+                Some((stage, preset.clone()))
+            })
+            .collect();
+        Self {
+            by_stage: Arc::new(by_stage),
+        }
+    }
+
+    pub(super) fn for_organ(&self, organ: CognitionOrgan) -> Option<&ModelParameterPreset> {
+        self.by_stage.get(organ.stage())
+    }
+
+    /// The preset's output-token cap for `organ`, falling back to the configured one.
+    pub(super) fn max_output_tokens(&self, organ: CognitionOrgan, configured_cap: u64) -> u64 {
+        self.for_organ(organ)
+            .and_then(|preset| preset.max_output_tokens)
+            .unwrap_or(configured_cap)
+    }
+}
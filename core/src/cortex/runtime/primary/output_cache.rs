@@ -0,0 +1,192 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use sha2::{Digest, Sha256};
+
+use super::PrimaryEngineResult;
+use crate::{
+    config::PrimaryOutputCacheConfig, cortex::helpers::sense_input_helper::SenseToolContext,
+};
+
+/// Primary results by input IR hash, reused for exact-match inputs within the TTL.
+///
+/// Only settled results with no side effects are stored: continuations carry live session state,
+/// and a result that dispatched acts or committed a thread cannot be replayed without repeating
+/// them, so neither is cached. At most `capacity` entries are kept, evicting the least recently
+/// used one.
+#[derive(Clone, Default)]
+pub(super) struct PrimaryOutputCache {
+    ttl: Option<Duration>,
+    capacity: usize,
+    entries: Arc<Mutex<CacheEntries>>,
+}
+
+#[derive(Default)]
+struct CacheEntries {
+    by_key: HashMap<String, CachedOutput>,
+    /// Monotonic use counter; the entry with the smallest `last_used` is evicted first.
+    clock: u64,
+}
+
+struct CachedOutput {
+    stored_at: Instant,
+    last_used: u64,
+    result: PrimaryEngineResult,
+}
+
+impl CacheEntries {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+}
+
+impl PrimaryOutputCache {
+    pub(super) fn new(config: Option<&PrimaryOutputCacheConfig>) -> Self {
+        Self {
+            ttl: config.map(|config| Duration::from_millis(config.ttl_ms)),
+            capacity: config.map_or(0, |config| config.capacity),
+            entries: Arc::default(),
+        }
+    }
+
+    /// Cache key for the input IR content, or `None` when caching is disabled.
+    ///
+    /// Senses are hashed by endpoint, descriptor, weight, and payload rather than by their
+    /// rendered section, whose per-cycle reference ids would make replayed senses never match.
+    pub(super) fn key_for(
+        &self,
+        sense_tool_context: &SenseToolContext,
+        proprioception_section: &str,
+        goal_forest_section: &str,
+    ) -> Option<String> {
+        self.ttl?;
+        let senses = sense_tool_context
+            .entries()
+            .iter()
+            .map(|entry| serde_json::json!([entry.fq_sense_id, entry.weight, entry.payload]))
+            .collect::<Vec<_>>();
+        let mut hasher = Sha256::new();
+        hasher.update(serde_json::Value::Array(senses).to_string().as_bytes());
+        hasher.update([0]);
+        hasher.update(proprioception_section.as_bytes());
+        hasher.update([0]);
+        hasher.update(goal_forest_section.as_bytes());
+        let digest = hasher.finalize();
+        Some(
+            digest
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>(),
+        )
+    }
+
+    pub(super) fn get(&self, key: &str) -> Option<PrimaryEngineResult> {
+        let ttl = self.ttl?;
+        let mut entries = self.entries.lock().expect("lock poisoned");
+        let now = entries.tick();
+        match entries.by_key.get_mut(key) {
+            Some(entry) if entry.stored_at.elapsed() < ttl => {
+                entry.last_used = now;
+                Some(entry.result.clone())
+            }
+            Some(_) => {
+                entries.by_key.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(super) fn insert(&self, key: String, result: &PrimaryEngineResult) {
+        let Some(ttl) = self.ttl else {
+            return;
+        };
+        if result.pending_continuation
+            || result.dispatched_act_count > 0
+            || result.committed_thread.is_some()
+        {
+            return;
+        }
+        let mut entries = self.entries.lock().expect("lock poisoned");
+        entries
+            .by_key
+            .retain(|_, entry| entry.stored_at.elapsed() < ttl);
+        let now = entries.tick();
+        entries.by_key.insert(
+            key,
+            CachedOutput {
+                stored_at: Instant::now(),
+                last_used: now,
+                result: result.clone(),
+            },
+        );
+        while entries.by_key.len() > self.capacity {
+            let Some(oldest) = entries
+                .by_key
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            entries.by_key.remove(&oldest);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settled(output_text: &str) -> PrimaryEngineResult {
+        PrimaryEngineResult {
+            output_text: output_text.to_string(),
+            dispatched_act_count: 0,
+            pending_continuation: false,
+            goal_forest_nodes: Vec::new(),
+            break_primary_phase_requested: false,
+            committed_thread: None,
+        }
+    }
+
+    #[test]
+    fn inserting_beyond_capacity_evicts_least_recently_used_entry() {
+        let cache = PrimaryOutputCache::new(Some(&PrimaryOutputCacheConfig {
+            ttl_ms: 60_000,
+            capacity: 2,
+        }));
+        cache.insert("a".to_string(), &settled("first"));
+        cache.insert("b".to_string(), &settled("second"));
+        assert!(cache.get("a").is_some());
+
+        cache.insert("c".to_string(), &settled("third"));
+
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("b").is_none());
+        assert_eq!(cache.get("a").expect("a kept").output_text, "first");
+        assert_eq!(cache.get("c").expect("c kept").output_text, "third");
+    }
+
+    #[test]
+    fn result_that_dispatched_acts_is_not_cached() {
+        let cache = PrimaryOutputCache::new(Some(&PrimaryOutputCacheConfig::default()));
+        cache.insert(
+            "acted".to_string(),
+            &PrimaryEngineResult {
+                dispatched_act_count: 1,
+                ..settled("ran a tool")
+            },
+        );
+        cache.insert("quiet".to_string(), &settled("nothing to do"));
+
+        assert!(cache.get("acted").is_none());
+        assert_eq!(
+            cache.get("quiet").expect("quiet kept").output_text,
+            "nothing to do"
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use super::Cortex;
+use crate::{
+    ai_gateway::{
+        chat::OutputMode,
+        error::{GatewayError, GatewayErrorKind},
+    },
+    config::StrictSchemaFallbackConfig,
+    cortex::{
+        error::{CortexError, extractor_failed},
+        helpers::CognitionOrgan,
+    },
+};
+
+/// Output mode resolved for one organ call.
+pub(super) struct ResolvedOutputMode {
+    pub(super) output_mode: OutputMode,
+    /// Whether the outcome of this call counts towards the stage's strict-mode standing.
+    pub(super) strict_attempt: bool,
+    /// Schema the response must satisfy locally because strict mode was downgraded.
+    pub(super) validate_against: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Default)]
+struct StageStrictState {
+    consecutive_failures: u32,
+    downgraded_at_cycle: Option<u64>,
+}
+
+/// Downgrades stages whose strict JSON-schema calls keep failing to JSON-object mode.
+///
+/// A downgraded stage re-attempts strict mode once the configured number of cycles has
+/// passed; a failed re-attempt downgrades it again right away.
+#[derive(Clone, Default)]
+pub(super) struct StrictSchemaFallback {
+    config: Option<StrictSchemaFallbackConfig>,
+    stages: Arc<Mutex<BTreeMap<&'static str, StageStrictState>>>,
+}
+
+impl StrictSchemaFallback {
+    pub(super) fn new(config: Option<StrictSchemaFallbackConfig>) -> Self {
+        Self {
+            config,
+            stages: Arc::default(),
+        }
+    }
+
+    pub(super) fn resolve(
+        &self,
+        organ: CognitionOrgan,
+        cycle_id: u64,
+        output_mode: OutputMode,
+    ) -> ResolvedOutputMode {
+        let passthrough = |output_mode, strict_attempt| ResolvedOutputMode {
+            output_mode,
+            strict_attempt,
+            validate_against: None,
+        };
+        let Some(config) = &self.config else {
+            return passthrough(output_mode, false);
+        };
+        if !matches!(output_mode, OutputMode::JsonSchema { strict: true, .. }) {
+            return passthrough(output_mode, false);
+        }
+
+        let stage = organ.stage();
+        let stages = self.stages.lock().expect("lock poisoned");
+        let Some(downgraded_at_cycle) = stages
+            .get(stage)
+            .and_then(|state| state.downgraded_at_cycle)
+        else {
+            return passthrough(output_mode, true);
+        };
+        if cycle_id < downgraded_at_cycle.saturating_add(config.retry_strict_after_cycles) {
+            let OutputMode::JsonSchema { schema, .. } = output_mode else {
+                unreachable!("checked above");
+            };
+            return ResolvedOutputMode {
+                output_mode: OutputMode::JsonObject,
+                strict_attempt: false,
+                validate_against: Some(schema),
+            };
+        }
+        tracing::info!(
+            target: "cortex",
+            stage = stage,
+            cycle_id = cycle_id,
+            downgraded_at_cycle = downgraded_at_cycle,
+            "strict_schema_mode_reattempt"
+        );
+        passthrough(output_mode, true)
+    }
+
+    pub(super) fn record(&self, organ: CognitionOrgan, cycle_id: u64, succeeded: bool) {
+        let Some(config) = &self.config else {
+            return;
+        };
+        let stage = organ.stage();
+        let mut stages = self.stages.lock().expect("lock poisoned");
+        if succeeded {
+            if stages
+                .remove(stage)
+                .is_some_and(|state| state.downgraded_at_cycle.is_some())
+            {
+                tracing::info!(
+                    target: "cortex",
+                    stage = stage,
+                    cycle_id = cycle_id,
+                    "strict_schema_mode_restored"
+                );
+            }
+            return;
+        }
+
+        let state = stages.entry(stage).or_default();
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        if state.downgraded_at_cycle.is_none()
+            && state.consecutive_failures < config.failure_threshold
+        {
+            return;
+        }
+        state.downgraded_at_cycle = Some(cycle_id);
+        tracing::warn!(
+            target: "cortex",
+            stage = stage,
+            cycle_id = cycle_id,
+            consecutive_failures = state.consecutive_failures,
+            retry_strict_after_cycles = config.retry_strict_after_cycles,
+            "strict_schema_mode_downgraded"
+        );
+    }
+}
+
+/// Whether a failed strict call says something about strict mode itself.
+///
+/// Schema violations, a backend without schema support and a request the provider rejected
+/// count; timeouts, transport, rate-limit, credential and locally rejected requests do not.
+pub(super) fn is_strict_mode_failure(err: &GatewayError) -> bool {
+    match err.kind {
+        GatewayErrorKind::ProtocolViolation | GatewayErrorKind::UnsupportedCapability => true,
+        GatewayErrorKind::InvalidRequest => err.provider_http_status.is_some(),
+        _ => false,
+    }
+}
+
+impl Cortex {
+    /// Checks a JSON-object response against the schema its downgraded strict request carried.
+    pub(super) fn validate_downgraded_output(
+        &self,
+        organ: CognitionOrgan,
+        schema: &serde_json::Value,
+        output_text: &str,
+    ) -> Result<(), CortexError> {
+        let compiled = jsonschema::JSONSchema::compile(schema).map_err(|err| {
+            extractor_failed(format!("{} output schema is invalid: {err}", organ.stage()))
+        })?;
+        let output = serde_json::from_str::<serde_json::Value>(output_text).map_err(|err| {
+            extractor_failed(format!("{} output is not valid JSON: {err}", organ.stage()))
+        })?;
+        if !compiled.is_valid(&output) {
+            return Err(extractor_failed(format!(
+                "{} output does not match its schema",
+                organ.stage()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn strict_mode() -> OutputMode {
+        OutputMode::JsonSchema {
+            name: "acts".to_string(),
+            schema: json!({ "type": "object" }),
+            strict: true,
+        }
+    }
+
+    #[test]
+    fn only_schema_related_errors_count_as_strict_failures() {
+        let error = |kind| GatewayError::new(kind, "failed");
+
+        assert!(is_strict_mode_failure(&error(
+            GatewayErrorKind::ProtocolViolation
+        )));
+        assert!(is_strict_mode_failure(&error(
+            GatewayErrorKind::UnsupportedCapability
+        )));
+        assert!(is_strict_mode_failure(
+            &error(GatewayErrorKind::InvalidRequest).with_provider_http_status(400)
+        ));
+        assert!(!is_strict_mode_failure(&error(
+            GatewayErrorKind::InvalidRequest
+        )));
+        assert!(!is_strict_mode_failure(&error(GatewayErrorKind::Timeout)));
+        assert!(!is_strict_mode_failure(&error(
+            GatewayErrorKind::RateLimited
+        )));
+        assert!(!is_strict_mode_failure(&error(
+            GatewayErrorKind::BackendTransient
+        )));
+    }
+
+    #[test]
+    fn repeated_strict_failures_downgrade_stage_until_retry() {
+        let fallback = StrictSchemaFallback::new(Some(StrictSchemaFallbackConfig {
+            failure_threshold: 2,
+            retry_strict_after_cycles: 5,
+        }));
+        let organ = CognitionOrgan::Acts;
+
+        fallback.record(organ, 1, false);
+        let resolved = fallback.resolve(organ, 2, strict_mode());
+        assert!(resolved.strict_attempt);
+        fallback.record(organ, 2, false);
+
+        let resolved = fallback.resolve(organ, 3, strict_mode());
+        assert!(matches!(resolved.output_mode, OutputMode::JsonObject));
+        assert!(!resolved.strict_attempt);
+        assert_eq!(resolved.validate_against, Some(json!({ "type": "object" })));
+        let other_stage = fallback.resolve(CognitionOrgan::Primary, 3, strict_mode());
+        assert!(matches!(
+            other_stage.output_mode,
+            OutputMode::JsonSchema { strict: true, .. }
+        ));
+
+        let retry = fallback.resolve(organ, 7, strict_mode());
+        assert!(matches!(
+            retry.output_mode,
+            OutputMode::JsonSchema { strict: true, .. }
+        ));
+        assert!(retry.strict_attempt);
+        fallback.record(organ, 7, false);
+        assert!(matches!(
+            fallback.resolve(organ, 8, strict_mode()).output_mode,
+            OutputMode::JsonObject
+        ));
+
+        fallback.record(organ, 12, true);
+        assert!(matches!(
+            fallback.resolve(organ, 13, strict_mode()).output_mode,
+            OutputMode::JsonSchema { strict: true, .. }
+        ));
+    }
+}
@@ -1,9 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use serde::Deserialize;
 
 use crate::{
     ai_gateway::chat::{ChatToolDefinition, ToolOverride},
+    config::ActCatalogMode,
     types::{NeuralSignalDescriptor, build_fq_neural_signal_id},
 };
 
@@ -12,10 +13,12 @@ pub(super) struct ActToolBinding {
     pub(super) alias: String,
     pub(super) descriptor: NeuralSignalDescriptor,
     pub(super) might_emit_sense_ids: Vec<String>,
+    pub(super) degraded: bool,
 }
 
 pub(super) const PRIMARY_TOOL_EXPAND_SENSES: &str = "expand-senses";
 pub(super) const PRIMARY_TOOL_BREAK_PRIMARY_PHASE: &str = "break-primary-phase";
+pub(super) const PRIMARY_TOOL_CONTROL_SLEEP: &str = "control-sleep";
 
 #[derive(Debug, Deserialize)]
 pub(super) struct ExpandSenseTask {
@@ -24,14 +27,24 @@ pub(super) struct ExpandSenseTask {
     pub(super) use_subagent_and_instruction_is: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct ControlSleepArgs {
+    #[serde(default)]
+    pub(super) reason: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub(super) struct ActToolArgs {
     #[serde(default)]
     pub(super) payload: serde_json::Value,
+    /// Goal forest node the act serves.
+    #[serde(default)]
+    pub(super) goal_id: Option<String>,
 }
 
-pub(super) fn primary_internal_tools() -> Vec<ChatToolDefinition> {
-    vec![
+pub(super) fn primary_internal_tools(control_sleep: bool) -> Vec<ChatToolDefinition> {
+    let mut tools = vec![
         ChatToolDefinition {
             name: PRIMARY_TOOL_EXPAND_SENSES.to_string(),
             description: Some(
@@ -67,12 +80,34 @@ pub(super) fn primary_internal_tools() -> Vec<ChatToolDefinition> {
                 "additionalProperties": false
             }),
         },
-    ]
+    ];
+    if control_sleep {
+        tools.push(ChatToolDefinition {
+            name: PRIMARY_TOOL_CONTROL_SLEEP.to_string(),
+            description: Some(
+                concat!(
+                    "Request a graceful sleep of the whole runtime, e.g. when no goals are pending ",
+                    "or budget is low. Senses stop being admitted and the runtime shuts down after ",
+                    "in-flight work drains."
+                )
+                .to_string(),
+            ),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "reason": { "type": "string", "minLength": 1 }
+                },
+                "additionalProperties": false
+            }),
+        });
+    }
+    tools
 }
 
 pub(super) fn build_act_tool_bindings(
     act_descriptors: &[NeuralSignalDescriptor],
     sense_descriptors: &[NeuralSignalDescriptor],
+    degraded_endpoint_ids: &BTreeSet<String>,
 ) -> Vec<ActToolBinding> {
     let mut endpoint_emitted_sense_catalog: HashMap<String, Vec<String>> = HashMap::new();
     for descriptor in sense_descriptors {
@@ -104,6 +139,7 @@ pub(super) fn build_act_tool_bindings(
                 ),
                 descriptor: descriptor.clone(),
                 might_emit_sense_ids,
+                degraded: degraded_endpoint_ids.contains(&descriptor.endpoint_id),
             }
         })
         .collect()
@@ -112,24 +148,43 @@ pub(super) fn build_act_tool_bindings(
 pub(super) fn dynamic_act_tool_overrides(
     act_bindings: &[ActToolBinding],
     _max_waiting_ticks: u64,
+    mode: ActCatalogMode,
 ) -> Vec<ToolOverride> {
     act_bindings
         .iter()
         .map(|binding| {
+            let fq_act_id = build_fq_neural_signal_id(
+                &binding.descriptor.endpoint_id,
+                &binding.descriptor.neural_signal_descriptor_id,
+            );
+            let payload_schema = &binding.descriptor.payload_schema;
+            // TODO: replace with NSDescriptor.description
+            let (mut description, payload_input_schema) = match mode {
+                ActCatalogMode::FullSchema => (format!("Emit {fq_act_id}"), payload_schema.clone()),
+                ActCatalogMode::SchemaSummary => (
+                    format!(
+                        "Emit {fq_act_id}. Payload: {}",
+                        summarize_payload_schema(payload_schema)
+                    ),
+                    shallow_payload_schema(payload_schema),
+                ),
+            };
+            if binding.degraded {
+                description.push_str(
+                    ". Degraded: this endpoint is failing deliveries, prefer an alternative if one exists",
+                );
+            }
             ToolOverride::Set(ChatToolDefinition {
                 name: binding.alias.clone(),
-                // TODO: replace with NSDescriptor.description
-                description: Some(format!(
-                    "Emit {}",
-                    build_fq_neural_signal_id(
-                        &binding.descriptor.endpoint_id,
-                        &binding.descriptor.neural_signal_descriptor_id
-                    )
-                )),
+                description: Some(description),
                 input_schema: serde_json::json!({
                     "type": "object",
                     "properties": {
-                        "payload": binding.descriptor.payload_schema.clone()
+                        "payload": payload_input_schema,
+                        "goal_id": {
+                            "type": "string",
+                            "description": "Id of the goal this act serves, if any"
+                        }
                     },
                     "required": ["payload"],
                     "additionalProperties": false
@@ -139,6 +194,60 @@ pub(super) fn dynamic_act_tool_overrides(
         .collect()
 }
 
+/// Deterministic one-line summary of a payload schema: top-level type, required and optional fields.
+pub(super) fn summarize_payload_schema(schema: &serde_json::Value) -> String {
+    let schema_type = schema_type_label(schema);
+    let Some(properties) = schema.get("properties").and_then(|value| value.as_object()) else {
+        return schema_type;
+    };
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(|value| value.as_array())
+        .map(|items| items.iter().filter_map(|item| item.as_str()).collect())
+        .unwrap_or_default();
+
+    let mut required_fields = Vec::new();
+    let mut optional_fields = Vec::new();
+    for (name, property) in properties {
+        let field = format!("{name}: {}", schema_type_label(property));
+        if required.contains(&name.as_str()) {
+            required_fields.push(field);
+        } else {
+            optional_fields.push(field);
+        }
+    }
+
+    let mut summary = format!(
+        "{schema_type} {{ required: [{}]",
+        required_fields.join(", ")
+    );
+    if !optional_fields.is_empty() {
+        summary.push_str(&format!("; optional: [{}]", optional_fields.join(", ")));
+    }
+    summary.push_str(" }");
+    summary
+}
+
+fn schema_type_label(schema: &serde_json::Value) -> String {
+    match schema.get("type") {
+        Some(serde_json::Value::String(value)) => value.clone(),
+        Some(serde_json::Value::Array(values)) => values
+            .iter()
+            .filter_map(|value| value.as_str())
+            .collect::<Vec<_>>()
+            .join("|"),
+        _ if schema.get("enum").is_some() => "enum".to_string(),
+        _ => "any".to_string(),
+    }
+}
+
+fn shallow_payload_schema(schema: &serde_json::Value) -> serde_json::Value {
+    match schema.get("type") {
+        Some(schema_type) => serde_json::json!({ "type": schema_type }),
+        None => serde_json::json!({}),
+    }
+}
+
 fn transport_safe_act_tool_alias(endpoint_id: &str, neural_signal_descriptor_id: &str) -> String {
     let fq_act_id = build_fq_neural_signal_id(endpoint_id, neural_signal_descriptor_id);
     let mut normalized = String::with_capacity(fq_act_id.len());
@@ -152,3 +261,70 @@ fn transport_safe_act_tool_alias(endpoint_id: &str, neural_signal_descriptor_id:
     }
     format!("act_{normalized}")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::NeuralSignalType;
+
+    #[test]
+    fn schema_summary_mode_lists_required_fields_without_full_schema() {
+        let descriptor = NeuralSignalDescriptor {
+            r#type: NeuralSignalType::Act,
+            endpoint_id: "std.shell".to_string(),
+            neural_signal_descriptor_id: "exec".to_string(),
+            payload_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "argv": { "type": "array", "items": { "type": "string" } },
+                    "timeout_ms": { "type": "integer", "minimum": 1 }
+                },
+                "required": ["argv"]
+            }),
+            priority: None,
+        };
+        let bindings = build_act_tool_bindings(&[descriptor], &[], &BTreeSet::new());
+
+        let overrides = dynamic_act_tool_overrides(&bindings, 1, ActCatalogMode::SchemaSummary);
+
+        let [ToolOverride::Set(definition)] = overrides.as_slice() else {
+            panic!("expected exactly one act tool override");
+        };
+        let description = definition.description.as_deref().unwrap_or_default();
+        assert!(description.contains("required: [argv: array]"));
+        assert!(description.contains("optional: [timeout_ms: integer]"));
+        assert_eq!(
+            definition.input_schema["properties"]["payload"],
+            serde_json::json!({ "type": "object" })
+        );
+    }
+
+    #[test]
+    fn degraded_endpoint_acts_are_marked_in_the_catalog() {
+        let descriptor = NeuralSignalDescriptor {
+            r#type: NeuralSignalType::Act,
+            endpoint_id: "std.shell".to_string(),
+            neural_signal_descriptor_id: "exec".to_string(),
+            payload_schema: serde_json::json!({ "type": "object" }),
+            priority: None,
+        };
+        let bindings = build_act_tool_bindings(
+            &[descriptor],
+            &[],
+            &BTreeSet::from(["std.shell".to_string()]),
+        );
+
+        let overrides = dynamic_act_tool_overrides(&bindings, 1, ActCatalogMode::FullSchema);
+
+        let [ToolOverride::Set(definition)] = overrides.as_slice() else {
+            panic!("expected exactly one act tool override");
+        };
+        assert!(
+            definition
+                .description
+                .as_deref()
+                .unwrap_or_default()
+                .contains("Degraded")
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Cortex, CortexTelemetryEvent};
+use crate::cortex::types::{ReactionLimitBounds, ReactionLimits};
+
+/// Reaction-limit changes proposed by the cortex itself; unset fields keep their value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct ReactionLimitsProposal {
+    #[serde(default)]
+    pub(super) max_cycle_time_ms: Option<u64>,
+    #[serde(default)]
+    pub(super) max_primary_turns_per_tick: Option<u8>,
+    #[serde(default)]
+    pub(super) sense_passthrough_max_bytes: Option<usize>,
+    #[serde(default)]
+    pub(super) max_waiting_ticks: Option<u64>,
+    #[serde(default)]
+    pub(super) max_sense_sub_agent_concurrency: Option<usize>,
+}
+
+impl ReactionLimitsProposal {
+    pub(super) fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Holds the self-tuned reaction limits that replace the configured ones from the next cycle.
+#[derive(Clone, Default)]
+pub(super) struct ReactionLimitTuner {
+    bounds: Option<ReactionLimitBounds>,
+    tuned: Arc<Mutex<Option<ReactionLimits>>>,
+}
+
+impl ReactionLimitTuner {
+    pub(super) fn new(bounds: Option<ReactionLimitBounds>) -> Self {
+        Self {
+            bounds,
+            tuned: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub(super) fn bounds(&self) -> Option<&ReactionLimitBounds> {
+        self.bounds.as_ref()
+    }
+
+    pub(super) fn tuned_limits(&self) -> Option<ReactionLimits> {
+        self.tuned.lock().expect("lock poisoned").clone()
+    }
+
+    fn store(&self, limits: ReactionLimits) {
+        *self.tuned.lock().expect("lock poisoned") = Some(limits);
+    }
+}
+
+/// Applies `proposal` on top of `current`, clamping every proposed value into `[1, bound]`.
+///
+/// Returns the resulting limits and the names of fields whose proposed value was clamped.
+pub(super) fn clamp_reaction_limits(
+    current: &ReactionLimits,
+    proposal: &ReactionLimitsProposal,
+    bounds: &ReactionLimitBounds,
+) -> (ReactionLimits, Vec<&'static str>) {
+    fn clamp<T: Copy + Ord + From<u8>>(
+        proposed: Option<T>,
+        current: T,
+        bound: T,
+        field: &'static str,
+        clamped: &mut Vec<&'static str>,
+    ) -> T {
+        let Some(proposed) = proposed else {
+            return current;
+        };
+        let value = proposed.clamp(T::from(1), bound.max(T::from(1)));
+        if value != proposed {
+            clamped.push(field);
+        }
+        value
+    }
+
+    let mut clamped = Vec::new();
+    let mut limits = current.clone();
+    limits.max_cycle_time_ms = clamp(
+        proposal.max_cycle_time_ms,
+        current.max_cycle_time_ms,
+        bounds.max_cycle_time_ms,
+        "max_cycle_time_ms",
+        &mut clamped,
+    );
+    limits.max_primary_turns_per_tick = clamp(
+        proposal.max_primary_turns_per_tick,
+        current.max_primary_turns_per_tick,
+        bounds.max_primary_turns_per_tick,
+        "max_primary_turns_per_tick",
+        &mut clamped,
+    );
+    limits.sense_passthrough_max_bytes = clamp(
+        proposal.sense_passthrough_max_bytes,
+        current.sense_passthrough_max_bytes,
+        bounds.sense_passthrough_max_bytes,
+        "sense_passthrough_max_bytes",
+        &mut clamped,
+    );
+    limits.max_waiting_ticks = clamp(
+        proposal.max_waiting_ticks,
+        current.max_waiting_ticks,
+        bounds.max_waiting_ticks,
+        "max_waiting_ticks",
+        &mut clamped,
+    );
+    limits.max_sense_sub_agent_concurrency = clamp(
+        proposal.max_sense_sub_agent_concurrency,
+        current.max_sense_sub_agent_concurrency,
+        bounds.max_sense_sub_agent_concurrency,
+        "max_sense_sub_agent_concurrency",
+        &mut clamped,
+    );
+    (limits, clamped)
+}
+
+impl Cortex {
+    /// Returns a cortex running with the self-tuned limits, if they differ from the current ones.
+    pub(super) fn with_tuned_limits(&self) -> Option<Cortex> {
+        let limits = self.limit_tuner.tuned_limits()?;
+        if limits == self.limits {
+            return None;
+        }
+        let mut tuned = self.clone();
+        tuned.limits = limits;
+        Some(tuned)
+    }
+
+    pub(super) fn apply_reaction_limits_proposal(
+        &self,
+        cycle_id: u64,
+        proposal: &ReactionLimitsProposal,
+    ) {
+        let Some(bounds) = self.limit_tuner.bounds() else {
+            tracing::warn!(
+                target: "cortex",
+                cycle_id = cycle_id,
+                "reaction_limits_proposal_ignored_without_bounds"
+            );
+            return;
+        };
+        let (limits, clamped_fields) = clamp_reaction_limits(&self.limits, proposal, bounds);
+        self.limit_tuner.store(limits.clone());
+        self.emit(CortexTelemetryEvent::ReactionLimitsTuned {
+            cycle_id,
+            limits,
+            clamped_fields,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proposal_beyond_hard_bound_is_clamped() {
+        let current = ReactionLimits::default();
+        let bounds = ReactionLimitBounds {
+            max_cycle_time_ms: 90_000,
+            max_primary_turns_per_tick: 6,
+            sense_passthrough_max_bytes: 4_096,
+            max_waiting_ticks: 60,
+            max_sense_sub_agent_concurrency: 8,
+        };
+        let proposal = ReactionLimitsProposal {
+            max_cycle_time_ms: Some(600_000),
+            max_primary_turns_per_tick: Some(0),
+            max_waiting_ticks: Some(45),
+            ..ReactionLimitsProposal::default()
+        };
+
+        let (limits, clamped) = clamp_reaction_limits(&current, &proposal, &bounds);
+
+        assert_eq!(limits.max_cycle_time_ms, 90_000);
+        assert_eq!(limits.max_primary_turns_per_tick, 1);
+        assert_eq!(limits.max_waiting_ticks, 45);
+        assert_eq!(
+            limits.sense_passthrough_max_bytes,
+            current.sense_passthrough_max_bytes
+        );
+        assert_eq!(
+            clamped,
+            vec!["max_cycle_time_ms", "max_primary_turns_per_tick"]
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::config::CycleRateLimitConfig;
+
+/// Generic cell rate limiter over cycle starts: each start books one emission interval, and
+/// up to `burst` starts may run ahead of that schedule.
+pub(crate) struct CycleRateGovernor {
+    interval: Duration,
+    burst_tolerance: Duration,
+    theoretical_start: Option<Instant>,
+}
+
+impl CycleRateGovernor {
+    pub(crate) fn new(config: &CycleRateLimitConfig) -> Self {
+        let interval = Duration::from_secs_f64(1.0 / config.max_cycles_per_second);
+        Self {
+            interval,
+            burst_tolerance: interval.saturating_mul(config.burst.saturating_sub(1)),
+            theoretical_start: None,
+        }
+    }
+
+    /// Books the next cycle start and returns how long to wait before it may begin.
+    pub(crate) fn reserve_start(&mut self) -> Duration {
+        let now = Instant::now();
+        let scheduled = self
+            .theoretical_start
+            .map_or(now, |theoretical| theoretical.max(now));
+        let earliest = scheduled.checked_sub(self.burst_tolerance).unwrap_or(now);
+        self.theoretical_start = Some(scheduled + self.interval);
+        earliest.saturating_duration_since(now)
+    }
+}
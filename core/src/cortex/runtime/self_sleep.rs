@@ -0,0 +1,42 @@
+use std::sync::Mutex;
+
+use tokio_util::sync::CancellationToken;
+
+/// Sleep request raised by cortex through its `control-sleep` tool.
+///
+/// The binary waits on it next to the OS signals and runs the same gate-close shutdown
+/// when it fires. Only the first request's reason is kept.
+#[derive(Debug, Default)]
+pub struct SelfSleepRequest {
+    requested: CancellationToken,
+    reason: Mutex<Option<String>>,
+}
+
+impl SelfSleepRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn request(&self, reason: Option<String>) {
+        {
+            let mut current = self.reason.lock().expect("lock poisoned");
+            if !self.requested.is_cancelled() {
+                *current = reason;
+            }
+        }
+        self.requested.cancel();
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.requested.is_cancelled()
+    }
+
+    pub fn reason(&self) -> Option<String> {
+        self.reason.lock().expect("lock poisoned").clone()
+    }
+
+    /// Resolves once sleep has been requested.
+    pub async fn requested(&self) {
+        self.requested.cancelled().await;
+    }
+}
@@ -0,0 +1,97 @@
+use std::{collections::BTreeMap, sync::Mutex};
+
+use crate::{
+    config::{SenseLane, SenseLaneRule},
+    types::Sense,
+};
+
+/// Latest sense recorded for one endpoint and descriptor pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SituationEntry {
+    pub tag: String,
+    pub sense: Sense,
+}
+
+/// Latest situation-lane senses, keyed by `(endpoint_id, neural_signal_descriptor_id)`.
+///
+/// Senses recorded here never reach a primary cycle as senses; each cycle shows Primary the
+/// latest payload of every entry in its proprioception section instead.
+#[derive(Debug, Default)]
+pub struct SituationView {
+    entries: Mutex<BTreeMap<(String, String), SituationEntry>>,
+}
+
+impl SituationView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn record(&self, tag: &str, sense: Sense) {
+        let key = (
+            sense.endpoint_id.clone(),
+            sense.neural_signal_descriptor_id.clone(),
+        );
+        self.entries.lock().expect("lock poisoned").insert(
+            key,
+            SituationEntry {
+                tag: tag.to_string(),
+                sense,
+            },
+        );
+    }
+
+    pub fn snapshot(&self) -> BTreeMap<(String, String), SituationEntry> {
+        self.entries.lock().expect("lock poisoned").clone()
+    }
+
+    /// Adds every entry to `proprioception` as `situation.<tag>.<endpoint_id>.<descriptor_id>`,
+    /// leaving keys the physical state already set untouched.
+    pub(super) fn project_into(&self, proprioception: &mut BTreeMap<String, String>) {
+        for ((endpoint_id, neural_signal_descriptor_id), entry) in
+            self.entries.lock().expect("lock poisoned").iter()
+        {
+            proprioception
+                .entry(format!(
+                    "situation.{}.{endpoint_id}.{neural_signal_descriptor_id}",
+                    entry.tag
+                ))
+                .or_insert_with(|| entry.sense.payload.clone());
+        }
+    }
+}
+
+/// Classifies senses by the configured lane rules, first match wins.
+#[derive(Debug, Default)]
+pub(super) struct SenseLaneRouter {
+    rules: Vec<SenseLaneRule>,
+}
+
+impl SenseLaneRouter {
+    pub(super) fn new(rules: Vec<SenseLaneRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Returns the sense back when it belongs to the primary lane.
+    pub(super) fn route(&self, sense: Sense, situation_view: &SituationView) -> Option<Sense> {
+        let Some(rule) = self.rules.iter().find(|rule| {
+            rule.neural_signal_descriptor_ids
+                .contains(&sense.neural_signal_descriptor_id)
+        }) else {
+            return Some(sense);
+        };
+        match rule.lane {
+            SenseLane::Primary => Some(sense),
+            SenseLane::SituationView => {
+                tracing::debug!(
+                    target = "cortex",
+                    tag = %rule.tag,
+                    endpoint_id = %sense.endpoint_id,
+                    neural_signal_descriptor_id = %sense.neural_signal_descriptor_id,
+                    "sense_recorded_into_situation_view"
+                );
+                situation_view.record(&rule.tag, sense);
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::{
+    config::{EndpointSensePolicyRule, SenseProcessingPolicy},
+    spine::endpoint_name_of,
+    types::Sense,
+};
+
+/// Most `(endpoint_id, neural_signal_descriptor_id)` pairs `DropDuplicates` remembers; the
+/// oldest is forgotten first, so reconnecting endpoints cannot grow the map without bound.
+const MAX_TRACKED_PAYLOADS: usize = 1024;
+
+/// Resolves the sense-processing policy of each arriving sense, first match wins.
+#[derive(Debug, Default)]
+pub(super) struct EndpointSensePolicyRouter {
+    rules: Vec<EndpointSensePolicyRule>,
+    /// Last payload seen per `(endpoint_id, neural_signal_descriptor_id)` under `DropDuplicates`.
+    last_payloads: BTreeMap<(String, String), String>,
+    /// Keys of `last_payloads` in the order they were first tracked.
+    tracked_order: VecDeque<(String, String)>,
+}
+
+impl EndpointSensePolicyRouter {
+    pub(super) fn new(rules: Vec<EndpointSensePolicyRule>) -> Self {
+        Self {
+            rules,
+            last_payloads: BTreeMap::new(),
+            tracked_order: VecDeque::new(),
+        }
+    }
+
+    pub(super) fn policy_for(&self, sense: &Sense) -> SenseProcessingPolicy {
+        self.rules
+            .iter()
+            .find(|rule| rule_matches(rule, sense))
+            .map(|rule| rule.policy)
+            .unwrap_or_default()
+    }
+
+    /// Records the sense payload and reports whether it repeats the previous one.
+    pub(super) fn is_duplicate(&mut self, sense: &Sense) -> bool {
+        let key = (
+            sense.endpoint_id.clone(),
+            sense.neural_signal_descriptor_id.clone(),
+        );
+        match self.last_payloads.get_mut(&key) {
+            Some(previous) if *previous == sense.payload => true,
+            Some(previous) => {
+                previous.clone_from(&sense.payload);
+                false
+            }
+            None => {
+                if self.tracked_order.len() >= MAX_TRACKED_PAYLOADS
+                    && let Some(oldest) = self.tracked_order.pop_front()
+                {
+                    self.last_payloads.remove(&oldest);
+                }
+                self.tracked_order.push_back(key.clone());
+                self.last_payloads.insert(key, sense.payload.clone());
+                false
+            }
+        }
+    }
+}
+
+fn rule_matches(rule: &EndpointSensePolicyRule, sense: &Sense) -> bool {
+    if let Some(endpoint_name) = &rule.endpoint_name
+        && endpoint_name_of(&sense.endpoint_id) != endpoint_name
+    {
+        return false;
+    }
+    rule.neural_signal_descriptor_ids.is_empty()
+        || rule
+            .neural_signal_descriptor_ids
+            .contains(&sense.neural_signal_descriptor_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reading(endpoint_id: &str, payload: &str) -> Sense {
+        Sense {
+            sense_instance_id: uuid::Uuid::now_v7().to_string(),
+            endpoint_id: endpoint_id.to_string(),
+            neural_signal_descriptor_id: "reading".to_string(),
+            payload: payload.to_string(),
+            weight: 1.0,
+            act_instance_id: None,
+        }
+    }
+
+    #[test]
+    fn duplicate_tracking_forgets_the_oldest_endpoint_past_capacity() {
+        let mut router = EndpointSensePolicyRouter::new(Vec::new());
+        assert!(!router.is_duplicate(&reading("sensor.0", "same")));
+        assert!(router.is_duplicate(&reading("sensor.0", "same")));
+
+        for seq in 1..=MAX_TRACKED_PAYLOADS {
+            router.is_duplicate(&reading(&format!("sensor.{seq}"), "same"));
+        }
+
+        assert_eq!(router.last_payloads.len(), MAX_TRACKED_PAYLOADS);
+        assert!(!router.is_duplicate(&reading("sensor.0", "same")));
+        assert!(router.is_duplicate(&reading(&format!("sensor.{MAX_TRACKED_PAYLOADS}"), "same")));
+    }
+}
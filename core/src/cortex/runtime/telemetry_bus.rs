@@ -0,0 +1,79 @@
+use tokio::sync::broadcast;
+
+use super::{CortexTelemetryEvent, CortexTelemetryPort};
+
+/// Events buffered per subscriber before a slow one starts skipping the oldest.
+const TELEMETRY_BUS_CAPACITY: usize = 256;
+
+/// Fans cortex telemetry events out to any number of subscribers.
+///
+/// Attach it to the cortex as a telemetry port. Subscribers may join or drop at any time;
+/// publishing without subscribers is a no-op, and a subscriber that falls behind sees
+/// `RecvError::Lagged` instead of blocking the cortex.
+#[derive(Clone)]
+pub struct CortexTelemetryBus {
+    sender: broadcast::Sender<CortexTelemetryEvent>,
+}
+
+impl CortexTelemetryBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(TELEMETRY_BUS_CAPACITY);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<CortexTelemetryEvent> {
+        self.sender.subscribe()
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    pub(crate) fn publish(&self, event: &CortexTelemetryEvent) {
+        if self.sender.receiver_count() > 0 {
+            let _ = self.sender.send(event.clone());
+        }
+    }
+}
+
+impl CortexTelemetryPort for CortexTelemetryBus {
+    fn emit(&self, event: CortexTelemetryEvent) {
+        self.publish(&event);
+    }
+}
+
+impl Default for CortexTelemetryBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn every_live_subscriber_receives_a_published_event() {
+        let bus = CortexTelemetryBus::new();
+        let mut metrics = bus.subscribe();
+        let mut live_ui = bus.subscribe();
+        drop(bus.subscribe());
+        assert_eq!(bus.subscriber_count(), 2);
+
+        bus.publish(&CortexTelemetryEvent::ReactionStarted { cycle_id: 7 });
+
+        for receiver in [&mut metrics, &mut live_ui] {
+            let event = receiver.recv().await.expect("event");
+            assert!(matches!(
+                event,
+                CortexTelemetryEvent::ReactionStarted { cycle_id: 7 }
+            ));
+        }
+        drop(live_ui);
+        bus.publish(&CortexTelemetryEvent::ReactionStarted { cycle_id: 8 });
+        assert!(matches!(
+            metrics.recv().await.expect("event"),
+            CortexTelemetryEvent::ReactionStarted { cycle_id: 8 }
+        ));
+    }
+}
@@ -0,0 +1,23 @@
+use super::{CortexTelemetryEvent, CortexTelemetryHook};
+
+/// External consumer of cortex telemetry, called synchronously after internal tracing.
+///
+/// Implementations run on the cortex task and must not block; hand events off to a channel for
+/// anything slow.
+pub trait CortexTelemetryPort: Send + Sync {
+    fn emit(&self, event: CortexTelemetryEvent);
+}
+
+/// Port that discards every event.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopCortexTelemetry;
+
+impl CortexTelemetryPort for NoopCortexTelemetry {
+    fn emit(&self, _event: CortexTelemetryEvent) {}
+}
+
+impl CortexTelemetryPort for CortexTelemetryHook {
+    fn emit(&self, event: CortexTelemetryEvent) {
+        self(event);
+    }
+}
@@ -0,0 +1,97 @@
+use std::{
+    collections::VecDeque,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::observability::metrics as observability_metrics;
+
+const THROUGHPUT_WINDOW_CYCLES: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CycleThroughputSnapshot {
+    pub cycles_total: u64,
+    /// Completed cycles per second over the recent window, from the first start to the last finish.
+    pub cycles_per_second: f64,
+    pub average_latency: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CycleSample {
+    started_at: Instant,
+    finished_at: Instant,
+}
+
+#[derive(Debug, Default)]
+struct CycleThroughputState {
+    cycles_total: u64,
+    recent: VecDeque<CycleSample>,
+}
+
+/// Tracks completed cortex cycles over a window of the most recent cycles.
+#[derive(Debug, Default)]
+pub struct CycleThroughput {
+    state: Mutex<CycleThroughputState>,
+}
+
+impl CycleThroughput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records one completed cycle and publishes the updated throughput metrics.
+    pub fn record_cycle(&self, started_at: Instant, finished_at: Instant) {
+        let snapshot = {
+            let mut state = self.state.lock().expect("lock poisoned");
+            state.cycles_total = state.cycles_total.saturating_add(1);
+            state.recent.push_back(CycleSample {
+                started_at,
+                finished_at,
+            });
+            while state.recent.len() > THROUGHPUT_WINDOW_CYCLES {
+                state.recent.pop_front();
+            }
+            snapshot_of(&state)
+        };
+
+        observability_metrics::record_cortex_cycle_throughput(
+            snapshot.cycles_per_second,
+            snapshot.average_latency.as_secs_f64() * 1_000.0,
+        );
+    }
+
+    pub fn snapshot(&self) -> CycleThroughputSnapshot {
+        snapshot_of(&self.state.lock().expect("lock poisoned"))
+    }
+}
+
+fn snapshot_of(state: &CycleThroughputState) -> CycleThroughputSnapshot {
+    let (Some(first), Some(last)) = (state.recent.front(), state.recent.back()) else {
+        return CycleThroughputSnapshot {
+            cycles_total: state.cycles_total,
+            cycles_per_second: 0.0,
+            average_latency: Duration::ZERO,
+        };
+    };
+
+    let span = last.finished_at.saturating_duration_since(first.started_at);
+    let cycles = state.recent.len() as u32;
+    let total_latency = state
+        .recent
+        .iter()
+        .map(|sample| {
+            sample
+                .finished_at
+                .saturating_duration_since(sample.started_at)
+        })
+        .sum::<Duration>();
+    CycleThroughputSnapshot {
+        cycles_total: state.cycles_total,
+        cycles_per_second: if span.is_zero() {
+            0.0
+        } else {
+            f64::from(cycles) / span.as_secs_f64()
+        },
+        average_latency: total_latency / cycles,
+    }
+}
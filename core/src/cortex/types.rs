@@ -1,10 +1,16 @@
-use std::collections::BTreeSet;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    time::Duration,
+};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
-use super::helpers::goal_forest_helper::{GoalForest, GoalNode};
+use super::helpers::{
+    CognitionOrgan,
+    goal_forest_helper::{GoalForest, GoalNode},
+};
 
 fn default_sense_passthrough_max_bytes() -> usize {
     2_048
@@ -18,6 +24,48 @@ fn default_max_primary_turns_per_tick() -> u8 {
     4
 }
 
+fn default_max_sense_sub_agent_concurrency() -> usize {
+    4
+}
+
+fn default_output_token_budget_policy() -> OutputTokenBudgetPolicy {
+    OutputTokenBudgetPolicy::Equal
+}
+
+fn validate_organ_timeouts_ms(timeouts: &BTreeMap<String, u64>) -> Result<(), ValidationError> {
+    for (stage, timeout_ms) in timeouts {
+        if !CognitionOrgan::ALL
+            .iter()
+            .any(|organ| organ.stage() == stage)
+        {
+            return Err(ValidationError::new("unknown_organ_stage"));
+        }
+        if *timeout_ms == 0 {
+            return Err(ValidationError::new("zero_organ_timeout"));
+        }
+    }
+    Ok(())
+}
+
+/// How a cycle output-token budget is shared between organ calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputTokenBudgetPolicy {
+    /// Every organ call receives the same share of the remaining budget.
+    Equal,
+    /// Primary receives a larger share of the remaining budget than helper organs.
+    WeightedByStage,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CycleOutputTokenBudget {
+    #[validate(range(min = 1))]
+    pub total_output_tokens: u64,
+    #[serde(default = "default_output_token_budget_policy")]
+    pub policy: OutputTokenBudgetPolicy,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct ReactionLimits {
@@ -36,10 +84,10 @@ pub struct ReactionLimits {
     pub max_sub_calls: u8,
     #[validate(range(min = 0, max = 1))]
     pub max_repair_attempts: u8,
-    /// Paused: this config is retained for compatibility but currently ignored.
+    /// Paused unless `cycle_output_token_budget` is set; then it caps each Primary turn.
     #[validate(range(min = 1))]
     pub max_primary_output_tokens: u64,
-    /// Paused: this config is retained for compatibility but currently ignored.
+    /// Paused unless `cycle_output_token_budget` is set; then it caps each helper organ call.
     #[validate(range(min = 1))]
     pub max_sub_output_tokens: u64,
     #[serde(default = "default_sense_passthrough_max_bytes")]
@@ -48,6 +96,16 @@ pub struct ReactionLimits {
     #[serde(default = "default_max_waiting_ticks")]
     #[validate(range(min = 1))]
     pub max_waiting_ticks: u64,
+    /// Upper bound on concurrent per-sense sub-agent organ calls within one expansion.
+    #[serde(default = "default_max_sense_sub_agent_concurrency")]
+    #[validate(range(min = 1))]
+    pub max_sense_sub_agent_concurrency: usize,
+    /// Optional total output-token budget shared by all organ calls of one cycle.
+    #[validate(nested)]
+    pub cycle_output_token_budget: Option<CycleOutputTokenBudget>,
+    /// Per-stage organ call timeouts keyed by stage name; unlisted stages use `max_cycle_time_ms`.
+    #[validate(custom(function = "validate_organ_timeouts_ms"))]
+    pub organ_timeouts_ms: BTreeMap<String, u64>,
 }
 
 impl Default for ReactionLimits {
@@ -64,6 +122,52 @@ impl Default for ReactionLimits {
             max_sub_output_tokens: 768,
             sense_passthrough_max_bytes: default_sense_passthrough_max_bytes(),
             max_waiting_ticks: default_max_waiting_ticks(),
+            max_sense_sub_agent_concurrency: default_max_sense_sub_agent_concurrency(),
+            cycle_output_token_budget: None,
+            organ_timeouts_ms: BTreeMap::new(),
+        }
+    }
+}
+
+impl ReactionLimits {
+    /// How long one call of the organ at `stage` may take before it is abandoned.
+    pub(crate) fn organ_timeout(&self, stage: &str) -> Duration {
+        let timeout_ms = self
+            .organ_timeouts_ms
+            .get(stage)
+            .copied()
+            .unwrap_or(self.max_cycle_time_ms);
+        Duration::from_millis(timeout_ms.max(1))
+    }
+}
+
+/// Operator hard upper bounds for reaction limits the cortex may tune itself.
+///
+/// Every tunable limit keeps a lower bound of 1; proposals outside the range are clamped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReactionLimitBounds {
+    #[validate(range(min = 1))]
+    pub max_cycle_time_ms: u64,
+    #[validate(range(min = 1))]
+    pub max_primary_turns_per_tick: u8,
+    #[validate(range(min = 1))]
+    pub sense_passthrough_max_bytes: usize,
+    #[validate(range(min = 1))]
+    pub max_waiting_ticks: u64,
+    #[validate(range(min = 1))]
+    pub max_sense_sub_agent_concurrency: usize,
+}
+
+impl Default for ReactionLimitBounds {
+    fn default() -> Self {
+        let limits = ReactionLimits::default();
+        Self {
+            max_cycle_time_ms: limits.max_cycle_time_ms,
+            max_primary_turns_per_tick: limits.max_primary_turns_per_tick,
+            sense_passthrough_max_bytes: limits.sense_passthrough_max_bytes,
+            max_waiting_ticks: limits.max_waiting_ticks,
+            max_sense_sub_agent_concurrency: limits.max_sense_sub_agent_concurrency,
         }
     }
 }
@@ -103,6 +207,247 @@ impl Default for CognitionState {
     }
 }
 
+/// Goal-level changes between two cognition states, keyed by goal id across the whole forest.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CognitionStateDiff {
+    pub from_revision: u64,
+    pub to_revision: u64,
+    pub added_goals: Vec<GoalNode>,
+    pub removed_goals: Vec<GoalNode>,
+    /// Ids of goals present in both states whose status, weight, or summary changed.
+    pub changed_goal_ids: Vec<String>,
+}
+
+impl CognitionStateDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_goals.is_empty()
+            && self.removed_goals.is_empty()
+            && self.changed_goal_ids.is_empty()
+    }
+}
+
+/// What [`CognitionState::trim_to_serialized_size`] removed to approach the cap.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CognitionStateTrim {
+    pub trimmed_goal_ids: Vec<String>,
+    pub serialized_bytes_before: usize,
+    pub serialized_bytes_after: usize,
+}
+
+impl CognitionState {
+    pub fn serialized_size(&self) -> usize {
+        serde_json::to_vec(self).map_or(0, |bytes| bytes.len())
+    }
+
+    /// Removes goals with a trimmable status, oldest first, until the serialized state fits in
+    /// `max_bytes`; `None` when it already fits or no goal could be removed.
+    ///
+    /// A goal is only removed together with its whole subtree, and never while any goal in that
+    /// subtree is still active, so the result may stay above the cap.
+    pub fn trim_to_serialized_size(
+        &mut self,
+        max_bytes: usize,
+        trimmable_statuses: &[String],
+    ) -> Option<CognitionStateTrim> {
+        let serialized_bytes_before = self.serialized_size();
+        if serialized_bytes_before <= max_bytes {
+            return None;
+        }
+
+        let is_trimmable = |node: &GoalNode| {
+            trimmable_statuses
+                .iter()
+                .any(|status| status.eq_ignore_ascii_case(node.status.trim()))
+        };
+        let mut trimmed_goal_ids = Vec::new();
+        let mut serialized_bytes_after = serialized_bytes_before;
+        while serialized_bytes_after > max_bytes {
+            let Some(removed) =
+                remove_first_trimmable_goal(&mut self.goal_forest.nodes, &is_trimmable)
+            else {
+                break;
+            };
+            collect_goal_ids(&removed, &mut trimmed_goal_ids);
+            serialized_bytes_after = self.serialized_size();
+        }
+        if trimmed_goal_ids.is_empty() {
+            return None;
+        }
+
+        Some(CognitionStateTrim {
+            trimmed_goal_ids,
+            serialized_bytes_before,
+            serialized_bytes_after,
+        })
+    }
+
+    /// Describes how `other` differs from `self`; children are reported as their own goals.
+    pub fn diff(&self, other: &CognitionState) -> CognitionStateDiff {
+        let before = index_goal_nodes(&self.goal_forest.nodes);
+        let after = index_goal_nodes(&other.goal_forest.nodes);
+
+        let mut diff = CognitionStateDiff {
+            from_revision: self.revision,
+            to_revision: other.revision,
+            ..CognitionStateDiff::default()
+        };
+        for (id, node) in &after {
+            match before.get(id) {
+                None => diff.added_goals.push(leaf_goal(node)),
+                Some(previous)
+                    if previous.status != node.status
+                        || previous.weight != node.weight
+                        || previous.summary != node.summary =>
+                {
+                    diff.changed_goal_ids.push(id.to_string());
+                }
+                Some(_) => {}
+            }
+        }
+        for (id, node) in &before {
+            if !after.contains_key(id) {
+                diff.removed_goals.push(leaf_goal(node));
+            }
+        }
+        diff
+    }
+}
+
+/// The goal forest flattened into nodes and parent-to-child edges, for external graph tools.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GoalGraph {
+    pub nodes: Vec<GoalGraphNode>,
+    pub edges: Vec<GoalGraphEdge>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GoalGraphNode {
+    pub id: String,
+    pub status: String,
+    pub weight: f64,
+    pub summary: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GoalGraphEdge {
+    pub parent: String,
+    pub child: String,
+}
+
+impl GoalGraph {
+    /// Nodes in depth-first order; roots carry no incoming edge.
+    pub fn from_forest(forest: &GoalForest) -> Self {
+        let mut graph = Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        };
+        let mut stack = forest
+            .nodes
+            .iter()
+            .rev()
+            .map(|node| (None, node))
+            .collect::<Vec<_>>();
+        while let Some((parent, node)) = stack.pop() {
+            graph.nodes.push(GoalGraphNode {
+                id: node.id.clone(),
+                status: node.status.clone(),
+                weight: node.weight,
+                summary: node.summary.clone(),
+            });
+            if let Some(parent) = parent {
+                graph.edges.push(GoalGraphEdge {
+                    parent,
+                    child: node.id.clone(),
+                });
+            }
+            stack.extend(
+                node.children
+                    .iter()
+                    .rev()
+                    .map(|child| (Some(node.id.clone()), child)),
+            );
+        }
+        graph
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph goal_forest {\n");
+        for node in &self.nodes {
+            dot.push_str(&format!(
+                "  \"{}\" [label=\"{}\\n{} ({:.2})\"];\n",
+                dot_escape(&node.id),
+                dot_escape(&node.summary),
+                dot_escape(&node.status),
+                node.weight
+            ));
+        }
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "  \"{}\" -> \"{}\";\n",
+                dot_escape(&edge.parent),
+                dot_escape(&edge.child)
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn dot_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn index_goal_nodes(nodes: &[GoalNode]) -> BTreeMap<&str, &GoalNode> {
+    let mut index = BTreeMap::new();
+    let mut stack = nodes.iter().collect::<Vec<_>>();
+    while let Some(node) = stack.pop() {
+        index.insert(node.id.as_str(), node);
+        stack.extend(node.children.iter());
+    }
+    index
+}
+
+fn remove_first_trimmable_goal(
+    nodes: &mut Vec<GoalNode>,
+    is_trimmable: &impl Fn(&GoalNode) -> bool,
+) -> Option<GoalNode> {
+    for index in 0..nodes.len() {
+        if subtree_is_trimmable(&nodes[index], is_trimmable) {
+            return Some(nodes.remove(index));
+        }
+        if let Some(removed) = remove_first_trimmable_goal(&mut nodes[index].children, is_trimmable)
+        {
+            return Some(removed);
+        }
+    }
+    None
+}
+
+fn subtree_is_trimmable(node: &GoalNode, is_trimmable: &impl Fn(&GoalNode) -> bool) -> bool {
+    is_trimmable(node)
+        && node
+            .children
+            .iter()
+            .all(|child| subtree_is_trimmable(child, is_trimmable))
+}
+
+fn collect_goal_ids(node: &GoalNode, ids: &mut Vec<String>) {
+    ids.push(node.id.clone());
+    for child in &node.children {
+        collect_goal_ids(child, ids);
+    }
+}
+
+fn leaf_goal(node: &GoalNode) -> GoalNode {
+    GoalNode {
+        children: Vec::new(),
+        ..node.clone()
+    }
+}
+
 pub(crate) fn validate_cognition_state(state: &CognitionState) -> Result<(), String> {
     let mut ids = BTreeSet::new();
     for node in &state.goal_forest.nodes {
@@ -142,3 +487,137 @@ pub(crate) struct InputIr {
 pub(crate) struct OutputIr {
     pub text: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn goal(id: &str, status: &str, children: Vec<GoalNode>) -> GoalNode {
+        GoalNode {
+            status: status.to_string(),
+            weight: 0.5,
+            id: id.to_string(),
+            summary: format!("goal {id}"),
+            children,
+        }
+    }
+
+    #[test]
+    fn diff_reports_sprouted_and_removed_goals() {
+        let before = CognitionState {
+            revision: 3,
+            goal_forest: GoalForest {
+                nodes: vec![goal(
+                    "root",
+                    "open",
+                    vec![goal("stale", "open", vec![]), goal("kept", "open", vec![])],
+                )],
+            },
+        };
+        let after = CognitionState {
+            revision: 4,
+            goal_forest: GoalForest {
+                nodes: vec![goal(
+                    "root",
+                    "open",
+                    vec![
+                        goal("kept", "done", vec![]),
+                        goal("sprouted", "open", vec![]),
+                    ],
+                )],
+            },
+        };
+
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.from_revision, 3);
+        assert_eq!(diff.to_revision, 4);
+        assert_eq!(diff.added_goals, vec![goal("sprouted", "open", vec![])]);
+        assert_eq!(diff.removed_goals, vec![goal("stale", "open", vec![])]);
+        assert_eq!(diff.changed_goal_ids, vec!["kept".to_string()]);
+        assert!(after.diff(&after).is_empty());
+    }
+
+    #[test]
+    fn oversized_state_trims_completed_goals_and_keeps_active_ones() {
+        let mut state = CognitionState {
+            revision: 1,
+            goal_forest: GoalForest {
+                nodes: vec![
+                    goal("old-done", "done", vec![goal("old-step", "done", vec![])]),
+                    goal(
+                        "root",
+                        "open",
+                        vec![
+                            goal("finished", "Completed", vec![]),
+                            goal("blocked-parent", "done", vec![goal("live", "open", vec![])]),
+                        ],
+                    ),
+                    goal("recent-done", "done", vec![]),
+                ],
+            },
+        };
+        let mut expected = state.clone();
+        expected.goal_forest.nodes.remove(0);
+        expected.goal_forest.nodes[0].children.remove(0);
+        let max_bytes = expected.serialized_size() + 1;
+        let trimmable = vec!["done".to_string(), "completed".to_string()];
+
+        let trim = state
+            .trim_to_serialized_size(max_bytes, &trimmable)
+            .expect("oversized state should be trimmed");
+
+        assert_eq!(
+            trim.trimmed_goal_ids,
+            vec!["old-done", "old-step", "finished"]
+        );
+        assert!(trim.serialized_bytes_after <= max_bytes);
+        assert!(trim.serialized_bytes_before > max_bytes);
+        assert_eq!(state, expected);
+        assert!(
+            state
+                .trim_to_serialized_size(max_bytes, &trimmable)
+                .is_none()
+        );
+        assert!(
+            state
+                .trim_to_serialized_size(1, &["archived".to_string()])
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn goal_graph_dot_lists_nodes_and_parent_edges() {
+        let forest = GoalForest {
+            nodes: vec![
+                goal(
+                    "root",
+                    "open",
+                    vec![goal("child", "done", vec![goal("leaf", "open", vec![])])],
+                ),
+                goal("solo", "open", vec![]),
+            ],
+        };
+
+        let graph = GoalGraph::from_forest(&forest);
+        let dot = graph.to_dot();
+
+        assert_eq!(
+            graph
+                .nodes
+                .iter()
+                .map(|node| node.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["root", "child", "leaf", "solo"]
+        );
+        assert!(dot.starts_with("digraph goal_forest {"));
+        assert!(dot.contains(r#""root" [label="goal root\nopen (0.50)"];"#));
+        assert!(dot.contains(r#""root" -> "child";"#));
+        assert!(dot.contains(r#""child" -> "leaf";"#));
+        assert!(!dot.contains(r#"-> "solo""#));
+        assert_eq!(
+            serde_json::to_value(&graph).expect("json")["edges"][1],
+            serde_json::json!({ "parent": "child", "child": "leaf" })
+        );
+    }
+}
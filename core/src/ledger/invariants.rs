@@ -0,0 +1,287 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::ledger::{
+    SurvivalLedger,
+    types::{LedgerEntryKind, ReservationRecord, ReservationState},
+};
+
+/// Settlement rules a consistent ledger always satisfies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettlementInvariant {
+    /// A spine settlement event carries the ticket's reserve entry and cost attribution.
+    TicketMatchesEvent,
+    /// Open reservations have no terminal entry; terminal ones have exactly one, of their kind.
+    TerminalStrictness,
+    /// Terminal reservations record their reference and cycle; open ones record neither.
+    TerminalReference,
+    /// The reserve entry debits, and a refund or expiry returns, the reserved amount.
+    ReservationAmounts,
+    /// Every reservation is listed under its cost attribution id.
+    AttributionIndexed,
+    /// Entry sequence numbers strictly increase and end at the ledger's next sequence.
+    EntrySequence,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SettlementInvariantViolation {
+    pub rule: SettlementInvariant,
+    pub reserve_entry_id: Option<String>,
+    pub detail: String,
+    /// Ledger figures the rule was checked against.
+    pub figures: BTreeMap<&'static str, i64>,
+}
+
+impl SettlementInvariantViolation {
+    pub(crate) fn new(
+        rule: SettlementInvariant,
+        reserve_entry_id: Option<&str>,
+        detail: impl Into<String>,
+        figures: impl IntoIterator<Item = (&'static str, i64)>,
+    ) -> Self {
+        Self {
+            rule,
+            reserve_entry_id: reserve_entry_id.map(str::to_string),
+            detail: detail.into(),
+            figures: figures.into_iter().collect(),
+        }
+    }
+}
+
+impl SurvivalLedger {
+    /// Checks every settlement invariant and reports each violation with its ledger figures.
+    pub fn settlement_invariant_violations(&self) -> Vec<SettlementInvariantViolation> {
+        let mut violations = Vec::new();
+        for reservation in self.reservations.values() {
+            self.check_reservation(reservation, &mut violations);
+        }
+        self.check_entry_sequence(&mut violations);
+        violations
+    }
+
+    fn check_reservation(
+        &self,
+        reservation: &ReservationRecord,
+        violations: &mut Vec<SettlementInvariantViolation>,
+    ) {
+        let id = reservation.reserve_entry_id.as_str();
+        let reserved = reservation.reserved_survival_micro;
+        let mut reserve_amounts = Vec::new();
+        let mut terminal_entries = Vec::new();
+        for entry in &self.entries {
+            match &entry.kind {
+                LedgerEntryKind::Reserve { reserve_entry_id } if reserve_entry_id == id => {
+                    reserve_amounts.push(entry.amount_survival_micro);
+                }
+                LedgerEntryKind::Settle { reserve_entry_id } if reserve_entry_id == id => {
+                    terminal_entries.push((ReservationState::Settled, entry.amount_survival_micro));
+                }
+                LedgerEntryKind::Refund { reserve_entry_id } if reserve_entry_id == id => {
+                    terminal_entries
+                        .push((ReservationState::Refunded, entry.amount_survival_micro));
+                }
+                LedgerEntryKind::Expire { reserve_entry_id } if reserve_entry_id == id => {
+                    terminal_entries.push((ReservationState::Expired, entry.amount_survival_micro));
+                }
+                _ => {}
+            }
+        }
+
+        let is_open = reservation.state == ReservationState::Open;
+        let strict = if is_open {
+            terminal_entries.is_empty()
+        } else {
+            terminal_entries.len() == 1 && terminal_entries[0].0 == reservation.state
+        };
+        if !strict {
+            violations.push(SettlementInvariantViolation::new(
+                SettlementInvariant::TerminalStrictness,
+                Some(id),
+                format!(
+                    "reservation in state {:?} has terminal entries {:?}",
+                    reservation.state,
+                    terminal_entries
+                        .iter()
+                        .map(|(state, _)| *state)
+                        .collect::<Vec<_>>()
+                ),
+                [("terminal_entry_count", terminal_entries.len() as i64)],
+            ));
+        }
+
+        let has_reference = reservation.terminal_reference_id.is_some();
+        let has_cycle = reservation.terminal_cycle.is_some();
+        if has_reference == is_open || has_cycle == is_open {
+            violations.push(SettlementInvariantViolation::new(
+                SettlementInvariant::TerminalReference,
+                Some(id),
+                format!(
+                    "reservation in state {:?} has terminal_reference_id={:?}, terminal_cycle={:?}",
+                    reservation.state,
+                    reservation.terminal_reference_id,
+                    reservation.terminal_cycle
+                ),
+                [
+                    (
+                        "terminal_cycle",
+                        reservation.terminal_cycle.map_or(-1, |cycle| cycle as i64),
+                    ),
+                    ("created_cycle", reservation.created_cycle as i64),
+                ],
+            ));
+        }
+
+        if reserve_amounts.as_slice() != [-reserved] {
+            violations.push(SettlementInvariantViolation::new(
+                SettlementInvariant::ReservationAmounts,
+                Some(id),
+                format!("reserve entries {reserve_amounts:?} do not debit the reserved amount"),
+                [
+                    ("reserved_survival_micro", reserved),
+                    ("reserve_entry_count", reserve_amounts.len() as i64),
+                    ("reserve_entry_total", reserve_amounts.iter().sum()),
+                ],
+            ));
+        }
+        for (state, amount) in &terminal_entries {
+            let returns_reservation = matches!(
+                state,
+                ReservationState::Refunded | ReservationState::Expired
+            );
+            if returns_reservation && *amount != reserved {
+                violations.push(SettlementInvariantViolation::new(
+                    SettlementInvariant::ReservationAmounts,
+                    Some(id),
+                    format!("{state:?} entry does not return the reserved amount"),
+                    [
+                        ("reserved_survival_micro", reserved),
+                        ("returned_survival_micro", *amount),
+                    ],
+                ));
+            }
+        }
+
+        if !self
+            .attribution_records(&reservation.cost_attribution_id)
+            .iter()
+            .any(|record| record == id)
+        {
+            violations.push(SettlementInvariantViolation::new(
+                SettlementInvariant::AttributionIndexed,
+                Some(id),
+                format!(
+                    "reservation missing from attribution index under '{}'",
+                    reservation.cost_attribution_id
+                ),
+                [(
+                    "indexed_record_count",
+                    self.attribution_records(&reservation.cost_attribution_id)
+                        .len() as i64,
+                )],
+            ));
+        }
+    }
+
+    fn check_entry_sequence(&self, violations: &mut Vec<SettlementInvariantViolation>) {
+        let next_sequence = self.next_sequence();
+        let mut previous = 0;
+        for entry in &self.entries {
+            if entry.seq_no <= previous {
+                violations.push(SettlementInvariantViolation::new(
+                    SettlementInvariant::EntrySequence,
+                    None,
+                    format!("entry '{}' does not advance the sequence", entry.entry_id),
+                    [
+                        ("previous_seq_no", previous as i64),
+                        ("seq_no", entry.seq_no as i64),
+                    ],
+                ));
+            }
+            previous = entry.seq_no;
+        }
+        if let Some(last) = self.entries.last()
+            && last.seq_no != next_sequence
+        {
+            violations.push(SettlementInvariantViolation::new(
+                SettlementInvariant::EntrySequence,
+                None,
+                "last entry does not end at the ledger's next sequence",
+                [
+                    ("last_seq_no", last.seq_no as i64),
+                    ("next_sequence", next_sequence as i64),
+                ],
+            ));
+        }
+    }
+}
+
+/// Logs each violation as a `settlement_invariant_violated` warning.
+pub fn emit_settlement_invariant_violations(violations: &[SettlementInvariantViolation]) {
+    for violation in violations {
+        tracing::warn!(
+            target: "ledger",
+            rule = ?violation.rule,
+            reserve_entry_id = violation.reserve_entry_id.as_deref().unwrap_or("-"),
+            detail = %violation.detail,
+            figures = %serde_json::json!(violation.figures),
+            "settlement_invariant_violated"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::types::PolicyVersionTuple;
+
+    fn policy_versions() -> PolicyVersionTuple {
+        PolicyVersionTuple {
+            affordance_registry_version: "v2".to_string(),
+            cost_policy_version: "v2".to_string(),
+            admission_ruleset_version: "removed".to_string(),
+        }
+    }
+
+    #[test]
+    fn settled_state_without_settle_entry_is_reported_by_rule()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let mut ledger = SurvivalLedger::new(1_000);
+        let consistent =
+            ledger.reserve(1, 10, 8, "cat:a".into(), "ref:1".into(), policy_versions())?;
+        ledger.refund_reservation(2, &consistent, "ref:1:refund", None, policy_versions())?;
+        let tampered =
+            ledger.reserve(2, 20, 8, "cat:b".into(), "ref:2".into(), policy_versions())?;
+        assert!(ledger.settlement_invariant_violations().is_empty());
+
+        ledger
+            .reservations
+            .get_mut(&tampered)
+            .expect("reservation")
+            .state = ReservationState::Settled;
+
+        let violations = ledger.settlement_invariant_violations();
+        assert_eq!(
+            violations
+                .iter()
+                .map(|violation| violation.rule)
+                .collect::<Vec<_>>(),
+            vec![
+                SettlementInvariant::TerminalStrictness,
+                SettlementInvariant::TerminalReference,
+            ]
+        );
+        assert!(
+            violations
+                .iter()
+                .all(|violation| violation.reserve_entry_id.as_deref() == Some(tampered.as_str()))
+        );
+        assert_eq!(violations[0].figures["terminal_entry_count"], 0);
+        assert_eq!(
+            serde_json::to_value(&violations[0])?["rule"],
+            "terminal_strictness"
+        );
+        Ok(())
+    }
+}
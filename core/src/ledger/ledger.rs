@@ -1,22 +1,36 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, io::Write};
 
 use crate::{
     continuity::{
-        error::{ContinuityError, arithmetic_error, invalid_request, ledger_conflict},
-        types::ExternalDebitObservation,
+        ContinuityEngine,
+        error::{
+            ContinuityError, arithmetic_error, internal_error, invalid_request,
+            invariant_violation, ledger_conflict,
+        },
+        types::{ContinuityRecordBody, ContinuityRecordKey, ExternalDebitObservation},
     },
     ledger::types::{
-        CycleId, LedgerEntry, LedgerEntryId, LedgerEntryKind, PolicyVersionTuple,
+        CycleId, LedgerEntry, LedgerEntryId, LedgerEntryKind, LedgerSnapshot, PolicyVersionTuple,
         ReservationRecord, ReservationState,
     },
 };
 
+const LEDGER_NAMESPACE: &str = "continuity.ledger";
+const LEDGER_RECORD_ID: &str = "survival";
+const LEDGER_SCHEMA_VERSION: &str = "ledger-snapshot.v1";
+const LEDGER_CONTENT_TYPE: &str = "application/json";
+
+fn ledger_record_key() -> ContinuityRecordKey {
+    ContinuityRecordKey::new(LEDGER_NAMESPACE, LEDGER_RECORD_ID)
+}
+
 #[derive(Debug, Clone)]
 pub struct SurvivalLedger {
     balance_survival_micro: i64,
     next_sequence: u64,
     pub entries: Vec<LedgerEntry>,
     pub reservations: BTreeMap<String, ReservationRecord>,
+    attribution_index: BTreeMap<String, Vec<String>>,
 }
 
 impl SurvivalLedger {
@@ -26,13 +40,170 @@ impl SurvivalLedger {
             next_sequence: 0,
             entries: Vec::new(),
             reservations: BTreeMap::new(),
+            attribution_index: BTreeMap::new(),
+        }
+    }
+
+    pub fn snapshot(&self) -> LedgerSnapshot {
+        LedgerSnapshot {
+            balance_survival_micro: self.balance_survival_micro,
+            next_sequence: self.next_sequence,
+            entries: self.entries.clone(),
+            reservations: self.reservations.clone(),
+            attribution_index: Some(self.attribution_index.clone()),
+        }
+    }
+
+    /// Restores a ledger from `snapshot`, keeping the attribution index consistent with the
+    /// restored reservations: records without a matching reservation are dropped and
+    /// reservations missing from the index are added back.
+    pub fn restore(snapshot: LedgerSnapshot) -> Result<Self, ContinuityError> {
+        if let Some((key, reservation)) = snapshot
+            .reservations
+            .iter()
+            .find(|(key, reservation)| **key != reservation.reserve_entry_id)
+        {
+            return Err(invariant_violation(format!(
+                "reservation keyed '{}' carries reserve_entry_id '{}'",
+                key, reservation.reserve_entry_id
+            )));
+        }
+
+        let mut attribution_index: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (cost_attribution_id, reserve_entry_ids) in
+            snapshot.attribution_index.unwrap_or_default()
+        {
+            for reserve_entry_id in reserve_entry_ids {
+                let indexed =
+                    snapshot
+                        .reservations
+                        .get(&reserve_entry_id)
+                        .is_some_and(|reservation| {
+                            reservation.cost_attribution_id == cost_attribution_id
+                        });
+                if !indexed {
+                    continue;
+                }
+                let records = attribution_index
+                    .entry(cost_attribution_id.clone())
+                    .or_default();
+                if !records.contains(&reserve_entry_id) {
+                    records.push(reserve_entry_id);
+                }
+            }
         }
+        for reservation in snapshot.reservations.values() {
+            let records = attribution_index
+                .entry(reservation.cost_attribution_id.clone())
+                .or_default();
+            if !records.contains(&reservation.reserve_entry_id) {
+                records.push(reservation.reserve_entry_id.clone());
+            }
+        }
+
+        Ok(Self {
+            balance_survival_micro: snapshot.balance_survival_micro,
+            next_sequence: snapshot.next_sequence,
+            entries: snapshot.entries,
+            reservations: snapshot.reservations,
+            attribution_index,
+        })
+    }
+
+    /// Restores the ledger continuity persisted, or starts a fresh one with
+    /// `initial_survival_micro` when none was saved.
+    pub fn load(
+        continuity: &ContinuityEngine,
+        initial_survival_micro: i64,
+    ) -> Result<Self, ContinuityError> {
+        let Some(record) = continuity.get_record(&ledger_record_key())? else {
+            return Ok(Self::new(initial_survival_micro));
+        };
+        if record.schema_version != LEDGER_SCHEMA_VERSION {
+            return Err(internal_error(format!(
+                "unsupported ledger snapshot schema_version '{}'",
+                record.schema_version
+            )));
+        }
+        if record.body.content_type != LEDGER_CONTENT_TYPE {
+            return Err(internal_error(format!(
+                "unsupported ledger snapshot content_type '{}'",
+                record.body.content_type
+            )));
+        }
+        let snapshot: LedgerSnapshot = serde_json::from_slice(&record.body.bytes)
+            .map_err(|err| internal_error(format!("failed to decode ledger snapshot: {err}")))?;
+        Self::restore(snapshot)
+    }
+
+    /// Writes the ledger snapshot into continuity, which persists it with its other records.
+    pub fn save(&self, continuity: &mut ContinuityEngine) -> Result<(), ContinuityError> {
+        let bytes = serde_json::to_vec(&self.snapshot())
+            .map_err(|err| internal_error(format!("failed to encode ledger snapshot: {err}")))?;
+        continuity.put_record(
+            ledger_record_key(),
+            None,
+            LEDGER_SCHEMA_VERSION,
+            ContinuityRecordBody::new(LEDGER_CONTENT_TYPE, bytes),
+        )?;
+        Ok(())
+    }
+
+    pub fn attribution_records(&self, cost_attribution_id: &str) -> &[String] {
+        self.attribution_index
+            .get(cost_attribution_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Up to `limit` entries recorded after `after_seq_no`, in ledger order.
+    ///
+    /// Pass the last exported `seq_no` back as `after_seq_no` to page through long histories.
+    pub fn export_entries(&self, after_seq_no: u64, limit: usize) -> &[LedgerEntry] {
+        let start = self
+            .entries
+            .partition_point(|entry| entry.seq_no <= after_seq_no);
+        let end = start.saturating_add(limit).min(self.entries.len());
+        &self.entries[start..end]
+    }
+
+    /// Writes one page of [`Self::export_entries`] as NDJSON and returns the last written
+    /// `seq_no`, or `None` when the page is empty.
+    pub fn write_entries_ndjson(
+        &self,
+        writer: &mut impl Write,
+        after_seq_no: u64,
+        limit: usize,
+    ) -> std::io::Result<Option<u64>> {
+        let entries = self.export_entries(after_seq_no, limit);
+        for entry in entries {
+            serde_json::to_writer(&mut *writer, entry)?;
+            writer.write_all(b"\n")?;
+        }
+        Ok(entries.last().map(|entry| entry.seq_no))
+    }
+
+    /// Writes one NDJSON page of the ledger persisted in `continuity`, as `beluna ledger export`
+    /// does; an empty continuity exports nothing.
+    pub fn export_persisted_entries_ndjson(
+        continuity: &ContinuityEngine,
+        writer: &mut impl Write,
+        after_seq_no: u64,
+        limit: usize,
+    ) -> Result<Option<u64>, ContinuityError> {
+        Self::load(continuity, 0)?
+            .write_entries_ndjson(writer, after_seq_no, limit)
+            .map_err(|err| internal_error(format!("failed to write ledger entries: {err}")))
     }
 
     pub fn balance_survival_micro(&self) -> i64 {
         self.balance_survival_micro
     }
 
+    pub fn next_sequence(&self) -> u64 {
+        self.next_sequence
+    }
+
     pub fn available_survival_micro(&self) -> i64 {
         self.balance_survival_micro
     }
@@ -80,6 +251,10 @@ impl SurvivalLedger {
 
         self.reservations
             .insert(reserve_entry_id.clone(), reservation);
+        self.attribution_index
+            .entry(cost_attribution_id.clone())
+            .or_default()
+            .push(reserve_entry_id.clone());
 
         self.append_entry(
             cycle_id,
@@ -264,7 +439,7 @@ impl SurvivalLedger {
         cycle_id: CycleId,
         reference_prefix: &str,
         policy_versions: PolicyVersionTuple,
-    ) -> Result<Vec<String>, ContinuityError> {
+    ) -> Result<Vec<ReservationRecord>, ContinuityError> {
         let to_expire: Vec<String> = self
             .reservations
             .iter()
@@ -284,7 +459,10 @@ impl SurvivalLedger {
             )?;
         }
 
-        Ok(to_expire)
+        Ok(to_expire
+            .iter()
+            .filter_map(|reserve_entry_id| self.reservations.get(reserve_entry_id).cloned())
+            .collect())
     }
 
     fn expire_reservation(
@@ -413,3 +591,178 @@ impl Default for SurvivalLedger {
         Self::new(1_000_000)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy_versions() -> PolicyVersionTuple {
+        PolicyVersionTuple {
+            affordance_registry_version: "v2".to_string(),
+            cost_policy_version: "v2".to_string(),
+            admission_ruleset_version: "removed".to_string(),
+        }
+    }
+
+    #[test]
+    fn ledger_saved_to_continuity_is_loaded_after_restart() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let path = std::env::temp_dir()
+            .join(format!("beluna-ledger-{}", uuid::Uuid::new_v4()))
+            .join("continuity.json");
+        let mut ledger = SurvivalLedger::new(1_000);
+        let reserved =
+            ledger.reserve(1, 10, 8, "cat:a".into(), "ref:1".into(), policy_versions())?;
+
+        {
+            let mut continuity = ContinuityEngine::with_defaults_at(path.clone())?;
+            assert_eq!(
+                SurvivalLedger::load(&continuity, 500)?.snapshot(),
+                SurvivalLedger::new(500).snapshot()
+            );
+            ledger.save(&mut continuity)?;
+        }
+        let continuity = ContinuityEngine::with_defaults_at(path.clone())?;
+        let restored = SurvivalLedger::load(&continuity, 500)?;
+
+        assert_eq!(restored.snapshot(), ledger.snapshot());
+        assert_eq!(restored.attribution_records("cat:a"), [reserved]);
+
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::remove_dir_all(parent);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn persisted_ledger_exports_as_ndjson_from_a_fresh_continuity()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let path = std::env::temp_dir()
+            .join(format!("beluna-ledger-export-{}", uuid::Uuid::new_v4()))
+            .join("state.json");
+        let mut ledger = SurvivalLedger::new(1_000);
+        let reserved =
+            ledger.reserve(1, 40, 8, "cat:a".into(), "ref:1".into(), policy_versions())?;
+        ledger.settle_reservation(2, &reserved, "ref:1:settle", 40, None, policy_versions())?;
+        {
+            let mut continuity = ContinuityEngine::with_defaults_at(path.clone())?;
+            let mut exported = Vec::new();
+            assert_eq!(
+                SurvivalLedger::export_persisted_entries_ndjson(&continuity, &mut exported, 0, 10)?,
+                None
+            );
+            assert!(exported.is_empty());
+            ledger.save(&mut continuity)?;
+        }
+
+        let continuity = ContinuityEngine::with_defaults_at(path.clone())?;
+        let mut exported = Vec::new();
+        let cursor =
+            SurvivalLedger::export_persisted_entries_ndjson(&continuity, &mut exported, 0, 10)?;
+
+        let lines = String::from_utf8(exported)?
+            .lines()
+            .map(serde_json::from_str::<LedgerEntry>)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(lines, ledger.entries);
+        assert_eq!(cursor, ledger.entries.last().map(|entry| entry.seq_no));
+
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::remove_dir_all(parent);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn snapshot_round_trip_keeps_attribution_records() -> Result<(), Box<dyn std::error::Error>> {
+        let mut ledger = SurvivalLedger::new(1_000);
+        let first = ledger.reserve(1, 10, 8, "cat:a".into(), "ref:1".into(), policy_versions())?;
+        let second = ledger.reserve(1, 20, 8, "cat:a".into(), "ref:2".into(), policy_versions())?;
+        let third = ledger.reserve(2, 30, 8, "cat:b".into(), "ref:3".into(), policy_versions())?;
+        ledger.settle_reservation(2, &first, "ref:1:settle", 10, None, policy_versions())?;
+
+        let encoded = serde_json::to_vec(&ledger.snapshot())?;
+        let restored = SurvivalLedger::restore(serde_json::from_slice(&encoded)?)?;
+
+        assert_eq!(restored.snapshot(), ledger.snapshot());
+        assert_eq!(restored.attribution_records("cat:a"), [first, second]);
+        assert_eq!(restored.attribution_records("cat:b"), [third]);
+        Ok(())
+    }
+
+    #[test]
+    fn exported_entries_follow_reserve_settle_refund_order()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let mut ledger = SurvivalLedger::new(1_000);
+        let settled =
+            ledger.reserve(1, 100, 8, "cat:a".into(), "ref:1".into(), policy_versions())?;
+        let refunded =
+            ledger.reserve(1, 50, 8, "cat:b".into(), "ref:2".into(), policy_versions())?;
+        ledger.settle_reservation(2, &settled, "ref:1:settle", 60, None, policy_versions())?;
+        ledger.refund_reservation(2, &refunded, "ref:2:refund", None, policy_versions())?;
+
+        let kinds = ledger
+            .export_entries(0, usize::MAX)
+            .iter()
+            .map(|entry| entry.kind.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                LedgerEntryKind::Reserve {
+                    reserve_entry_id: settled.clone()
+                },
+                LedgerEntryKind::Reserve {
+                    reserve_entry_id: refunded.clone()
+                },
+                LedgerEntryKind::Adjustment {
+                    reserve_entry_id: settled.clone()
+                },
+                LedgerEntryKind::Settle {
+                    reserve_entry_id: settled
+                },
+                LedgerEntryKind::Refund {
+                    reserve_entry_id: refunded
+                },
+            ]
+        );
+
+        let mut first_page = Vec::new();
+        let cursor = ledger.write_entries_ndjson(&mut first_page, 0, 3)?;
+        assert_eq!(cursor, Some(3));
+        let mut second_page = Vec::new();
+        assert_eq!(
+            ledger.write_entries_ndjson(&mut second_page, 3, 3)?,
+            Some(5)
+        );
+        let exported = [first_page, second_page]
+            .concat()
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(serde_json::from_slice::<LedgerEntry>)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(exported, ledger.entries);
+        Ok(())
+    }
+
+    #[test]
+    fn restore_drops_dangling_attribution_records() -> Result<(), Box<dyn std::error::Error>> {
+        let mut ledger = SurvivalLedger::new(1_000);
+        let reserve_entry_id =
+            ledger.reserve(1, 10, 8, "cat:a".into(), "ref:1".into(), policy_versions())?;
+        let mut snapshot = ledger.snapshot();
+        snapshot.attribution_index = Some(BTreeMap::from([
+            (
+                "cat:a".to_string(),
+                vec![reserve_entry_id.clone(), "resv:9:9".to_string()],
+            ),
+            ("cat:gone".to_string(), vec!["resv:9:10".to_string()]),
+        ]));
+
+        let restored = SurvivalLedger::restore(snapshot)?;
+
+        assert_eq!(restored.attribution_records("cat:a"), [reserve_entry_id]);
+        assert!(restored.attribution_records("cat:gone").is_empty());
+        Ok(())
+    }
+}
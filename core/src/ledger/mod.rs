@@ -1,12 +1,14 @@
 #![allow(dead_code)]
 
+pub mod invariants;
 pub mod ledger;
 pub mod stage;
 pub mod types;
 
+pub use invariants::{SettlementInvariant, SettlementInvariantViolation};
 pub use ledger::SurvivalLedger;
 pub use stage::{DispatchContext, LedgerDispatchTicket, LedgerStage};
 pub use types::{
-    CycleId, LedgerEntry, LedgerEntryId, LedgerEntryKind, PolicyVersionTuple, ReservationRecord,
-    ReservationState,
+    CycleId, LedgerEntry, LedgerEntryId, LedgerEntryKind, LedgerSnapshot, PolicyVersionTuple,
+    ReservationRecord, ReservationState,
 };
@@ -1,10 +1,15 @@
 use sha2::{Digest, Sha256};
 
 use crate::{
-    continuity::error::{ContinuityError, invariant_violation, ledger_conflict},
+    continuity::error::{
+        ContinuityError, ContinuityErrorKind, invariant_violation, ledger_conflict,
+    },
     ledger::{
         SurvivalLedger,
-        types::{PolicyVersionTuple, ReservationState},
+        invariants::{
+            SettlementInvariant, SettlementInvariantViolation, emit_settlement_invariant_violations,
+        },
+        types::{PolicyVersionTuple, ReservationRecord, ReservationState},
     },
     spine::types::SpineEvent,
     types::{Act, DispatchDecision, PhysicalLedgerSnapshot},
@@ -60,7 +65,6 @@ impl LedgerStage {
         act: &Act,
         ctx: &DispatchContext,
     ) -> Result<(DispatchDecision, Option<LedgerDispatchTicket>), ContinuityError> {
-        let _ = act;
         let reserve_survival_micro = 0;
         if self.ledger.available_survival_micro() < reserve_survival_micro {
             return Ok((DispatchDecision::Break, None));
@@ -75,6 +79,8 @@ impl LedgerStage {
             format!("reserve:{}", act.act_instance_id),
             self.policy_versions.clone(),
         )?;
+        self.ledger
+            .attach_action_id(&reserve_entry_id, act.act_instance_id.clone())?;
 
         Ok((
             DispatchDecision::Continue,
@@ -91,6 +97,43 @@ impl LedgerStage {
         ticket: &LedgerDispatchTicket,
         event: &SpineEvent,
         ctx: &DispatchContext,
+    ) -> Result<(), ContinuityError> {
+        let result = self.settle_ticket(ticket, event, ctx);
+        if let Err(err) = &result
+            && err.kind == ContinuityErrorKind::InvariantViolation
+        {
+            emit_settlement_invariant_violations(&self.settlement_failure_report(
+                ticket,
+                &err.message,
+                ctx,
+            ));
+        }
+        result
+    }
+
+    /// Every settlement rule the failed settlement and the ledger now break, each with its
+    /// ledger figures; the ticket/event mismatch that rejected the settlement comes first.
+    fn settlement_failure_report(
+        &self,
+        ticket: &LedgerDispatchTicket,
+        failure: &str,
+        ctx: &DispatchContext,
+    ) -> Vec<SettlementInvariantViolation> {
+        let mut violations = vec![SettlementInvariantViolation::new(
+            SettlementInvariant::TicketMatchesEvent,
+            Some(&ticket.reserve_entry_id),
+            failure.to_string(),
+            [("cycle_id", ctx.cycle_id as i64)],
+        )];
+        violations.extend(self.ledger.settlement_invariant_violations());
+        violations
+    }
+
+    fn settle_ticket(
+        &mut self,
+        ticket: &LedgerDispatchTicket,
+        event: &SpineEvent,
+        ctx: &DispatchContext,
     ) -> Result<(), ContinuityError> {
         if event.reserve_entry_id() != ticket.reserve_entry_id {
             return Err(invariant_violation(format!(
@@ -166,7 +209,7 @@ impl LedgerStage {
     pub fn expire_open_reservations(
         &mut self,
         cycle_id: u64,
-    ) -> Result<Vec<String>, ContinuityError> {
+    ) -> Result<Vec<ReservationRecord>, ContinuityError> {
         self.ledger
             .expire_open_reservations(cycle_id, "expiry", self.policy_versions.clone())
     }
@@ -199,3 +242,53 @@ fn derive_cost_attribution_id(cycle_id: u64, act_instance_id: &str) -> String {
     let hex = format!("{:x}", digest);
     format!("cat:{}", &hex[..24])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn act(act_instance_id: &str) -> Act {
+        Act {
+            act_instance_id: act_instance_id.to_string(),
+            endpoint_id: "tool.1".to_string(),
+            neural_signal_descriptor_id: "say".to_string(),
+            might_emit_sense_ids: Vec::new(),
+            payload: serde_json::json!("hello"),
+            goal_id: None,
+        }
+    }
+
+    fn ctx(cycle_id: u64) -> DispatchContext {
+        DispatchContext {
+            cycle_id,
+            act_seq_no: 1,
+        }
+    }
+
+    #[test]
+    fn mismatched_settlement_is_reported_by_rule() -> Result<(), ContinuityError> {
+        let mut stage = LedgerStage::new(100);
+        let (_, ticket) = stage.pre_dispatch(&act("act-1"), &ctx(1))?;
+        let ticket = ticket.expect("reservation ticket");
+        let event = SpineEvent::ActApplied {
+            cycle_id: 1,
+            seq_no: 1,
+            act_instance_id: "act-1".to_string(),
+            reserve_entry_id: "someone-else".to_string(),
+            cost_attribution_id: ticket.cost_attribution_id.clone(),
+            actual_cost_micro: 10,
+            reference_id: "ref-1".to_string(),
+        };
+
+        let err = stage
+            .settle_from_spine(&ticket, &event, &ctx(1))
+            .expect_err("mismatched settlement should fail");
+        let report = stage.settlement_failure_report(&ticket, &err.message, &ctx(1));
+
+        assert_eq!(err.kind, ContinuityErrorKind::InvariantViolation);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].rule, SettlementInvariant::TicketMatchesEvent);
+        assert_eq!(report[0].figures.get("cycle_id"), Some(&1));
+        Ok(())
+    }
+}
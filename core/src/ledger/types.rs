@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 pub type CycleId = u64;
@@ -46,6 +48,18 @@ pub enum LedgerEntryKind {
     ExternalDebit { reference_id: String },
 }
 
+/// Serializable ledger state used to carry the ledger across restarts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedgerSnapshot {
+    pub balance_survival_micro: i64,
+    pub next_sequence: u64,
+    pub entries: Vec<LedgerEntry>,
+    pub reservations: BTreeMap<String, ReservationRecord>,
+    /// Cost attribution id to reserve entry ids; rebuilt from reservations when absent.
+    #[serde(default)]
+    pub attribution_index: Option<BTreeMap<String, Vec<String>>>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LedgerEntry {
     pub entry_id: LedgerEntryId,
@@ -8,6 +8,11 @@ pub mod cortex;
 pub mod ledger;
 pub mod logging;
 pub mod observability;
+pub mod shutdown;
 pub mod spine;
 pub mod stem;
 pub mod types;
+
+#[cfg(test)]
+#[path = "../tests/ai_gateway/kit/local_http.rs"]
+pub(crate) mod local_http;
@@ -7,15 +7,16 @@ use std::{
 
 use anyhow::{Context, Result, anyhow};
 use time::{OffsetDateTime, UtcOffset};
+use tracing::Subscriber;
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_error::ErrorLayer;
 use tracing_subscriber::{
-    EnvFilter, Layer, Registry, filter::LevelFilter, fmt, layer::SubscriberExt,
-    util::SubscriberInitExt,
+    EnvFilter, Layer, Registry, filter::LevelFilter, fmt, fmt::MakeWriter, layer::SubscriberExt,
+    registry::LookupSpan, util::SubscriberInitExt,
 };
 use uuid::Uuid;
 
-use crate::config::LoggingConfig;
+use crate::config::{LoggingConfig, StderrLogFormat};
 
 const LOG_FILE_PREFIX: &str = "core.log";
 const LOG_FILE_DATE_SEPARATOR: char = '-';
@@ -64,9 +65,7 @@ pub fn init_tracing(
         .with_filter(file_filter);
 
     let stderr_layer = logging_config.stderr_warn_enabled.then(|| {
-        fmt::layer()
-            .with_writer(std::io::stderr)
-            .with_target(true)
+        build_stderr_layer(logging_config.stderr_format, std::io::stderr)
             .with_filter(LevelFilter::WARN)
     });
 
@@ -89,6 +88,7 @@ pub fn init_tracing(
         filter = %logging_config.filter,
         retention_days = logging_config.retention_days,
         stderr_warn_enabled = logging_config.stderr_warn_enabled,
+        stderr_format = ?logging_config.stderr_format,
         "logging_initialized"
     );
     for warning in retention_warnings {
@@ -101,6 +101,25 @@ pub fn init_tracing(
     })
 }
 
+fn build_stderr_layer<S, W>(format: StderrLogFormat, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    match format {
+        StderrLogFormat::Pretty => fmt::layer().with_writer(writer).with_target(true).boxed(),
+        StderrLogFormat::Json => fmt::layer()
+            .json()
+            .with_timer(fmt::time::UtcTime::rfc_3339())
+            .with_target(true)
+            .with_current_span(true)
+            .with_span_list(true)
+            .with_ansi(false)
+            .with_writer(writer)
+            .boxed(),
+    }
+}
+
 fn compose_otlp_layers(
     otlp_trace_layer: Option<Box<dyn Layer<Registry> + Send + Sync>>,
     otlp_log_layer: Option<Box<dyn Layer<Registry> + Send + Sync>>,
@@ -297,11 +316,65 @@ fn purge_old_log_files_at(
 mod tests {
     use std::sync::{Arc, Mutex};
 
-    use tracing::Subscriber;
-    use tracing_subscriber::{layer::Context, layer::SubscriberExt};
+    use tracing_subscriber::layer::Context;
 
     use super::*;
 
+    #[test]
+    fn json_stderr_layer_emits_parseable_lines_with_span_fields() {
+        let buffer = SharedBuffer::default();
+        let subscriber =
+            Registry::default().with(build_stderr_layer(StderrLogFormat::Json, buffer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("cortex_cycle", cycle_id = 7_u64, route = "primary");
+            let _entered = span.enter();
+            tracing::warn!(target: "beluna::test", detail = "quoted \"value\"", "sample_warning");
+        });
+
+        let bytes = buffer
+            .0
+            .lock()
+            .expect("buffer lock should not poison")
+            .clone();
+        let output = String::from_utf8(bytes).expect("output should be utf-8");
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 1);
+        let line: serde_json::Value =
+            serde_json::from_str(lines[0]).expect("json line should parse");
+        assert_eq!(line["target"], "beluna::test");
+        assert_eq!(line["fields"]["message"], "sample_warning");
+        assert_eq!(line["fields"]["detail"], "quoted \"value\"");
+        assert_eq!(line["span"]["name"], "cortex_cycle");
+        assert_eq!(line["span"]["cycle_id"], 7);
+        assert_eq!(line["spans"][0]["route"], "primary");
+    }
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0
+                .lock()
+                .expect("buffer lock should not poison")
+                .extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'writer> MakeWriter<'writer> for SharedBuffer {
+        type Writer = SharedBuffer;
+
+        fn make_writer(&'writer self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
     #[test]
     fn composed_otlp_layer_obeys_logging_filter() {
         let captured = Arc::new(Mutex::new(Vec::new()));
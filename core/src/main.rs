@@ -12,23 +12,29 @@ use tracing::Instrument;
 
 use beluna::{
     ai_gateway::{chat::Chat, credentials::EnvCredentialProvider},
-    body::start_inline_body_endpoints,
-    cli::{CliCommand, command_from_args},
+    body::{
+        start_cognition_inline_endpoint, start_gateway_inline_endpoint, start_inline_body_endpoints,
+    },
+    cli::{CliCommand, SenseReplayArgs, command_from_args},
     config::{Config, TickMissedBehavior, generate_schema_json_pretty, write_schema_to_path},
-    continuity::ContinuityEngine,
+    continuity::{ContinuityEngine, flush_on_blocking_pool, spawn_periodic_flush},
     cortex::{
         AfferentRuleControlPort, Cortex, CortexAfferentAdmission, CortexDeps, CortexRuntime,
-        PhysicalStateReadPort,
+        PhysicalStateReadPort, SelfSleepRequest,
     },
+    ledger::SurvivalLedger,
     logging::{init_tracing, new_run_id},
     observability::{otel::OpenTelemetryRuntime, owner_log, runtime as observability_runtime},
+    shutdown::{ShutdownProgress, drain_within_deadline},
     spine::{Spine, shutdown_global_spine},
     stem::{
-        AfferentControlHandle, ContinuityEfferentMiddleware, SenseAfferentPathway,
-        SpineEfferentMiddleware, StemControlPort, StemDeps, StemPhysicalStateStore,
-        StemTickRuntime, new_efferent_pathway, spawn_efferent_runtime,
+        ActConfirmationGate, ActResultInjection, AfferentControlHandle, AfferentMiddleware,
+        ConfirmationEfferentMiddleware, ContinuityEfferentMiddleware, EfferentMiddleware,
+        SenseAfferentPathway, SenseQueueWatermarks, SpineEfferentMiddleware, StemControlPort,
+        StemDeps, StemPhysicalStateStore, StemTickRuntime, load_sense_recording,
+        new_efferent_pathway, replay_senses, spawn_efferent_runtime,
     },
-    types::PhysicalState,
+    types::{PhysicalState, ProprioceptionPatch},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -40,6 +46,22 @@ enum AppState {
     Closed,
 }
 
+/// Why the runtime started shutting down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TerminalReason {
+    Signal(&'static str),
+    SelfRequestedSleep,
+}
+
+impl TerminalReason {
+    fn as_label(self) -> &'static str {
+        match self {
+            Self::Signal(signal_name) => signal_name,
+            Self::SelfRequestedSleep => "SelfRequestedSleep",
+        }
+    }
+}
+
 #[derive(Clone)]
 struct AppLifecycle {
     state: Arc<RwLock<AppState>>,
@@ -78,6 +100,27 @@ impl PhysicalStateReadPort for MainPhysicalStateReader {
     }
 }
 
+fn spawn_sense_queue_pressure_proprioception(
+    watermarks: &SenseQueueWatermarks,
+    stem_control: Arc<dyn StemControlPort>,
+) {
+    let mut pressure = watermarks.subscribe_pressure();
+    tokio::spawn(async move {
+        while pressure.changed().await.is_ok() {
+            let level = if *pressure.borrow_and_update() {
+                "high"
+            } else {
+                "normal"
+            };
+            let mut entries = BTreeMap::new();
+            entries.insert("stem.sense_queue.pressure".to_string(), level.to_string());
+            stem_control
+                .apply_proprioception_patch(ProprioceptionPatch { entries })
+                .await;
+        }
+    });
+}
+
 fn collect_main_startup_proprioception() -> BTreeMap<String, String> {
     let mut entries = BTreeMap::new();
     entries.insert("main.os".to_string(), collect_os_summary());
@@ -134,12 +177,27 @@ fn collect_network_summary() -> String {
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let config_path = match command_from_args()? {
-        CliCommand::Run { config_path } => config_path,
+    let (config_path, sense_replay) = match command_from_args()? {
+        CliCommand::Run {
+            config_path,
+            sense_replay,
+        } => (config_path, sense_replay),
         CliCommand::ConfigSchema { output } => {
             handle_schema_command(output)?;
             return Ok(());
         }
+        CliCommand::ConfigShow { config_path } => {
+            handle_show_command(&config_path)?;
+            return Ok(());
+        }
+        CliCommand::LedgerExport {
+            config_path,
+            after_seq_no,
+            limit,
+        } => {
+            handle_ledger_export_command(&config_path, after_seq_no, limit)?;
+            return Ok(());
+        }
     };
     let config = Config::load(&config_path)
         .with_context(|| format!("failed to load config from {}", config_path.display()))?;
@@ -203,19 +261,39 @@ async fn main() -> Result<()> {
         tracing::warn!(target: "core", "unsupported_tick_missed_behavior_fallback_to_skip");
     }
 
-    let (cortex_afferent_admission, afferent_consumer) = CortexAfferentAdmission::new(
+    let sense_queue_watermarks = config
+        .r#loop
+        .sense_queue_watermarks
+        .map(|watermarks| Arc::new(SenseQueueWatermarks::new(watermarks)));
+    let (cortex_afferent_admission, afferent_consumer) = CortexAfferentAdmission::with_watermarks(
         config.r#loop.sense_queue_capacity,
         config.r#loop.max_deferring_nums,
+        sense_queue_watermarks.clone(),
     );
     let cortex_afferent_admission = Arc::new(cortex_afferent_admission);
-    let (afferent_ingress, afferent_control) = SenseAfferentPathway::new_handles(
-        config.r#loop.sense_queue_capacity,
-        vec![cortex_afferent_admission.clone()],
-    );
+    let act_confirmation_gate = config
+        .r#loop
+        .act_confirmation
+        .clone()
+        .map(|confirmation| Arc::new(ActConfirmationGate::new(confirmation)));
+    let mut afferent_middleware: Vec<Arc<dyn AfferentMiddleware>> = Vec::new();
+    if config.r#loop.act_result_injection {
+        tracing::warn!(target: "core", "act_result_injection_enabled");
+        afferent_middleware.push(Arc::new(ActResultInjection));
+    }
+    if let Some(gate) = &act_confirmation_gate {
+        afferent_middleware.push(gate.clone());
+    }
+    afferent_middleware.push(cortex_afferent_admission.clone());
+    let (afferent_ingress, afferent_control) =
+        SenseAfferentPathway::new_handles(config.r#loop.sense_queue_capacity, afferent_middleware);
     let stem_state = Arc::new(StemPhysicalStateStore::new(
         collect_main_startup_proprioception(),
     ));
     let stem_control: Arc<dyn StemControlPort> = stem_state.clone();
+    if let Some(watermarks) = &sense_queue_watermarks {
+        spawn_sense_queue_pressure_proprioception(watermarks, stem_control.clone());
+    }
 
     let chat = Arc::new(
         Chat::new(&config.ai_gateway, Arc::new(EnvCredentialProvider))
@@ -231,12 +309,17 @@ async fn main() -> Result<()> {
         .inline_adapter()
         .context("inline body endpoints require spine.adapters entry with type=inline")?;
     start_inline_body_endpoints(
-        inline_adapter,
+        Arc::clone(&inline_adapter),
         config.body.std_shell.enabled,
         config.body.std_shell.limits.clone(),
         config.body.std_web.enabled,
         config.body.std_web.limits.clone(),
+        config.body.std_fs.enabled,
+        config.body.std_fs.limits.clone(),
     )?;
+    if config.body.gateway_health.enabled {
+        start_gateway_inline_endpoint(Arc::clone(&inline_adapter), Arc::clone(&chat)).await?;
+    }
 
     let continuity = Arc::new(Mutex::new(
         ContinuityEngine::with_defaults_at(config.continuity.state_path.clone())
@@ -246,7 +329,8 @@ async fn main() -> Result<()> {
     let (efferent_producer, efferent_rx) =
         new_efferent_pathway(Some(config.cortex.outbox_capacity));
 
-    let cortex = Arc::new(Cortex::from_config(
+    let self_sleep = Arc::new(SelfSleepRequest::new());
+    let mut cortex = Cortex::from_config(
         &config.cortex,
         config.r#loop.tick_interval_ms,
         Arc::clone(&chat),
@@ -254,7 +338,14 @@ async fn main() -> Result<()> {
         Some(continuity.clone()),
         Some(afferent_rule_control.clone()),
         Some(efferent_producer.clone()),
-    ));
+    );
+    if config.cortex.self_sleep_enabled {
+        cortex = cortex.with_self_sleep(Arc::clone(&self_sleep));
+    }
+    let cortex = Arc::new(cortex);
+    if config.body.cognition_diff.enabled {
+        start_cognition_inline_endpoint(Arc::clone(&inline_adapter), Arc::clone(&cortex)).await?;
+    }
 
     let (tick_grant_tx, tick_grant_rx) = mpsc::channel(config.cortex.inbox_capacity);
 
@@ -281,22 +372,47 @@ async fn main() -> Result<()> {
             .instrument(tracing::info_span!(target: "core", "stem_tick_task")),
     );
 
+    let mut efferent_middleware: Vec<Arc<dyn EfferentMiddleware>> = vec![Arc::new(
+        ContinuityEfferentMiddleware::new(continuity.clone()),
+    )];
+    if let Some(gate) = act_confirmation_gate {
+        efferent_middleware.push(Arc::new(ConfirmationEfferentMiddleware::new(
+            gate,
+            spine_runtime.clone(),
+        )));
+    }
+    efferent_middleware.push(Arc::new(SpineEfferentMiddleware::new(spine_runtime)));
     let efferent_task = spawn_efferent_runtime(
         efferent_rx,
-        vec![
-            Arc::new(ContinuityEfferentMiddleware::new(continuity.clone())),
-            Arc::new(SpineEfferentMiddleware::new(spine_runtime)),
-        ],
+        efferent_middleware,
+        config
+            .r#loop
+            .act_result_feedback
+            .then(|| afferent_ingress.clone()),
         app_context.shutdown.child_token(),
         Duration::from_millis(config.r#loop.efferent_shutdown_drain_timeout_ms),
     );
 
+    let continuity_flush_task = config.continuity.flush_interval_ms.map(|interval_ms| {
+        spawn_periodic_flush(
+            continuity.clone(),
+            Duration::from_millis(interval_ms),
+            app_context.shutdown.child_token(),
+        )
+    });
+
     let cortex_runtime = CortexRuntime::new(
         CortexDeps {
             tick_grant_rx,
             afferent_consumer,
             physical_state_reader,
             cortex_core: cortex,
+            idle_sense_interval_ticks: config.cortex.idle_sense_interval_ticks,
+            on_demand_cycle_trigger: config.cortex.on_demand_cycle_trigger,
+            sense_lanes: config.cortex.sense_lanes.clone(),
+            endpoint_sense_policies: config.cortex.endpoint_sense_policies.clone(),
+            sense_batch_ordering: config.cortex.sense_batch_ordering,
+            cycle_rate_limit: config.cortex.cycle_rate_limit.clone(),
         },
         app_context.shutdown.child_token(),
     );
@@ -307,40 +423,114 @@ async fn main() -> Result<()> {
 
     app_context.lifecycle.set(AppState::Running).await;
 
+    if let Some(sense_replay) = sense_replay {
+        spawn_sense_replay(
+            sense_replay,
+            afferent_ingress,
+            app_context.shutdown.child_token(),
+        )?;
+    }
+
     let mut sigint =
         signal(SignalKind::interrupt()).context("unable to listen for SIGINT (Ctrl+C)")?;
     let mut sigterm = signal(SignalKind::terminate()).context("unable to listen for SIGTERM")?;
 
-    let signal_name = tokio::select! {
-        _ = sigint.recv() => "SIGINT",
-        _ = sigterm.recv() => "SIGTERM",
+    let terminal_reason = tokio::select! {
+        _ = sigint.recv() => TerminalReason::Signal("SIGINT"),
+        _ = sigterm.recv() => TerminalReason::Signal("SIGTERM"),
+        _ = self_sleep.requested() => TerminalReason::SelfRequestedSleep,
     };
 
-    tracing::info!(
-        target: "core",
-        signal_name = signal_name,
-        "received_signal_starting_shutdown"
-    );
+    match terminal_reason {
+        TerminalReason::Signal(signal_name) => tracing::info!(
+            target: "core",
+            signal_name = signal_name,
+            "received_signal_starting_shutdown"
+        ),
+        TerminalReason::SelfRequestedSleep => tracing::info!(
+            target: "core",
+            reason = ?self_sleep.reason(),
+            "self_requested_sleep_starting_shutdown"
+        ),
+    }
     app_context.lifecycle.set(AppState::Closing).await;
     app_context.afferent_control.close_gate().await;
     app_context.shutdown.cancel();
 
-    stem_task.await.context("stem tick task join failed")?;
-    cortex_task
-        .await
-        .context("cortex runtime task join failed")?;
-    efferent_task
-        .await
-        .context("efferent runtime task join failed")?;
+    let mut pending_components = vec!["stem_task", "cortex_task", "efferent_task"];
+    if continuity_flush_task.is_some() {
+        pending_components.push("continuity_flush_task");
+    }
+    pending_components.extend(["continuity_flush", "spine_adapters"]);
+    let shutdown_progress = ShutdownProgress::new(pending_components);
+    let drain = async {
+        shutdown_progress
+            .step("stem_task", stem_task)
+            .await
+            .context("stem tick task join failed")?;
+        shutdown_progress
+            .step("cortex_task", cortex_task)
+            .await
+            .context("cortex runtime task join failed")?;
+        shutdown_progress
+            .step("efferent_task", efferent_task)
+            .await
+            .context("efferent runtime task join failed")?;
+        if let Some(continuity_flush_task) = continuity_flush_task {
+            shutdown_progress
+                .step("continuity_flush_task", continuity_flush_task)
+                .await
+                .context("continuity flush task join failed")?;
+        }
 
-    app_context.continuity.lock().await.flush()?;
-    shutdown_global_spine(app_context.spine.clone()).await?;
+        shutdown_progress
+            .step(
+                "continuity_flush",
+                flush_on_blocking_pool(&app_context.continuity),
+            )
+            .await?;
+        shutdown_progress
+            .step(
+                "spine_adapters",
+                shutdown_global_spine(app_context.spine.clone()),
+            )
+            .await
+    };
+    let shutdown_deadline = Duration::from_millis(config.r#loop.shutdown_deadline_ms);
+    match drain_within_deadline(shutdown_deadline, &shutdown_progress, drain).await {
+        Ok(result) => result?,
+        Err(exceeded) => {
+            tracing::error!(
+                target: "core",
+                deadline_ms = config.r#loop.shutdown_deadline_ms,
+                pending = ?exceeded.pending,
+                "shutdown_deadline_exceeded_forcing_exit"
+            );
+            eprintln!("ERROR core: {exceeded}");
+            if let Err(err) = observability_runtime.shutdown() {
+                eprintln!("WARN observability: opentelemetry_otlp_shutdown_failed error={err}");
+            }
+            std::process::exit(1);
+        }
+    }
 
     app_context.lifecycle.set(AppState::Closed).await;
 
+    for (backend_id, slo) in chat.backend_slo_snapshots() {
+        tracing::info!(
+            target: "ai_gateway",
+            backend_id = %backend_id,
+            attempts = slo.attempts,
+            success_rate = slo.success_rate,
+            p50_latency_ms = slo.p50_latency_ms,
+            p95_latency_ms = slo.p95_latency_ms,
+            error_kinds = ?slo.error_kinds,
+            "backend_slo_summary"
+        );
+    }
     tracing::info!(
         target: "core",
-        signal_name = signal_name,
+        terminal_reason = terminal_reason.as_label(),
         "core_runtime_stopped"
     );
     if let Err(err) = observability_runtime.shutdown() {
@@ -349,6 +539,71 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+fn spawn_sense_replay(
+    args: SenseReplayArgs,
+    afferent_ingress: SenseAfferentPathway,
+    shutdown: CancellationToken,
+) -> Result<()> {
+    let recording = load_sense_recording(&args.recording_path)?;
+    let recording_path = args.recording_path.display().to_string();
+    tracing::info!(
+        target: "core",
+        recording_path = %recording_path,
+        sense_count = recording.len(),
+        pacing = ?args.pacing,
+        "sense_replay_started"
+    );
+    tokio::spawn(
+        async move {
+            match replay_senses(&afferent_ingress, recording, args.pacing, shutdown).await {
+                Ok(replayed) => tracing::info!(
+                    target: "core",
+                    recording_path = %recording_path,
+                    replayed = replayed,
+                    "sense_replay_finished"
+                ),
+                Err(err) => tracing::warn!(
+                    target: "core",
+                    recording_path = %recording_path,
+                    error = %err,
+                    "sense_replay_failed"
+                ),
+            }
+        }
+        .instrument(tracing::info_span!(target: "core", "sense_replay_task")),
+    );
+    Ok(())
+}
+
+fn handle_show_command(config_path: &std::path::Path) -> Result<()> {
+    let config = Config::load(config_path)
+        .with_context(|| format!("failed to load config from {}", config_path.display()))?;
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&config.redacted_json()?)?
+    );
+    Ok(())
+}
+
+fn handle_ledger_export_command(
+    config_path: &std::path::Path,
+    after_seq_no: u64,
+    limit: usize,
+) -> Result<()> {
+    let config = Config::load(config_path)
+        .with_context(|| format!("failed to load config from {}", config_path.display()))?;
+    let continuity = ContinuityEngine::with_defaults_at(config.continuity.state_path.clone())
+        .context("failed to open continuity state")?;
+    SurvivalLedger::export_persisted_entries_ndjson(
+        &continuity,
+        &mut std::io::stdout().lock(),
+        after_seq_no,
+        limit,
+    )
+    .context("failed to export ledger entries")?;
+    Ok(())
+}
+
 fn handle_schema_command(output: Option<std::path::PathBuf>) -> Result<()> {
     match output {
         Some(path) => {
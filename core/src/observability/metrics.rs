@@ -5,9 +5,16 @@ use opentelemetry::{
     metrics::{Counter, Gauge, Meter},
 };
 
+use crate::{
+    ai_gateway::{resilience::BreakerPhase, slo::BackendSloSnapshot},
+    spine::channel_stats::ChannelStatsSnapshot,
+};
+
 pub const CORTEX_CYCLE_ID_METRIC: &str = "beluna_cortex_cycle_id";
 pub const CORTEX_INPUT_IR_ACT_DESCRIPTOR_CATALOG_COUNT_METRIC: &str =
     "beluna_cortex_input_ir_act_descriptor_catalog_count";
+pub const CORTEX_CYCLES_PER_SECOND_METRIC: &str = "beluna_cortex_cycles_per_second";
+pub const CORTEX_CYCLE_AVERAGE_LATENCY_MS_METRIC: &str = "beluna_cortex_cycle_average_latency_ms";
 pub const CHAT_TASK_LATENCY_MS_METRIC: &str = "beluna_chat_task_latency_ms";
 pub const CHAT_TASK_FAILURES_TOTAL_METRIC: &str = "beluna_chat_task_failures_total";
 pub const CHAT_TASK_RETRIES_TOTAL_METRIC: &str = "beluna_chat_task_retries_total";
@@ -16,11 +23,27 @@ pub const CHAT_THREAD_TOOL_CALLS_TOTAL_METRIC: &str = "beluna_chat_thread_tool_c
 pub const CHAT_THREAD_TOKENS_IN_TOTAL_METRIC: &str = "beluna_chat_thread_tokens_in_total";
 pub const CHAT_THREAD_TOKENS_OUT_TOTAL_METRIC: &str = "beluna_chat_thread_tokens_out_total";
 pub const CHAT_THREAD_FAILURES_TOTAL_METRIC: &str = "beluna_chat_thread_failures_total";
+pub const AI_GATEWAY_BREAKER_OPEN_METRIC: &str = "beluna_ai_gateway_breaker_open";
+pub const AI_GATEWAY_BREAKER_FAILURE_STREAK_METRIC: &str =
+    "beluna_ai_gateway_breaker_failure_streak";
+pub const AI_GATEWAY_BACKEND_SUCCESS_RATE_METRIC: &str = "beluna_ai_gateway_backend_success_rate";
+pub const AI_GATEWAY_BACKEND_LATENCY_P50_MS_METRIC: &str =
+    "beluna_ai_gateway_backend_latency_p50_ms";
+pub const AI_GATEWAY_BACKEND_LATENCY_P95_MS_METRIC: &str =
+    "beluna_ai_gateway_backend_latency_p95_ms";
+pub const AI_GATEWAY_BACKEND_ERRORS_METRIC: &str = "beluna_ai_gateway_backend_errors";
 pub const CHAT_THREAD_LAST_TURN_LATENCY_MS_METRIC: &str = "beluna_chat_thread_last_turn_latency_ms";
+pub const SPINE_CHANNEL_ACTS_SENT_METRIC: &str = "beluna_spine_channel_acts_sent";
+pub const SPINE_CHANNEL_ACKS_RECEIVED_METRIC: &str = "beluna_spine_channel_acks_received";
+pub const SPINE_CHANNEL_RETRIES_METRIC: &str = "beluna_spine_channel_retries";
+pub const SPINE_CHANNEL_QUEUE_DEPTH_METRIC: &str = "beluna_spine_channel_queue_depth";
+pub const STEM_SENSE_QUEUE_DEPTH_METRIC: &str = "beluna_stem_sense_queue_depth";
 
 struct MetricsInstruments {
     cortex_cycle_id: Gauge<f64>,
     cortex_input_ir_act_descriptor_catalog_count: Gauge<f64>,
+    cortex_cycles_per_second: Gauge<f64>,
+    cortex_cycle_average_latency_ms: Gauge<f64>,
     chat_task_latency_ms: Gauge<f64>,
     chat_task_failures_total: Counter<u64>,
     chat_task_retries_total: Counter<u64>,
@@ -30,6 +53,17 @@ struct MetricsInstruments {
     chat_thread_tokens_out_total: Counter<u64>,
     chat_thread_failures_total: Counter<u64>,
     chat_thread_last_turn_latency_ms: Gauge<f64>,
+    ai_gateway_breaker_open: Gauge<f64>,
+    ai_gateway_breaker_failure_streak: Gauge<f64>,
+    ai_gateway_backend_success_rate: Gauge<f64>,
+    ai_gateway_backend_latency_p50_ms: Gauge<f64>,
+    ai_gateway_backend_latency_p95_ms: Gauge<f64>,
+    ai_gateway_backend_errors: Gauge<f64>,
+    spine_channel_acts_sent: Gauge<f64>,
+    spine_channel_acks_received: Gauge<f64>,
+    spine_channel_retries: Gauge<f64>,
+    spine_channel_queue_depth: Gauge<f64>,
+    stem_sense_queue_depth: Gauge<f64>,
 }
 
 static METRICS: OnceLock<MetricsInstruments> = OnceLock::new();
@@ -54,6 +88,16 @@ fn instruments() -> &'static MetricsInstruments {
                 )
                 .with_unit("count")
                 .build(),
+            cortex_cycles_per_second: meter
+                .f64_gauge(CORTEX_CYCLES_PER_SECOND_METRIC)
+                .with_description("Completed cortex cycles per second over the recent window.")
+                .with_unit("1/s")
+                .build(),
+            cortex_cycle_average_latency_ms: meter
+                .f64_gauge(CORTEX_CYCLE_AVERAGE_LATENCY_MS_METRIC)
+                .with_description("Average cortex cycle latency over the recent window.")
+                .with_unit("ms")
+                .build(),
             chat_task_latency_ms: meter
                 .f64_gauge(CHAT_TASK_LATENCY_MS_METRIC)
                 .with_description(
@@ -101,6 +145,65 @@ fn instruments() -> &'static MetricsInstruments {
                 .with_description("Latency of the last observed terminal turn per chat thread.")
                 .with_unit("ms")
                 .build(),
+            ai_gateway_breaker_open: meter
+                .f64_gauge(AI_GATEWAY_BREAKER_OPEN_METRIC)
+                .with_description(
+                    "Circuit breaker state per backend: 0 closed, 1 open, 0.5 half-open.",
+                )
+                .with_unit("state")
+                .build(),
+            ai_gateway_breaker_failure_streak: meter
+                .f64_gauge(AI_GATEWAY_BREAKER_FAILURE_STREAK_METRIC)
+                .with_description("Consecutive breaker-counted failures per backend.")
+                .with_unit("count")
+                .build(),
+            ai_gateway_backend_success_rate: meter
+                .f64_gauge(AI_GATEWAY_BACKEND_SUCCESS_RATE_METRIC)
+                .with_description("Fraction of successful attempts per backend.")
+                .with_unit("ratio")
+                .build(),
+            ai_gateway_backend_latency_p50_ms: meter
+                .f64_gauge(AI_GATEWAY_BACKEND_LATENCY_P50_MS_METRIC)
+                .with_description("Median attempt latency per backend over recent attempts.")
+                .with_unit("ms")
+                .build(),
+            ai_gateway_backend_latency_p95_ms: meter
+                .f64_gauge(AI_GATEWAY_BACKEND_LATENCY_P95_MS_METRIC)
+                .with_description(
+                    "95th percentile attempt latency per backend over recent attempts.",
+                )
+                .with_unit("ms")
+                .build(),
+            ai_gateway_backend_errors: meter
+                .f64_gauge(AI_GATEWAY_BACKEND_ERRORS_METRIC)
+                .with_description("Failed attempts per backend and error kind.")
+                .with_unit("count")
+                .build(),
+            spine_channel_acts_sent: meter
+                .f64_gauge(SPINE_CHANNEL_ACTS_SENT_METRIC)
+                .with_description("Acts written to each adapter channel, excluding retries.")
+                .with_unit("count")
+                .build(),
+            spine_channel_acks_received: meter
+                .f64_gauge(SPINE_CHANNEL_ACKS_RECEIVED_METRIC)
+                .with_description("Act acknowledgements received per adapter channel.")
+                .with_unit("count")
+                .build(),
+            spine_channel_retries: meter
+                .f64_gauge(SPINE_CHANNEL_RETRIES_METRIC)
+                .with_description("Act resends after an ack timeout per adapter channel.")
+                .with_unit("count")
+                .build(),
+            spine_channel_queue_depth: meter
+                .f64_gauge(SPINE_CHANNEL_QUEUE_DEPTH_METRIC)
+                .with_description("Acts waiting in each adapter channel's outbound queue.")
+                .with_unit("count")
+                .build(),
+            stem_sense_queue_depth: meter
+                .f64_gauge(STEM_SENSE_QUEUE_DEPTH_METRIC)
+                .with_description("Senses waiting in the queue feeding cortex.")
+                .with_unit("count")
+                .build(),
         }
     })
 }
@@ -115,6 +218,15 @@ pub fn record_cortex_input_ir_act_descriptor_catalog_count(catalog_count: usize)
         .record(catalog_count as f64, &[]);
 }
 
+pub fn record_cortex_cycle_throughput(cycles_per_second: f64, average_latency_ms: f64) {
+    instruments()
+        .cortex_cycles_per_second
+        .record(cycles_per_second, &[]);
+    instruments()
+        .cortex_cycle_average_latency_ms
+        .record(average_latency_ms, &[]);
+}
+
 pub fn record_chat_task_latency_ms(task_type: &str, backend: &str, model: &str, latency_ms: u64) {
     instruments().chat_task_latency_ms.record(
         latency_ms as f64,
@@ -224,3 +336,66 @@ pub fn set_chat_thread_last_turn_latency_ms(session_id: &str, thread_id: &str, l
         ],
     );
 }
+
+pub fn record_ai_gateway_breaker_state(backend: &str, phase: BreakerPhase, failure_streak: u32) {
+    let attributes = [KeyValue::new("backend", backend.to_string())];
+    let open = match phase {
+        BreakerPhase::Closed => 0.0,
+        BreakerPhase::HalfOpen => 0.5,
+        BreakerPhase::Open => 1.0,
+    };
+    instruments()
+        .ai_gateway_breaker_open
+        .record(open, &attributes);
+    instruments()
+        .ai_gateway_breaker_failure_streak
+        .record(failure_streak as f64, &attributes);
+}
+
+pub fn record_ai_gateway_backend_slo(backend: &str, snapshot: &BackendSloSnapshot) {
+    let attributes = [KeyValue::new("backend", backend.to_string())];
+    instruments()
+        .ai_gateway_backend_success_rate
+        .record(snapshot.success_rate, &attributes);
+    instruments()
+        .ai_gateway_backend_latency_p50_ms
+        .record(snapshot.p50_latency_ms as f64, &attributes);
+    instruments()
+        .ai_gateway_backend_latency_p95_ms
+        .record(snapshot.p95_latency_ms as f64, &attributes);
+    for (kind, count) in &snapshot.error_kinds {
+        let error_kind = serde_json::to_value(kind)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default();
+        instruments().ai_gateway_backend_errors.record(
+            *count as f64,
+            &[
+                KeyValue::new("backend", backend.to_string()),
+                KeyValue::new("error_kind", error_kind),
+            ],
+        );
+    }
+}
+
+pub fn record_spine_channel_stats(channel: &str, snapshot: &ChannelStatsSnapshot) {
+    let attributes = [KeyValue::new("channel", channel.to_string())];
+    instruments()
+        .spine_channel_acts_sent
+        .record(snapshot.acts_sent as f64, &attributes);
+    instruments()
+        .spine_channel_acks_received
+        .record(snapshot.acks_received as f64, &attributes);
+    instruments()
+        .spine_channel_retries
+        .record(snapshot.retries as f64, &attributes);
+    instruments()
+        .spine_channel_queue_depth
+        .record(snapshot.queue_depth as f64, &attributes);
+}
+
+pub fn record_stem_sense_queue_depth(depth: usize) {
+    instruments()
+        .stem_sense_queue_depth
+        .record(depth as f64, &[]);
+}
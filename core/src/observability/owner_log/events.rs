@@ -13,7 +13,7 @@ pub(crate) use spine::{
 };
 pub(crate) use stem::{
     emit_stem_afferent_pathway, emit_stem_afferent_rule, emit_stem_descriptor_catalog,
-    emit_stem_efferent_pathway, emit_stem_proprioception,
+    emit_stem_efferent_pathway, emit_stem_proprioception, emit_stem_sense_queue_watermark,
 };
 
 use super::{
@@ -158,6 +158,33 @@ pub(crate) fn emit_stem_afferent_rule(
     });
 }
 
+pub(crate) fn emit_stem_sense_queue_watermark(kind: &str, depth: usize, high: usize, low: usize) {
+    let event_name = sense_queue_watermark_event_name(kind);
+
+    emit(OwnerLogEvent {
+        scope: OwnerScope::StemAfferentPathway,
+        event_name,
+        tick: pre_tick_or_known_tick(None),
+        span_key: "queue:sense".to_string(),
+        severity: severity_for_pathway_event(event_name),
+        attributes: Vec::new(),
+        body: json!({
+            "summary": format!("Stem afferent pathway {event_name}."),
+            "watermark_event_kind": kind,
+            "queue_depth": depth,
+            "high_watermark": high,
+            "low_watermark": low,
+        }),
+    });
+}
+
+fn sense_queue_watermark_event_name(kind: &str) -> &'static str {
+    match kind {
+        "high" => "sense_queue.high_watermark",
+        _ => "sense_queue.low_watermark",
+    }
+}
+
 fn stem_afferent_event_name(kind: &str) -> &'static str {
     match kind {
         "enqueue" => "sense.enqueued",
@@ -205,7 +232,7 @@ fn afferent_rule_event_name(kind: &str) -> &'static str {
 
 fn severity_for_pathway_event(event_name: &str) -> OwnerLogSeverity {
     match event_name {
-        "sense.dropped" => OwnerLogSeverity::Warn,
+        "sense.dropped" | "sense_queue.high_watermark" => OwnerLogSeverity::Warn,
         _ => OwnerLogSeverity::Info,
     }
 }
@@ -242,5 +269,13 @@ mod tests {
         assert_eq!(stem_efferent_event_name("dispatch"), "act.started");
         assert_eq!(stem_efferent_event_name("result"), "act.finished");
         assert_eq!(afferent_rule_event_name("replace"), "rules.replaced");
+        assert_eq!(
+            sense_queue_watermark_event_name("high"),
+            "sense_queue.high_watermark"
+        );
+        assert_eq!(
+            sense_queue_watermark_event_name("low"),
+            "sense_queue.low_watermark"
+        );
     }
 }
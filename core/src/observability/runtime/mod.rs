@@ -23,7 +23,7 @@ pub use spine::{
 };
 pub use stem::{
     emit_stem_afferent, emit_stem_afferent_rule, emit_stem_efferent, emit_stem_ns_catalog,
-    emit_stem_proprioception, emit_stem_tick,
+    emit_stem_proprioception, emit_stem_sense_queue_watermark, emit_stem_tick,
 };
 
 static RUN_ID: OnceLock<String> = OnceLock::new();
@@ -96,3 +96,7 @@ pub fn emit_stem_afferent_rule(
 ) {
     owner_log::events::emit_stem_afferent_rule(tick, kind, revision, rule_id, rule, removed);
 }
+
+pub fn emit_stem_sense_queue_watermark(kind: &str, depth: usize, high: usize, low: usize) {
+    owner_log::events::emit_stem_sense_queue_watermark(kind, depth, high, low);
+}
@@ -0,0 +1,117 @@
+use std::{
+    fmt,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Shutdown components that have not finished draining yet, in drain order.
+#[derive(Debug, Clone)]
+pub struct ShutdownProgress {
+    pending: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl ShutdownProgress {
+    pub fn new(components: impl IntoIterator<Item = &'static str>) -> Self {
+        Self {
+            pending: Arc::new(Mutex::new(components.into_iter().collect())),
+        }
+    }
+
+    /// Runs one component's drain and marks it finished once `drain` completes.
+    pub async fn step<T>(&self, component: &'static str, drain: impl Future<Output = T>) -> T {
+        let output = drain.await;
+        self.pending
+            .lock()
+            .expect("shutdown progress lock poisoned")
+            .retain(|pending| *pending != component);
+        output
+    }
+
+    pub fn pending(&self) -> Vec<&'static str> {
+        self.pending
+            .lock()
+            .expect("shutdown progress lock poisoned")
+            .clone()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShutdownDeadlineExceeded {
+    pub deadline: Duration,
+    pub pending: Vec<&'static str>,
+}
+
+impl fmt::Display for ShutdownDeadlineExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "shutdown did not finish within {}ms; still pending: {}",
+            self.deadline.as_millis(),
+            self.pending.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for ShutdownDeadlineExceeded {}
+
+/// Awaits `drain`, giving up once `deadline` passes and reporting what `progress` still holds.
+///
+/// The caller is expected to force-exit on error; the abandoned drain is not cancelled.
+pub async fn drain_within_deadline<T>(
+    deadline: Duration,
+    progress: &ShutdownProgress,
+    drain: impl Future<Output = T>,
+) -> Result<T, ShutdownDeadlineExceeded> {
+    tokio::time::timeout(deadline, drain)
+        .await
+        .map_err(|_| ShutdownDeadlineExceeded {
+            deadline,
+            pending: progress.pending(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn deadline_reports_slow_component_and_everything_after_it() {
+        let progress = ShutdownProgress::new(["stem_task", "spine_adapters", "continuity_flush"]);
+        let drain = async {
+            progress.step("stem_task", async {}).await;
+            progress
+                .step(
+                    "spine_adapters",
+                    tokio::time::sleep(Duration::from_secs(60)),
+                )
+                .await;
+            progress.step("continuity_flush", async {}).await;
+        };
+
+        let exceeded = drain_within_deadline(Duration::from_millis(20), &progress, drain)
+            .await
+            .expect_err("slow adapter shutdown should exceed the deadline");
+
+        assert_eq!(exceeded.pending, vec!["spine_adapters", "continuity_flush"]);
+        assert_eq!(
+            exceeded.to_string(),
+            "shutdown did not finish within 20ms; still pending: spine_adapters, continuity_flush"
+        );
+    }
+
+    #[tokio::test]
+    async fn drain_within_deadline_returns_drain_output() {
+        let progress = ShutdownProgress::new(["stem_task"]);
+        let output = drain_within_deadline(
+            Duration::from_secs(1),
+            &progress,
+            progress.step("stem_task", async { 7 }),
+        )
+        .await
+        .expect("fast drain should finish");
+
+        assert_eq!(output, 7);
+        assert!(progress.pending().is_empty());
+    }
+}
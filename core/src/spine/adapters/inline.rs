@@ -1,18 +1,28 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    sync::{
+        Arc, Weak,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
 
 use anyhow::{Result, anyhow};
 use tokio::{
     sync::{Mutex, mpsc},
     task::JoinHandle,
+    time::MissedTickBehavior,
 };
 use tokio_util::sync::CancellationToken;
 use tracing::Instrument;
 
 use crate::{
-    observability::runtime::{self as observability_runtime, AdapterLifecycleState},
+    observability::runtime::{
+        self as observability_runtime, AdapterLifecycleState, EndpointLifecycleTransition,
+    },
     spine::{
-        AdapterContext, AdapterId, NeuralSignalDescriptor, SpineAdapterPort,
-        types::ActDispatchResult,
+        AdapterContext, AdapterId, InFlightAct, NeuralSignalDescriptor, SpineAdapterPort,
+        SpineExecutionMode, types::ActDispatchResult,
     },
     types::{Act, Sense},
 };
@@ -33,6 +43,36 @@ struct RegisteredInlineEndpoint {
     body_endpoint_id: String,
     act_tx: mpsc::Sender<Arc<Act>>,
     sense_task: JoinHandle<()>,
+    enqueued_acts: Arc<AtomicU64>,
+    last_probe: Option<ActQueueProbe>,
+    health: InlineEndpointHealth,
+}
+
+/// Liveness of an inline worker, judged by whether it drains its act queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InlineEndpointHealth {
+    Healthy,
+    /// Acts stayed queued across a whole probe interval without any being consumed.
+    Degraded,
+}
+
+impl InlineEndpointHealth {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Healthy => "healthy",
+            Self::Degraded => "degraded",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ActQueueProbe {
+    consumed: u64,
+    pending: usize,
+}
+
+fn endpoint_health_proprioception_key(body_endpoint_id: &str) -> String {
+    format!("spine.body_endpoint.{body_endpoint_id}.health")
 }
 
 pub struct InlineEndpointRuntimeHandles {
@@ -42,11 +82,13 @@ pub struct InlineEndpointRuntimeHandles {
 
 pub struct SpineInlineAdapter {
     adapter_id: AdapterId,
+    execution_mode: SpineExecutionMode,
     port: Arc<dyn SpineAdapterPort>,
     sense_tx: mpsc::UnboundedSender<Sense>,
     shutdown: CancellationToken,
     act_queue_capacity: usize,
     sense_queue_capacity: usize,
+    health_probe_interval: Duration,
     endpoints: Mutex<BTreeMap<String, RegisteredInlineEndpoint>>,
 }
 
@@ -57,24 +99,27 @@ impl SpineInlineAdapter {
     ) -> (Arc<Self>, JoinHandle<Result<()>>) {
         let adapter = Arc::new(Self::new(config, &context));
         let dispatch_task = Self::spawn_dispatch_task(Arc::clone(&adapter), context.act_rx);
+        Self::spawn_health_probe_task(Arc::downgrade(&adapter));
         (adapter, dispatch_task)
     }
 
     fn new(config: InlineAdapterConfig, context: &AdapterContext) -> Self {
         Self {
             adapter_id: context.adapter_id,
+            execution_mode: context.execution_mode,
             port: Arc::clone(&context.port),
             sense_tx: context.sense_tx.clone(),
             shutdown: context.shutdown.clone(),
             act_queue_capacity: config.act_queue_capacity.max(1),
             sense_queue_capacity: config.sense_queue_capacity.max(1),
+            health_probe_interval: Duration::from_millis(config.health_probe_interval_ms.max(1)),
             endpoints: Mutex::new(BTreeMap::new()),
         }
     }
 
     fn spawn_dispatch_task(
         adapter: Arc<Self>,
-        mut act_rx: mpsc::UnboundedReceiver<Act>,
+        mut act_rx: mpsc::UnboundedReceiver<InFlightAct>,
     ) -> JoinHandle<Result<()>> {
         let shutdown = adapter.shutdown.clone();
         tokio::spawn(async move {
@@ -84,10 +129,32 @@ impl SpineInlineAdapter {
                         break;
                     }
                     maybe_act = act_rx.recv() => {
-                        let Some(act) = maybe_act else {
+                        let Some(InFlightAct { act, permit }) = maybe_act else {
                             break;
                         };
-                        adapter.enqueue_act(&act.endpoint_id.clone(), act).await?;
+                        match adapter.execution_mode {
+                            SpineExecutionMode::SerializedDeterministic => {
+                                let _permit = permit;
+                                adapter.enqueue_act(&act.endpoint_id.clone(), act).await?;
+                            }
+                            SpineExecutionMode::BestEffortReplayable => {
+                                let adapter = Arc::clone(&adapter);
+                                tokio::spawn(async move {
+                                    let _permit = permit;
+                                    let act_instance_id = act.act_instance_id.clone();
+                                    if let Err(err) =
+                                        adapter.enqueue_act(&act.endpoint_id.clone(), act).await
+                                    {
+                                        tracing::warn!(
+                                            target: "spine.inline_adapter",
+                                            act_instance_id = %act_instance_id,
+                                            error = %err,
+                                            "concurrent_act_enqueue_failed"
+                                        );
+                                    }
+                                });
+                            }
+                        }
                     }
                 }
             }
@@ -95,6 +162,86 @@ impl SpineInlineAdapter {
         })
     }
 
+    /// Probes on a separate task so a dispatch blocked on a full endpoint queue cannot stall it.
+    fn spawn_health_probe_task(adapter: Weak<Self>) {
+        let Some((shutdown, period)) = adapter
+            .upgrade()
+            .map(|adapter| (adapter.shutdown.clone(), adapter.health_probe_interval))
+        else {
+            return;
+        };
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval.tick().await;
+            loop {
+                tokio::select! {
+                    _ = shutdown.cancelled() => {
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        let Some(adapter) = adapter.upgrade() else {
+                            break;
+                        };
+                        adapter.probe_endpoint_health().await;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Checks each inline worker for progress since the previous probe and publishes changes.
+    ///
+    /// A worker is degraded when acts were already pending at the previous probe and none has
+    /// been consumed since; it recovers as soon as it drains one.
+    pub async fn probe_endpoint_health(&self) -> BTreeMap<String, InlineEndpointHealth> {
+        let mut statuses = BTreeMap::new();
+        let mut changed = BTreeMap::new();
+        {
+            let mut state = self.endpoints.lock().await;
+            for entry in state.values_mut() {
+                let pending = entry
+                    .act_tx
+                    .max_capacity()
+                    .saturating_sub(entry.act_tx.capacity());
+                let probe = ActQueueProbe {
+                    consumed: entry
+                        .enqueued_acts
+                        .load(Ordering::Acquire)
+                        .saturating_sub(pending as u64),
+                    pending,
+                };
+                let stalled = entry.last_probe.is_some_and(|previous| {
+                    previous.pending > 0 && probe.pending > 0 && probe.consumed == previous.consumed
+                });
+                entry.last_probe = Some(probe);
+                let health = if stalled {
+                    InlineEndpointHealth::Degraded
+                } else {
+                    InlineEndpointHealth::Healthy
+                };
+                if health != entry.health {
+                    entry.health = health;
+                    tracing::warn!(
+                        target: "spine.inline_adapter",
+                        adapter_id = self.adapter_id,
+                        endpoint_id = %entry.body_endpoint_id,
+                        health = health.as_str(),
+                        pending_acts = pending,
+                        "inline_endpoint_health_changed"
+                    );
+                    changed.insert(
+                        endpoint_health_proprioception_key(&entry.body_endpoint_id),
+                        health.as_str().to_string(),
+                    );
+                }
+                statuses.insert(entry.body_endpoint_id.clone(), health);
+            }
+        }
+        self.port.apply_proprioception_patch(changed).await;
+        statuses
+    }
+
     pub fn adapter_id(&self) -> u64 {
         self.adapter_id
     }
@@ -160,6 +307,7 @@ impl SpineInlineAdapter {
 
         let adapter = Arc::downgrade(self);
         let shutdown = self.shutdown.clone();
+        let act_tx_for_task = act_tx.clone();
         let endpoint_name_for_task = endpoint_name.clone();
         let body_endpoint_id_for_task = body_endpoint_id.clone();
         let sense_span = tracing::info_span!(
@@ -170,17 +318,20 @@ impl SpineInlineAdapter {
         );
         let sense_task = tokio::spawn(
             async move {
-                loop {
+                let exit_reason = loop {
                     tokio::select! {
                         _ = shutdown.cancelled() => {
-                            break;
+                            break None;
+                        }
+                        _ = act_tx_for_task.closed() => {
+                            break Some("act_channel_closed");
                         }
                         maybe_sense = sense_rx.recv() => {
                             let Some(sense) = maybe_sense else {
-                                break;
+                                break Some("sense_channel_closed");
                             };
                             let Some(adapter) = adapter.upgrade() else {
-                                break;
+                                break None;
                             };
                             // Inline endpoints do not carry endpoint_id; adapter injects the bound endpoint id.
                             let sense = Sense {
@@ -192,13 +343,17 @@ impl SpineInlineAdapter {
                                 act_instance_id: sense.act_instance_id.clone(),
                             };
                             if adapter.sense_tx.send(sense).is_err() {
-                                break;
+                                break None;
                             }
                         }
                     }
-                }
+                };
+                drop(act_tx_for_task);
 
                 if let Some(adapter) = adapter.upgrade() {
+                    if let Some(reason) = exit_reason {
+                        adapter.emit_endpoint_worker_exited(&body_endpoint_id_for_task, reason);
+                    }
                     adapter
                         .remove_endpoint_by_name(&endpoint_name_for_task, Some(&body_endpoint_id_for_task), false)
                         .await;
@@ -218,18 +373,45 @@ impl SpineInlineAdapter {
             state.insert(
                 endpoint_name,
                 RegisteredInlineEndpoint {
-                    body_endpoint_id,
+                    body_endpoint_id: body_endpoint_id.clone(),
                     act_tx,
                     sense_task,
+                    enqueued_acts: Arc::new(AtomicU64::new(0)),
+                    last_probe: None,
+                    health: InlineEndpointHealth::Healthy,
                 },
             );
         }
 
+        self.port
+            .apply_proprioception_patch(BTreeMap::from([(
+                endpoint_health_proprioception_key(&body_endpoint_id),
+                InlineEndpointHealth::Healthy.as_str().to_string(),
+            )]))
+            .await;
         self.port.publish_topology_proprioception_snapshot().await;
 
         Ok(InlineEndpointRuntimeHandles { act_rx, sense_tx })
     }
 
+    fn emit_endpoint_worker_exited(&self, body_endpoint_id: &str, reason: &str) {
+        tracing::warn!(
+            target: "spine.inline_adapter",
+            adapter_id = self.adapter_id,
+            endpoint_id = body_endpoint_id,
+            reason = reason,
+            "inline_endpoint_worker_exited"
+        );
+        observability_runtime::emit_spine_endpoint_lifecycle(
+            body_endpoint_id,
+            Some(&self.adapter_id.to_string()),
+            EndpointLifecycleTransition::Disconnected,
+            None,
+            None,
+            Some(reason),
+        );
+    }
+
     async fn enqueue_act(&self, body_endpoint_id: &str, act: Act) -> Result<ActDispatchResult> {
         let act_instance_id = act.act_instance_id.clone();
         tracing::debug!(
@@ -245,9 +427,15 @@ impl SpineInlineAdapter {
             state
                 .iter()
                 .find(|(_, entry)| entry.body_endpoint_id == body_endpoint_id)
-                .map(|(endpoint_name, entry)| (endpoint_name.clone(), entry.act_tx.clone()))
+                .map(|(endpoint_name, entry)| {
+                    (
+                        endpoint_name.clone(),
+                        entry.act_tx.clone(),
+                        Arc::clone(&entry.enqueued_acts),
+                    )
+                })
         };
-        let Some((endpoint_name, tx)) = target else {
+        let Some((endpoint_name, tx, enqueued_acts)) = target else {
             tracing::warn!(
                 target: "spine.inline_adapter",
                 endpoint_id = body_endpoint_id,
@@ -269,6 +457,7 @@ impl SpineInlineAdapter {
                 act_instance_id = %act_instance_id,
                 "inline_endpoint_unavailable_during_dispatch"
             );
+            self.port.record_act_delivery(body_endpoint_id, false).await;
             self.remove_endpoint_by_name(&endpoint_name, None, true)
                 .await;
             return Ok(ActDispatchResult::Rejected {
@@ -276,6 +465,8 @@ impl SpineInlineAdapter {
                 reference_id: format!("inline_adapter:endpoint_unavailable:{act_instance_id}"),
             });
         }
+        enqueued_acts.fetch_add(1, Ordering::Release);
+        self.port.record_act_delivery(body_endpoint_id, true).await;
 
         tracing::debug!(
             target: "spine.inline_adapter",
@@ -319,7 +510,320 @@ impl SpineInlineAdapter {
             removed.sense_task.abort();
         }
 
+        self.port
+            .apply_proprioception_drop(vec![endpoint_health_proprioception_key(
+                &removed.body_endpoint_id,
+            )])
+            .await;
         self.port.drop_endpoint(&removed.body_endpoint_id).await;
         self.port.publish_topology_proprioception_snapshot().await;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::{
+        spine::{ActPayloadTransform, AdapterChannelStats, runtime::BodyEndpointHandle},
+        types::{NeuralSignalDescriptorRouteKey, NeuralSignalType},
+    };
+
+    #[derive(Default)]
+    struct RecordingPort {
+        dropped_endpoints: std::sync::Mutex<Vec<String>>,
+        deliveries: std::sync::Mutex<Vec<(String, bool)>>,
+        proprioception: std::sync::Mutex<BTreeMap<String, String>>,
+    }
+
+    #[async_trait]
+    impl SpineAdapterPort for RecordingPort {
+        async fn register_endpoint(
+            &self,
+            _adapter_id: AdapterId,
+            endpoint_name: &str,
+        ) -> Result<BodyEndpointHandle> {
+            Ok(BodyEndpointHandle {
+                body_endpoint_id: format!("{endpoint_name}.1"),
+            })
+        }
+
+        async fn add_ns_descriptors(
+            &self,
+            _body_endpoint_id: &str,
+            descriptors: Vec<NeuralSignalDescriptor>,
+        ) -> Result<Vec<NeuralSignalDescriptor>> {
+            Ok(descriptors)
+        }
+
+        async fn update_ns_descriptors(
+            &self,
+            _body_endpoint_id: &str,
+            descriptors: Vec<NeuralSignalDescriptor>,
+        ) -> Result<Vec<NeuralSignalDescriptor>> {
+            Ok(descriptors)
+        }
+
+        async fn drop_ns_descriptors(
+            &self,
+            _body_endpoint_id: &str,
+            routes: Vec<NeuralSignalDescriptorRouteKey>,
+        ) -> Result<Vec<NeuralSignalDescriptorRouteKey>> {
+            Ok(routes)
+        }
+
+        async fn set_act_payload_transform(
+            &self,
+            _body_endpoint_id: &str,
+            _transform: ActPayloadTransform,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        async fn drop_endpoint(&self, body_endpoint_id: &str) {
+            self.dropped_endpoints
+                .lock()
+                .expect("lock poisoned")
+                .push(body_endpoint_id.to_string());
+        }
+
+        async fn set_act_dispatch_paused(&self, _body_endpoint_id: &str, _paused: bool) {}
+
+        async fn record_act_delivery(&self, body_endpoint_id: &str, delivered: bool) {
+            self.deliveries
+                .lock()
+                .expect("lock poisoned")
+                .push((body_endpoint_id.to_string(), delivered));
+        }
+
+        async fn apply_proprioception_patch(&self, entries: BTreeMap<String, String>) {
+            self.proprioception
+                .lock()
+                .expect("lock poisoned")
+                .extend(entries);
+        }
+
+        async fn apply_proprioception_drop(&self, keys: Vec<String>) {
+            let mut proprioception = self.proprioception.lock().expect("lock poisoned");
+            for key in keys {
+                proprioception.remove(&key);
+            }
+        }
+
+        async fn publish_topology_proprioception_snapshot(&self) {}
+    }
+
+    fn worker_descriptor() -> NeuralSignalDescriptor {
+        NeuralSignalDescriptor {
+            r#type: NeuralSignalType::Act,
+            endpoint_id: "worker".to_string(),
+            neural_signal_descriptor_id: "run".to_string(),
+            payload_schema: serde_json::json!({ "type": "object" }),
+            priority: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn stalled_inline_worker_is_reported_degraded_until_it_drains() {
+        let port = Arc::new(RecordingPort::default());
+        let (_spine_act_tx, act_rx) = mpsc::unbounded_channel();
+        let (sense_tx, _sense_rx) = mpsc::unbounded_channel();
+        let (adapter, _dispatch_task) = SpineInlineAdapter::from_config(
+            InlineAdapterConfig {
+                health_probe_interval_ms: 3_600_000,
+                ..InlineAdapterConfig::default()
+            },
+            AdapterContext {
+                adapter_id: 1,
+                execution_mode: SpineExecutionMode::default(),
+                shutdown: CancellationToken::new(),
+                act_rx,
+                sense_tx,
+                port: port.clone(),
+                channel_stats: AdapterChannelStats::default(),
+            },
+        );
+        let mut handles = adapter
+            .attach_inline_endpoint("worker".to_string(), vec![worker_descriptor()])
+            .await
+            .expect("endpoint should attach");
+        let act = Act {
+            act_instance_id: "act-1".to_string(),
+            endpoint_id: "worker.1".to_string(),
+            neural_signal_descriptor_id: "run".to_string(),
+            might_emit_sense_ids: Vec::new(),
+            payload: serde_json::json!({}),
+            goal_id: None,
+        };
+        adapter
+            .enqueue_act("worker.1", act)
+            .await
+            .expect("act should enqueue");
+
+        let first = adapter.probe_endpoint_health().await;
+        assert_eq!(first["worker.1"], InlineEndpointHealth::Healthy);
+
+        let second = adapter.probe_endpoint_health().await;
+        assert_eq!(second["worker.1"], InlineEndpointHealth::Degraded);
+        assert_eq!(
+            port.proprioception
+                .lock()
+                .expect("lock poisoned")
+                .get("spine.body_endpoint.worker.1.health")
+                .map(String::as_str),
+            Some("degraded")
+        );
+
+        handles.act_rx.recv().await.expect("act should be queued");
+        let third = adapter.probe_endpoint_health().await;
+        assert_eq!(third["worker.1"], InlineEndpointHealth::Healthy);
+    }
+
+    fn act_for(act_instance_id: &str, endpoint_id: &str) -> Act {
+        Act {
+            act_instance_id: act_instance_id.to_string(),
+            endpoint_id: endpoint_id.to_string(),
+            neural_signal_descriptor_id: "run".to_string(),
+            might_emit_sense_ids: Vec::new(),
+            payload: serde_json::json!({}),
+            goal_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrent_mode_dispatches_past_a_full_endpoint_queue() {
+        let (spine_act_tx, act_rx) = mpsc::unbounded_channel();
+        let (sense_tx, _sense_rx) = mpsc::unbounded_channel();
+        let (adapter, _dispatch_task) = SpineInlineAdapter::from_config(
+            InlineAdapterConfig {
+                act_queue_capacity: 1,
+                ..InlineAdapterConfig::default()
+            },
+            AdapterContext {
+                adapter_id: 1,
+                execution_mode: SpineExecutionMode::BestEffortReplayable,
+                shutdown: CancellationToken::new(),
+                act_rx,
+                sense_tx,
+                port: Arc::new(RecordingPort::default()),
+                channel_stats: AdapterChannelStats::default(),
+            },
+        );
+        let _busy = adapter
+            .attach_inline_endpoint("busy".to_string(), vec![worker_descriptor()])
+            .await
+            .expect("busy endpoint should attach");
+        let mut idle = adapter
+            .attach_inline_endpoint("idle".to_string(), vec![worker_descriptor()])
+            .await
+            .expect("idle endpoint should attach");
+
+        for act in [
+            act_for("act-1", "busy.1"),
+            act_for("act-2", "busy.1"),
+            act_for("act-3", "idle.1"),
+        ] {
+            spine_act_tx
+                .send(InFlightAct { act, permit: None })
+                .expect("adapter should accept act");
+        }
+
+        let delivered = tokio::time::timeout(Duration::from_secs(1), idle.act_rx.recv())
+            .await
+            .expect("act for idle endpoint should not wait behind the busy one")
+            .expect("act should be delivered");
+        assert_eq!(delivered.act_instance_id, "act-3");
+    }
+
+    #[tokio::test]
+    async fn closing_act_channel_drops_endpoint_routes() {
+        let port = Arc::new(RecordingPort::default());
+        let (_spine_act_tx, act_rx) = mpsc::unbounded_channel();
+        let (sense_tx, _sense_rx) = mpsc::unbounded_channel();
+        let (adapter, _dispatch_task) = SpineInlineAdapter::from_config(
+            InlineAdapterConfig::default(),
+            AdapterContext {
+                adapter_id: 1,
+                execution_mode: SpineExecutionMode::default(),
+                shutdown: CancellationToken::new(),
+                act_rx,
+                sense_tx,
+                port: port.clone(),
+                channel_stats: AdapterChannelStats::default(),
+            },
+        );
+
+        let handles = adapter
+            .attach_inline_endpoint("worker".to_string(), vec![worker_descriptor()])
+            .await
+            .expect("endpoint should attach");
+        let InlineEndpointRuntimeHandles {
+            act_rx,
+            sense_tx: _sense_tx,
+        } = handles;
+        drop(act_rx);
+
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while port
+                .dropped_endpoints
+                .lock()
+                .expect("lock poisoned")
+                .is_empty()
+            {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("endpoint should be dropped after act channel closes");
+
+        assert_eq!(
+            port.dropped_endpoints
+                .lock()
+                .expect("lock poisoned")
+                .as_slice(),
+            ["worker.1".to_string()]
+        );
+        assert!(adapter.endpoints.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn act_to_a_closed_endpoint_counts_as_a_failed_delivery() {
+        let port = Arc::new(RecordingPort::default());
+        let (_spine_act_tx, act_rx) = mpsc::unbounded_channel();
+        let (sense_tx, _sense_rx) = mpsc::unbounded_channel();
+        let (adapter, _dispatch_task) = SpineInlineAdapter::from_config(
+            InlineAdapterConfig::default(),
+            AdapterContext {
+                adapter_id: 1,
+                execution_mode: SpineExecutionMode::default(),
+                shutdown: CancellationToken::new(),
+                act_rx,
+                sense_tx,
+                port: port.clone(),
+                channel_stats: AdapterChannelStats::default(),
+            },
+        );
+        let handles = adapter
+            .attach_inline_endpoint("worker".to_string(), vec![worker_descriptor()])
+            .await
+            .expect("endpoint should attach");
+        drop(handles.act_rx);
+
+        let outcome = adapter
+            .enqueue_act("worker.1", act_for("act-1", "worker.1"))
+            .await
+            .expect("dispatch should settle");
+
+        assert!(matches!(
+            outcome,
+            ActDispatchResult::Rejected { ref reason_code, .. } if reason_code == "endpoint_unavailable"
+        ));
+        assert_eq!(
+            port.deliveries.lock().expect("lock poisoned").as_slice(),
+            [("worker.1".to_string(), false)]
+        );
+    }
+}
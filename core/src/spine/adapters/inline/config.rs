@@ -10,6 +10,10 @@ fn default_inline_sense_queue_capacity() -> usize {
     32
 }
 
+fn default_inline_health_probe_interval_ms() -> u64 {
+    10_000
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct InlineAdapterConfig {
@@ -19,6 +23,10 @@ pub struct InlineAdapterConfig {
     #[serde(default = "default_inline_sense_queue_capacity")]
     #[validate(range(min = 1))]
     pub sense_queue_capacity: usize,
+    /// How often inline workers are checked for progress on their queued acts.
+    #[serde(default = "default_inline_health_probe_interval_ms")]
+    #[validate(range(min = 1))]
+    pub health_probe_interval_ms: u64,
 }
 
 impl Default for InlineAdapterConfig {
@@ -26,6 +34,7 @@ impl Default for InlineAdapterConfig {
         Self {
             act_queue_capacity: default_inline_act_queue_capacity(),
             sense_queue_capacity: default_inline_sense_queue_capacity(),
+            health_probe_interval_ms: default_inline_health_probe_interval_ms(),
         }
     }
 }
@@ -1,2 +1,3 @@
 pub mod inline;
+pub mod tcp_ndjson;
 pub mod unix_socket;
@@ -0,0 +1,315 @@
+use std::{collections::BTreeMap, io, sync::Arc};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+use tracing::Instrument;
+
+use super::unix_socket::{
+    BodyEndpointSessionPolicy, NdjsonListener, config::decode_sense_signing_keys,
+    serve_body_endpoints,
+};
+use crate::{
+    observability::runtime::{self as observability_runtime, AdapterLifecycleState},
+    spine::{
+        AdapterContext, SenseSigningKey,
+        adapters::unix_socket::{BodyEndpointHeartbeatConfig, DuplicateAuthPolicy},
+    },
+};
+
+pub mod config;
+pub use config::TcpNdjsonAdapterConfig;
+
+#[async_trait]
+impl NdjsonListener for TcpListener {
+    type Stream = TcpStream;
+
+    async fn accept_stream(&self) -> io::Result<TcpStream> {
+        let (stream, _) = self.accept().await?;
+        stream.set_nodelay(true)?;
+        Ok(stream)
+    }
+}
+
+/// Serves the unix socket NDJSON protocol over TCP, for endpoints that cannot share a socket file.
+pub struct TcpNdjsonAdapter {
+    pub host: String,
+    pub port: u16,
+    pub adapter_id: u64,
+    pub max_connections: usize,
+    pub duplicate_auth_policy: DuplicateAuthPolicy,
+    pub require_signed_senses: bool,
+    pub sense_signing_keys: BTreeMap<String, SenseSigningKey>,
+    pub act_ack_timeout_ms: u64,
+    pub act_ack_max_retries: usize,
+    pub heartbeat: Option<BodyEndpointHeartbeatConfig>,
+}
+
+impl TcpNdjsonAdapter {
+    pub fn from_config(adapter_id: u64, config: TcpNdjsonAdapterConfig) -> Self {
+        Self {
+            host: config.host,
+            port: config.port,
+            adapter_id,
+            max_connections: config.max_connections.max(1),
+            duplicate_auth_policy: config.duplicate_auth_policy,
+            require_signed_senses: config.require_signed_senses,
+            sense_signing_keys: decode_sense_signing_keys(&config.sense_signing_keys),
+            act_ack_timeout_ms: config.act_ack_timeout_ms,
+            act_ack_max_retries: config.act_ack_max_retries,
+            heartbeat: config.heartbeat,
+        }
+    }
+
+    pub fn bind_address(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+
+    pub async fn run(&self, context: AdapterContext) -> Result<()> {
+        let address = self.bind_address();
+        let listener = TcpListener::bind(&address)
+            .await
+            .with_context(|| format!("unable to bind tcp listener {address}"))?;
+
+        serve_body_endpoints(
+            &listener,
+            context,
+            BodyEndpointSessionPolicy {
+                max_connections: Some(self.max_connections),
+                duplicate_auth_policy: self.duplicate_auth_policy,
+                require_signed_senses: self.require_signed_senses,
+                sense_signing_keys: Arc::new(self.sense_signing_keys.clone()),
+                act_ack_timeout_ms: self.act_ack_timeout_ms,
+                act_ack_max_retries: self.act_ack_max_retries,
+                heartbeat: self.heartbeat,
+            },
+        )
+        .await
+    }
+}
+
+pub fn spawn_adapter_task(
+    config: TcpNdjsonAdapterConfig,
+    context: AdapterContext,
+) -> JoinHandle<Result<()>> {
+    let adapter_id = context.adapter_id;
+    let adapter = TcpNdjsonAdapter::from_config(adapter_id, config);
+    let address = adapter.bind_address();
+    let adapter_span = tracing::info_span!(
+        target: "spine",
+        "tcp_ndjson_adapter_task",
+        adapter_id = adapter_id,
+        address = %address
+    );
+
+    tokio::spawn(
+        async move {
+            tracing::info!(
+                target: "spine",
+                adapter_type = "tcp-ndjson",
+                adapter_id = adapter_id,
+                address = %address,
+                "adapter_started"
+            );
+            observability_runtime::emit_spine_adapter_lifecycle(
+                "tcp_ndjson",
+                &adapter_id.to_string(),
+                AdapterLifecycleState::Enabled,
+                None,
+            );
+            let result = adapter.run(context).await;
+            if let Err(err) = &result {
+                let reason = err.to_string();
+                observability_runtime::emit_spine_adapter_lifecycle(
+                    "tcp_ndjson",
+                    &adapter_id.to_string(),
+                    AdapterLifecycleState::Faulted,
+                    Some(&reason),
+                );
+            }
+            result
+        }
+        .instrument(adapter_span),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        sync::{Arc, Mutex},
+    };
+
+    use tokio::{
+        io::AsyncWriteExt,
+        sync::mpsc,
+        time::{Duration, timeout},
+    };
+    use tokio_util::sync::CancellationToken;
+
+    use super::*;
+    use crate::{
+        spine::{
+            ActPayloadTransform, AdapterChannelStats, SpineAdapterPort, SpineExecutionMode,
+            runtime::BodyEndpointHandle, types::NeuralSignalDescriptor,
+        },
+        types::NeuralSignalDescriptorRouteKey,
+    };
+
+    #[derive(Default)]
+    struct RegistrationPort {
+        registered: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl SpineAdapterPort for RegistrationPort {
+        async fn register_endpoint(
+            &self,
+            _adapter_id: u64,
+            endpoint_name: &str,
+        ) -> Result<BodyEndpointHandle> {
+            self.registered
+                .lock()
+                .expect("lock poisoned")
+                .push(endpoint_name.to_string());
+            Ok(BodyEndpointHandle {
+                body_endpoint_id: format!("{endpoint_name}.1"),
+            })
+        }
+
+        async fn add_ns_descriptors(
+            &self,
+            _body_endpoint_id: &str,
+            descriptors: Vec<NeuralSignalDescriptor>,
+        ) -> Result<Vec<NeuralSignalDescriptor>> {
+            Ok(descriptors)
+        }
+
+        async fn update_ns_descriptors(
+            &self,
+            _body_endpoint_id: &str,
+            descriptors: Vec<NeuralSignalDescriptor>,
+        ) -> Result<Vec<NeuralSignalDescriptor>> {
+            Ok(descriptors)
+        }
+
+        async fn drop_ns_descriptors(
+            &self,
+            _body_endpoint_id: &str,
+            routes: Vec<NeuralSignalDescriptorRouteKey>,
+        ) -> Result<Vec<NeuralSignalDescriptorRouteKey>> {
+            Ok(routes)
+        }
+
+        async fn set_act_payload_transform(
+            &self,
+            _body_endpoint_id: &str,
+            _transform: ActPayloadTransform,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        async fn drop_endpoint(&self, _body_endpoint_id: &str) {}
+
+        async fn set_act_dispatch_paused(&self, _body_endpoint_id: &str, _paused: bool) {}
+
+        async fn record_act_delivery(&self, _body_endpoint_id: &str, _delivered: bool) {}
+
+        async fn apply_proprioception_patch(&self, _entries: BTreeMap<String, String>) {}
+
+        async fn apply_proprioception_drop(&self, _keys: Vec<String>) {}
+
+        async fn publish_topology_proprioception_snapshot(&self) {}
+    }
+
+    #[tokio::test]
+    async fn endpoint_registers_over_loopback_tcp() {
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .expect("reserve loopback port")
+            .port();
+        let adapter = TcpNdjsonAdapter::from_config(
+            1,
+            TcpNdjsonAdapterConfig {
+                host: "127.0.0.1".to_string(),
+                port,
+                max_connections: 1,
+                duplicate_auth_policy: DuplicateAuthPolicy::default(),
+                require_signed_senses: false,
+                sense_signing_keys: BTreeMap::new(),
+                act_ack_timeout_ms: 1_500,
+                act_ack_max_retries: 2,
+                heartbeat: None,
+            },
+        );
+        let registration_port = Arc::new(RegistrationPort::default());
+        let shutdown = CancellationToken::new();
+        let (_act_tx, act_rx) = mpsc::unbounded_channel();
+        let (sense_tx, _sense_rx) = mpsc::unbounded_channel();
+        let context = AdapterContext {
+            adapter_id: 1,
+            execution_mode: SpineExecutionMode::default(),
+            shutdown: shutdown.clone(),
+            act_rx,
+            sense_tx,
+            port: registration_port.clone(),
+            channel_stats: AdapterChannelStats::default(),
+        };
+        let run_task = tokio::spawn(async move { adapter.run(context).await });
+
+        let mut stream = timeout(Duration::from_secs(1), async {
+            loop {
+                if let Ok(stream) = TcpStream::connect(("127.0.0.1", port)).await {
+                    break stream;
+                }
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("adapter should start listening");
+        let auth = serde_json::json!({
+            "method": "auth",
+            "id": uuid::Uuid::new_v4().to_string(),
+            "timestamp": 1,
+            "body": {
+                "endpoint_name": "tool",
+                "ns_descriptors": [{
+                    "type": "act",
+                    "endpoint_id": "tool",
+                    "neural_signal_descriptor_id": "run",
+                    "payload_schema": { "type": "object" }
+                }]
+            }
+        });
+        stream
+            .write_all(format!("{auth}\n").as_bytes())
+            .await
+            .expect("auth should be written");
+
+        timeout(Duration::from_secs(1), async {
+            while registration_port
+                .registered
+                .lock()
+                .expect("lock poisoned")
+                .is_empty()
+            {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("endpoint should register");
+        assert_eq!(
+            *registration_port.registered.lock().expect("lock poisoned"),
+            vec!["tool".to_string()]
+        );
+
+        shutdown.cancel();
+        run_task
+            .await
+            .expect("adapter task should join")
+            .expect("adapter should stop cleanly");
+    }
+}
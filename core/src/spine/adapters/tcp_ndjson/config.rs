@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+use crate::spine::adapters::unix_socket::{
+    DuplicateAuthPolicy,
+    config::{
+        BodyEndpointHeartbeatConfig, default_act_ack_max_retries, default_act_ack_timeout_ms,
+        validate_sense_signing_keys,
+    },
+};
+
+fn default_host() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_max_connections() -> usize {
+    64
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TcpNdjsonAdapterConfig {
+    /// Interface to bind; use `0.0.0.0` to accept endpoints from other hosts or containers.
+    #[serde(default = "default_host")]
+    #[validate(custom(function = "validate_non_blank_host"))]
+    pub host: String,
+    #[validate(range(min = 1))]
+    pub port: u16,
+    /// Maximum concurrently connected body endpoints; further accepts are rejected.
+    #[serde(default = "default_max_connections")]
+    #[validate(range(min = 1))]
+    pub max_connections: usize,
+    /// How a second `auth` on an already authenticated session is handled.
+    #[serde(default)]
+    pub duplicate_auth_policy: DuplicateAuthPolicy,
+    /// Reject `auth` from any endpoint name without an entry in `sense_signing_keys`.
+    #[serde(default)]
+    pub require_signed_senses: bool,
+    /// Pre-shared hex HMAC keys by `endpoint_name`; see the unix socket adapter's field.
+    #[serde(default)]
+    #[validate(custom(function = "validate_sense_signing_keys"))]
+    pub sense_signing_keys: BTreeMap<String, String>,
+    /// How long to wait for an endpoint's `act_ack` before re-sending the act.
+    #[serde(default = "default_act_ack_timeout_ms")]
+    #[validate(range(min = 1))]
+    pub act_ack_timeout_ms: u64,
+    /// Re-sends of an unacknowledged act before the session is closed as failed.
+    #[serde(default = "default_act_ack_max_retries")]
+    pub act_ack_max_retries: usize,
+    /// Ping endpoints periodically and close sessions that miss a `pong`; off when unset.
+    #[serde(default)]
+    #[validate(nested)]
+    pub heartbeat: Option<BodyEndpointHeartbeatConfig>,
+}
+
+fn validate_non_blank_host(host: &str) -> Result<(), ValidationError> {
+    if host.trim().is_empty() {
+        return Err(ValidationError::new("non_blank_host"));
+    }
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
     fs,
-    io::ErrorKind,
+    io::{self, ErrorKind},
     os::unix::fs::FileTypeExt,
     path::{Path, PathBuf},
     sync::{Arc, Mutex},
@@ -9,11 +9,12 @@ use std::{
 };
 
 use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use tokio::{
-    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader},
     net::{UnixListener, UnixStream},
-    sync::mpsc,
+    sync::{Semaphore, mpsc},
     task::JoinHandle,
     time::{Duration, Instant, timeout},
 };
@@ -22,14 +23,22 @@ use tracing::Instrument;
 
 use crate::{
     observability::runtime::{self as observability_runtime, AdapterLifecycleState},
-    spine::{AdapterContext, SpineAdapterPort, types::NeuralSignalDescriptor},
-    types::{Act, Sense, default_sense_weight, is_uuid_v4, is_uuid_v7},
+    spine::{
+        ActPayloadTransform, AdapterChannelStats, AdapterContext, InFlightAct, SenseSigningKey,
+        SignedSenseFields, SpineAdapterPort, types::NeuralSignalDescriptor,
+    },
+    types::{
+        Act, NeuralSignalDescriptorRouteKey, Sense, default_sense_weight, is_uuid_v4, is_uuid_v7,
+    },
 };
 
 pub mod config;
-pub use config::UnixSocketNdjsonAdapterConfig;
+use config::decode_sense_signing_keys;
+pub use config::{BodyEndpointHeartbeatConfig, DuplicateAuthPolicy, UnixSocketNdjsonAdapterConfig};
 
-type SessionActSenders = Arc<Mutex<BTreeMap<String, mpsc::UnboundedSender<Act>>>>;
+type SessionActSenders = Arc<Mutex<BTreeMap<String, mpsc::UnboundedSender<InFlightAct>>>>;
+/// Unanswered `ping` ids of one session, with the time each was recorded.
+type PendingPings = Arc<Mutex<BTreeMap<String, Instant>>>;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct NdjsonEnvelope<T> {
@@ -50,6 +59,8 @@ enum InboundBodyMessage {
         endpoint_name: String,
         ns_descriptors: Vec<NeuralSignalDescriptor>,
         proprioceptions: BTreeMap<String, String>,
+        act_payload_transform: Option<ActPayloadTransform>,
+        auth_signature: Option<String>,
     },
     Sense(InboundSenseFrame),
     NewProprioceptions {
@@ -58,10 +69,18 @@ enum InboundBodyMessage {
     DropProprioceptions {
         keys: Vec<String>,
     },
+    UpdateNsDescriptors {
+        ns_descriptors: Vec<NeuralSignalDescriptor>,
+    },
     ActAck {
         act_instance_id: String,
     },
     Unplug,
+    PauseActs,
+    ResumeActs,
+    Pong {
+        ping_id: String,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -71,6 +90,8 @@ struct InboundSenseFrame {
     payload: String,
     weight: f64,
     act_instance_id: Option<String>,
+    sequence: Option<u64>,
+    signature: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -81,6 +102,10 @@ struct InboundAuthBody {
     ns_descriptors: Vec<NeuralSignalDescriptor>,
     #[serde(default)]
     proprioceptions: BTreeMap<String, String>,
+    #[serde(default)]
+    act_payload_transform: Option<ActPayloadTransform>,
+    #[serde(default)]
+    auth_signature: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -97,6 +122,12 @@ struct InboundDropProprioceptionsBody {
     keys: Vec<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InboundUpdateNsDescriptorsBody {
+    ns_descriptors: Vec<NeuralSignalDescriptor>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(deny_unknown_fields)]
 struct InboundSenseBody {
@@ -107,6 +138,10 @@ struct InboundSenseBody {
     weight: f64,
     #[serde(default)]
     act_instance_id: Option<String>,
+    #[serde(default)]
+    sequence: Option<u64>,
+    #[serde(default)]
+    signature: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -115,6 +150,12 @@ struct InboundActAckBody {
     act_instance_id: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InboundPongBody {
+    ping_id: String,
+}
+
 fn parse_body_afferent_message(line: &str) -> Result<InboundBodyMessage, serde_json::Error> {
     let wire: NdjsonEnvelope<serde_json::Value> = serde_json::from_str(line)?;
     if !is_uuid_v4(&wire.id) {
@@ -135,6 +176,8 @@ fn parse_body_afferent_message(line: &str) -> Result<InboundBodyMessage, serde_j
                 endpoint_name: body.endpoint_name,
                 ns_descriptors: body.ns_descriptors,
                 proprioceptions: body.proprioceptions,
+                act_payload_transform: body.act_payload_transform,
+                auth_signature: body.auth_signature,
             }
         }
         "new_proprioceptions" => {
@@ -147,6 +190,12 @@ fn parse_body_afferent_message(line: &str) -> Result<InboundBodyMessage, serde_j
             let body: InboundDropProprioceptionsBody = decode_envelope_body(wire.body)?;
             InboundBodyMessage::DropProprioceptions { keys: body.keys }
         }
+        "update_ns_descriptors" => {
+            let body: InboundUpdateNsDescriptorsBody = decode_envelope_body(wire.body)?;
+            InboundBodyMessage::UpdateNsDescriptors {
+                ns_descriptors: body.ns_descriptors,
+            }
+        }
         "sense" => {
             let body: InboundSenseBody = decode_envelope_body(wire.body)?;
             if !is_uuid_v4(&body.sense_instance_id) {
@@ -182,6 +231,8 @@ fn parse_body_afferent_message(line: &str) -> Result<InboundBodyMessage, serde_j
                 payload: body.payload,
                 weight: body.weight,
                 act_instance_id: body.act_instance_id,
+                sequence: body.sequence,
+                signature: body.signature,
             })
         }
         "act_ack" => {
@@ -196,14 +247,23 @@ fn parse_body_afferent_message(line: &str) -> Result<InboundBodyMessage, serde_j
             }
         }
         "unplug" => InboundBodyMessage::Unplug,
-        "act" => {
-            return Err(invalid_correlated_sense_error(
-                "direction violation: endpoint cannot send method 'act'",
-            ));
+        "pause_acts" => InboundBodyMessage::PauseActs,
+        "resume_acts" => InboundBodyMessage::ResumeActs,
+        "pong" => {
+            let body: InboundPongBody = decode_envelope_body(wire.body)?;
+            InboundBodyMessage::Pong {
+                ping_id: body.ping_id,
+            }
+        }
+        "act" | "ping" | "challenge" => {
+            return Err(invalid_correlated_sense_error(&format!(
+                "direction violation: endpoint cannot send method '{}'",
+                wire.method
+            )));
         }
         _ => {
             return Err(invalid_correlated_sense_error(
-                "unsupported method, expected one of: auth|sense|act_ack|unplug|new_proprioceptions|drop_proprioceptions",
+                "unsupported method, expected one of: auth|sense|act_ack|unplug|pause_acts|resume_acts|pong|new_proprioceptions|drop_proprioceptions|update_ns_descriptors",
             ));
         }
     };
@@ -233,6 +293,27 @@ fn encode_body_egress_act_message(act: &Act) -> Result<String, serde_json::Error
     Ok(format!("{encoded}\n"))
 }
 
+fn encode_body_egress_challenge_message(session_nonce: &str) -> Result<String, serde_json::Error> {
+    let encoded = serde_json::to_string(&NdjsonEnvelope {
+        method: "challenge".to_string(),
+        id: uuid::Uuid::new_v4().to_string(),
+        timestamp: timestamp_millis(),
+        body: serde_json::json!({ "nonce": session_nonce }),
+    })?;
+    Ok(format!("{encoded}\n"))
+}
+
+/// Endpoints answer with a `pong` whose `ping_id` echoes this envelope's `id`.
+fn encode_body_egress_ping_message(ping_id: &str) -> Result<String, serde_json::Error> {
+    let encoded = serde_json::to_string(&NdjsonEnvelope {
+        method: "ping".to_string(),
+        id: ping_id.to_string(),
+        timestamp: timestamp_millis(),
+        body: serde_json::json!({}),
+    })?;
+    Ok(format!("{encoded}\n"))
+}
+
 fn timestamp_millis() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -321,6 +402,13 @@ async fn emit_spine_topology_proprioception(port: &Arc<dyn SpineAdapterPort>) {
 pub struct UnixSocketAdapter {
     pub socket_path: PathBuf,
     pub adapter_id: u64,
+    pub max_connections: Option<usize>,
+    pub duplicate_auth_policy: DuplicateAuthPolicy,
+    pub require_signed_senses: bool,
+    pub sense_signing_keys: BTreeMap<String, SenseSigningKey>,
+    pub act_ack_timeout_ms: u64,
+    pub act_ack_max_retries: usize,
+    pub heartbeat: Option<BodyEndpointHeartbeatConfig>,
 }
 
 impl UnixSocketAdapter {
@@ -328,14 +416,24 @@ impl UnixSocketAdapter {
         Self {
             socket_path: config.socket_path,
             adapter_id,
+            max_connections: config.max_connections.map(|max| max.max(1)),
+            duplicate_auth_policy: config.duplicate_auth_policy,
+            require_signed_senses: config.require_signed_senses,
+            sense_signing_keys: decode_sense_signing_keys(&config.sense_signing_keys),
+            act_ack_timeout_ms: config.act_ack_timeout_ms,
+            act_ack_max_retries: config.act_ack_max_retries,
+            heartbeat: config.heartbeat,
         }
     }
 
     pub fn new(socket_path: PathBuf, adapter_id: u64) -> Self {
-        Self {
-            socket_path,
+        Self::from_config(
             adapter_id,
-        }
+            UnixSocketNdjsonAdapterConfig {
+                socket_path,
+                ..UnixSocketNdjsonAdapterConfig::default()
+            },
+        )
     }
 
     pub async fn run(&self, context: AdapterContext) -> Result<()> {
@@ -343,78 +441,23 @@ impl UnixSocketAdapter {
         let listener = UnixListener::bind(&self.socket_path)
             .with_context(|| format!("unable to bind socket {}", self.socket_path.display()))?;
 
-        let AdapterContext {
-            adapter_id,
-            shutdown,
-            act_rx,
-            sense_tx,
-            port,
-        } = context;
-        let sessions = Arc::new(Mutex::new(BTreeMap::new()));
-        let dispatch_task = tokio::spawn(dispatch_adapter_acts(
-            act_rx,
-            Arc::clone(&sessions),
-            Arc::clone(&port),
-            shutdown.clone(),
-        ));
-        let mut next_session_id = 0_u64;
-
-        loop {
-            tokio::select! {
-                _ = shutdown.cancelled() => {
-                    break;
-                }
-                accept_result = listener.accept() => {
-                    match accept_result {
-                        Ok((stream, _)) => {
-                            next_session_id = next_session_id.saturating_add(1);
-                            let session_id = next_session_id;
-                            let port = Arc::clone(&port);
-                            let sense_tx = sense_tx.clone();
-                            let sessions = Arc::clone(&sessions);
-                            let session_span = tracing::info_span!(
-                                target: "spine.unix_socket",
-                                "body_endpoint_session",
-                                adapter_id = adapter_id,
-                                session_id = session_id
-                            );
-                            tokio::spawn(async move {
-                                if let Err(err) =
-                                    handle_body_endpoint(
-                                        stream,
-                                        port,
-                                        sense_tx,
-                                        sessions,
-                                        adapter_id,
-                                        session_id,
-                                    )
-                                        .await
-                                {
-                                    tracing::warn!(
-                                        target: "spine.unix_socket",
-                                        error = ?err,
-                                        "body_endpoint_handling_failed"
-                                    );
-                                }
-                            }.instrument(session_span));
-                        }
-                        Err(err) => {
-                            tracing::warn!(
-                                target: "spine.unix_socket",
-                                error = %err,
-                                "accept_failed"
-                            );
-                        }
-                    }
-                }
-            }
-        }
-
-        dispatch_task.abort();
+        serve_body_endpoints(&listener, context, self.session_policy()).await?;
         Self::cleanup_socket_path(&self.socket_path)?;
         Ok(())
     }
 
+    fn session_policy(&self) -> BodyEndpointSessionPolicy {
+        BodyEndpointSessionPolicy {
+            max_connections: self.max_connections,
+            duplicate_auth_policy: self.duplicate_auth_policy,
+            require_signed_senses: self.require_signed_senses,
+            sense_signing_keys: Arc::new(self.sense_signing_keys.clone()),
+            act_ack_timeout_ms: self.act_ack_timeout_ms,
+            act_ack_max_retries: self.act_ack_max_retries,
+            heartbeat: self.heartbeat,
+        }
+    }
+
     fn prepare_socket_path(path: &Path) -> Result<()> {
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)
@@ -452,6 +495,155 @@ impl UnixSocketAdapter {
     }
 }
 
+/// Listener whose accepted streams carry the body endpoint NDJSON protocol.
+#[async_trait]
+pub(super) trait NdjsonListener: Send + Sync {
+    type Stream: AsyncRead + AsyncWrite + Send + 'static;
+
+    async fn accept_stream(&self) -> io::Result<Self::Stream>;
+}
+
+#[async_trait]
+impl NdjsonListener for UnixListener {
+    type Stream = UnixStream;
+
+    async fn accept_stream(&self) -> io::Result<UnixStream> {
+        self.accept().await.map(|(stream, _)| stream)
+    }
+}
+
+/// Per-connection limits shared by the NDJSON adapters.
+#[derive(Debug, Clone)]
+pub(super) struct BodyEndpointSessionPolicy {
+    /// Concurrent session cap; `None` accepts every connection.
+    pub(super) max_connections: Option<usize>,
+    pub(super) duplicate_auth_policy: DuplicateAuthPolicy,
+    pub(super) require_signed_senses: bool,
+    pub(super) sense_signing_keys: Arc<BTreeMap<String, SenseSigningKey>>,
+    pub(super) act_ack_timeout_ms: u64,
+    pub(super) act_ack_max_retries: usize,
+    pub(super) heartbeat: Option<BodyEndpointHeartbeatConfig>,
+}
+
+impl Default for BodyEndpointSessionPolicy {
+    fn default() -> Self {
+        UnixSocketAdapter::from_config(0, UnixSocketNdjsonAdapterConfig::default()).session_policy()
+    }
+}
+
+/// Adapter-wide handles every body endpoint session of one adapter shares.
+#[derive(Clone)]
+struct BodyEndpointSessionContext {
+    port: Arc<dyn SpineAdapterPort>,
+    sense_tx: mpsc::UnboundedSender<Sense>,
+    sessions: SessionActSenders,
+    channel_stats: AdapterChannelStats,
+}
+
+/// Runs one body endpoint session per accepted stream until `context.shutdown` fires.
+pub(super) async fn serve_body_endpoints<L: NdjsonListener>(
+    listener: &L,
+    context: AdapterContext,
+    policy: BodyEndpointSessionPolicy,
+) -> Result<()> {
+    let AdapterContext {
+        adapter_id,
+        execution_mode: _,
+        shutdown,
+        act_rx,
+        sense_tx,
+        port,
+        channel_stats,
+    } = context;
+    let sessions = Arc::new(Mutex::new(BTreeMap::new()));
+    let dispatch_task = tokio::spawn(dispatch_adapter_acts(
+        act_rx,
+        Arc::clone(&sessions),
+        Arc::clone(&port),
+        shutdown.clone(),
+        channel_stats.clone(),
+    ));
+    let session_context = BodyEndpointSessionContext {
+        port,
+        sense_tx,
+        sessions,
+        channel_stats,
+    };
+    let connection_permits = policy
+        .max_connections
+        .map(|max_connections| Arc::new(Semaphore::new(max_connections)));
+    let mut next_session_id = 0_u64;
+
+    loop {
+        tokio::select! {
+            _ = shutdown.cancelled() => {
+                break;
+            }
+            accept_result = listener.accept_stream() => {
+                match accept_result {
+                    Ok(stream) => {
+                        let permit = match &connection_permits {
+                            Some(permits) => match Arc::clone(permits).try_acquire_owned() {
+                                Ok(permit) => Some(permit),
+                                Err(_) => {
+                                    tracing::warn!(
+                                        target: "spine.unix_socket",
+                                        adapter_id = adapter_id,
+                                        max_connections = policy.max_connections,
+                                        "connection_rejected_max_connections"
+                                    );
+                                    drop(stream);
+                                    continue;
+                                }
+                            },
+                            None => None,
+                        };
+                        next_session_id = next_session_id.saturating_add(1);
+                        let session_id = next_session_id;
+                        let session_context = session_context.clone();
+                        let policy = policy.clone();
+                        let session_span = tracing::info_span!(
+                            target: "spine.unix_socket",
+                            "body_endpoint_session",
+                            adapter_id = adapter_id,
+                            session_id = session_id
+                        );
+                        tokio::spawn(async move {
+                            let _permit = permit;
+                            if let Err(err) =
+                                handle_body_endpoint(
+                                    stream,
+                                    session_context,
+                                    policy,
+                                    adapter_id,
+                                    session_id,
+                                )
+                                    .await
+                            {
+                                tracing::warn!(
+                                    target: "spine.unix_socket",
+                                    error = ?err,
+                                    "body_endpoint_handling_failed"
+                                );
+                            }
+                        }.instrument(session_span));
+                    }
+                    Err(err) => {
+                        tracing::warn!(
+                            target: "spine.unix_socket",
+                            error = %err,
+                            "accept_failed"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    dispatch_task.abort();
+    Ok(())
+}
+
 pub fn spawn_adapter_task(
     config: UnixSocketNdjsonAdapterConfig,
     context: AdapterContext,
@@ -497,14 +689,12 @@ pub fn spawn_adapter_task(
     )
 }
 
-const ACT_ACK_TIMEOUT_MS: u64 = 1_500;
-const ACT_ACK_MAX_RETRIES: usize = 2;
-
 async fn dispatch_adapter_acts(
-    mut act_rx: mpsc::UnboundedReceiver<Act>,
+    mut act_rx: mpsc::UnboundedReceiver<InFlightAct>,
     sessions: SessionActSenders,
     port: Arc<dyn SpineAdapterPort>,
     shutdown: CancellationToken,
+    channel_stats: AdapterChannelStats,
 ) -> Result<()> {
     loop {
         tokio::select! {
@@ -512,9 +702,10 @@ async fn dispatch_adapter_acts(
                 break;
             }
             maybe_act = act_rx.recv() => {
-                let Some(act) = maybe_act else {
+                let Some(in_flight) = maybe_act else {
                     break;
                 };
+                let act = &in_flight.act;
                 let tx = {
                     sessions
                         .lock()
@@ -533,18 +724,23 @@ async fn dispatch_adapter_acts(
                     port.publish_topology_proprioception_snapshot().await;
                     continue;
                 };
-                if tx.send(act.clone()).is_err() {
+                let endpoint_id = act.endpoint_id.clone();
+                let act_instance_id = act.act_instance_id.clone();
+                // Counted before the send so the writer's `record_sent` never runs first.
+                channel_stats.record_enqueued(&endpoint_id);
+                if tx.send(in_flight).is_err() {
+                    channel_stats.record_unqueued(&endpoint_id);
                     tracing::warn!(
                         target: "spine.unix_socket",
-                        endpoint_id = %act.endpoint_id,
-                        act_instance_id = %act.act_instance_id,
+                        endpoint_id = %endpoint_id,
+                        act_instance_id = %act_instance_id,
                         "unix_socket_session_closed_for_dispatch"
                     );
                     sessions
                         .lock()
                         .expect("lock poisoned")
-                        .remove(&act.endpoint_id);
-                    port.drop_endpoint(&act.endpoint_id).await;
+                        .remove(&endpoint_id);
+                    port.drop_endpoint(&endpoint_id).await;
                     port.publish_topology_proprioception_snapshot().await;
                 }
             }
@@ -578,33 +774,214 @@ async fn wait_for_act_ack(
     }
 }
 
+async fn next_heartbeat_tick(interval: &mut Option<tokio::time::Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Resolves once the oldest pending `ping` has gone unanswered for longer than `pong_timeout_ms`.
+async fn pong_deadline(
+    oldest_pending_ping: Option<Instant>,
+    heartbeat: Option<BodyEndpointHeartbeatConfig>,
+) {
+    match (oldest_pending_ping, heartbeat) {
+        (Some(sent_at), Some(heartbeat)) => {
+            tokio::time::sleep_until(sent_at + Duration::from_millis(heartbeat.pong_timeout_ms))
+                .await
+        }
+        _ => std::future::pending().await,
+    }
+}
+
+fn route_key(descriptor: &NeuralSignalDescriptor) -> NeuralSignalDescriptorRouteKey {
+    NeuralSignalDescriptorRouteKey {
+        r#type: descriptor.r#type,
+        endpoint_id: descriptor.endpoint_id.clone(),
+        neural_signal_descriptor_id: descriptor.neural_signal_descriptor_id.clone(),
+    }
+}
+
+/// Swaps an authenticated session's descriptors and proprioceptions for a new `auth` body.
+///
+/// New entries are committed before stale ones are dropped, so routes present in both bodies stay
+/// dispatchable throughout.
+async fn reauth_body_endpoint(
+    port: &Arc<dyn SpineAdapterPort>,
+    body_endpoint_id: &str,
+    ns_descriptors: Vec<NeuralSignalDescriptor>,
+    proprioceptions: &BTreeMap<String, String>,
+    auth_routes: &mut BTreeSet<NeuralSignalDescriptorRouteKey>,
+    endpoint_proprioception_keys: &mut BTreeSet<String>,
+) {
+    let accepted = match port
+        .add_ns_descriptors(body_endpoint_id, ns_descriptors)
+        .await
+    {
+        Ok(accepted) => accepted,
+        Err(err) => {
+            tracing::warn!(
+                target: "spine.unix_socket",
+                error = ?err,
+                "body_endpoint_ns_descriptor_registration_failed_during_reauth"
+            );
+            return;
+        }
+    };
+    let next_routes = accepted.iter().map(route_key).collect::<BTreeSet<_>>();
+    let stale_routes = auth_routes
+        .difference(&next_routes)
+        .cloned()
+        .collect::<Vec<_>>();
+    if !stale_routes.is_empty()
+        && let Err(err) = port
+            .drop_ns_descriptors(body_endpoint_id, stale_routes)
+            .await
+    {
+        tracing::warn!(
+            target: "spine.unix_socket",
+            error = ?err,
+            "body_endpoint_stale_ns_descriptor_drop_failed_during_reauth"
+        );
+    }
+    *auth_routes = next_routes;
+
+    let namespaced_entries =
+        namespaced_body_proprioception_entries(body_endpoint_id, proprioceptions);
+    let stale_keys = endpoint_proprioception_keys
+        .iter()
+        .filter(|key| !namespaced_entries.contains_key(*key))
+        .cloned()
+        .collect::<Vec<_>>();
+    *endpoint_proprioception_keys = namespaced_entries.keys().cloned().collect();
+    emit_proprioception_patch(port, namespaced_entries, "reauth_proprioception_patch").await;
+    if !stale_keys.is_empty() {
+        emit_proprioception_drop(port, stale_keys, "reauth_proprioception_drop").await;
+    }
+    emit_spine_topology_proprioception(port).await;
+    tracing::info!(
+        target: "spine.unix_socket",
+        body_endpoint_id = %body_endpoint_id,
+        "body_endpoint_reauthenticated"
+    );
+}
+
+/// Checks an `auth` against the adapter's pre-shared keys.
+///
+/// Returns the key the session's senses must be signed with, or the reason the `auth` is rejected.
+fn authenticate_endpoint(
+    policy: &BodyEndpointSessionPolicy,
+    session_nonce: Option<&str>,
+    endpoint_name: &str,
+    auth_signature: Option<&str>,
+) -> Result<Option<SenseSigningKey>, &'static str> {
+    let Some(key) = policy.sense_signing_keys.get(endpoint_name) else {
+        if policy.require_signed_senses || auth_signature.is_some() {
+            return Err("sense_signing_key_not_configured");
+        }
+        return Ok(None);
+    };
+    let (Some(session_nonce), Some(auth_signature)) = (session_nonce, auth_signature) else {
+        return Err("auth_signature_missing");
+    };
+    if !key.verify_auth(session_nonce, endpoint_name, auth_signature) {
+        return Err("auth_signature_invalid");
+    }
+    Ok(Some(key.clone()))
+}
+
+async fn set_act_payload_transform(
+    port: &Arc<dyn SpineAdapterPort>,
+    body_endpoint_id: &str,
+    transform: ActPayloadTransform,
+) {
+    if let Err(err) = port
+        .set_act_payload_transform(body_endpoint_id, transform)
+        .await
+    {
+        tracing::warn!(
+            target: "spine.unix_socket",
+            error = ?err,
+            "body_endpoint_act_payload_transform_registration_failed"
+        );
+    }
+}
+
 #[tracing::instrument(
     name = "handle_body_endpoint",
     target = "spine.unix_socket",
-    skip(stream, port, sense_tx, sessions),
+    skip(stream, context, policy),
     fields(adapter_id = adapter_id, session_id = session_id)
 )]
-async fn handle_body_endpoint(
-    stream: UnixStream,
-    port: Arc<dyn SpineAdapterPort>,
-    sense_tx: mpsc::UnboundedSender<Sense>,
-    sessions: SessionActSenders,
+async fn handle_body_endpoint<S>(
+    stream: S,
+    context: BodyEndpointSessionContext,
+    policy: BodyEndpointSessionPolicy,
     adapter_id: u64,
     session_id: u64,
-) -> Result<()> {
-    let (read_half, mut write_half) = stream.into_split();
+) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Send + 'static,
+{
+    let BodyEndpointSessionContext {
+        port,
+        sense_tx,
+        sessions,
+        channel_stats,
+    } = context;
+    let (read_half, mut write_half) = tokio::io::split(stream);
+    let session_nonce =
+        (!policy.sense_signing_keys.is_empty()).then(|| uuid::Uuid::new_v4().simple().to_string());
+    if let Some(session_nonce) = session_nonce.as_deref() {
+        let encoded = encode_body_egress_challenge_message(session_nonce)?;
+        write_half.write_all(encoded.as_bytes()).await?;
+        write_half.flush().await?;
+    }
 
-    let (outbound_tx, mut outbound_rx) = mpsc::unbounded_channel::<Act>();
+    let (outbound_tx, mut outbound_rx) = mpsc::unbounded_channel::<InFlightAct>();
     let (ack_tx, mut ack_rx) = mpsc::unbounded_channel::<String>();
+    let (ping_sent_tx, mut ping_sent_rx) = mpsc::unbounded_channel::<()>();
+    let pending_pings: PendingPings = Arc::new(Mutex::new(BTreeMap::new()));
 
     let writer_span = tracing::debug_span!(
         target: "spine.unix_socket",
         "body_endpoint_writer_task",
         session_id = session_id
     );
+    let writer_port = Arc::clone(&port);
+    let writer_pending_pings = Arc::clone(&pending_pings);
     let writer_task = tokio::spawn(
         async move {
-            while let Some(act) = outbound_rx.recv().await {
+            let mut ping_interval = policy.heartbeat.map(|heartbeat| {
+                let period = Duration::from_millis(heartbeat.interval_ms);
+                tokio::time::interval_at(Instant::now() + period, period)
+            });
+            loop {
+                let in_flight = tokio::select! {
+                    maybe_act = outbound_rx.recv() => {
+                        let Some(in_flight) = maybe_act else {
+                            break;
+                        };
+                        in_flight
+                    }
+                    _ = next_heartbeat_tick(&mut ping_interval) => {
+                        let ping_id = uuid::Uuid::new_v4().to_string();
+                        // Recorded before the write so even an immediate pong finds its ping.
+                        writer_pending_pings
+                            .lock()
+                            .expect("lock poisoned")
+                            .insert(ping_id.clone(), Instant::now());
+                        let _ = ping_sent_tx.send(());
+                        let encoded = encode_body_egress_ping_message(&ping_id)?;
+                        write_half.write_all(encoded.as_bytes()).await?;
+                        write_half.flush().await?;
+                        continue;
+                    }
+                };
+                let act = &in_flight.act;
                 let dispatch_started_at = Instant::now();
                 tracing::debug!(
                     target: "spine.unix_socket",
@@ -615,13 +992,27 @@ async fn handle_body_endpoint(
                     "dispatching_act_to_unix_socket_endpoint"
                 );
                 let mut acknowledged = false;
-                for attempt in 0..=ACT_ACK_MAX_RETRIES {
-                    let encoded = encode_body_egress_act_message(&act)?;
+                for attempt in 0..=policy.act_ack_max_retries {
+                    if attempt == 0 {
+                        channel_stats.record_sent(&act.endpoint_id);
+                    } else {
+                        channel_stats.record_retry(&act.endpoint_id);
+                    }
+                    let encoded = encode_body_egress_act_message(act)?;
                     write_half.write_all(encoded.as_bytes()).await?;
                     write_half.flush().await?;
 
-                    if wait_for_act_ack(&mut ack_rx, &act.act_instance_id, ACT_ACK_TIMEOUT_MS).await
-                    {
+                    let acked = wait_for_act_ack(
+                        &mut ack_rx,
+                        &act.act_instance_id,
+                        policy.act_ack_timeout_ms,
+                    )
+                    .await;
+                    writer_port
+                        .record_act_delivery(&act.endpoint_id, acked)
+                        .await;
+                    if acked {
+                        channel_stats.record_ack(&act.endpoint_id);
                         acknowledged = true;
                         tracing::info!(
                             target: "spine.unix_socket",
@@ -634,7 +1025,7 @@ async fn handle_body_endpoint(
                         break;
                     }
 
-                    if attempt < ACT_ACK_MAX_RETRIES {
+                    if attempt < policy.act_ack_max_retries {
                         tracing::warn!(
                             target: "spine.unix_socket",
                             act_instance_id = %act.act_instance_id,
@@ -649,7 +1040,7 @@ async fn handle_body_endpoint(
                         target: "spine.unix_socket",
                         session_id = session_id,
                         act_instance_id = %act.act_instance_id,
-                        attempts = ACT_ACK_MAX_RETRIES + 1,
+                        attempts = policy.act_ack_max_retries + 1,
                         latency_ms = dispatch_started_at.elapsed().as_millis() as u64,
                         "act_dispatch_failed_after_ack_retries"
                     );
@@ -667,9 +1058,38 @@ async fn handle_body_endpoint(
 
     let mut lines = BufReader::new(read_half).lines();
     let mut auth_endpoint_id: Option<String> = None;
+    let mut auth_endpoint_name = String::new();
+    let mut auth_routes = BTreeSet::new();
     let mut endpoint_proprioception_keys = BTreeSet::new();
+    let mut sense_signing_key: Option<SenseSigningKey> = None;
+    let mut last_sense_sequence: Option<u64> = None;
 
-    while let Some(line) = lines.next_line().await? {
+    loop {
+        let oldest_pending_ping = pending_pings
+            .lock()
+            .expect("lock poisoned")
+            .values()
+            .min()
+            .copied();
+        let line = tokio::select! {
+            line = lines.next_line() => {
+                let Some(line) = line? else {
+                    break;
+                };
+                line
+            }
+            // A new ping only needs the pong deadline recomputed.
+            Some(()) = ping_sent_rx.recv() => continue,
+            _ = pong_deadline(oldest_pending_ping, policy.heartbeat) => {
+                tracing::warn!(
+                    target: "spine.unix_socket",
+                    session_id = session_id,
+                    endpoint_id = auth_endpoint_id.as_deref().unwrap_or_default(),
+                    "heartbeat_pong_missed_closing_session"
+                );
+                break;
+            }
+        };
         let line = line.trim();
         if line.is_empty() {
             continue;
@@ -681,12 +1101,68 @@ async fn handle_body_endpoint(
                     endpoint_name,
                     ns_descriptors,
                     proprioceptions,
+                    act_payload_transform,
+                    auth_signature,
                 } => {
-                    if auth_endpoint_id.is_some() {
-                        tracing::warn!(
-                            target: "spine.unix_socket",
-                            "auth_ignored_endpoint_already_authenticated_on_session"
-                        );
+                    let endpoint_signing_key = match authenticate_endpoint(
+                        &policy,
+                        session_nonce.as_deref(),
+                        &endpoint_name,
+                        auth_signature.as_deref(),
+                    ) {
+                        Ok(key) => key,
+                        Err(reason) => {
+                            tracing::warn!(
+                                target: "spine.unix_socket",
+                                endpoint_name = %endpoint_name,
+                                reason = reason,
+                                "auth_rejected_closing_session"
+                            );
+                            break;
+                        }
+                    };
+                    if let Some(body_endpoint_id) = auth_endpoint_id.as_deref() {
+                        match policy.duplicate_auth_policy {
+                            DuplicateAuthPolicy::Ignore => {
+                                tracing::warn!(
+                                    target: "spine.unix_socket",
+                                    "auth_ignored_endpoint_already_authenticated_on_session"
+                                );
+                            }
+                            DuplicateAuthPolicy::RejectAndClose => {
+                                tracing::warn!(
+                                    target: "spine.unix_socket",
+                                    "auth_rejected_closing_already_authenticated_session"
+                                );
+                                break;
+                            }
+                            DuplicateAuthPolicy::Reauth if endpoint_name != auth_endpoint_name => {
+                                tracing::warn!(
+                                    target: "spine.unix_socket",
+                                    endpoint_name = %endpoint_name,
+                                    authenticated_endpoint_name = %auth_endpoint_name,
+                                    "reauth_rejected_endpoint_name_mismatch"
+                                );
+                            }
+                            DuplicateAuthPolicy::Reauth => {
+                                reauth_body_endpoint(
+                                    &port,
+                                    body_endpoint_id,
+                                    ns_descriptors,
+                                    &proprioceptions,
+                                    &mut auth_routes,
+                                    &mut endpoint_proprioception_keys,
+                                )
+                                .await;
+                                set_act_payload_transform(
+                                    &port,
+                                    body_endpoint_id,
+                                    act_payload_transform.unwrap_or_default(),
+                                )
+                                .await;
+                                sense_signing_key = endpoint_signing_key;
+                            }
+                        }
                         continue;
                     }
                     if endpoint_name.trim().is_empty() {
@@ -709,20 +1185,28 @@ async fn handle_body_endpoint(
                         }
                     };
                     auth_endpoint_id = Some(handle.body_endpoint_id.clone());
+                    auth_endpoint_name = endpoint_name;
+                    sense_signing_key = endpoint_signing_key;
                     sessions
                         .lock()
                         .expect("lock poisoned")
                         .insert(handle.body_endpoint_id.clone(), outbound_tx.clone());
+                    if let Some(transform) = act_payload_transform {
+                        set_act_payload_transform(&port, &handle.body_endpoint_id, transform).await;
+                    }
 
-                    if let Err(err) = port
+                    match port
                         .add_ns_descriptors(&handle.body_endpoint_id, ns_descriptors)
                         .await
                     {
-                        tracing::warn!(
-                            target: "spine.unix_socket",
-                            error = ?err,
-                            "body_endpoint_ns_descriptor_registration_failed_during_auth"
-                        );
+                        Ok(accepted) => auth_routes.extend(accepted.iter().map(route_key)),
+                        Err(err) => {
+                            tracing::warn!(
+                                target: "spine.unix_socket",
+                                error = ?err,
+                                "body_endpoint_ns_descriptor_registration_failed_during_auth"
+                            );
+                        }
                     }
 
                     let namespaced_entries = namespaced_body_proprioception_entries(
@@ -770,6 +1254,23 @@ async fn handle_body_endpoint(
                     }
                     break;
                 }
+                toggle @ (InboundBodyMessage::PauseActs | InboundBodyMessage::ResumeActs) => {
+                    let Some(body_endpoint_id) = auth_endpoint_id.as_deref() else {
+                        tracing::warn!(
+                            target: "spine.unix_socket",
+                            "act_pause_rejected_endpoint_must_auth_first"
+                        );
+                        continue;
+                    };
+                    let paused = toggle == InboundBodyMessage::PauseActs;
+                    port.set_act_dispatch_paused(body_endpoint_id, paused).await;
+                }
+                InboundBodyMessage::Pong { ping_id } => {
+                    pending_pings
+                        .lock()
+                        .expect("lock poisoned")
+                        .remove(&ping_id);
+                }
                 InboundBodyMessage::Sense(sense) => {
                     let Some(body_endpoint_id) = auth_endpoint_id.as_deref() else {
                         tracing::warn!(
@@ -778,6 +1279,41 @@ async fn handle_body_endpoint(
                         );
                         continue;
                     };
+                    if let Some(key) = sense_signing_key.as_ref() {
+                        let verified = match (
+                            session_nonce.as_deref(),
+                            sense.sequence,
+                            sense.signature.as_deref(),
+                        ) {
+                            (Some(session_nonce), Some(sequence), Some(signature))
+                                if last_sense_sequence.is_none_or(|last| sequence > last) =>
+                            {
+                                key.verify_sense(
+                                    &SignedSenseFields {
+                                        session_nonce,
+                                        endpoint_name: &auth_endpoint_name,
+                                        sequence,
+                                        sense_instance_id: &sense.sense_instance_id,
+                                        neural_signal_descriptor_id: &sense
+                                            .neural_signal_descriptor_id,
+                                        payload: &sense.payload,
+                                        weight: sense.weight,
+                                    },
+                                    signature,
+                                )
+                            }
+                            _ => false,
+                        };
+                        if !verified {
+                            tracing::warn!(
+                                target: "spine.unix_socket",
+                                sense_instance_id = %sense.sense_instance_id,
+                                "sense_rejected_invalid_signature"
+                            );
+                            continue;
+                        }
+                        last_sense_sequence = sense.sequence;
+                    }
 
                     // Adapter injects endpoint_id from authenticated endpoint binding.
                     let sense = Sense {
@@ -828,6 +1364,26 @@ async fn handle_body_endpoint(
                     emit_proprioception_drop(&port, namespaced_keys, "runtime_proprioception_drop")
                         .await;
                 }
+                InboundBodyMessage::UpdateNsDescriptors { ns_descriptors } => {
+                    let Some(body_endpoint_id) = auth_endpoint_id.as_deref() else {
+                        tracing::warn!(
+                            target: "spine.unix_socket",
+                            "update_ns_descriptors_rejected_endpoint_must_auth_first"
+                        );
+                        continue;
+                    };
+
+                    if let Err(err) = port
+                        .update_ns_descriptors(body_endpoint_id, ns_descriptors)
+                        .await
+                    {
+                        tracing::warn!(
+                            target: "spine.unix_socket",
+                            error = ?err,
+                            "body_endpoint_ns_descriptor_update_failed"
+                        );
+                    }
+                }
             },
             Err(err) => {
                 tracing::warn!(
@@ -853,7 +1409,843 @@ async fn handle_body_endpoint(
     }
     emit_spine_topology_proprioception(&port).await;
 
+    drop(outbound_tx);
     writer_task.await??;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use tokio::io::AsyncReadExt;
+
+    use super::*;
+    use crate::{
+        spine::{ChannelStatsSnapshot, SpineExecutionMode, runtime::BodyEndpointHandle},
+        types::NeuralSignalDescriptorRouteKey,
+    };
+
+    struct NoopPort;
+
+    #[async_trait]
+    impl SpineAdapterPort for NoopPort {
+        async fn register_endpoint(
+            &self,
+            _adapter_id: u64,
+            endpoint_name: &str,
+        ) -> Result<BodyEndpointHandle> {
+            Ok(BodyEndpointHandle {
+                body_endpoint_id: format!("{endpoint_name}.1"),
+            })
+        }
+
+        async fn add_ns_descriptors(
+            &self,
+            _body_endpoint_id: &str,
+            descriptors: Vec<NeuralSignalDescriptor>,
+        ) -> Result<Vec<NeuralSignalDescriptor>> {
+            Ok(descriptors)
+        }
+
+        async fn update_ns_descriptors(
+            &self,
+            _body_endpoint_id: &str,
+            descriptors: Vec<NeuralSignalDescriptor>,
+        ) -> Result<Vec<NeuralSignalDescriptor>> {
+            Ok(descriptors)
+        }
+
+        async fn drop_ns_descriptors(
+            &self,
+            _body_endpoint_id: &str,
+            routes: Vec<NeuralSignalDescriptorRouteKey>,
+        ) -> Result<Vec<NeuralSignalDescriptorRouteKey>> {
+            Ok(routes)
+        }
+
+        async fn set_act_payload_transform(
+            &self,
+            _body_endpoint_id: &str,
+            _transform: ActPayloadTransform,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        async fn drop_endpoint(&self, _body_endpoint_id: &str) {}
+
+        async fn set_act_dispatch_paused(&self, _body_endpoint_id: &str, _paused: bool) {}
+
+        async fn record_act_delivery(&self, _body_endpoint_id: &str, _delivered: bool) {}
+
+        async fn apply_proprioception_patch(&self, _entries: BTreeMap<String, String>) {}
+
+        async fn apply_proprioception_drop(&self, _keys: Vec<String>) {}
+
+        async fn publish_topology_proprioception_snapshot(&self) {}
+    }
+
+    #[derive(Default)]
+    struct RecordingPort {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl RecordingPort {
+        fn record(&self, event: String) {
+            self.events.lock().expect("lock poisoned").push(event);
+        }
+
+        fn events(&self) -> Vec<String> {
+            self.events.lock().expect("lock poisoned").clone()
+        }
+    }
+
+    #[async_trait]
+    impl SpineAdapterPort for RecordingPort {
+        async fn register_endpoint(
+            &self,
+            _adapter_id: u64,
+            endpoint_name: &str,
+        ) -> Result<BodyEndpointHandle> {
+            self.record(format!("register:{endpoint_name}"));
+            Ok(BodyEndpointHandle {
+                body_endpoint_id: format!("{endpoint_name}.1"),
+            })
+        }
+
+        async fn add_ns_descriptors(
+            &self,
+            body_endpoint_id: &str,
+            descriptors: Vec<NeuralSignalDescriptor>,
+        ) -> Result<Vec<NeuralSignalDescriptor>> {
+            Ok(descriptors
+                .into_iter()
+                .map(|mut descriptor| {
+                    descriptor.endpoint_id = body_endpoint_id.to_string();
+                    self.record(format!("add:{}", descriptor.neural_signal_descriptor_id));
+                    descriptor
+                })
+                .collect())
+        }
+
+        async fn update_ns_descriptors(
+            &self,
+            _body_endpoint_id: &str,
+            descriptors: Vec<NeuralSignalDescriptor>,
+        ) -> Result<Vec<NeuralSignalDescriptor>> {
+            Ok(descriptors)
+        }
+
+        async fn drop_ns_descriptors(
+            &self,
+            _body_endpoint_id: &str,
+            routes: Vec<NeuralSignalDescriptorRouteKey>,
+        ) -> Result<Vec<NeuralSignalDescriptorRouteKey>> {
+            for route in &routes {
+                self.record(format!("drop:{}", route.neural_signal_descriptor_id));
+            }
+            Ok(routes)
+        }
+
+        async fn set_act_payload_transform(
+            &self,
+            _body_endpoint_id: &str,
+            _transform: ActPayloadTransform,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        async fn drop_endpoint(&self, body_endpoint_id: &str) {
+            self.record(format!("drop_endpoint:{body_endpoint_id}"));
+        }
+
+        async fn set_act_dispatch_paused(&self, body_endpoint_id: &str, paused: bool) {
+            self.record(format!("act_dispatch_paused:{body_endpoint_id}={paused}"));
+        }
+
+        async fn record_act_delivery(&self, _body_endpoint_id: &str, _delivered: bool) {}
+
+        async fn apply_proprioception_patch(&self, entries: BTreeMap<String, String>) {
+            for (key, value) in entries {
+                self.record(format!("proprioception:{key}={value}"));
+            }
+        }
+
+        async fn apply_proprioception_drop(&self, keys: Vec<String>) {
+            for key in keys {
+                self.record(format!("proprioception_drop:{key}"));
+            }
+        }
+
+        async fn publish_topology_proprioception_snapshot(&self) {}
+    }
+
+    fn auth_line(neural_signal_descriptor_id: &str, proprioception_key: &str) -> String {
+        let envelope = serde_json::json!({
+            "method": "auth",
+            "id": uuid::Uuid::new_v4().to_string(),
+            "timestamp": 1,
+            "body": {
+                "endpoint_name": "tool",
+                "ns_descriptors": [{
+                    "type": "act",
+                    "endpoint_id": "tool",
+                    "neural_signal_descriptor_id": neural_signal_descriptor_id,
+                    "payload_schema": { "type": "object" }
+                }],
+                "proprioceptions": { proprioception_key: "on" }
+            }
+        });
+        format!("{envelope}\n")
+    }
+
+    /// Sends two `auth` messages and reports the port events and whether the session closed.
+    async fn run_duplicate_auth_session(policy: DuplicateAuthPolicy) -> (Vec<String>, bool) {
+        let (server, mut client) = UnixStream::pair().expect("stream pair");
+        let port = Arc::new(RecordingPort::default());
+        let (sense_tx, _sense_rx) = mpsc::unbounded_channel();
+        let session = tokio::spawn(handle_body_endpoint(
+            server,
+            BodyEndpointSessionContext {
+                port: port.clone(),
+                sense_tx,
+                sessions: Arc::new(Mutex::new(BTreeMap::new())),
+                channel_stats: AdapterChannelStats::default(),
+            },
+            BodyEndpointSessionPolicy {
+                duplicate_auth_policy: policy,
+                ..BodyEndpointSessionPolicy::default()
+            },
+            1,
+            1,
+        ));
+
+        client
+            .write_all(auth_line("run", "first").as_bytes())
+            .await
+            .expect("first auth should be written");
+        client
+            .write_all(auth_line("probe", "second").as_bytes())
+            .await
+            .expect("second auth should be written");
+        let mut buffer = [0_u8; 1];
+        let closed_by_server = matches!(
+            timeout(Duration::from_millis(200), client.read(&mut buffer)).await,
+            Ok(Ok(0))
+        );
+        let events_before_disconnect = port.events();
+
+        client.shutdown().await.expect("client shutdown");
+        timeout(Duration::from_secs(1), session)
+            .await
+            .expect("session should finish")
+            .expect("session task should join")
+            .expect("session should end cleanly");
+        (events_before_disconnect, closed_by_server)
+    }
+
+    #[tokio::test]
+    async fn duplicate_auth_is_ignored_by_default_policy() {
+        let (events, closed) = run_duplicate_auth_session(DuplicateAuthPolicy::Ignore).await;
+
+        assert!(!closed);
+        assert_eq!(
+            events,
+            vec![
+                "register:tool".to_string(),
+                "add:run".to_string(),
+                "proprioception:body.tool.1.first=on".to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn duplicate_auth_closes_session_under_reject_policy() {
+        let (events, closed) =
+            run_duplicate_auth_session(DuplicateAuthPolicy::RejectAndClose).await;
+
+        assert!(closed);
+        assert!(!events.contains(&"add:probe".to_string()));
+        assert!(events.contains(&"drop_endpoint:tool.1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn pause_and_resume_acts_reach_the_port_for_the_authed_endpoint() {
+        let (server, mut client) = UnixStream::pair().expect("stream pair");
+        let port = Arc::new(RecordingPort::default());
+        let (sense_tx, _sense_rx) = mpsc::unbounded_channel();
+        let session = tokio::spawn(handle_body_endpoint(
+            server,
+            BodyEndpointSessionContext {
+                port: port.clone(),
+                sense_tx,
+                sessions: Arc::new(Mutex::new(BTreeMap::new())),
+                channel_stats: AdapterChannelStats::default(),
+            },
+            BodyEndpointSessionPolicy::default(),
+            1,
+            1,
+        ));
+
+        let control_line = |method: &str| {
+            format!(
+                "{}\n",
+                serde_json::json!({
+                    "method": method,
+                    "id": uuid::Uuid::new_v4().to_string(),
+                    "timestamp": 1,
+                    "body": {}
+                })
+            )
+        };
+        client
+            .write_all(control_line("pause_acts").as_bytes())
+            .await
+            .expect("unauthenticated pause should be written");
+        client
+            .write_all(auth_line("run", "ready").as_bytes())
+            .await
+            .expect("auth should be written");
+        for method in ["pause_acts", "resume_acts"] {
+            client
+                .write_all(control_line(method).as_bytes())
+                .await
+                .expect("control message should be written");
+        }
+        client.shutdown().await.expect("client shutdown");
+        timeout(Duration::from_secs(1), session)
+            .await
+            .expect("session should finish")
+            .expect("session task should join")
+            .expect("session should end cleanly");
+
+        let toggles = port
+            .events()
+            .into_iter()
+            .filter(|event| event.starts_with("act_dispatch_paused:"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            toggles,
+            vec![
+                "act_dispatch_paused:tool.1=true".to_string(),
+                "act_dispatch_paused:tool.1=false".to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn endpoint_that_stops_answering_pings_is_dropped() {
+        let (server, client) = UnixStream::pair().expect("stream pair");
+        let port = Arc::new(RecordingPort::default());
+        let (sense_tx, _sense_rx) = mpsc::unbounded_channel();
+        let session = tokio::spawn(handle_body_endpoint(
+            server,
+            BodyEndpointSessionContext {
+                port: port.clone(),
+                sense_tx,
+                sessions: Arc::new(Mutex::new(BTreeMap::new())),
+                channel_stats: AdapterChannelStats::default(),
+            },
+            BodyEndpointSessionPolicy {
+                heartbeat: Some(BodyEndpointHeartbeatConfig {
+                    interval_ms: 20,
+                    pong_timeout_ms: 100,
+                }),
+                ..BodyEndpointSessionPolicy::default()
+            },
+            1,
+            1,
+        ));
+        let (client_read, mut client_write) = tokio::io::split(client);
+        let mut client_lines = BufReader::new(client_read).lines();
+        client_write
+            .write_all(auth_line("run", "probe").as_bytes())
+            .await
+            .expect("auth should be written");
+
+        for _ in 0..5 {
+            let line = timeout(Duration::from_secs(1), client_lines.next_line())
+                .await
+                .expect("ping should arrive")
+                .expect("ping should be readable")
+                .expect("session should stay open");
+            let ping: NdjsonEnvelope<serde_json::Value> =
+                serde_json::from_str(&line).expect("ping should be an envelope");
+            assert_eq!(ping.method, "ping");
+            let pong = serde_json::json!({
+                "method": "pong",
+                "id": uuid::Uuid::new_v4().to_string(),
+                "timestamp": 1,
+                "body": { "ping_id": ping.id }
+            });
+            client_write
+                .write_all(format!("{pong}\n").as_bytes())
+                .await
+                .expect("pong should be written");
+        }
+        assert!(!port.events().contains(&"drop_endpoint:tool.1".to_string()));
+
+        timeout(Duration::from_secs(1), session)
+            .await
+            .expect("missed pong should end the session")
+            .expect("session task should join")
+            .expect("session should end cleanly");
+        assert!(port.events().contains(&"drop_endpoint:tool.1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn duplicate_auth_replaces_capabilities_under_reauth_policy() {
+        let (events, closed) = run_duplicate_auth_session(DuplicateAuthPolicy::Reauth).await;
+
+        assert!(!closed);
+        assert_eq!(
+            events,
+            vec![
+                "register:tool".to_string(),
+                "add:run".to_string(),
+                "proprioception:body.tool.1.first=on".to_string(),
+                "add:probe".to_string(),
+                "drop:run".to_string(),
+                "proprioception:body.tool.1.second=on".to_string(),
+                "proprioception_drop:body.tool.1.first".to_string(),
+            ]
+        );
+    }
+
+    /// Dispatches one act to a fresh session whose endpoint acks it after `ack_delay`.
+    async fn dispatch_act_with_delayed_ack(
+        policy: BodyEndpointSessionPolicy,
+        ack_delay: Duration,
+    ) -> ChannelStatsSnapshot {
+        let (server, client) = UnixStream::pair().expect("stream pair");
+        let (client_read, mut client_write) = client.into_split();
+        let mut client_lines = BufReader::new(client_read).lines();
+        let port: Arc<dyn SpineAdapterPort> = Arc::new(RecordingPort::default());
+        let sessions: SessionActSenders = Arc::new(Mutex::new(BTreeMap::new()));
+        let channel_stats = AdapterChannelStats::default();
+        let (sense_tx, _sense_rx) = mpsc::unbounded_channel();
+        let session = tokio::spawn(handle_body_endpoint(
+            server,
+            BodyEndpointSessionContext {
+                port: Arc::clone(&port),
+                sense_tx,
+                sessions: Arc::clone(&sessions),
+                channel_stats: channel_stats.clone(),
+            },
+            policy,
+            1,
+            1,
+        ));
+        client_write
+            .write_all(auth_line("run", "first").as_bytes())
+            .await
+            .expect("auth should be written");
+        timeout(Duration::from_secs(1), async {
+            while !sessions
+                .lock()
+                .expect("lock poisoned")
+                .contains_key("tool.1")
+            {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("auth should register the session");
+
+        let shutdown = CancellationToken::new();
+        let (act_tx, act_rx) = mpsc::unbounded_channel();
+        let dispatch = tokio::spawn(dispatch_adapter_acts(
+            act_rx,
+            Arc::clone(&sessions),
+            Arc::clone(&port),
+            shutdown.clone(),
+            channel_stats.clone(),
+        ));
+        let act_instance_id = uuid::Uuid::now_v7().to_string();
+        act_tx
+            .send(InFlightAct {
+                act: Act {
+                    act_instance_id: act_instance_id.clone(),
+                    endpoint_id: "tool.1".to_string(),
+                    neural_signal_descriptor_id: "run".to_string(),
+                    might_emit_sense_ids: Vec::new(),
+                    payload: serde_json::json!({}),
+                    goal_id: None,
+                },
+                permit: None,
+            })
+            .expect("act should be queued");
+
+        let written = timeout(Duration::from_secs(1), client_lines.next_line())
+            .await
+            .expect("act should be written to the endpoint")
+            .expect("read should succeed")
+            .expect("act line");
+        assert!(written.contains(&act_instance_id));
+        tokio::time::sleep(ack_delay).await;
+        let ack = serde_json::json!({
+            "method": "act_ack",
+            "id": uuid::Uuid::new_v4().to_string(),
+            "timestamp": 1,
+            "body": { "act_instance_id": act_instance_id }
+        });
+        client_write
+            .write_all(format!("{ack}\n").as_bytes())
+            .await
+            .expect("ack should be written");
+        timeout(Duration::from_secs(1), async {
+            while channel_stats.snapshots()["tool.1"].acks_received == 0 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("ack should be counted");
+        let snapshot = channel_stats.snapshots()["tool.1"];
+
+        shutdown.cancel();
+        dispatch
+            .await
+            .expect("dispatch task should join")
+            .expect("dispatch should stop cleanly");
+        client_write.shutdown().await.expect("client shutdown");
+        timeout(Duration::from_secs(1), session)
+            .await
+            .expect("session should finish")
+            .expect("session task should join")
+            .expect("session should end cleanly");
+        snapshot
+    }
+
+    #[tokio::test]
+    async fn dispatched_act_updates_channel_sent_and_ack_counters() {
+        let snapshot =
+            dispatch_act_with_delayed_ack(BodyEndpointSessionPolicy::default(), Duration::ZERO)
+                .await;
+
+        assert_eq!(
+            snapshot,
+            ChannelStatsSnapshot {
+                acts_sent: 1,
+                acks_received: 1,
+                retries: 0,
+                queue_depth: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn act_for_a_closed_session_does_not_leave_queue_depth_behind() {
+        let (closed_tx, closed_rx) = mpsc::unbounded_channel();
+        drop(closed_rx);
+        let sessions: SessionActSenders = Arc::new(Mutex::new(BTreeMap::from([(
+            "tool.1".to_string(),
+            closed_tx,
+        )])));
+        let channel_stats = AdapterChannelStats::default();
+        let shutdown = CancellationToken::new();
+        let (act_tx, act_rx) = mpsc::unbounded_channel();
+        let dispatch = tokio::spawn(dispatch_adapter_acts(
+            act_rx,
+            Arc::clone(&sessions),
+            Arc::new(NoopPort),
+            shutdown.clone(),
+            channel_stats.clone(),
+        ));
+        act_tx
+            .send(InFlightAct {
+                act: Act {
+                    act_instance_id: uuid::Uuid::now_v7().to_string(),
+                    endpoint_id: "tool.1".to_string(),
+                    neural_signal_descriptor_id: "run".to_string(),
+                    might_emit_sense_ids: Vec::new(),
+                    payload: serde_json::json!({}),
+                    goal_id: None,
+                },
+                permit: None,
+            })
+            .expect("act should be queued");
+        timeout(Duration::from_secs(1), async {
+            while sessions
+                .lock()
+                .expect("lock poisoned")
+                .contains_key("tool.1")
+            {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("closed session should be removed");
+
+        shutdown.cancel();
+        dispatch
+            .await
+            .expect("dispatch task should join")
+            .expect("dispatch should stop cleanly");
+        assert_eq!(channel_stats.snapshots()["tool.1"].queue_depth, 0);
+    }
+
+    #[tokio::test]
+    async fn raised_act_ack_timeout_waits_for_slow_ack_without_retrying() {
+        let slow_ack = Duration::from_millis(250);
+        let short_timeout = dispatch_act_with_delayed_ack(
+            BodyEndpointSessionPolicy {
+                act_ack_timeout_ms: 100,
+                act_ack_max_retries: 5,
+                ..BodyEndpointSessionPolicy::default()
+            },
+            slow_ack,
+        )
+        .await;
+        assert!(short_timeout.retries >= 1);
+
+        let raised_timeout = dispatch_act_with_delayed_ack(
+            BodyEndpointSessionPolicy {
+                act_ack_timeout_ms: 2_000,
+                ..BodyEndpointSessionPolicy::default()
+            },
+            slow_ack,
+        )
+        .await;
+        assert_eq!(raised_timeout.acts_sent, 1);
+        assert_eq!(raised_timeout.retries, 0);
+    }
+
+    const SIGNING_KEY_HEX: &str = "000102030405060708090a0b0c0d0e0f";
+
+    fn signed_session_policy() -> BodyEndpointSessionPolicy {
+        let key = SenseSigningKey::from_hex(SIGNING_KEY_HEX).expect("key should decode");
+        BodyEndpointSessionPolicy {
+            require_signed_senses: true,
+            sense_signing_keys: Arc::new(BTreeMap::from([("sensor".to_string(), key)])),
+            ..BodyEndpointSessionPolicy::default()
+        }
+    }
+
+    fn signed_sense_line(
+        key: &SenseSigningKey,
+        session_nonce: &str,
+        sequence: u64,
+        payload: &str,
+    ) -> String {
+        let sense_instance_id = uuid::Uuid::new_v4().to_string();
+        let signature = key.sign_sense(&SignedSenseFields {
+            session_nonce,
+            endpoint_name: "sensor",
+            sequence,
+            sense_instance_id: &sense_instance_id,
+            neural_signal_descriptor_id: "reading",
+            payload,
+            weight: 1.0,
+        });
+        let envelope = serde_json::json!({
+            "method": "sense",
+            "id": uuid::Uuid::new_v4().to_string(),
+            "timestamp": 1,
+            "body": {
+                "sense_instance_id": sense_instance_id,
+                "neural_signal_descriptor_id": "reading",
+                "payload": payload,
+                "weight": 1.0,
+                "sequence": sequence,
+                "signature": signature
+            }
+        });
+        format!("{envelope}\n")
+    }
+
+    fn signed_auth_line(auth_signature: &str) -> String {
+        let envelope = serde_json::json!({
+            "method": "auth",
+            "id": uuid::Uuid::new_v4().to_string(),
+            "timestamp": 1,
+            "body": { "endpoint_name": "sensor", "auth_signature": auth_signature }
+        });
+        format!("{envelope}\n")
+    }
+
+    async fn read_challenge_nonce<R: AsyncBufReadExt + Unpin>(
+        lines: &mut tokio::io::Lines<R>,
+    ) -> String {
+        let line = timeout(Duration::from_secs(1), lines.next_line())
+            .await
+            .expect("challenge should arrive")
+            .expect("read should succeed")
+            .expect("challenge line");
+        let envelope: serde_json::Value = serde_json::from_str(&line).expect("challenge json");
+        assert_eq!(envelope["method"], "challenge");
+        envelope["body"]["nonce"]
+            .as_str()
+            .expect("challenge nonce")
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn signed_sense_is_delivered_and_tampered_or_replayed_sense_is_rejected() {
+        let key = SenseSigningKey::from_hex(SIGNING_KEY_HEX).expect("key should decode");
+        let (server, client) = UnixStream::pair().expect("stream pair");
+        let (sense_tx, mut sense_rx) = mpsc::unbounded_channel();
+        let session = tokio::spawn(handle_body_endpoint(
+            server,
+            BodyEndpointSessionContext {
+                port: Arc::new(NoopPort),
+                sense_tx,
+                sessions: Arc::new(Mutex::new(BTreeMap::new())),
+                channel_stats: AdapterChannelStats::default(),
+            },
+            signed_session_policy(),
+            1,
+            1,
+        ));
+        let (client_read, mut client_write) = tokio::io::split(client);
+        let mut client_lines = BufReader::new(client_read).lines();
+        let nonce = read_challenge_nonce(&mut client_lines).await;
+        client_write
+            .write_all(signed_auth_line(&key.sign_auth(&nonce, "sensor")).as_bytes())
+            .await
+            .expect("auth should be written");
+
+        let signed = signed_sense_line(&key, &nonce, 1, "42");
+        client_write
+            .write_all(signed.as_bytes())
+            .await
+            .expect("signed sense should be written");
+        let delivered = timeout(Duration::from_secs(1), sense_rx.recv())
+            .await
+            .expect("signed sense should be delivered")
+            .expect("sense channel open");
+        assert_eq!(delivered.payload, "42");
+        assert_eq!(delivered.endpoint_id, "sensor.1");
+
+        let tampered = signed_sense_line(&key, &nonce, 2, "42").replace("\"42\"", "\"9000\"");
+        let other_session = signed_sense_line(&key, "other-session", 3, "7");
+        for rejected in [signed.as_str(), tampered.as_str(), other_session.as_str()] {
+            client_write
+                .write_all(rejected.as_bytes())
+                .await
+                .expect("rejected sense should be written");
+        }
+        client_write.shutdown().await.expect("client shutdown");
+        timeout(Duration::from_secs(1), session)
+            .await
+            .expect("session should finish")
+            .expect("session task should join")
+            .expect("session should end cleanly");
+        assert!(
+            sense_rx.try_recv().is_err(),
+            "replayed, tampered and cross-session senses should not be delivered"
+        );
+    }
+
+    #[tokio::test]
+    async fn auth_without_valid_proof_of_the_configured_key_closes_session() {
+        let wrong_key =
+            SenseSigningKey::from_hex("ffeeddccbbaa99887766554433221100").expect("key decodes");
+        for auth_signature in [None, Some("bad-proof")] {
+            let (server, client) = UnixStream::pair().expect("stream pair");
+            let port = Arc::new(RecordingPort::default());
+            let (sense_tx, _sense_rx) = mpsc::unbounded_channel();
+            let session = tokio::spawn(handle_body_endpoint(
+                server,
+                BodyEndpointSessionContext {
+                    port: port.clone(),
+                    sense_tx,
+                    sessions: Arc::new(Mutex::new(BTreeMap::new())),
+                    channel_stats: AdapterChannelStats::default(),
+                },
+                signed_session_policy(),
+                1,
+                1,
+            ));
+            let (client_read, mut client_write) = tokio::io::split(client);
+            let mut client_lines = BufReader::new(client_read).lines();
+            let nonce = read_challenge_nonce(&mut client_lines).await;
+            let auth = match auth_signature {
+                Some(_) => signed_auth_line(&wrong_key.sign_auth(&nonce, "sensor")),
+                None => auth_line("run", "probe").replace("\"tool\"", "\"sensor\""),
+            };
+            client_write
+                .write_all(auth.as_bytes())
+                .await
+                .expect("auth should be written");
+
+            let closed = timeout(Duration::from_secs(1), client_lines.next_line())
+                .await
+                .expect("session should close");
+            assert!(matches!(closed, Ok(None)));
+            timeout(Duration::from_secs(1), session)
+                .await
+                .expect("session should finish")
+                .expect("session task should join")
+                .expect("session should end cleanly");
+            assert!(
+                !port
+                    .events()
+                    .iter()
+                    .any(|event| event.starts_with("register:"))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn connection_beyond_max_connections_is_rejected() {
+        let socket_path = std::env::temp_dir()
+            .join(format!("beluna-unix-socket-{}", uuid::Uuid::new_v4()))
+            .join("beluna.sock");
+        let adapter = UnixSocketAdapter::from_config(
+            1,
+            UnixSocketNdjsonAdapterConfig {
+                socket_path: socket_path.clone(),
+                max_connections: Some(1),
+                ..UnixSocketNdjsonAdapterConfig::default()
+            },
+        );
+        let shutdown = CancellationToken::new();
+        let (_act_tx, act_rx) = mpsc::unbounded_channel();
+        let (sense_tx, _sense_rx) = mpsc::unbounded_channel();
+        let context = AdapterContext {
+            adapter_id: 1,
+            execution_mode: SpineExecutionMode::default(),
+            shutdown: shutdown.clone(),
+            act_rx,
+            sense_tx,
+            port: Arc::new(NoopPort),
+            channel_stats: AdapterChannelStats::default(),
+        };
+        let run_task = tokio::spawn(async move { adapter.run(context).await });
+
+        let mut first = timeout(Duration::from_secs(1), async {
+            loop {
+                if let Ok(stream) = UnixStream::connect(&socket_path).await {
+                    break stream;
+                }
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("adapter should start listening");
+        let mut second = UnixStream::connect(&socket_path)
+            .await
+            .expect("listener accepts at the os level");
+
+        let mut buffer = [0_u8; 1];
+        let second_read = timeout(Duration::from_secs(1), second.read(&mut buffer))
+            .await
+            .expect("rejected connection should be closed");
+        assert_eq!(second_read.expect("read should succeed"), 0);
+        assert!(
+            timeout(Duration::from_millis(50), first.read(&mut buffer))
+                .await
+                .is_err(),
+            "first connection should stay open"
+        );
+
+        shutdown.cancel();
+        run_task
+            .await
+            .expect("adapter task should join")
+            .expect("adapter should stop cleanly");
+        if let Some(parent) = socket_path.parent() {
+            let _ = fs::remove_dir(parent);
+        }
+    }
+}
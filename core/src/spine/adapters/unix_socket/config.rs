@@ -1,18 +1,85 @@
-use std::path::{Path, PathBuf};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationError};
 
+use crate::spine::SenseSigningKey;
+
 fn default_socket_path() -> PathBuf {
     PathBuf::from("beluna.sock")
 }
 
+pub(crate) fn default_act_ack_timeout_ms() -> u64 {
+    1_500
+}
+
+pub(crate) fn default_act_ack_max_retries() -> usize {
+    2
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct UnixSocketNdjsonAdapterConfig {
     #[validate(custom(function = "validate_non_empty_path"))]
     pub socket_path: PathBuf,
+    /// Maximum concurrently connected body endpoints; further accepts are rejected.
+    ///
+    /// Unbounded when unset, as before this option existed.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub max_connections: Option<usize>,
+    /// How a second `auth` on an already authenticated session is handled.
+    #[serde(default)]
+    pub duplicate_auth_policy: DuplicateAuthPolicy,
+    /// Reject `auth` from any endpoint name without an entry in `sense_signing_keys`.
+    #[serde(default)]
+    pub require_signed_senses: bool,
+    /// Pre-shared hex HMAC keys (at least 16 bytes) by `endpoint_name`.
+    ///
+    /// An endpoint with a key must answer core's `challenge` at `auth` and sign every sense, even
+    /// when `require_signed_senses` is off.
+    #[serde(default)]
+    #[validate(custom(function = "validate_sense_signing_keys"))]
+    pub sense_signing_keys: BTreeMap<String, String>,
+    /// How long to wait for an endpoint's `act_ack` before re-sending the act.
+    #[serde(default = "default_act_ack_timeout_ms")]
+    #[validate(range(min = 1))]
+    pub act_ack_timeout_ms: u64,
+    /// Re-sends of an unacknowledged act before the session is closed as failed.
+    #[serde(default = "default_act_ack_max_retries")]
+    pub act_ack_max_retries: usize,
+    /// Ping endpoints periodically and close sessions that miss a `pong`; off when unset.
+    #[serde(default)]
+    #[validate(nested)]
+    pub heartbeat: Option<BodyEndpointHeartbeatConfig>,
+}
+
+/// Opt-in liveness probe for endpoints that answer `ping` with `pong`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BodyEndpointHeartbeatConfig {
+    /// How often a `ping` is sent to each connected endpoint.
+    #[validate(range(min = 1))]
+    pub interval_ms: u64,
+    /// How long an endpoint has to answer a `ping` before its session is dropped.
+    #[validate(range(min = 1))]
+    pub pong_timeout_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum DuplicateAuthPolicy {
+    /// Keep the first registration and drop the repeated `auth` with a warning.
+    #[default]
+    Ignore,
+    /// Treat a repeated `auth` as a protocol violation and close the session.
+    RejectAndClose,
+    /// Replace the session's descriptors and proprioceptions with the new `auth` body.
+    Reauth,
 }
 
 impl UnixSocketNdjsonAdapterConfig {
@@ -27,6 +94,13 @@ impl Default for UnixSocketNdjsonAdapterConfig {
     fn default() -> Self {
         Self {
             socket_path: default_socket_path(),
+            max_connections: None,
+            duplicate_auth_policy: DuplicateAuthPolicy::default(),
+            require_signed_senses: false,
+            sense_signing_keys: BTreeMap::new(),
+            act_ack_timeout_ms: default_act_ack_timeout_ms(),
+            act_ack_max_retries: default_act_ack_max_retries(),
+            heartbeat: None,
         }
     }
 }
@@ -37,3 +111,34 @@ fn validate_non_empty_path(path: &PathBuf) -> Result<(), ValidationError> {
     }
     Ok(())
 }
+
+pub(crate) fn validate_sense_signing_keys(
+    keys: &BTreeMap<String, String>,
+) -> Result<(), ValidationError> {
+    for (endpoint_name, key) in keys {
+        if endpoint_name.trim().is_empty() {
+            return Err(ValidationError::new(
+                "sense_signing_key_endpoint_name_blank",
+            ));
+        }
+        if let Err(message) = SenseSigningKey::from_hex(key) {
+            return Err(
+                ValidationError::new("invalid_sense_signing_key").with_message(message.into())
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Decodes validated `sense_signing_keys`; entries that fail to decode are left out.
+pub(crate) fn decode_sense_signing_keys(
+    keys: &BTreeMap<String, String>,
+) -> BTreeMap<String, SenseSigningKey> {
+    keys.iter()
+        .filter_map(|(endpoint_name, key)| {
+            SenseSigningKey::from_hex(key)
+                .ok()
+                .map(|key| (endpoint_name.clone(), key))
+        })
+        .collect()
+}
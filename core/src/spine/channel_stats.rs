@@ -0,0 +1,126 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use serde::Serialize;
+
+use crate::observability::metrics as observability_metrics;
+
+/// Point-in-time counters for one adapter channel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ChannelStatsSnapshot {
+    pub acts_sent: u64,
+    pub acks_received: u64,
+    pub retries: u64,
+    pub queue_depth: u64,
+}
+
+#[derive(Default)]
+struct ChannelCounters {
+    acts_sent: AtomicU64,
+    acks_received: AtomicU64,
+    retries: AtomicU64,
+    queue_depth: AtomicU64,
+}
+
+impl ChannelCounters {
+    fn snapshot(&self) -> ChannelStatsSnapshot {
+        ChannelStatsSnapshot {
+            acts_sent: self.acts_sent.load(Ordering::Relaxed),
+            acks_received: self.acks_received.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            queue_depth: self.queue_depth.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Per-channel act delivery counters, keyed by body endpoint id and shared by every adapter.
+///
+/// Adapters record into it as acts move through their channels; Spine exposes the snapshots
+/// and mirrors each update to metrics.
+#[derive(Clone, Default)]
+pub struct AdapterChannelStats {
+    channels: Arc<Mutex<BTreeMap<String, Arc<ChannelCounters>>>>,
+}
+
+impl AdapterChannelStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn channel(&self, channel_id: &str) -> Arc<ChannelCounters> {
+        let mut channels = self.channels.lock().expect("lock poisoned");
+        Arc::clone(channels.entry(channel_id.to_string()).or_default())
+    }
+
+    fn update(&self, channel_id: &str, apply: impl FnOnce(&ChannelCounters)) {
+        let channel = self.channel(channel_id);
+        apply(&channel);
+        observability_metrics::record_spine_channel_stats(channel_id, &channel.snapshot());
+    }
+
+    /// An act was queued on the channel's outbound queue.
+    pub fn record_enqueued(&self, channel_id: &str) {
+        self.update(channel_id, |channel| {
+            channel.queue_depth.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+
+    /// An act counted by `record_enqueued` never reached the outbound queue.
+    pub fn record_unqueued(&self, channel_id: &str) {
+        self.update(channel_id, |channel| {
+            let _ =
+                channel
+                    .queue_depth
+                    .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |depth| {
+                        Some(depth.saturating_sub(1))
+                    });
+        });
+    }
+
+    /// An act left the outbound queue and was written for the first time.
+    pub fn record_sent(&self, channel_id: &str) {
+        self.update(channel_id, |channel| {
+            let _ =
+                channel
+                    .queue_depth
+                    .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |depth| {
+                        Some(depth.saturating_sub(1))
+                    });
+            channel.acts_sent.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+
+    pub fn record_retry(&self, channel_id: &str) {
+        self.update(channel_id, |channel| {
+            channel.retries.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+
+    pub fn record_ack(&self, channel_id: &str) {
+        self.update(channel_id, |channel| {
+            channel.acks_received.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+
+    /// Drops a channel's counters once its endpoint is gone.
+    pub fn forget(&self, channel_id: &str) {
+        self.channels
+            .lock()
+            .expect("lock poisoned")
+            .remove(channel_id);
+    }
+
+    pub fn snapshots(&self) -> BTreeMap<String, ChannelStatsSnapshot> {
+        self.channels
+            .lock()
+            .expect("lock poisoned")
+            .iter()
+            .map(|(channel_id, channel)| (channel_id.clone(), channel.snapshot()))
+            .collect()
+    }
+}
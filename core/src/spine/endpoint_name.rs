@@ -0,0 +1,99 @@
+use anyhow::{Result, anyhow};
+
+use crate::config::{EndpointNameNormalization, EndpointNameRulesConfig};
+
+/// Trims and, when rules are configured, normalizes and validates an endpoint name.
+///
+/// Returns the name to register, or an error naming the rule it broke.
+pub(crate) fn normalize_endpoint_name(
+    endpoint_name: &str,
+    rules: Option<&EndpointNameRulesConfig>,
+) -> Result<String> {
+    let endpoint_name = endpoint_name.trim();
+    if endpoint_name.is_empty() {
+        return Err(anyhow!("endpoint_name cannot be empty"));
+    }
+    let Some(rules) = rules else {
+        return Ok(endpoint_name.to_string());
+    };
+
+    let normalized = match rules.normalization {
+        EndpointNameNormalization::None => endpoint_name.to_string(),
+        EndpointNameNormalization::AsciiLowercase => endpoint_name.to_ascii_lowercase(),
+    };
+    let length = normalized.chars().count();
+    if length > rules.max_length {
+        return Err(anyhow!(
+            "endpoint_name '{}' is {} characters, over the {} allowed",
+            normalized,
+            length,
+            rules.max_length
+        ));
+    }
+    if let Some(disallowed) = normalized
+        .chars()
+        .find(|ch| !ch.is_ascii_alphanumeric() && !rules.allowed_punctuation.contains(*ch))
+    {
+        return Err(anyhow!(
+            "endpoint_name '{}' contains disallowed character {:?}",
+            normalized,
+            disallowed
+        ));
+    }
+    Ok(normalized)
+}
+
+/// The registered endpoint name of a body endpoint id, without Spine's `.<seq>` suffix.
+pub(crate) fn endpoint_name_of(body_endpoint_id: &str) -> &str {
+    match body_endpoint_id.rsplit_once('.') {
+        Some((name, suffix))
+            if !suffix.is_empty() && suffix.bytes().all(|byte| byte.is_ascii_digit()) =>
+        {
+            name
+        }
+        _ => body_endpoint_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> EndpointNameRulesConfig {
+        EndpointNameRulesConfig {
+            max_length: 12,
+            normalization: EndpointNameNormalization::AsciiLowercase,
+            ..EndpointNameRulesConfig::default()
+        }
+    }
+
+    #[test]
+    fn valid_name_is_trimmed_and_normalized() {
+        assert_eq!(
+            normalize_endpoint_name("  Web-Chat ", Some(&rules())).expect("valid"),
+            "web-chat"
+        );
+    }
+
+    #[test]
+    fn over_long_name_is_rejected() {
+        let err =
+            normalize_endpoint_name("desktop-assistant", Some(&rules())).expect_err("too long");
+        assert!(
+            err.to_string()
+                .contains("17 characters, over the 12 allowed")
+        );
+    }
+
+    #[test]
+    fn disallowed_characters_are_rejected() {
+        for name in ["chat bot", "chat_bot", "c\u{0430}t"] {
+            let err = normalize_endpoint_name(name, Some(&rules())).expect_err(name);
+            assert!(err.to_string().contains("disallowed character"), "{name}");
+        }
+        assert_eq!(
+            normalize_endpoint_name("chat bot", None).expect("unvalidated"),
+            "chat bot"
+        );
+    }
+}
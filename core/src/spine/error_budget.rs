@@ -0,0 +1,133 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::config::EndpointErrorBudgetConfig;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointStanding {
+    Full,
+    Degraded,
+}
+
+impl EndpointStanding {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Degraded => "degraded",
+        }
+    }
+}
+
+const STANDING_KEY_PREFIX: &str = "spine.body_endpoint.";
+const STANDING_KEY_SUFFIX: &str = ".standing";
+
+pub(crate) fn endpoint_standing_proprioception_key(body_endpoint_id: &str) -> String {
+    format!("{STANDING_KEY_PREFIX}{body_endpoint_id}{STANDING_KEY_SUFFIX}")
+}
+
+/// Endpoint ids whose published standing is degraded.
+pub fn degraded_endpoint_ids(proprioception: &BTreeMap<String, String>) -> BTreeSet<String> {
+    proprioception
+        .iter()
+        .filter(|(_, value)| value.as_str() == EndpointStanding::Degraded.as_str())
+        .filter_map(|(key, _)| {
+            key.strip_prefix(STANDING_KEY_PREFIX)?
+                .strip_suffix(STANDING_KEY_SUFFIX)
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+#[derive(Debug, Default)]
+struct EndpointDeliveryWindow {
+    outcomes: VecDeque<bool>,
+    failures: usize,
+    standing: Option<EndpointStanding>,
+}
+
+/// Tracks per-endpoint delivery failures over a sliding window of recent attempts.
+///
+/// An endpoint is degraded while the failure ratio in its window exceeds the budget, and
+/// regains full standing once the ratio drops back within it.
+#[derive(Debug)]
+pub(crate) struct EndpointErrorBudget {
+    config: EndpointErrorBudgetConfig,
+    windows: BTreeMap<String, EndpointDeliveryWindow>,
+}
+
+impl EndpointErrorBudget {
+    pub(crate) fn new(config: EndpointErrorBudgetConfig) -> Self {
+        Self {
+            config,
+            windows: BTreeMap::new(),
+        }
+    }
+
+    /// Records one delivery attempt and returns the new standing if it changed.
+    pub(crate) fn record(
+        &mut self,
+        body_endpoint_id: &str,
+        delivered: bool,
+    ) -> Option<EndpointStanding> {
+        let window_size = self.config.window_size.max(1);
+        let window = self
+            .windows
+            .entry(body_endpoint_id.to_string())
+            .or_default();
+        window.outcomes.push_back(delivered);
+        if !delivered {
+            window.failures += 1;
+        }
+        while window.outcomes.len() > window_size {
+            if window.outcomes.pop_front() == Some(false) {
+                window.failures -= 1;
+            }
+        }
+        if window.outcomes.len() < self.config.min_samples.clamp(1, window_size) {
+            return None;
+        }
+
+        let failure_ratio = window.failures as f64 / window.outcomes.len() as f64;
+        let standing = if failure_ratio > self.config.max_failure_ratio {
+            EndpointStanding::Degraded
+        } else {
+            EndpointStanding::Full
+        };
+        let previous = window
+            .standing
+            .replace(standing)
+            .unwrap_or(EndpointStanding::Full);
+        (previous != standing).then_some(standing)
+    }
+
+    pub(crate) fn forget(&mut self, body_endpoint_id: &str) -> Option<EndpointStanding> {
+        self.windows
+            .remove(body_endpoint_id)
+            .and_then(|window| window.standing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sustained_partial_failures_degrade_until_recovery() {
+        let mut budget = EndpointErrorBudget::new(EndpointErrorBudgetConfig {
+            window_size: 10,
+            min_samples: 10,
+            max_failure_ratio: 0.2,
+        });
+
+        let mut changes = Vec::new();
+        for attempt in 0..10 {
+            changes.extend(budget.record("tool.1", attempt % 3 != 0));
+        }
+        assert_eq!(changes, vec![EndpointStanding::Degraded]);
+
+        changes.clear();
+        for _ in 0..10 {
+            changes.extend(budget.record("tool.1", true));
+        }
+        assert_eq!(changes, vec![EndpointStanding::Full]);
+    }
+}
@@ -3,17 +3,29 @@
 use std::sync::{Arc, OnceLock};
 
 pub mod adapters;
+pub mod channel_stats;
 pub mod endpoint;
+mod endpoint_name;
 pub mod error;
+pub mod error_budget;
+mod pacing;
+mod pause;
+pub mod payload_transform;
 pub mod runtime;
+pub mod sense_signing;
 pub mod types;
 
+pub use channel_stats::{AdapterChannelStats, ChannelStatsSnapshot};
 pub use endpoint::{Endpoint, NativeFunctionEndpoint};
+pub(crate) use endpoint_name::endpoint_name_of;
 pub use error::{SpineError, SpineErrorKind};
+pub use error_budget::EndpointStanding;
+pub use payload_transform::ActPayloadTransform;
 pub use runtime::{
-    AdapterContext, AdapterId, EndpointBinding, Spine, SpineAdapterPort, SpineControlPort,
-    shutdown_global_spine,
+    AdapterContext, AdapterId, EndpointBinding, InFlightAct, Spine, SpineAdapterPort,
+    SpineControlPort, shutdown_global_spine,
 };
+pub use sense_signing::{SenseSigningKey, SignedSenseFields};
 pub use types::{
     ActDispatchResult, CostAttributionId, EndpointExecutionOutcome, NeuralSignalDescriptor,
     NeuralSignalDescriptorCatalog, NeuralSignalDescriptorRouteKey, ReserveEntryId, SpineEvent,
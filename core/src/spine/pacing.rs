@@ -0,0 +1,67 @@
+use std::{collections::BTreeMap, sync::Mutex, time::Duration};
+
+use tokio::time::Instant;
+
+/// Spaces acts to rate-limited endpoints at least a configured interval apart.
+///
+/// Intervals are keyed by endpoint name, so every body endpoint registered under that name is
+/// paced independently. Each dispatch reserves the next free slot before waiting, which keeps
+/// concurrent dispatches to one endpoint queued in arrival order.
+#[derive(Debug)]
+pub(crate) struct EndpointActPacing {
+    intervals: BTreeMap<String, Duration>,
+    next_slots: Mutex<BTreeMap<String, Instant>>,
+}
+
+impl EndpointActPacing {
+    pub(crate) fn new(min_inter_act_ms: &BTreeMap<String, u64>) -> Option<Self> {
+        let intervals = min_inter_act_ms
+            .iter()
+            .filter(|(_, interval_ms)| **interval_ms > 0)
+            .map(|(endpoint_name, interval_ms)| {
+                (endpoint_name.clone(), Duration::from_millis(*interval_ms))
+            })
+            .collect::<BTreeMap<_, _>>();
+        (!intervals.is_empty()).then(|| Self {
+            intervals,
+            next_slots: Mutex::new(BTreeMap::new()),
+        })
+    }
+
+    /// Waits until `body_endpoint_id` may receive its next act.
+    pub(crate) async fn wait_for_slot(&self, body_endpoint_id: &str) {
+        let Some(interval) = body_endpoint_id
+            .rsplit_once('.')
+            .and_then(|(endpoint_name, _)| self.intervals.get(endpoint_name))
+        else {
+            return;
+        };
+
+        let slot = {
+            let mut next_slots = self.next_slots.lock().expect("lock poisoned");
+            let now = Instant::now();
+            let slot = next_slots
+                .get(body_endpoint_id)
+                .copied()
+                .map_or(now, |next_slot| next_slot.max(now));
+            next_slots.insert(body_endpoint_id.to_string(), slot + *interval);
+            slot
+        };
+        if slot > Instant::now() {
+            tracing::debug!(
+                target: "spine.act",
+                endpoint_id = %body_endpoint_id,
+                wait_ms = slot.saturating_duration_since(Instant::now()).as_millis() as u64,
+                "act_dispatch_paced"
+            );
+            tokio::time::sleep_until(slot).await;
+        }
+    }
+
+    pub(crate) fn forget(&self, body_endpoint_id: &str) {
+        self.next_slots
+            .lock()
+            .expect("lock poisoned")
+            .remove(body_endpoint_id);
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::Mutex,
+};
+
+use crate::types::Act;
+
+pub(crate) enum PauseHold {
+    NotPaused,
+    Queued,
+    QueueFull,
+}
+
+/// Holds acts to paused body endpoints until they are resumed.
+///
+/// Queued acts are released in dispatch order; the endpoint stays paused until its queue is
+/// drained, so acts arriving during the drain cannot overtake earlier ones.
+#[derive(Debug)]
+pub(crate) struct EndpointActPause {
+    queue_capacity: usize,
+    paused: Mutex<BTreeMap<String, VecDeque<(u64, Act)>>>,
+}
+
+impl EndpointActPause {
+    pub(crate) fn new(queue_capacity: usize) -> Self {
+        Self {
+            queue_capacity,
+            paused: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns whether the endpoint was not already paused.
+    pub(crate) fn pause(&self, body_endpoint_id: &str) -> bool {
+        let mut paused = self.paused.lock().expect("lock poisoned");
+        if paused.contains_key(body_endpoint_id) {
+            return false;
+        }
+        paused.insert(body_endpoint_id.to_string(), VecDeque::new());
+        true
+    }
+
+    pub(crate) fn hold(&self, tick: u64, act: &Act) -> PauseHold {
+        let mut paused = self.paused.lock().expect("lock poisoned");
+        let Some(queue) = paused.get_mut(&act.endpoint_id) else {
+            return PauseHold::NotPaused;
+        };
+        if queue.len() >= self.queue_capacity {
+            return PauseHold::QueueFull;
+        }
+        queue.push_back((tick, act.clone()));
+        PauseHold::Queued
+    }
+
+    /// Pops the next queued act, unpausing the endpoint once nothing is left.
+    pub(crate) fn release_next(&self, body_endpoint_id: &str) -> Option<(u64, Act)> {
+        let mut paused = self.paused.lock().expect("lock poisoned");
+        let queue = paused.get_mut(body_endpoint_id)?;
+        let next = queue.pop_front();
+        if next.is_none() {
+            paused.remove(body_endpoint_id);
+        }
+        next
+    }
+
+    /// Drops the pause and returns the acts that were still queued.
+    pub(crate) fn forget(&self, body_endpoint_id: &str) -> Vec<Act> {
+        self.paused
+            .lock()
+            .expect("lock poisoned")
+            .remove(body_endpoint_id)
+            .map(|queue| queue.into_iter().map(|(_, act)| act).collect())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn paused_endpoint_ids(&self) -> Vec<String> {
+        self.paused
+            .lock()
+            .expect("lock poisoned")
+            .keys()
+            .cloned()
+            .collect()
+    }
+}
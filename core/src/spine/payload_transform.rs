@@ -0,0 +1,35 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Declarative reshaping an endpoint registers at auth; Spine applies it to act payloads
+/// right before dispatch. The default transform is the identity.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ActPayloadTransform {
+    /// Top-level payload fields to rename, keyed by the name the cortex produces.
+    #[serde(default)]
+    pub rename_fields: BTreeMap<String, String>,
+}
+
+impl ActPayloadTransform {
+    pub fn is_identity(&self) -> bool {
+        self.rename_fields.is_empty()
+    }
+
+    /// Renames take effect together, so swapping two fields is expressible. Payloads that
+    /// are not JSON objects pass through unchanged.
+    pub fn apply(&self, payload: Value) -> Value {
+        let Value::Object(mut fields) = payload else {
+            return payload;
+        };
+        let renamed = self
+            .rename_fields
+            .iter()
+            .filter_map(|(from, to)| fields.remove(from).map(|value| (to.clone(), value)))
+            .collect::<Vec<_>>();
+        fields.extend(renamed);
+        Value::Object(fields)
+    }
+}
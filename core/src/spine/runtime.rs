@@ -9,24 +9,33 @@ use std::{
 use anyhow::Result;
 use async_trait::async_trait;
 use serde_json::json;
-use tokio::{sync::mpsc, task::JoinHandle};
+use tokio::{
+    sync::{OwnedSemaphorePermit, Semaphore, mpsc},
+    task::JoinHandle,
+};
 use tokio_util::sync::CancellationToken;
 
 use crate::{
-    config::SpineRuntimeConfig,
+    config::{EndpointNameRulesConfig, SpineRuntimeConfig},
     observability::runtime::{
         self as observability_runtime, DispatchOutcomeClass, EndpointLifecycleTransition,
     },
     spine::{
         SpineExecutionMode,
-        adapters::{inline::SpineInlineAdapter, unix_socket::spawn_adapter_task},
+        adapters::{inline::SpineInlineAdapter, tcp_ndjson, unix_socket},
+        channel_stats::{AdapterChannelStats, ChannelStatsSnapshot},
+        endpoint_name::{endpoint_name_of, normalize_endpoint_name},
         error::{SpineError, backend_failure, invalid_batch, registration_invalid},
+        error_budget::{EndpointErrorBudget, endpoint_standing_proprioception_key},
+        pacing::EndpointActPacing,
+        pause::{EndpointActPause, PauseHold},
+        payload_transform::ActPayloadTransform,
         types::{ActDispatchResult, NeuralSignalDescriptor, NeuralSignalDescriptorRouteKey},
     },
     stem::{SenseAfferentPathway, StemControlPort},
     types::{
-        Act, NeuralSignalDescriptorDropPatch, NeuralSignalDescriptorPatch, ProprioceptionDropPatch,
-        ProprioceptionPatch, Sense,
+        Act, NeuralSignalDescriptorDropPatch, NeuralSignalDescriptorPatch, NeuralSignalType,
+        ProprioceptionDropPatch, ProprioceptionPatch, Sense,
     },
 };
 
@@ -37,12 +46,22 @@ pub struct BodyEndpointHandle {
 
 pub type AdapterId = u64;
 
+/// An act handed to an adapter, holding a slot of the global in-flight limit until dropped.
+///
+/// Adapters keep it alive until the act is delivered (or acknowledged, for remote endpoints).
+pub struct InFlightAct {
+    pub act: Act,
+    pub(crate) permit: Option<OwnedSemaphorePermit>,
+}
+
 pub struct AdapterContext {
     pub adapter_id: AdapterId,
+    pub execution_mode: SpineExecutionMode,
     pub shutdown: CancellationToken,
-    pub act_rx: mpsc::UnboundedReceiver<Act>,
+    pub act_rx: mpsc::UnboundedReceiver<InFlightAct>,
     pub sense_tx: mpsc::UnboundedSender<Sense>,
     pub port: Arc<dyn SpineAdapterPort>,
+    pub channel_stats: AdapterChannelStats,
 }
 
 pub enum EndpointBinding {
@@ -87,6 +106,7 @@ struct RegisteredBodyEndpoint {
     body_endpoint_id: String,
     dispatch: EndpointDispatch,
     route_keys: BTreeSet<NeuralSignalDescriptorRouteKey>,
+    act_payload_transform: Option<ActPayloadTransform>,
 }
 
 #[derive(Default)]
@@ -97,12 +117,14 @@ struct EndpointState {
 struct RegisteredEndpointRoutes {
     dispatch: EndpointDispatch,
     route_keys: BTreeSet<NeuralSignalDescriptorRouteKey>,
+    /// Descriptor priorities of routes that declared one.
+    route_priorities: BTreeMap<NeuralSignalDescriptorRouteKey, i32>,
 }
 
 #[derive(Default)]
 struct RoutingState {
     by_endpoint: BTreeMap<String, RegisteredEndpointRoutes>,
-    adapters: BTreeMap<AdapterId, mpsc::UnboundedSender<Act>>,
+    adapters: BTreeMap<AdapterId, mpsc::UnboundedSender<InFlightAct>>,
 }
 
 pub struct Spine {
@@ -116,6 +138,13 @@ pub struct Spine {
     afferent_pathway: SenseAfferentPathway,
     stem_control: Arc<dyn StemControlPort>,
     endpoint_proprioception: RwLock<BTreeMap<String, String>>,
+    in_flight_acts: Option<Arc<Semaphore>>,
+    max_descriptor_payload_bytes: Option<u64>,
+    error_budget: Option<Mutex<EndpointErrorBudget>>,
+    act_pacing: Option<EndpointActPacing>,
+    act_pause: EndpointActPause,
+    endpoint_name_rules: Option<EndpointNameRulesConfig>,
+    channel_stats: AdapterChannelStats,
 }
 
 #[async_trait]
@@ -138,12 +167,24 @@ pub trait SpineAdapterPort: Send + Sync {
         body_endpoint_id: &str,
         descriptors: Vec<NeuralSignalDescriptor>,
     ) -> Result<Vec<NeuralSignalDescriptor>>;
+    async fn update_ns_descriptors(
+        &self,
+        body_endpoint_id: &str,
+        descriptors: Vec<NeuralSignalDescriptor>,
+    ) -> Result<Vec<NeuralSignalDescriptor>>;
     async fn drop_ns_descriptors(
         &self,
         body_endpoint_id: &str,
         routes: Vec<NeuralSignalDescriptorRouteKey>,
     ) -> Result<Vec<NeuralSignalDescriptorRouteKey>>;
+    async fn set_act_payload_transform(
+        &self,
+        body_endpoint_id: &str,
+        transform: ActPayloadTransform,
+    ) -> Result<()>;
     async fn drop_endpoint(&self, body_endpoint_id: &str);
+    async fn set_act_dispatch_paused(&self, body_endpoint_id: &str, paused: bool);
+    async fn record_act_delivery(&self, body_endpoint_id: &str, delivered: bool);
     async fn apply_proprioception_patch(&self, entries: BTreeMap<String, String>);
     async fn apply_proprioception_drop(&self, keys: Vec<String>);
     async fn publish_topology_proprioception_snapshot(&self);
@@ -156,7 +197,7 @@ impl Spine {
         stem_control: Arc<dyn StemControlPort>,
     ) -> Arc<Self> {
         let spine = Arc::new(Self {
-            mode: SpineExecutionMode::SerializedDeterministic,
+            mode: config.execution_mode,
             routing: RwLock::new(RoutingState::default()),
             next_body_endpoint_seq: AtomicU64::new(0),
             shutdown: CancellationToken::new(),
@@ -166,6 +207,18 @@ impl Spine {
             afferent_pathway: afferent_pathway.clone(),
             stem_control,
             endpoint_proprioception: RwLock::new(BTreeMap::new()),
+            in_flight_acts: config
+                .max_in_flight_acts
+                .map(|limit| Arc::new(Semaphore::new(limit.max(1)))),
+            max_descriptor_payload_bytes: config.max_descriptor_payload_bytes,
+            error_budget: config
+                .endpoint_error_budget
+                .clone()
+                .map(|budget| Mutex::new(EndpointErrorBudget::new(budget))),
+            act_pacing: EndpointActPacing::new(&config.endpoint_min_inter_act_ms),
+            act_pause: EndpointActPause::new(config.max_queued_acts_per_paused_endpoint),
+            endpoint_name_rules: config.endpoint_names.clone(),
+            channel_stats: AdapterChannelStats::new(),
         });
 
         spine.start_adapters(config);
@@ -206,7 +259,14 @@ impl Spine {
                     config: adapter_cfg,
                 } => {
                     let context = self.create_adapter_context(adapter_id);
-                    let task = spawn_adapter_task(adapter_cfg.clone(), context);
+                    let task = unix_socket::spawn_adapter_task(adapter_cfg.clone(), context);
+                    self.tasks.lock().expect("lock poisoned").push(task);
+                }
+                crate::config::SpineAdapterConfig::TcpNdjson {
+                    config: adapter_cfg,
+                } => {
+                    let context = self.create_adapter_context(adapter_id);
+                    let task = tcp_ndjson::spawn_adapter_task(adapter_cfg.clone(), context);
                     self.tasks.lock().expect("lock poisoned").push(task);
                 }
             }
@@ -214,7 +274,7 @@ impl Spine {
     }
 
     fn create_adapter_context(self: &Arc<Self>, adapter_id: AdapterId) -> AdapterContext {
-        let (act_tx, act_rx) = mpsc::unbounded_channel::<Act>();
+        let (act_tx, act_rx) = mpsc::unbounded_channel::<InFlightAct>();
         let (sense_tx, mut sense_rx) = mpsc::unbounded_channel::<Sense>();
 
         {
@@ -251,10 +311,12 @@ impl Spine {
         let port: Arc<dyn SpineAdapterPort> = Arc::clone(self) as Arc<dyn SpineAdapterPort>;
         AdapterContext {
             adapter_id,
+            execution_mode: self.mode,
             shutdown: self.shutdown.clone(),
             act_rx,
             sense_tx,
             port,
+            channel_stats: self.channel_stats.clone(),
         }
     }
 
@@ -262,6 +324,11 @@ impl Spine {
         self.mode
     }
 
+    /// Act delivery counters for every adapter channel with a live endpoint.
+    pub fn channel_stats_snapshot(&self) -> BTreeMap<String, ChannelStatsSnapshot> {
+        self.channel_stats.snapshots()
+    }
+
     pub fn body_endpoint_ids_snapshot(&self) -> Vec<String> {
         let state = self.endpoint_state.lock().expect("lock poisoned");
         let mut ids = state.by_id.keys().cloned().collect::<Vec<_>>();
@@ -273,6 +340,55 @@ impl Spine {
         self.inline_adapter.get().cloned()
     }
 
+    /// Holds further acts to `body_endpoint_id` while keeping it connected.
+    ///
+    /// Held acts are acknowledged with a `spine:paused_queue:` reference; once the queue bound
+    /// is reached, acts are rejected with `endpoint_paused`. Returns false for unknown endpoints.
+    pub fn pause_endpoint(&self, body_endpoint_id: &str) -> bool {
+        if !self
+            .endpoint_state
+            .lock()
+            .expect("lock poisoned")
+            .by_id
+            .contains_key(body_endpoint_id)
+        {
+            return false;
+        }
+        if self.act_pause.pause(body_endpoint_id) {
+            tracing::info!(
+                target: "spine.act",
+                endpoint_id = %body_endpoint_id,
+                "endpoint_act_dispatch_paused"
+            );
+        }
+        true
+    }
+
+    /// Dispatches the acts held for `body_endpoint_id` in order, then lifts the pause.
+    ///
+    /// Returns how many held acts were dispatched.
+    pub async fn resume_endpoint(&self, body_endpoint_id: &str) -> usize {
+        let mut released = 0_usize;
+        while let Some((tick, act)) = self.act_pause.release_next(body_endpoint_id) {
+            let dispatched = self
+                .dispatch_act_inner(tick, act.clone(), Some(body_endpoint_id))
+                .await;
+            let _ = self.settle_act_final(&act, dispatched).await;
+            released += 1;
+        }
+        tracing::info!(
+            target: "spine.act",
+            endpoint_id = %body_endpoint_id,
+            released_acts = released,
+            "endpoint_act_dispatch_resumed"
+        );
+        released
+    }
+
+    pub fn paused_endpoint_ids(&self) -> Vec<String> {
+        self.act_pause.paused_endpoint_ids()
+    }
+
     #[tracing::instrument(
         name = "spine_on_act_final",
         target = "spine.act",
@@ -284,7 +400,17 @@ impl Spine {
         )
     )]
     pub async fn on_act_final(&self, tick: u64, act: Act) -> Result<ActDispatchResult, SpineError> {
-        match self.dispatch_act(tick, act.clone()).await {
+        let dispatched = self.dispatch_act(tick, act.clone()).await;
+        self.settle_act_final(&act, dispatched).await
+    }
+
+    /// Reports failed dispatches back to cortex as `dispatch.failed` senses.
+    async fn settle_act_final(
+        &self,
+        act: &Act,
+        dispatched: Result<ActDispatchResult, SpineError>,
+    ) -> Result<ActDispatchResult, SpineError> {
+        match dispatched {
             Ok(ActDispatchResult::Acknowledged { reference_id }) => {
                 Ok(ActDispatchResult::Acknowledged { reference_id })
             }
@@ -292,7 +418,7 @@ impl Spine {
                 reason_code,
                 reference_id,
             }) => {
-                self.emit_dispatch_failure_sense(&act, &reason_code, &reference_id)
+                self.emit_dispatch_failure_sense(act, &reason_code, &reference_id)
                     .await;
                 Ok(ActDispatchResult::Rejected {
                     reason_code,
@@ -303,7 +429,7 @@ impl Spine {
                 reason_code,
                 reference_id,
             }) => {
-                self.emit_dispatch_failure_sense(&act, &reason_code, &reference_id)
+                self.emit_dispatch_failure_sense(act, &reason_code, &reference_id)
                     .await;
                 Ok(ActDispatchResult::Lost {
                     reason_code,
@@ -314,7 +440,7 @@ impl Spine {
                 let reason_code = "spine_dispatch_error".to_string();
                 let reference_id =
                     format!("spine:error:{}:{}", act.act_instance_id, err.kind as u8);
-                self.emit_dispatch_failure_sense(&act, &reason_code, &reference_id)
+                self.emit_dispatch_failure_sense(act, &reason_code, &reference_id)
                     .await;
                 Ok(ActDispatchResult::Lost {
                     reason_code,
@@ -335,6 +461,15 @@ impl Spine {
         )
     )]
     pub async fn dispatch_act(&self, tick: u64, act: Act) -> Result<ActDispatchResult, SpineError> {
+        self.dispatch_act_inner(tick, act, None).await
+    }
+
+    async fn dispatch_act_inner(
+        &self,
+        tick: u64,
+        act: Act,
+        released_from: Option<&str>,
+    ) -> Result<ActDispatchResult, SpineError> {
         if act.act_instance_id.trim().is_empty() || act.endpoint_id.trim().is_empty() {
             tracing::warn!(
                 target: "spine.act",
@@ -345,55 +480,112 @@ impl Spine {
             ));
         }
 
-        let Some(dispatch) = self.resolve_dispatch(&act.endpoint_id) else {
-            let outcome = ActDispatchResult::Rejected {
-                reason_code: "endpoint_not_found".to_string(),
-                reference_id: format!("spine:missing_endpoint:{}", act.act_instance_id),
-            };
+        if let Some(outcome) = self.reject_oversized_payload(&act) {
             Self::log_dispatch_outcome(tick, &act, "unknown", &outcome);
             return Ok(outcome);
-        };
+        }
 
-        match dispatch {
-            EndpointDispatch::Adapter(adapter_id) => {
-                observability_runtime::emit_spine_act_bind(
-                    tick,
-                    &act.act_instance_id,
-                    Some(&act.endpoint_id),
-                    Some(&act.neural_signal_descriptor_id),
-                    Some("adapter"),
-                    None,
-                    Some(act.payload.clone()),
-                );
-                tracing::debug!(
-                    target: "spine.act",
-                    dispatch_binding = "adapter",
-                    adapter_id = adapter_id,
-                    "dispatching_act_to_adapter"
-                );
-                match self.invoke_adapter(adapter_id, act.clone()) {
-                    Ok(outcome) => {
-                        Self::log_dispatch_outcome(tick, &act, "adapter", &outcome);
-                        Ok(outcome)
+        let mut act = act;
+        let mut failure = None;
+        for (endpoint_id, dispatch) in self.resolve_dispatch(&act) {
+            if endpoint_id != act.endpoint_id {
+                match &failure {
+                    None => tracing::debug!(
+                        target: "spine.act",
+                        act_instance_id = %act.act_instance_id,
+                        requested_endpoint_id = %act.endpoint_id,
+                        endpoint_id = %endpoint_id,
+                        "act_routed_to_higher_priority_endpoint"
+                    ),
+                    Some((_, err)) => tracing::warn!(
+                        target: "spine.act",
+                        act_instance_id = %act.act_instance_id,
+                        failed_endpoint_id = %act.endpoint_id,
+                        endpoint_id = %endpoint_id,
+                        error = %err,
+                        "act_dispatch_falling_through_to_next_endpoint"
+                    ),
+                }
+                act.endpoint_id = endpoint_id;
+            }
+
+            match dispatch {
+                EndpointDispatch::Adapter(adapter_id) => {
+                    observability_runtime::emit_spine_act_bind(
+                        tick,
+                        &act.act_instance_id,
+                        Some(&act.endpoint_id),
+                        Some(&act.neural_signal_descriptor_id),
+                        Some("adapter"),
+                        None,
+                        Some(act.payload.clone()),
+                    );
+                    tracing::debug!(
+                        target: "spine.act",
+                        dispatch_binding = "adapter",
+                        adapter_id = adapter_id,
+                        "dispatching_act_to_adapter"
+                    );
+                    let hold = if released_from == Some(act.endpoint_id.as_str()) {
+                        PauseHold::NotPaused
+                    } else {
+                        self.act_pause.hold(tick, &act)
+                    };
+                    let held = match hold {
+                        PauseHold::NotPaused => None,
+                        PauseHold::Queued => Some(ActDispatchResult::Acknowledged {
+                            reference_id: format!("spine:paused_queue:{}", act.act_instance_id),
+                        }),
+                        PauseHold::QueueFull => Some(ActDispatchResult::Rejected {
+                            reason_code: "endpoint_paused".to_string(),
+                            reference_id: format!("spine:paused:{}", act.act_instance_id),
+                        }),
+                    };
+                    if let Some(outcome) = held {
+                        Self::log_dispatch_outcome(tick, &act, "paused", &outcome);
+                        return Ok(outcome);
                     }
-                    Err(err) => {
-                        tracing::warn!(
-                            target: "spine.act",
-                            dispatch_binding = "adapter",
-                            adapter_id = adapter_id,
-                            error = %err,
-                            "adapter_invoke_failed"
-                        );
-                        let outcome = ActDispatchResult::Lost {
-                            reason_code: "dispatch_lost".to_string(),
-                            reference_id: format!("spine:lost:{}", act.act_instance_id),
-                        };
-                        Self::log_dispatch_outcome(tick, &act, "adapter", &outcome);
-                        Ok(outcome)
+                    if let Some(pacing) = &self.act_pacing {
+                        pacing.wait_for_slot(&act.endpoint_id).await;
+                    }
+                    let permit = self.acquire_in_flight_act_permit(&act).await;
+                    match self.invoke_adapter(
+                        adapter_id,
+                        self.transform_act_payload(act.clone()),
+                        permit,
+                    ) {
+                        Ok(outcome) => {
+                            Self::log_dispatch_outcome(tick, &act, "adapter", &outcome);
+                            return Ok(outcome);
+                        }
+                        Err(err) => failure = Some((adapter_id, err)),
                     }
                 }
             }
         }
+
+        let Some((adapter_id, err)) = failure else {
+            let outcome = ActDispatchResult::Rejected {
+                reason_code: "endpoint_not_found".to_string(),
+                reference_id: format!("spine:missing_endpoint:{}", act.act_instance_id),
+            };
+            Self::log_dispatch_outcome(tick, &act, "unknown", &outcome);
+            return Ok(outcome);
+        };
+        tracing::warn!(
+            target: "spine.act",
+            dispatch_binding = "adapter",
+            adapter_id = adapter_id,
+            error = %err,
+            "adapter_invoke_failed"
+        );
+        self.record_act_delivery(&act.endpoint_id, false).await;
+        let outcome = ActDispatchResult::Lost {
+            reason_code: "dispatch_lost".to_string(),
+            reference_id: format!("spine:lost:{}", act.act_instance_id),
+        };
+        Self::log_dispatch_outcome(tick, &act, "adapter", &outcome);
+        Ok(outcome)
     }
 
     async fn emit_dispatch_failure_sense(&self, act: &Act, reason_code: &str, reference_id: &str) {
@@ -427,10 +619,8 @@ impl Spine {
         endpoint_name: &str,
         binding: EndpointBinding,
     ) -> Result<BodyEndpointHandle> {
-        let endpoint_name = endpoint_name.trim();
-        if endpoint_name.is_empty() {
-            return Err(anyhow::anyhow!("endpoint_name cannot be empty"));
-        }
+        let endpoint_name =
+            normalize_endpoint_name(endpoint_name, self.endpoint_name_rules.as_ref())?;
 
         let dispatch = binding.into_dispatch();
         let adapter_id = dispatch.adapter_id();
@@ -448,6 +638,7 @@ impl Spine {
             body_endpoint_id: body_endpoint_id.clone(),
             dispatch: dispatch.clone(),
             route_keys: BTreeSet::new(),
+            act_payload_transform: None,
         };
         let mut state = self.endpoint_state.lock().expect("lock poisoned");
         state.by_id.insert(body_endpoint_id.clone(), registered);
@@ -466,6 +657,32 @@ impl Spine {
         Ok(BodyEndpointHandle { body_endpoint_id })
     }
 
+    /// Replaces the payload transform applied to acts bound for the endpoint.
+    pub fn set_act_payload_transform(
+        &self,
+        body_endpoint_id: &str,
+        transform: ActPayloadTransform,
+    ) -> Result<()> {
+        let mut state = self.endpoint_state.lock().expect("lock poisoned");
+        let endpoint = state.by_id.get_mut(body_endpoint_id).ok_or_else(|| {
+            anyhow::anyhow!("body endpoint {} is not registered", body_endpoint_id)
+        })?;
+        endpoint.act_payload_transform = (!transform.is_identity()).then_some(transform);
+        Ok(())
+    }
+
+    fn transform_act_payload(&self, mut act: Act) -> Act {
+        let state = self.endpoint_state.lock().expect("lock poisoned");
+        if let Some(transform) = state
+            .by_id
+            .get(&act.endpoint_id)
+            .and_then(|endpoint| endpoint.act_payload_transform.as_ref())
+        {
+            act.payload = transform.apply(act.payload);
+        }
+        act
+    }
+
     pub async fn add_ns_descriptors(
         &self,
         body_endpoint_id: &str,
@@ -474,6 +691,7 @@ impl Spine {
         if descriptors.is_empty() {
             return Ok(Vec::new());
         }
+        ensure_payload_schemas_compile(&descriptors)?;
 
         let normalized_entries = {
             let state = self.endpoint_state.lock().expect("lock poisoned");
@@ -485,6 +703,7 @@ impl Spine {
                 .into_iter()
                 .map(|mut descriptor| {
                     descriptor.endpoint_id = endpoint.body_endpoint_id.clone();
+                    self.clamp_descriptor_payload_limit(&mut descriptor);
                     descriptor
                 })
                 .collect::<Vec<_>>()
@@ -554,11 +773,13 @@ impl Spine {
             return Ok(Vec::new());
         };
 
-        if let Err(err) = accepted_routes
-            .iter()
-            .cloned()
-            .try_for_each(|route| self.upsert_route(route, dispatch.clone()))
-        {
+        if let Err(err) = accepted_entries.iter().try_for_each(|entry| {
+            self.upsert_route(
+                route_key_from_descriptor(entry),
+                entry.priority,
+                dispatch.clone(),
+            )
+        }) {
             {
                 let mut state = self.endpoint_state.lock().expect("lock poisoned");
                 if let Some(endpoint) = state.by_id.get_mut(body_endpoint_id) {
@@ -583,6 +804,74 @@ impl Spine {
         Ok(accepted_entries)
     }
 
+    /// Replaces descriptors of routes the endpoint already owns, in place.
+    ///
+    /// Routing is unchanged; Stem bumps the catalog version when any entry changes.
+    /// Descriptors for routes the endpoint has not registered are rejected, not added.
+    pub async fn update_ns_descriptors(
+        &self,
+        body_endpoint_id: &str,
+        descriptors: Vec<NeuralSignalDescriptor>,
+    ) -> Result<Vec<NeuralSignalDescriptor>> {
+        if descriptors.is_empty() {
+            return Ok(Vec::new());
+        }
+        ensure_payload_schemas_compile(&descriptors)?;
+
+        let owned_entries = {
+            let state = self.endpoint_state.lock().expect("lock poisoned");
+            let endpoint = state
+                .by_id
+                .get(body_endpoint_id)
+                .ok_or_else(|| anyhow::anyhow!("body endpoint is not registered"))?;
+            descriptors
+                .into_iter()
+                .map(|mut descriptor| {
+                    descriptor.endpoint_id = endpoint.body_endpoint_id.clone();
+                    self.clamp_descriptor_payload_limit(&mut descriptor);
+                    descriptor
+                })
+                .filter(|descriptor| {
+                    if endpoint
+                        .route_keys
+                        .contains(&route_key_from_descriptor(descriptor))
+                    {
+                        return true;
+                    }
+                    tracing::warn!(
+                        target = "spine",
+                        endpoint_id = %descriptor.endpoint_id,
+                        neural_signal_descriptor_id = %descriptor.neural_signal_descriptor_id,
+                        "update_ns_descriptor_rejected_unregistered_route"
+                    );
+                    false
+                })
+                .collect::<Vec<_>>()
+        };
+        if owned_entries.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let patch_commit = self
+            .stem_control
+            .apply_neural_signal_descriptor_patch(NeuralSignalDescriptorPatch {
+                entries: owned_entries,
+            })
+            .await;
+
+        for rejected in &patch_commit.rejected_entries {
+            tracing::warn!(
+                target = "spine",
+                endpoint_id = %rejected.entry.endpoint_id,
+                neural_signal_descriptor_id = %rejected.entry.neural_signal_descriptor_id,
+                reason_code = %rejected.reason_code,
+                "stem_rejected_ns_descriptor_patch_entry"
+            );
+        }
+
+        Ok(patch_commit.accepted_entries)
+    }
+
     pub async fn drop_ns_descriptors(
         &self,
         body_endpoint_id: &str,
@@ -643,6 +932,37 @@ impl Spine {
         Ok(drop_commit.accepted_routes)
     }
 
+    /// Counts one delivery attempt against the endpoint's error budget, if one is configured.
+    ///
+    /// Standing changes are published as `spine.body_endpoint.<id>.standing` proprioception so
+    /// cortex can prefer other capabilities while an endpoint is degraded.
+    pub async fn record_act_delivery(&self, body_endpoint_id: &str, delivered: bool) {
+        let Some(budget) = &self.error_budget else {
+            return;
+        };
+        let changed = budget
+            .lock()
+            .expect("lock poisoned")
+            .record(body_endpoint_id, delivered);
+        let Some(standing) = changed else {
+            return;
+        };
+        tracing::warn!(
+            target: "spine",
+            endpoint_id = %body_endpoint_id,
+            standing = standing.as_str(),
+            "endpoint_standing_changed"
+        );
+        <Self as SpineControlPort>::apply_proprioception_patch(
+            self,
+            BTreeMap::from([(
+                endpoint_standing_proprioception_key(body_endpoint_id),
+                standing.as_str().to_string(),
+            )]),
+        )
+        .await;
+    }
+
     pub async fn remove_endpoint(&self, body_endpoint_id: &str) {
         let endpoint = {
             let mut state = self.endpoint_state.lock().expect("lock poisoned");
@@ -651,6 +971,28 @@ impl Spine {
             };
             endpoint
         };
+        if let Some(pacing) = &self.act_pacing {
+            pacing.forget(body_endpoint_id);
+        }
+        self.channel_stats.forget(body_endpoint_id);
+        for act in self.act_pause.forget(body_endpoint_id) {
+            let reference_id = format!("spine:paused_removed:{}", act.act_instance_id);
+            self.emit_dispatch_failure_sense(&act, "endpoint_removed", &reference_id)
+                .await;
+        }
+        let tracked_standing = self.error_budget.as_ref().and_then(|budget| {
+            budget
+                .lock()
+                .expect("lock poisoned")
+                .forget(body_endpoint_id)
+        });
+        if tracked_standing.is_some() {
+            <Self as SpineControlPort>::apply_proprioception_drop(
+                self,
+                vec![endpoint_standing_proprioception_key(body_endpoint_id)],
+            )
+            .await;
+        }
         let endpoint_routes = endpoint.route_keys.iter().cloned().collect::<Vec<_>>();
         observability_runtime::emit_spine_endpoint_lifecycle(
             body_endpoint_id,
@@ -739,19 +1081,113 @@ impl Spine {
             .contains_key(&adapter_id)
     }
 
-    fn resolve_dispatch(&self, endpoint_id: &str) -> Option<EndpointDispatch> {
-        self.routing
-            .read()
-            .expect("lock poisoned")
+    /// Endpoints that may take `act`, best first.
+    ///
+    /// Live endpoints sharing the addressed endpoint's name and the act descriptor compete on
+    /// descriptor priority, ties keeping the addressed endpoint first. Without such peers the
+    /// addressed endpoint is the only candidate.
+    fn resolve_dispatch(&self, act: &Act) -> Vec<(String, EndpointDispatch)> {
+        let routing = self.routing.read().expect("lock poisoned");
+        let Some(addressed) = routing.by_endpoint.get(&act.endpoint_id) else {
+            return Vec::new();
+        };
+        let route_for = |endpoint_id: &str| NeuralSignalDescriptorRouteKey {
+            r#type: NeuralSignalType::Act,
+            endpoint_id: endpoint_id.to_string(),
+            neural_signal_descriptor_id: act.neural_signal_descriptor_id.clone(),
+        };
+        let endpoint_name = endpoint_name_of(&act.endpoint_id);
+        let mut candidates = routing
             .by_endpoint
-            .get(endpoint_id)
-            .map(|entry| entry.dispatch.clone())
+            .iter()
+            .filter(|(endpoint_id, entry)| {
+                *endpoint_id != &act.endpoint_id
+                    && endpoint_name_of(endpoint_id) == endpoint_name
+                    && entry.route_keys.contains(&route_for(endpoint_id))
+                    && routing
+                        .adapters
+                        .get(&entry.dispatch.adapter_id())
+                        .is_some_and(|tx| !tx.is_closed())
+            })
+            .chain(std::iter::once((&act.endpoint_id, addressed)))
+            .map(|(endpoint_id, entry)| {
+                let priority = entry
+                    .route_priorities
+                    .get(&route_for(endpoint_id))
+                    .copied()
+                    .unwrap_or_default();
+                (priority, endpoint_id.clone(), entry.dispatch.clone())
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|(lhs_priority, lhs_id, _), (rhs_priority, rhs_id, _)| {
+            rhs_priority
+                .cmp(lhs_priority)
+                .then_with(|| (rhs_id == &act.endpoint_id).cmp(&(lhs_id == &act.endpoint_id)))
+                .then_with(|| lhs_id.cmp(rhs_id))
+        });
+        candidates
+            .into_iter()
+            .map(|(_, endpoint_id, dispatch)| (endpoint_id, dispatch))
+            .collect()
+    }
+
+    /// Lowers every `maxLength` an endpoint declares in its payload schema to the operator cap.
+    fn clamp_descriptor_payload_limit(&self, descriptor: &mut NeuralSignalDescriptor) {
+        let Some(cap) = self.max_descriptor_payload_bytes else {
+            return;
+        };
+        let declared = clamp_schema_max_length(&mut descriptor.payload_schema, cap);
+        if let Some(declared) = declared {
+            tracing::warn!(
+                target: "spine",
+                endpoint_id = %descriptor.endpoint_id,
+                neural_signal_descriptor_id = %descriptor.neural_signal_descriptor_id,
+                declared_max_length = declared,
+                max_descriptor_payload_bytes = cap,
+                "ns_descriptor_payload_limit_clamped"
+            );
+        }
+    }
+
+    /// Rejects an act whose serialized payload is larger than `max_descriptor_payload_bytes`.
+    fn reject_oversized_payload(&self, act: &Act) -> Option<ActDispatchResult> {
+        let cap = self.max_descriptor_payload_bytes?;
+        let payload_bytes = act.payload.to_string().len() as u64;
+        if payload_bytes <= cap {
+            return None;
+        }
+        tracing::warn!(
+            target: "spine.act",
+            act_instance_id = %act.act_instance_id,
+            endpoint_id = %act.endpoint_id,
+            payload_bytes = payload_bytes,
+            max_descriptor_payload_bytes = cap,
+            "act_payload_over_operator_cap"
+        );
+        Some(ActDispatchResult::Rejected {
+            reason_code: "payload_too_large".to_string(),
+            reference_id: format!("spine:payload_too_large:{}", act.act_instance_id),
+        })
+    }
+
+    /// Waits for a slot under `max_in_flight_acts`; dispatch queues here while the limit is reached.
+    async fn acquire_in_flight_act_permit(&self, act: &Act) -> Option<OwnedSemaphorePermit> {
+        let semaphore = self.in_flight_acts.as_ref()?;
+        if semaphore.available_permits() == 0 {
+            tracing::debug!(
+                target: "spine.act",
+                act_instance_id = %act.act_instance_id,
+                "act_dispatch_waiting_for_in_flight_slot"
+            );
+        }
+        Arc::clone(semaphore).acquire_owned().await.ok()
     }
 
     fn invoke_adapter(
         &self,
         adapter_id: AdapterId,
         act: Act,
+        permit: Option<OwnedSemaphorePermit>,
     ) -> Result<ActDispatchResult, SpineError> {
         let tx = self
             .routing
@@ -762,7 +1198,13 @@ impl Spine {
             .cloned()
             .ok_or_else(|| backend_failure(format!("adapter {} is unavailable", adapter_id)))?;
 
-        if tx.send(act.clone()).is_err() {
+        if tx
+            .send(InFlightAct {
+                act: act.clone(),
+                permit,
+            })
+            .is_err()
+        {
             tracing::warn!(
                 target: "spine.act",
                 dispatch_binding = "adapter",
@@ -853,6 +1295,7 @@ impl Spine {
     fn upsert_route(
         &self,
         route: NeuralSignalDescriptorRouteKey,
+        priority: Option<i32>,
         dispatch: EndpointDispatch,
     ) -> Result<(), SpineError> {
         if route.endpoint_id.trim().is_empty()
@@ -870,6 +1313,7 @@ impl Spine {
             .or_insert_with(|| RegisteredEndpointRoutes {
                 dispatch: dispatch.clone(),
                 route_keys: BTreeSet::new(),
+                route_priorities: BTreeMap::new(),
             });
 
         if !entry.dispatch.is_compatible_with(&dispatch) {
@@ -881,6 +1325,10 @@ impl Spine {
         }
 
         entry.dispatch = dispatch;
+        match priority {
+            Some(priority) => entry.route_priorities.insert(route.clone(), priority),
+            None => entry.route_priorities.remove(&route),
+        };
         entry.route_keys.insert(route);
         Ok(())
     }
@@ -891,6 +1339,7 @@ impl Spine {
 
         if let Some(entry) = routing.by_endpoint.get_mut(&route.endpoint_id) {
             entry.route_keys.remove(route);
+            entry.route_priorities.remove(route);
             remove_endpoint = entry.route_keys.is_empty();
         }
 
@@ -937,6 +1386,52 @@ fn route_summary_from_routes(routes: &[NeuralSignalDescriptorRouteKey]) -> serde
     })
 }
 
+/// Rejects descriptors whose `payload_schema` the act/sense payload validator cannot compile,
+/// so a misconfigured endpoint fails at registration rather than at its first payload.
+fn ensure_payload_schemas_compile(descriptors: &[NeuralSignalDescriptor]) -> Result<()> {
+    for descriptor in descriptors {
+        if let Err(err) = jsonschema::JSONSchema::compile(&descriptor.payload_schema) {
+            return Err(anyhow::anyhow!(
+                "ns descriptor '{}' has an invalid payload_schema: {}",
+                descriptor.neural_signal_descriptor_id,
+                err
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Clamps `maxLength` at any depth of `schema` to `cap`, returning the largest declared value
+/// that exceeded it.
+fn clamp_schema_max_length(schema: &mut serde_json::Value, cap: u64) -> Option<u64> {
+    let mut clamped_from = None;
+    match schema {
+        serde_json::Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                let nested = if key == "maxLength" {
+                    match value.as_u64() {
+                        Some(declared) if declared > cap => {
+                            *value = json!(cap);
+                            Some(declared)
+                        }
+                        _ => None,
+                    }
+                } else {
+                    clamp_schema_max_length(value, cap)
+                };
+                clamped_from = clamped_from.max(nested);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                clamped_from = clamped_from.max(clamp_schema_max_length(item, cap));
+            }
+        }
+        _ => {}
+    }
+    clamped_from
+}
+
 fn dispatch_outcome_class(outcome: &ActDispatchResult) -> DispatchOutcomeClass {
     match outcome {
         ActDispatchResult::Acknowledged { .. } => DispatchOutcomeClass::Acknowledged,
@@ -1045,6 +1540,15 @@ impl SpineAdapterPort for Spine {
         self.add_ns_descriptors(body_endpoint_id, descriptors).await
     }
 
+    async fn update_ns_descriptors(
+        &self,
+        body_endpoint_id: &str,
+        descriptors: Vec<NeuralSignalDescriptor>,
+    ) -> Result<Vec<NeuralSignalDescriptor>> {
+        self.update_ns_descriptors(body_endpoint_id, descriptors)
+            .await
+    }
+
     async fn drop_ns_descriptors(
         &self,
         body_endpoint_id: &str,
@@ -1053,10 +1557,30 @@ impl SpineAdapterPort for Spine {
         self.drop_ns_descriptors(body_endpoint_id, routes).await
     }
 
+    async fn set_act_payload_transform(
+        &self,
+        body_endpoint_id: &str,
+        transform: ActPayloadTransform,
+    ) -> Result<()> {
+        self.set_act_payload_transform(body_endpoint_id, transform)
+    }
+
     async fn drop_endpoint(&self, body_endpoint_id: &str) {
         self.remove_endpoint(body_endpoint_id).await;
     }
 
+    async fn set_act_dispatch_paused(&self, body_endpoint_id: &str, paused: bool) {
+        if paused {
+            self.pause_endpoint(body_endpoint_id);
+        } else {
+            self.resume_endpoint(body_endpoint_id).await;
+        }
+    }
+
+    async fn record_act_delivery(&self, body_endpoint_id: &str, delivered: bool) {
+        self.record_act_delivery(body_endpoint_id, delivered).await;
+    }
+
     async fn apply_proprioception_patch(&self, entries: BTreeMap<String, String>) {
         <Self as SpineControlPort>::apply_proprioception_patch(self, entries).await;
     }
@@ -1074,3 +1598,578 @@ pub async fn shutdown_global_spine(spine: Arc<Spine>) -> Result<()> {
     spine.shutdown().await;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        stem::{
+            AfferentMiddleware, AfferentMiddlewareDecision, AfferentPathwayError,
+            PathwayMiddlewareDecision, StemPhysicalStateStore,
+        },
+        types::{NeuralSignalType, PhysicalState},
+    };
+
+    use super::*;
+
+    fn act_descriptor(
+        neural_signal_descriptor_id: &str,
+        max_length: u64,
+    ) -> NeuralSignalDescriptor {
+        NeuralSignalDescriptor {
+            r#type: NeuralSignalType::Act,
+            endpoint_id: String::new(),
+            neural_signal_descriptor_id: neural_signal_descriptor_id.to_string(),
+            payload_schema: json!({ "type": "string", "maxLength": max_length }),
+            priority: None,
+        }
+    }
+
+    async fn catalog(store: &StemPhysicalStateStore) -> PhysicalState {
+        store.snapshot_for_cycle(0).await
+    }
+
+    struct CaptureSenseMiddleware {
+        tx: mpsc::Sender<Sense>,
+    }
+
+    #[async_trait]
+    impl AfferentMiddleware for CaptureSenseMiddleware {
+        async fn handle_sense(
+            &self,
+            sense: &Sense,
+        ) -> Result<AfferentMiddlewareDecision, AfferentPathwayError> {
+            self.tx
+                .send(sense.clone())
+                .await
+                .expect("capture channel should stay open");
+            Ok(PathwayMiddlewareDecision::Accepted(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn update_ns_descriptors_replaces_schema_in_place_and_bumps_catalog_version() -> Result<()>
+    {
+        let store = StemPhysicalStateStore::new(BTreeMap::new());
+        let spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                ..SpineRuntimeConfig::default()
+            },
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(store.clone()),
+        );
+        let _context = spine.create_adapter_context(1);
+        let handle = spine.add_endpoint("tool", EndpointBinding::Adapter { adapter_id: 1 })?;
+        spine
+            .add_ns_descriptors(&handle.body_endpoint_id, vec![act_descriptor("say", 16)])
+            .await?;
+        let registered = catalog(&store).await.ns_descriptor;
+
+        let updated = spine
+            .update_ns_descriptors(
+                &handle.body_endpoint_id,
+                vec![act_descriptor("say", 256), act_descriptor("unknown", 1)],
+            )
+            .await?;
+
+        assert_eq!(updated.len(), 1);
+        let current = catalog(&store).await.ns_descriptor;
+        assert_ne!(current.version, registered.version);
+        assert_eq!(current.entries.len(), 1);
+        assert_eq!(current.entries[0].endpoint_id, handle.body_endpoint_id);
+        assert_eq!(current.entries[0].payload_schema["maxLength"], 256);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn malformed_payload_schema_is_rejected_at_registration() -> Result<()> {
+        let store = StemPhysicalStateStore::new(BTreeMap::new());
+        let spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                ..SpineRuntimeConfig::default()
+            },
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(store.clone()),
+        );
+        let _context = spine.create_adapter_context(1);
+        let handle = spine.add_endpoint("tool", EndpointBinding::Adapter { adapter_id: 1 })?;
+        let malformed = NeuralSignalDescriptor {
+            payload_schema: json!({ "type": "strnig", "maxLength": "sixteen" }),
+            ..act_descriptor("shout", 16)
+        };
+
+        let err = spine
+            .add_ns_descriptors(
+                &handle.body_endpoint_id,
+                vec![act_descriptor("say", 16), malformed],
+            )
+            .await
+            .expect_err("malformed schema should be rejected");
+
+        assert!(
+            err.to_string()
+                .contains("'shout' has an invalid payload_schema")
+        );
+        assert!(catalog(&store).await.ns_descriptor.entries.is_empty());
+        Ok(())
+    }
+
+    fn act(act_instance_id: &str, endpoint_id: &str) -> Act {
+        Act {
+            act_instance_id: act_instance_id.to_string(),
+            endpoint_id: endpoint_id.to_string(),
+            neural_signal_descriptor_id: "say".to_string(),
+            might_emit_sense_ids: Vec::new(),
+            payload: json!("hello"),
+            goal_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn configured_execution_mode_is_reported_and_handed_to_adapters() {
+        let default_spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                ..SpineRuntimeConfig::default()
+            },
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(StemPhysicalStateStore::new(BTreeMap::new())),
+        );
+        assert_eq!(
+            default_spine.mode(),
+            SpineExecutionMode::SerializedDeterministic
+        );
+
+        let spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                execution_mode: SpineExecutionMode::BestEffortReplayable,
+                ..SpineRuntimeConfig::default()
+            },
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(StemPhysicalStateStore::new(BTreeMap::new())),
+        );
+        assert_eq!(spine.mode(), SpineExecutionMode::BestEffortReplayable);
+        assert_eq!(
+            spine.create_adapter_context(1).execution_mode,
+            SpineExecutionMode::BestEffortReplayable
+        );
+    }
+
+    #[tokio::test]
+    async fn dispatch_beyond_in_flight_limit_waits_for_a_released_slot() -> Result<()> {
+        let spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                max_in_flight_acts: Some(2),
+                ..SpineRuntimeConfig::default()
+            },
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(StemPhysicalStateStore::new(BTreeMap::new())),
+        );
+        let mut context = spine.create_adapter_context(1);
+        let handle = spine.add_endpoint("tool", EndpointBinding::Adapter { adapter_id: 1 })?;
+        spine
+            .add_ns_descriptors(&handle.body_endpoint_id, vec![act_descriptor("say", 16)])
+            .await?;
+
+        for act_instance_id in ["act-1", "act-2"] {
+            let outcome = spine
+                .dispatch_act(1, act(act_instance_id, &handle.body_endpoint_id))
+                .await?;
+            assert!(matches!(outcome, ActDispatchResult::Acknowledged { .. }));
+        }
+
+        let queued = tokio::spawn({
+            let spine = Arc::clone(&spine);
+            let endpoint_id = handle.body_endpoint_id.clone();
+            async move { spine.dispatch_act(1, act("act-3", &endpoint_id)).await }
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert!(!queued.is_finished());
+
+        let first = context
+            .act_rx
+            .recv()
+            .await
+            .expect("first act should arrive");
+        assert_eq!(first.act.act_instance_id, "act-1");
+        drop(first);
+
+        let outcome = tokio::time::timeout(std::time::Duration::from_secs(1), queued)
+            .await
+            .expect("queued dispatch should proceed once a slot is released")??;
+        assert!(matches!(outcome, ActDispatchResult::Acknowledged { .. }));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn registered_rename_transform_reshapes_outbound_act_payload() -> Result<()> {
+        let spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                ..SpineRuntimeConfig::default()
+            },
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(StemPhysicalStateStore::new(BTreeMap::new())),
+        );
+        let mut context = spine.create_adapter_context(1);
+        let handle = spine.add_endpoint("messenger", EndpointBinding::Adapter { adapter_id: 1 })?;
+        spine
+            .add_ns_descriptors(
+                &handle.body_endpoint_id,
+                vec![NeuralSignalDescriptor {
+                    payload_schema: json!({ "type": "object" }),
+                    ..act_descriptor("say", 16)
+                }],
+            )
+            .await?;
+        spine.set_act_payload_transform(
+            &handle.body_endpoint_id,
+            ActPayloadTransform {
+                rename_fields: BTreeMap::from([("text".to_string(), "message".to_string())]),
+            },
+        )?;
+
+        spine
+            .dispatch_act(
+                1,
+                Act {
+                    payload: json!({ "text": "hello", "channel": "general" }),
+                    ..act("act-1", &handle.body_endpoint_id)
+                },
+            )
+            .await?;
+
+        let in_flight = context.act_rx.recv().await.expect("act should arrive");
+        assert_eq!(
+            in_flight.act.payload,
+            json!({ "message": "hello", "channel": "general" })
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn acts_to_paced_endpoint_are_spaced_by_min_interval() -> Result<()> {
+        let spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                endpoint_min_inter_act_ms: BTreeMap::from([("messenger".to_string(), 80)]),
+                ..SpineRuntimeConfig::default()
+            },
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(StemPhysicalStateStore::new(BTreeMap::new())),
+        );
+        let mut context = spine.create_adapter_context(1);
+        let handle = spine.add_endpoint("messenger", EndpointBinding::Adapter { adapter_id: 1 })?;
+        spine
+            .add_ns_descriptors(&handle.body_endpoint_id, vec![act_descriptor("say", 16)])
+            .await?;
+
+        let receiver = tokio::spawn(async move {
+            let mut arrivals = Vec::new();
+            for _ in 0..2 {
+                let in_flight = context.act_rx.recv().await.expect("act should arrive");
+                arrivals.push((in_flight.act.act_instance_id, tokio::time::Instant::now()));
+            }
+            arrivals
+        });
+        for act_instance_id in ["act-1", "act-2"] {
+            spine
+                .dispatch_act(1, act(act_instance_id, &handle.body_endpoint_id))
+                .await?;
+        }
+
+        let arrivals = receiver.await.expect("receiver should join");
+        assert_eq!(arrivals[0].0, "act-1");
+        assert_eq!(arrivals[1].0, "act-2");
+        assert!(arrivals[1].1 - arrivals[0].1 >= std::time::Duration::from_millis(80));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn paused_endpoint_queues_acts_while_others_still_dispatch() -> Result<()> {
+        let spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                max_queued_acts_per_paused_endpoint: 1,
+                ..SpineRuntimeConfig::default()
+            },
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(StemPhysicalStateStore::new(BTreeMap::new())),
+        );
+        let mut context = spine.create_adapter_context(1);
+        let paused = spine.add_endpoint("printer", EndpointBinding::Adapter { adapter_id: 1 })?;
+        let flowing = spine.add_endpoint("tool", EndpointBinding::Adapter { adapter_id: 1 })?;
+        for handle in [&paused, &flowing] {
+            spine
+                .add_ns_descriptors(&handle.body_endpoint_id, vec![act_descriptor("say", 16)])
+                .await?;
+        }
+        assert!(spine.pause_endpoint(&paused.body_endpoint_id));
+        assert!(!spine.pause_endpoint("missing.9"));
+
+        let queued = spine
+            .dispatch_act(1, act("act-1", &paused.body_endpoint_id))
+            .await?;
+        assert_eq!(
+            queued,
+            ActDispatchResult::Acknowledged {
+                reference_id: "spine:paused_queue:act-1".to_string(),
+            }
+        );
+        let overflow = spine
+            .dispatch_act(1, act("act-2", &paused.body_endpoint_id))
+            .await?;
+        assert!(matches!(
+            overflow,
+            ActDispatchResult::Rejected { ref reason_code, .. } if reason_code == "endpoint_paused"
+        ));
+        let flowing_outcome = spine
+            .dispatch_act(1, act("act-3", &flowing.body_endpoint_id))
+            .await?;
+        assert!(matches!(
+            flowing_outcome,
+            ActDispatchResult::Acknowledged { .. }
+        ));
+        let delivered = context.act_rx.recv().await.expect("act-3 should arrive");
+        assert_eq!(delivered.act.act_instance_id, "act-3");
+        assert!(context.act_rx.try_recv().is_err());
+
+        assert_eq!(spine.resume_endpoint(&paused.body_endpoint_id).await, 1);
+        let released = context.act_rx.recv().await.expect("act-1 should arrive");
+        assert_eq!(released.act.act_instance_id, "act-1");
+        assert!(spine.paused_endpoint_ids().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn removing_a_paused_endpoint_rejects_its_held_acts() -> Result<()> {
+        let (sense_tx, mut sense_rx) = mpsc::channel(4);
+        let afferent_pathway =
+            SenseAfferentPathway::new(4, vec![Arc::new(CaptureSenseMiddleware { tx: sense_tx })]);
+        let spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                ..SpineRuntimeConfig::default()
+            },
+            afferent_pathway,
+            Arc::new(StemPhysicalStateStore::new(BTreeMap::new())),
+        );
+        let _context = spine.create_adapter_context(1);
+        let handle = spine.add_endpoint("printer", EndpointBinding::Adapter { adapter_id: 1 })?;
+        spine
+            .add_ns_descriptors(&handle.body_endpoint_id, vec![act_descriptor("say", 16)])
+            .await?;
+        assert!(spine.pause_endpoint(&handle.body_endpoint_id));
+        spine
+            .dispatch_act(1, act("act-1", &handle.body_endpoint_id))
+            .await?;
+
+        spine.remove_endpoint(&handle.body_endpoint_id).await;
+
+        let sense = sense_rx.recv().await.expect("held act should be rejected");
+        assert_eq!(sense.neural_signal_descriptor_id, "dispatch.failed");
+        assert_eq!(sense.act_instance_id.as_deref(), Some("act-1"));
+        assert!(sense.payload.contains("reason_code=endpoint_removed"));
+        assert!(spine.paused_endpoint_ids().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn shared_descriptor_dispatches_to_highest_priority_live_endpoint() -> Result<()> {
+        let spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                ..SpineRuntimeConfig::default()
+            },
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(StemPhysicalStateStore::new(BTreeMap::new())),
+        );
+        let desktop_context = spine.create_adapter_context(1);
+        let mut phone_context = spine.create_adapter_context(2);
+        let phone = spine.add_endpoint("present", EndpointBinding::Adapter { adapter_id: 2 })?;
+        let desktop = spine.add_endpoint("present", EndpointBinding::Adapter { adapter_id: 1 })?;
+        for (handle, priority) in [(&phone, 1), (&desktop, 10)] {
+            spine
+                .add_ns_descriptors(
+                    &handle.body_endpoint_id,
+                    vec![NeuralSignalDescriptor {
+                        priority: Some(priority),
+                        ..act_descriptor("say", 16)
+                    }],
+                )
+                .await?;
+        }
+        let mut desktop_rx = desktop_context.act_rx;
+
+        let outcome = spine
+            .dispatch_act(1, act("act-1", &phone.body_endpoint_id))
+            .await?;
+        assert!(matches!(outcome, ActDispatchResult::Acknowledged { .. }));
+        let delivered = desktop_rx.recv().await.expect("act-1 should reach desktop");
+        assert_eq!(delivered.act.endpoint_id, desktop.body_endpoint_id);
+        assert!(phone_context.act_rx.try_recv().is_err());
+
+        drop(desktop_rx);
+        let outcome = spine
+            .dispatch_act(1, act("act-2", &desktop.body_endpoint_id))
+            .await?;
+        assert!(matches!(outcome, ActDispatchResult::Acknowledged { .. }));
+        let delivered = phone_context
+            .act_rx
+            .recv()
+            .await
+            .expect("act-2 should fall through to phone");
+        assert_eq!(delivered.act.endpoint_id, phone.body_endpoint_id);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fallthrough_to_a_paused_endpoint_queues_the_act() -> Result<()> {
+        let spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                ..SpineRuntimeConfig::default()
+            },
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(StemPhysicalStateStore::new(BTreeMap::new())),
+        );
+        let desktop_context = spine.create_adapter_context(1);
+        let mut phone_context = spine.create_adapter_context(2);
+        let phone = spine.add_endpoint("present", EndpointBinding::Adapter { adapter_id: 2 })?;
+        let desktop = spine.add_endpoint("present", EndpointBinding::Adapter { adapter_id: 1 })?;
+        for (handle, priority) in [(&phone, 1), (&desktop, 10)] {
+            spine
+                .add_ns_descriptors(
+                    &handle.body_endpoint_id,
+                    vec![NeuralSignalDescriptor {
+                        priority: Some(priority),
+                        ..act_descriptor("say", 16)
+                    }],
+                )
+                .await?;
+        }
+        drop(desktop_context);
+        assert!(spine.pause_endpoint(&phone.body_endpoint_id));
+
+        let outcome = spine
+            .dispatch_act(1, act("act-1", &desktop.body_endpoint_id))
+            .await?;
+        assert_eq!(
+            outcome,
+            ActDispatchResult::Acknowledged {
+                reference_id: "spine:paused_queue:act-1".to_string(),
+            }
+        );
+        assert!(phone_context.act_rx.try_recv().is_err());
+
+        assert_eq!(spine.resume_endpoint(&phone.body_endpoint_id).await, 1);
+        let released = phone_context
+            .act_rx
+            .recv()
+            .await
+            .expect("act-1 should arrive");
+        assert_eq!(released.act.endpoint_id, phone.body_endpoint_id);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn registered_payload_limit_above_operator_cap_is_clamped() -> Result<()> {
+        let store = StemPhysicalStateStore::new(BTreeMap::new());
+        let spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                max_descriptor_payload_bytes: Some(1_024),
+                ..SpineRuntimeConfig::default()
+            },
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(store.clone()),
+        );
+        let _context = spine.create_adapter_context(1);
+        let handle = spine.add_endpoint("tool", EndpointBinding::Adapter { adapter_id: 1 })?;
+        let mut object_descriptor = act_descriptor("write", 0);
+        object_descriptor.payload_schema = json!({
+            "type": "object",
+            "properties": {
+                "path": { "type": "string", "maxLength": 128 },
+                "body": { "type": "string", "maxLength": 1_000_000_000u64 }
+            }
+        });
+
+        let accepted = spine
+            .add_ns_descriptors(
+                &handle.body_endpoint_id,
+                vec![act_descriptor("say", 1 << 40), object_descriptor],
+            )
+            .await?;
+
+        assert_eq!(accepted.len(), 2);
+        let entries = catalog(&store).await.ns_descriptor.entries;
+        let schema_of = |id: &str| {
+            entries
+                .iter()
+                .find(|entry| entry.neural_signal_descriptor_id == id)
+                .map(|entry| entry.payload_schema.clone())
+                .expect("descriptor should be registered")
+        };
+        assert_eq!(schema_of("say")["maxLength"], 1_024);
+        let write = schema_of("write");
+        assert_eq!(write["properties"]["path"]["maxLength"], 128);
+        assert_eq!(write["properties"]["body"]["maxLength"], 1_024);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn act_payload_over_operator_cap_is_rejected_at_dispatch() -> Result<()> {
+        let spine = Spine::new(
+            &SpineRuntimeConfig {
+                adapters: vec![],
+                max_descriptor_payload_bytes: Some(64),
+                ..SpineRuntimeConfig::default()
+            },
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(StemPhysicalStateStore::new(BTreeMap::new())),
+        );
+        let mut context = spine.create_adapter_context(1);
+        let handle = spine.add_endpoint("tool", EndpointBinding::Adapter { adapter_id: 1 })?;
+        spine
+            .add_ns_descriptors(&handle.body_endpoint_id, vec![act_descriptor("say", 16)])
+            .await?;
+        let with_payload = |act_instance_id: &str, payload: serde_json::Value| Act {
+            payload,
+            ..act(act_instance_id, &handle.body_endpoint_id)
+        };
+
+        let outcome = spine
+            .dispatch_act(
+                1,
+                with_payload(
+                    "act-1",
+                    json!({ "path": "notes.txt", "body": "x".repeat(64) }),
+                ),
+            )
+            .await?;
+        assert!(matches!(
+            outcome,
+            ActDispatchResult::Rejected { ref reason_code, .. } if reason_code == "payload_too_large"
+        ));
+        assert!(context.act_rx.try_recv().is_err());
+
+        let outcome = spine
+            .dispatch_act(
+                1,
+                with_payload("act-2", json!({ "path": "notes.txt", "body": "hi" })),
+            )
+            .await?;
+        assert!(matches!(outcome, ActDispatchResult::Acknowledged { .. }));
+        let delivered = context
+            .act_rx
+            .recv()
+            .await
+            .expect("act-2 should be delivered");
+        assert_eq!(delivered.act.act_instance_id, "act-2");
+        Ok(())
+    }
+}
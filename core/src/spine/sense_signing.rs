@@ -0,0 +1,105 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const MIN_SENSE_SIGNING_KEY_BYTES: usize = 16;
+
+/// Pre-shared HMAC-SHA256 key that binds a body endpoint name to the senses it sends.
+///
+/// Keys come from adapter config, never from the endpoint. Every MAC covers the session nonce
+/// core issued in its `challenge`, so a signature captured on one connection is useless on another.
+#[derive(Clone)]
+pub struct SenseSigningKey {
+    key: Vec<u8>,
+}
+
+/// Sense fields covered by a sense signature.
+#[derive(Debug, Clone, Copy)]
+pub struct SignedSenseFields<'a> {
+    pub session_nonce: &'a str,
+    pub endpoint_name: &'a str,
+    /// Strictly increasing per session; core rejects a sequence it has already accepted.
+    pub sequence: u64,
+    pub sense_instance_id: &'a str,
+    pub neural_signal_descriptor_id: &'a str,
+    pub payload: &'a str,
+    pub weight: f64,
+}
+
+impl SenseSigningKey {
+    pub fn from_hex(encoded: &str) -> Result<Self, String> {
+        let key = hex::decode(encoded.trim())
+            .map_err(|err| format!("sense signing key must be hex encoded: {err}"))?;
+        if key.len() < MIN_SENSE_SIGNING_KEY_BYTES {
+            return Err(format!(
+                "sense signing key must be at least {MIN_SENSE_SIGNING_KEY_BYTES} bytes"
+            ));
+        }
+        Ok(Self { key })
+    }
+
+    /// Hex-encoded proof of key possession an endpoint attaches to `auth` as `auth_signature`.
+    pub fn sign_auth(&self, session_nonce: &str, endpoint_name: &str) -> String {
+        hex::encode(
+            self.auth_mac(session_nonce, endpoint_name)
+                .finalize()
+                .into_bytes(),
+        )
+    }
+
+    pub fn verify_auth(&self, session_nonce: &str, endpoint_name: &str, signature: &str) -> bool {
+        let Ok(signature) = hex::decode(signature.trim()) else {
+            return false;
+        };
+        self.auth_mac(session_nonce, endpoint_name)
+            .verify_slice(&signature)
+            .is_ok()
+    }
+
+    /// Hex-encoded signature endpoints attach to a sense as `signature`.
+    pub fn sign_sense(&self, fields: &SignedSenseFields<'_>) -> String {
+        hex::encode(self.sense_mac(fields).finalize().into_bytes())
+    }
+
+    pub fn verify_sense(&self, fields: &SignedSenseFields<'_>, signature: &str) -> bool {
+        let Ok(signature) = hex::decode(signature.trim()) else {
+            return false;
+        };
+        self.sense_mac(fields).verify_slice(&signature).is_ok()
+    }
+
+    fn auth_mac(&self, session_nonce: &str, endpoint_name: &str) -> HmacSha256 {
+        self.mac(&[b"auth", session_nonce.as_bytes(), endpoint_name.as_bytes()])
+    }
+
+    fn sense_mac(&self, fields: &SignedSenseFields<'_>) -> HmacSha256 {
+        self.mac(&[
+            b"sense",
+            fields.session_nonce.as_bytes(),
+            fields.endpoint_name.as_bytes(),
+            &fields.sequence.to_be_bytes(),
+            fields.sense_instance_id.as_bytes(),
+            fields.neural_signal_descriptor_id.as_bytes(),
+            fields.payload.as_bytes(),
+            &fields.weight.to_bits().to_be_bytes(),
+        ])
+    }
+
+    /// Length-prefixes every field so no two field lists produce the same MAC input.
+    fn mac(&self, fields: &[&[u8]]) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.key).expect("hmac accepts keys of any length");
+        for field in fields {
+            mac.update(&(field.len() as u64).to_be_bytes());
+            mac.update(field);
+        }
+        mac
+    }
+}
+
+impl std::fmt::Debug for SenseSigningKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SenseSigningKey([redacted])")
+    }
+}
@@ -1,3 +1,4 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 pub use crate::types::{
@@ -8,10 +9,16 @@ pub type ReserveEntryId = String;
 pub type CostAttributionId = String;
 pub type CycleId = u64;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// How adapters hand dispatched acts to their endpoints.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SpineExecutionMode {
+    /// Acts are handed over concurrently, so an endpoint with a full queue never holds back
+    /// acts for other endpoints; delivery order across acts is not guaranteed.
     BestEffortReplayable,
+    /// Acts are handed over one at a time in dispatch order; a full endpoint queue holds back
+    /// every later act on the same adapter.
+    #[default]
     SerializedDeterministic,
 }
 
@@ -1,8 +1,14 @@
+pub mod act_confirmation;
+pub mod act_result_injection;
 pub mod afferent_pathway;
 pub mod efferent_pathway;
 pub mod pathway;
 pub mod runtime;
+pub mod sense_queue_watermarks;
+pub mod sense_replay;
 
+pub use act_confirmation::{ActConfirmationGate, ConfirmationEfferentMiddleware};
+pub use act_result_injection::{ACT_RESULT_INJECT_DESCRIPTOR_ID, ActResultInjection};
 pub use afferent_pathway::{
     AfferentControlHandle, AfferentDispatchResult, AfferentMiddleware, AfferentMiddlewareDecision,
     AfferentPathwayError, AfferentPathwayErrorKind, SenseAfferentPathway, SenseConsumerHandle,
@@ -15,3 +21,7 @@ pub use efferent_pathway::{
 };
 pub use pathway::{ContinueOutput, PathwayMiddlewareDecision};
 pub use runtime::{StemControlPort, StemDeps, StemPhysicalStateStore, StemTickRuntime, TickGrant};
+pub use sense_queue_watermarks::{SenseQueueWatermarkCrossing, SenseQueueWatermarks};
+pub use sense_replay::{
+    RecordedSense, SenseReplayPacing, load_sense_recording, parse_sense_recording, replay_senses,
+};
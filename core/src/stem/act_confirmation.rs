@@ -0,0 +1,310 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::{Mutex, oneshot};
+use uuid::Uuid;
+
+use crate::{
+    config::ActConfirmationConfig,
+    spine::{ActDispatchResult, Spine},
+    stem::{
+        AfferentMiddleware, AfferentMiddlewareDecision, AfferentPathwayError, ContinueOutput,
+        EfferentMiddleware, EfferentMiddlewareContext, EfferentMiddlewareDecision,
+        PathwayMiddlewareDecision,
+    },
+    types::{Act, Sense},
+};
+
+#[derive(Debug, Deserialize)]
+struct ConfirmResponse {
+    act_instance_id: String,
+    confirmed: bool,
+}
+
+/// Pending human confirmations, resolved by `confirm.response` senses on the afferent pathway.
+///
+/// Confirmation responses are consumed here and never reach cortex.
+pub struct ActConfirmationGate {
+    config: ActConfirmationConfig,
+    pending: Mutex<HashMap<String, oneshot::Sender<bool>>>,
+}
+
+impl ActConfirmationGate {
+    pub fn new(config: ActConfirmationConfig) -> Self {
+        Self {
+            config,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn requires_confirmation(&self, act: &Act) -> bool {
+        self.config
+            .neural_signal_descriptor_ids
+            .contains(&act.neural_signal_descriptor_id)
+            && !self.is_confirming_endpoint(&act.endpoint_id)
+    }
+
+    /// Body endpoint ids are `<endpoint_name>.<seq>`.
+    fn is_confirming_endpoint(&self, body_endpoint_id: &str) -> bool {
+        body_endpoint_id
+            .rsplit_once('.')
+            .is_some_and(|(name, _)| name == self.config.endpoint_name)
+    }
+
+    async fn register(&self, act_instance_id: &str) -> oneshot::Receiver<bool> {
+        let (tx, rx) = oneshot::channel();
+        self.pending
+            .lock()
+            .await
+            .insert(act_instance_id.to_string(), tx);
+        rx
+    }
+
+    async fn forget(&self, act_instance_id: &str) {
+        self.pending.lock().await.remove(act_instance_id);
+    }
+}
+
+#[async_trait]
+impl AfferentMiddleware for ActConfirmationGate {
+    async fn handle_sense(
+        &self,
+        sense: &Sense,
+    ) -> Result<AfferentMiddlewareDecision, AfferentPathwayError> {
+        if sense.neural_signal_descriptor_id != self.config.response_descriptor_id
+            || !self.is_confirming_endpoint(&sense.endpoint_id)
+        {
+            return Ok(PathwayMiddlewareDecision::Continue(
+                ContinueOutput::Original,
+            ));
+        }
+
+        let response = match serde_json::from_str::<ConfirmResponse>(&sense.payload) {
+            Ok(response) => response,
+            Err(err) => {
+                tracing::warn!(
+                    target: "stem.confirmation",
+                    sense_instance_id = %sense.sense_instance_id,
+                    error = %err,
+                    "confirm_response_invalid"
+                );
+                return Ok(PathwayMiddlewareDecision::Rejected {
+                    reason_code: "confirm_response_invalid".to_string(),
+                    message: Some(err.to_string()),
+                });
+            }
+        };
+        let Some(tx) = self.pending.lock().await.remove(&response.act_instance_id) else {
+            tracing::warn!(
+                target: "stem.confirmation",
+                act_instance_id = %response.act_instance_id,
+                "confirm_response_without_pending_act"
+            );
+            return Ok(PathwayMiddlewareDecision::Rejected {
+                reason_code: "confirm_response_unmatched".to_string(),
+                message: None,
+            });
+        };
+        let _ = tx.send(response.confirmed);
+        Ok(PathwayMiddlewareDecision::Accepted(()))
+    }
+}
+
+/// Holds acts that need confirmation until the gate resolves them; others pass through.
+pub struct ConfirmationEfferentMiddleware {
+    gate: Arc<ActConfirmationGate>,
+    spine: Arc<Spine>,
+}
+
+impl ConfirmationEfferentMiddleware {
+    pub fn new(gate: Arc<ActConfirmationGate>, spine: Arc<Spine>) -> Self {
+        Self { gate, spine }
+    }
+
+    fn confirming_endpoint_id(&self) -> Option<String> {
+        self.spine
+            .body_endpoint_ids_snapshot()
+            .into_iter()
+            .find(|body_endpoint_id| self.gate.is_confirming_endpoint(body_endpoint_id))
+    }
+}
+
+#[async_trait]
+impl EfferentMiddleware for ConfirmationEfferentMiddleware {
+    async fn handle_act(
+        &self,
+        ctx: &EfferentMiddlewareContext,
+        act: &Act,
+    ) -> EfferentMiddlewareDecision {
+        if !self.gate.requires_confirmation(act) {
+            return PathwayMiddlewareDecision::Continue(ContinueOutput::Original);
+        }
+        let Some(endpoint_id) = self.confirming_endpoint_id() else {
+            tracing::warn!(
+                target: "stem.confirmation",
+                act_instance_id = %act.act_instance_id,
+                endpoint_name = %self.gate.config.endpoint_name,
+                "confirmation_endpoint_unavailable"
+            );
+            return PathwayMiddlewareDecision::Rejected {
+                reason_code: "confirmation_unavailable".to_string(),
+                message: None,
+            };
+        };
+
+        let response_rx = self.gate.register(&act.act_instance_id).await;
+        let request = Act {
+            act_instance_id: Uuid::now_v7().to_string(),
+            endpoint_id,
+            neural_signal_descriptor_id: self.gate.config.request_descriptor_id.clone(),
+            might_emit_sense_ids: Vec::new(),
+            payload: json!({
+                "act_instance_id": act.act_instance_id,
+                "endpoint_id": act.endpoint_id,
+                "neural_signal_descriptor_id": act.neural_signal_descriptor_id,
+                "payload": act.payload,
+            }),
+            goal_id: act.goal_id.clone(),
+        };
+        let dispatched = self.spine.on_act_final(ctx.cycle_id, request).await;
+        if !matches!(dispatched, Ok(ActDispatchResult::Acknowledged { .. })) {
+            self.gate.forget(&act.act_instance_id).await;
+            return PathwayMiddlewareDecision::Rejected {
+                reason_code: "confirmation_unavailable".to_string(),
+                message: None,
+            };
+        }
+        tracing::info!(
+            target: "stem.confirmation",
+            act_instance_id = %act.act_instance_id,
+            "act_awaiting_confirmation"
+        );
+
+        let timeout = Duration::from_millis(self.gate.config.timeout_ms);
+        let (reason_code, confirmed) = match tokio::time::timeout(timeout, response_rx).await {
+            Ok(Ok(true)) => ("confirmation_granted", true),
+            Ok(Ok(false)) => ("confirmation_denied", false),
+            Ok(Err(_)) | Err(_) => {
+                self.gate.forget(&act.act_instance_id).await;
+                ("confirmation_timeout", false)
+            }
+        };
+        tracing::info!(
+            target: "stem.confirmation",
+            act_instance_id = %act.act_instance_id,
+            reason_code = reason_code,
+            "act_confirmation_resolved"
+        );
+        if confirmed {
+            PathwayMiddlewareDecision::Continue(ContinueOutput::Original)
+        } else {
+            PathwayMiddlewareDecision::Rejected {
+                reason_code: reason_code.to_string(),
+                message: None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{
+        config::SpineRuntimeConfig,
+        stem::{SenseAfferentPathway, StemPhysicalStateStore},
+        types::{NeuralSignalDescriptor, NeuralSignalType},
+    };
+
+    fn descriptor(
+        r#type: NeuralSignalType,
+        endpoint_id: &str,
+        neural_signal_descriptor_id: &str,
+    ) -> NeuralSignalDescriptor {
+        NeuralSignalDescriptor {
+            r#type,
+            endpoint_id: endpoint_id.to_string(),
+            neural_signal_descriptor_id: neural_signal_descriptor_id.to_string(),
+            payload_schema: json!({ "type": "object" }),
+            priority: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn confirmation_required_act_waits_for_affirmative_response() {
+        let spine = Spine::new(
+            &SpineRuntimeConfig::default(),
+            SenseAfferentPathway::new(4, vec![]),
+            Arc::new(StemPhysicalStateStore::new(BTreeMap::new())),
+        );
+        let inline_adapter = spine.inline_adapter().expect("default config has inline");
+        let mut operator = inline_adapter
+            .attach_inline_endpoint(
+                "operator".to_string(),
+                vec![
+                    descriptor(NeuralSignalType::Act, "operator", "confirm.request"),
+                    descriptor(NeuralSignalType::Sense, "operator", "confirm.response"),
+                ],
+            )
+            .await
+            .expect("operator endpoint should attach");
+        let gate = Arc::new(ActConfirmationGate::new(ActConfirmationConfig {
+            endpoint_name: "operator".to_string(),
+            neural_signal_descriptor_ids: vec!["shell.exec".to_string()],
+            timeout_ms: 5_000,
+            request_descriptor_id: "confirm.request".to_string(),
+            response_descriptor_id: "confirm.response".to_string(),
+        }));
+        let middleware = ConfirmationEfferentMiddleware::new(Arc::clone(&gate), spine);
+        let act = Act {
+            act_instance_id: "act-1".to_string(),
+            endpoint_id: "shell.2".to_string(),
+            neural_signal_descriptor_id: "shell.exec".to_string(),
+            might_emit_sense_ids: Vec::new(),
+            payload: json!({ "argv": ["rm", "-rf", "build"] }),
+            goal_id: None,
+        };
+
+        let decision = tokio::spawn(async move {
+            middleware
+                .handle_act(
+                    &EfferentMiddlewareContext {
+                        cycle_id: 1,
+                        act_seq_no: 1,
+                    },
+                    &act,
+                )
+                .await
+        });
+        let request = operator
+            .act_rx
+            .recv()
+            .await
+            .expect("confirm request should reach the operator");
+        assert_eq!(request.neural_signal_descriptor_id, "confirm.request");
+        assert_eq!(request.payload["act_instance_id"], "act-1");
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!decision.is_finished(), "act must wait for confirmation");
+
+        let accepted = gate
+            .handle_sense(&Sense {
+                sense_instance_id: "sense-1".to_string(),
+                endpoint_id: request.endpoint_id.clone(),
+                neural_signal_descriptor_id: "confirm.response".to_string(),
+                payload: json!({ "act_instance_id": "act-1", "confirmed": true }).to_string(),
+                weight: 1.0,
+                act_instance_id: None,
+            })
+            .await
+            .expect("confirm response should be handled");
+
+        assert!(matches!(accepted, PathwayMiddlewareDecision::Accepted(())));
+        assert!(matches!(
+            decision.await.expect("middleware task should finish"),
+            PathwayMiddlewareDecision::Continue(ContinueOutput::Original)
+        ));
+    }
+}
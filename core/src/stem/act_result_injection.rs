@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::{
+    spine::ActDispatchResult,
+    stem::{
+        AfferentMiddleware, AfferentMiddlewareDecision, AfferentPathwayError, ContinueOutput,
+        PathwayMiddlewareDecision, efferent_pathway::act_result_sense,
+    },
+    types::{Act, Sense},
+};
+
+pub const ACT_RESULT_INJECT_DESCRIPTOR_ID: &str = "act.result.inject";
+
+#[derive(Debug, Deserialize)]
+struct InjectedActResult {
+    act_instance_id: String,
+    endpoint_id: String,
+    neural_signal_descriptor_id: String,
+    result: ActDispatchResult,
+}
+
+/// Dev/test middleware turning `act.result.inject` senses into synthetic `act.result` feedback.
+///
+/// The payload names the act and carries an `ActDispatchResult`; cortex receives the same
+/// `core.stem`/`act.result` sense real dispatch feedback produces, so denials can be exercised
+/// without actually exhausting anything.
+pub struct ActResultInjection;
+
+#[async_trait]
+impl AfferentMiddleware for ActResultInjection {
+    async fn handle_sense(
+        &self,
+        sense: &Sense,
+    ) -> Result<AfferentMiddlewareDecision, AfferentPathwayError> {
+        if sense.neural_signal_descriptor_id != ACT_RESULT_INJECT_DESCRIPTOR_ID {
+            return Ok(PathwayMiddlewareDecision::Continue(
+                ContinueOutput::Original,
+            ));
+        }
+
+        let injected = match serde_json::from_str::<InjectedActResult>(&sense.payload) {
+            Ok(injected) => injected,
+            Err(err) => {
+                tracing::warn!(
+                    target: "stem.afferent",
+                    sense_instance_id = %sense.sense_instance_id,
+                    error = %err,
+                    "act_result_injection_invalid"
+                );
+                return Ok(PathwayMiddlewareDecision::Rejected {
+                    reason_code: "act_result_injection_invalid".to_string(),
+                    message: Some(err.to_string()),
+                });
+            }
+        };
+        tracing::info!(
+            target: "stem.afferent",
+            source_endpoint_id = %sense.endpoint_id,
+            act_instance_id = %injected.act_instance_id,
+            "act_result_injected"
+        );
+        let act = Act {
+            act_instance_id: injected.act_instance_id,
+            endpoint_id: injected.endpoint_id,
+            neural_signal_descriptor_id: injected.neural_signal_descriptor_id,
+            might_emit_sense_ids: Vec::new(),
+            payload: serde_json::Value::Null,
+            goal_id: None,
+        };
+        Ok(PathwayMiddlewareDecision::Continue(
+            ContinueOutput::Replace(vec![act_result_sense(&act, &injected.result)]),
+        ))
+    }
+}
@@ -12,7 +12,7 @@ use tokio::sync::{Mutex, mpsc, oneshot};
 
 use crate::{
     observability::runtime as observability_runtime,
-    stem::{ContinueOutput, PathwayMiddlewareDecision},
+    stem::{ContinueOutput, PathwayMiddlewareDecision, SenseQueueWatermarks},
     types::Sense,
 };
 
@@ -84,19 +84,42 @@ pub trait AfferentMiddleware: Send + Sync {
 
 pub struct SenseConsumerHandle {
     rx: mpsc::Receiver<Sense>,
+    watermarks: Option<Arc<SenseQueueWatermarks>>,
 }
 
 impl SenseConsumerHandle {
     pub fn new(rx: mpsc::Receiver<Sense>) -> Self {
-        Self { rx }
+        Self {
+            rx,
+            watermarks: None,
+        }
+    }
+
+    pub fn with_watermarks(
+        rx: mpsc::Receiver<Sense>,
+        watermarks: Option<Arc<SenseQueueWatermarks>>,
+    ) -> Self {
+        Self { rx, watermarks }
     }
 
     pub async fn recv(&mut self) -> Option<Sense> {
-        self.rx.recv().await
+        let sense = self.rx.recv().await;
+        self.observe_depth();
+        sense
     }
 
     pub fn try_recv(&mut self) -> Result<Sense, mpsc::error::TryRecvError> {
-        self.rx.try_recv()
+        let sense = self.rx.try_recv();
+        if sense.is_ok() {
+            self.observe_depth();
+        }
+        sense
+    }
+
+    fn observe_depth(&self) {
+        if let Some(watermarks) = &self.watermarks {
+            watermarks.observe_depth(self.rx.len());
+        }
     }
 }
 
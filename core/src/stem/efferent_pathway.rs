@@ -13,8 +13,8 @@ use crate::{
     continuity::{ContinuityEngine, DispatchContext as ContinuityDispatchContext},
     observability::runtime::{self as observability_runtime, DispatchOutcomeClass},
     spine::{ActDispatchResult, Spine},
-    stem::{ContinueOutput, PathwayMiddlewareDecision},
-    types::{Act, DispatchDecision},
+    stem::{ContinueOutput, PathwayMiddlewareDecision, SenseAfferentPathway},
+    types::{Act, DispatchDecision, Sense},
 };
 
 const DEFAULT_EFFERENT_QUEUE_CAPACITY: usize = 128;
@@ -259,9 +259,14 @@ pub fn new_efferent_pathway(
     (EfferentTx { tx }, rx)
 }
 
+/// Runs the efferent dispatch loop.
+///
+/// With `result_feedback` set, every terminal dispatch result is also emitted as an
+/// `act.result` sense so cortex sees whether each act landed on its next cycle.
 pub fn spawn_efferent_runtime(
     mut rx: mpsc::Receiver<EfferentActEnvelope>,
     middleware: Vec<Arc<dyn EfferentMiddleware>>,
+    result_feedback: Option<SenseAfferentPathway>,
     shutdown: CancellationToken,
     shutdown_drain_timeout: Duration,
 ) -> JoinHandle<()> {
@@ -284,6 +289,7 @@ pub fn spawn_efferent_runtime(
                         process_efferent_dispatch(
                             task,
                             &middleware,
+                            result_feedback.as_ref(),
                         ).await;
                         processed_count = processed_count.saturating_add(1);
                     }
@@ -324,6 +330,7 @@ pub fn spawn_efferent_runtime(
                     process_efferent_dispatch(
                         task,
                         &middleware,
+                        result_feedback.as_ref(),
                     ).await;
                     processed_count = processed_count.saturating_add(1);
                 }
@@ -335,6 +342,7 @@ pub fn spawn_efferent_runtime(
 async fn process_efferent_dispatch(
     task: EfferentActEnvelope,
     middleware: &[Arc<dyn EfferentMiddleware>],
+    result_feedback: Option<&SenseAfferentPathway>,
 ) {
     let EfferentActEnvelope {
         cycle_id,
@@ -386,11 +394,41 @@ async fn process_efferent_dispatch(
         Some(dispatch_result_reference(&dispatch_result)),
     );
 
+    if let Some(pathway) = result_feedback
+        && let Err(err) = pathway.send(act_result_sense(&act, &dispatch_result)).await
+    {
+        tracing::warn!(
+            target: "stem.efferent",
+            act_instance_id = %act.act_instance_id,
+            error = %err,
+            "failed_to_emit_act_result_sense"
+        );
+    }
+
     if let Some(tx) = response_tx {
         let _ = tx.send(dispatch_result);
     }
 }
 
+pub(crate) fn act_result_sense(act: &Act, dispatch_result: &ActDispatchResult) -> Sense {
+    let mut payload = dispatch_result_reference(dispatch_result);
+    payload["act_instance_id"] = json!(act.act_instance_id);
+    payload["endpoint_id"] = json!(act.endpoint_id);
+    payload["neural_signal_descriptor_id"] = json!(act.neural_signal_descriptor_id);
+    payload["status"] = json!(dispatch_terminal_status(dispatch_result));
+    if let Some(goal_id) = &act.goal_id {
+        payload["goal_id"] = json!(goal_id);
+    }
+    Sense {
+        sense_instance_id: uuid::Uuid::new_v4().to_string(),
+        endpoint_id: "core.stem".to_string(),
+        neural_signal_descriptor_id: "act.result".to_string(),
+        payload: payload.to_string(),
+        weight: 1.0,
+        act_instance_id: Some(act.act_instance_id.clone()),
+    }
+}
+
 async fn run_efferent_sequence(
     act: Act,
     middleware: &[Arc<dyn EfferentMiddleware>],
@@ -496,6 +534,7 @@ fn drain_remaining(rx: &mut mpsc::Receiver<EfferentActEnvelope>) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::stem::{AfferentMiddleware, AfferentMiddlewareDecision, AfferentPathwayError};
 
     struct PassThroughMiddleware;
 
@@ -525,6 +564,82 @@ mod tests {
         }
     }
 
+    struct RejectMiddleware;
+
+    #[async_trait]
+    impl EfferentMiddleware for RejectMiddleware {
+        async fn handle_act(
+            &self,
+            _ctx: &EfferentMiddlewareContext,
+            _act: &Act,
+        ) -> EfferentMiddlewareDecision {
+            PathwayMiddlewareDecision::Rejected {
+                reason_code: "confirmation_denied".to_string(),
+                message: None,
+            }
+        }
+    }
+
+    struct CaptureSenseMiddleware {
+        tx: mpsc::Sender<Sense>,
+    }
+
+    #[async_trait]
+    impl AfferentMiddleware for CaptureSenseMiddleware {
+        async fn handle_sense(
+            &self,
+            sense: &Sense,
+        ) -> Result<AfferentMiddlewareDecision, AfferentPathwayError> {
+            self.tx
+                .send(sense.clone())
+                .await
+                .expect("capture channel should stay open");
+            Ok(PathwayMiddlewareDecision::Accepted(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn rejected_act_is_fed_back_as_act_result_sense() {
+        let (sense_tx, mut sense_rx) = mpsc::channel(4);
+        let feedback =
+            SenseAfferentPathway::new(4, vec![Arc::new(CaptureSenseMiddleware { tx: sense_tx })]);
+        let (tx, rx) = new_efferent_pathway(Some(4));
+        let shutdown = CancellationToken::new();
+        let task = spawn_efferent_runtime(
+            rx,
+            vec![Arc::new(RejectMiddleware)],
+            Some(feedback),
+            shutdown.clone(),
+            Duration::from_millis(10),
+        );
+        let act = Act {
+            act_instance_id: "act-1".to_string(),
+            endpoint_id: "shell.1".to_string(),
+            neural_signal_descriptor_id: "shell.exec".to_string(),
+            might_emit_sense_ids: Vec::new(),
+            payload: json!({}),
+            goal_id: None,
+        };
+
+        tx.emit_act_and_wait(1, 1, act, Duration::from_millis(100))
+            .await;
+
+        let sense = timeout(Duration::from_secs(1), sense_rx.recv())
+            .await
+            .expect("feedback sense should arrive")
+            .expect("capture channel open");
+        assert_eq!(sense.endpoint_id, "core.stem");
+        assert_eq!(sense.neural_signal_descriptor_id, "act.result");
+        assert_eq!(sense.act_instance_id.as_deref(), Some("act-1"));
+        let payload: serde_json::Value =
+            serde_json::from_str(&sense.payload).expect("payload is json");
+        assert_eq!(payload["status"], "REJECTED");
+        assert_eq!(payload["reason_code"], "confirmation_denied");
+        drop(tx);
+        shutdown.cancel();
+        task.await.expect("efferent runtime should join");
+    }
+
     #[tokio::test]
     async fn emit_act_and_wait_uses_fixed_middleware_sequence() {
         let (tx, rx) = new_efferent_pathway(Some(4));
@@ -535,6 +650,7 @@ mod tests {
                 Arc::new(PassThroughMiddleware),
                 Arc::new(AcknowledgeMiddleware),
             ],
+            None,
             shutdown.clone(),
             Duration::from_millis(10),
         );
@@ -544,6 +660,7 @@ mod tests {
             neural_signal_descriptor_id: "act.test".to_string(),
             might_emit_sense_ids: Vec::new(),
             payload: json!({}),
+            goal_id: None,
         };
 
         let result = tx
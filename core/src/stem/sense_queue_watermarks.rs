@@ -0,0 +1,119 @@
+use std::sync::{
+    Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use tokio::sync::watch;
+
+use crate::{
+    config::SenseQueueWatermarksConfig,
+    observability::{metrics as observability_metrics, runtime as observability_runtime},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SenseQueueWatermarkCrossing {
+    High,
+    Low,
+}
+
+impl SenseQueueWatermarkCrossing {
+    fn as_str(self) -> &'static str {
+        match self {
+            SenseQueueWatermarkCrossing::High => "high",
+            SenseQueueWatermarkCrossing::Low => "low",
+        }
+    }
+}
+
+/// Tracks sense queue depth against the configured watermarks and publishes pressure changes.
+pub struct SenseQueueWatermarks {
+    high: usize,
+    low: usize,
+    depth: AtomicUsize,
+    pressure_lock: Mutex<()>,
+    pressure_tx: watch::Sender<bool>,
+}
+
+impl SenseQueueWatermarks {
+    pub fn new(config: SenseQueueWatermarksConfig) -> Self {
+        let (pressure_tx, _) = watch::channel(false);
+        Self {
+            high: config.high,
+            low: config.low,
+            depth: AtomicUsize::new(0),
+            pressure_lock: Mutex::new(()),
+            pressure_tx,
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth.load(Ordering::Acquire)
+    }
+
+    pub fn is_under_pressure(&self) -> bool {
+        *self.pressure_tx.borrow()
+    }
+
+    /// Receives `true` while the queue sits above the low watermark after crossing the high one.
+    pub fn subscribe_pressure(&self) -> watch::Receiver<bool> {
+        self.pressure_tx.subscribe()
+    }
+
+    pub fn observe_depth(&self, depth: usize) -> Option<SenseQueueWatermarkCrossing> {
+        let _guard = self
+            .pressure_lock
+            .lock()
+            .expect("sense queue watermark lock poisoned");
+        self.depth.store(depth, Ordering::Release);
+        observability_metrics::record_stem_sense_queue_depth(depth);
+
+        let under_pressure = self.is_under_pressure();
+        let crossing = if !under_pressure && depth >= self.high {
+            SenseQueueWatermarkCrossing::High
+        } else if under_pressure && depth <= self.low {
+            SenseQueueWatermarkCrossing::Low
+        } else {
+            return None;
+        };
+
+        self.pressure_tx
+            .send_replace(crossing == SenseQueueWatermarkCrossing::High);
+        observability_runtime::emit_stem_sense_queue_watermark(
+            crossing.as_str(),
+            depth,
+            self.high,
+            self.low,
+        );
+        Some(crossing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crossing_high_watermark_raises_pressure_and_tracks_depth() {
+        let watermarks = SenseQueueWatermarks::new(SenseQueueWatermarksConfig { high: 3, low: 1 });
+        let pressure = watermarks.subscribe_pressure();
+
+        assert_eq!(watermarks.observe_depth(2), None);
+        assert_eq!(
+            watermarks.observe_depth(3),
+            Some(SenseQueueWatermarkCrossing::High)
+        );
+        assert_eq!(watermarks.depth(), 3);
+        assert!(*pressure.borrow());
+
+        assert_eq!(watermarks.observe_depth(4), None);
+        assert_eq!(watermarks.observe_depth(2), None);
+        assert!(watermarks.is_under_pressure());
+
+        assert_eq!(
+            watermarks.observe_depth(1),
+            Some(SenseQueueWatermarkCrossing::Low)
+        );
+        assert_eq!(watermarks.depth(), 1);
+        assert!(!*pressure.borrow());
+    }
+}
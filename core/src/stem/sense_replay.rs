@@ -0,0 +1,148 @@
+use std::{path::Path, time::Duration};
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+
+use crate::{stem::SenseAfferentPathway, types::Sense};
+
+/// One line of a sense recording: the sense plus its offset from the start of the recording.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedSense {
+    #[serde(default)]
+    pub offset_ms: u64,
+    #[serde(flatten)]
+    pub sense: Sense,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SenseReplayPacing {
+    /// Wait until each sense's recorded offset before emitting it.
+    #[default]
+    Recorded,
+    AsFastAsPossible,
+}
+
+pub fn load_sense_recording(path: &Path) -> Result<Vec<RecordedSense>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read sense recording {}", path.display()))?;
+    parse_sense_recording(&text)
+        .with_context(|| format!("invalid sense recording {}", path.display()))
+}
+
+/// Parses an NDJSON sense recording; blank lines are skipped.
+pub fn parse_sense_recording(text: &str) -> Result<Vec<RecordedSense>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("line {} is not a recorded sense", index + 1))
+        })
+        .collect()
+}
+
+/// Emits `recording` through the afferent pathway in order and returns how many senses were sent.
+///
+/// Stops early without error when `shutdown` is cancelled.
+pub async fn replay_senses(
+    pathway: &SenseAfferentPathway,
+    recording: Vec<RecordedSense>,
+    pacing: SenseReplayPacing,
+    shutdown: CancellationToken,
+) -> Result<usize> {
+    let started_at = tokio::time::Instant::now();
+    let mut replayed = 0;
+    for recorded in recording {
+        if pacing == SenseReplayPacing::Recorded {
+            let due_at = started_at + Duration::from_millis(recorded.offset_ms);
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = tokio::time::sleep_until(due_at) => {}
+            }
+        } else if shutdown.is_cancelled() {
+            break;
+        }
+
+        let sense_instance_id = recorded.sense.sense_instance_id.clone();
+        pathway
+            .emit_sense(recorded.sense)
+            .await
+            .map_err(|err| anyhow!("failed to replay sense {sense_instance_id}: {err}"))?;
+        replayed += 1;
+    }
+
+    Ok(replayed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::stem::{
+        AfferentMiddleware, AfferentMiddlewareDecision, AfferentPathwayError,
+        PathwayMiddlewareDecision,
+    };
+
+    struct RecordingAcceptMiddleware {
+        tx: mpsc::Sender<String>,
+    }
+
+    #[async_trait]
+    impl AfferentMiddleware for RecordingAcceptMiddleware {
+        async fn handle_sense(
+            &self,
+            sense: &Sense,
+        ) -> Result<AfferentMiddlewareDecision, AfferentPathwayError> {
+            self.tx
+                .send(sense.sense_instance_id.clone())
+                .await
+                .expect("recording channel should stay open");
+            Ok(PathwayMiddlewareDecision::Accepted(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn replayed_senses_reach_consumer_in_recorded_order() {
+        let recording = parse_sense_recording(concat!(
+            r#"{"offset_ms":0,"sense_instance_id":"s-1","endpoint_id":"ep","neural_signal_descriptor_id":"tick","payload":"a"}"#,
+            "\n\n",
+            r#"{"offset_ms":30,"sense_instance_id":"s-2","endpoint_id":"ep","neural_signal_descriptor_id":"tick","payload":"b"}"#,
+            "\n",
+            r#"{"offset_ms":60,"sense_instance_id":"s-3","endpoint_id":"ep","neural_signal_descriptor_id":"tick","payload":"c"}"#,
+            "\n",
+        ))
+        .expect("recording should parse");
+        let (tx, mut rx) = mpsc::channel(8);
+        let pathway =
+            SenseAfferentPathway::new(8, vec![Arc::new(RecordingAcceptMiddleware { tx })]);
+
+        let started_at = tokio::time::Instant::now();
+        let replayed = replay_senses(
+            &pathway,
+            recording,
+            SenseReplayPacing::Recorded,
+            CancellationToken::new(),
+        )
+        .await
+        .expect("replay should succeed");
+
+        assert_eq!(replayed, 3);
+        assert!(started_at.elapsed() >= Duration::from_millis(60));
+        let mut seen = Vec::new();
+        for _ in 0..3 {
+            seen.push(rx.recv().await.expect("sense should reach consumer"));
+        }
+        assert_eq!(seen, vec!["s-1", "s-2", "s-3"]);
+    }
+
+    #[test]
+    fn malformed_recording_line_reports_line_number() {
+        let err = parse_sense_recording("\n{\"offset_ms\":0}\n").expect_err("should fail");
+        assert!(err.to_string().contains("line 2"));
+    }
+}
@@ -47,6 +47,43 @@ pub fn build_fq_neural_signal_id(endpoint_id: &str, neural_signal_descriptor_id:
     format!("{endpoint_id}/{neural_signal_descriptor_id}")
 }
 
+/// Serializes `value` with object keys sorted at every level, whatever the map ordering.
+pub fn canonical_json(value: &serde_json::Value) -> String {
+    fn write(value: &serde_json::Value, out: &mut String) {
+        match value {
+            serde_json::Value::Array(items) => {
+                out.push('[');
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+                    write(item, out);
+                }
+                out.push(']');
+            }
+            serde_json::Value::Object(map) => {
+                let mut entries = map.iter().collect::<Vec<_>>();
+                entries.sort_by_key(|(key, _)| key.as_str());
+                out.push('{');
+                for (index, (key, item)) in entries.into_iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+                    out.push_str(&serde_json::Value::String(key.clone()).to_string());
+                    out.push(':');
+                    write(item, out);
+                }
+                out.push('}');
+            }
+            scalar => out.push_str(&scalar.to_string()),
+        }
+    }
+
+    let mut out = String::new();
+    write(value, &mut out);
+    out
+}
+
 pub fn is_valid_neural_signal_identifier(value: &str) -> bool {
     if value.is_empty() {
         return false;
@@ -76,6 +113,11 @@ pub struct NeuralSignalDescriptor {
     pub endpoint_id: String,
     pub neural_signal_descriptor_id: String,
     pub payload_schema: serde_json::Value,
+    /// Act routing preference among endpoints of one name that share this descriptor id.
+    ///
+    /// Higher wins; unset counts as 0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
@@ -157,6 +199,9 @@ pub struct Act {
     #[serde(default)]
     pub might_emit_sense_ids: Vec<String>,
     pub payload: serde_json::Value,
+    /// Goal of the cognition state this act serves, so its outcome and cost roll up per goal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub goal_id: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -12,12 +12,12 @@ use beluna::{
         credentials::EnvCredentialProvider,
         types::{
             AIGatewayConfig, BackendDialect, BackendProfile, ChatConfig, CredentialRef,
-            ModelProfile, ResilienceConfig,
+            HttpClientConfig, ModelProfile, ResilienceConfig,
         },
     },
     body::{
         SHELL_ACT_EXEC_ID, SHELL_ENDPOINT_NAME,
-        payloads::{ShellLimits, WebLimits},
+        payloads::{FsLimits, ShellLimits, WebLimits},
         shell::handle_shell_invoke,
         start_inline_body_endpoints,
     },
@@ -137,6 +137,7 @@ impl AgentTaskRunner {
                 Arc::new(ContinuityEfferentMiddleware::new(Arc::clone(&continuity))),
                 Arc::new(SpineEfferentMiddleware::new(Arc::clone(&spine))),
             ],
+            None,
             shutdown.clone(),
             Duration::from_millis(100),
         );
@@ -442,6 +443,8 @@ async fn start_case_endpoints(
             shell_limits(),
             false,
             WebLimits::default(),
+            false,
+            FsLimits::default(),
         )?;
         run_shell_preflight(workspace).await?;
         let runtime_endpoint_id = spine
@@ -485,8 +488,16 @@ async fn run_shell_preflight(workspace: &CaseWorkspace) -> Result<()> {
             "stdout_max_bytes": 1024,
             "stderr_max_bytes": 1024,
         }),
+        goal_id: None,
     };
-    let output = handle_shell_invoke("agent-task-shell-preflight", &act, &shell_limits()).await;
+    let (sense_tx, _sense_rx) = tokio::sync::mpsc::channel(1);
+    let output = handle_shell_invoke(
+        "agent-task-shell-preflight",
+        &act,
+        &shell_limits(),
+        &sense_tx,
+    )
+    .await;
     match output.outcome {
         EndpointExecutionOutcome::Applied { .. } => {}
         other => bail!("invalid_environment: shell preflight failed: {:?}", other),
@@ -635,6 +646,7 @@ fn descriptor_from_spec(
         endpoint_id: endpoint_id.to_string(),
         neural_signal_descriptor_id: descriptor.neural_signal_descriptor_id.clone(),
         payload_schema: descriptor.payload_schema.clone(),
+        priority: None,
     })
 }
 
@@ -649,11 +661,15 @@ fn replay_ai_gateway_config(case: &AgentTaskCase, base_url: &str) -> AIGatewayCo
                 id: case.ai.model.clone(),
                 aliases: vec!["default".to_string()],
             }],
+            max_output_tokens_cap: None,
+            limits: None,
             capabilities: None,
             copilot: None,
+            post_finish_content: Default::default(),
         }],
         chat: ChatConfig::default(),
         resilience: ResilienceConfig::default(),
+        http: HttpClientConfig::default(),
     }
 }
 
@@ -673,10 +689,12 @@ fn spine_runtime_config(case: &AgentTaskCase) -> SpineRuntimeConfig {
             adapters: vec![SpineAdapterConfig::Inline {
                 config: InlineAdapterConfig::default(),
             }],
+            ..SpineRuntimeConfig::default()
         };
     }
     SpineRuntimeConfig {
         adapters: Vec::new(),
+        ..SpineRuntimeConfig::default()
     }
 }
 
@@ -704,6 +722,9 @@ fn shell_limits() -> ShellLimits {
         max_stdout_max_bytes: 16 * 1024,
         default_stderr_max_bytes: 4 * 1024,
         max_stderr_max_bytes: 16 * 1024,
+        stdin_max_bytes: 16 * 1024,
+        allowed_commands: Vec::new(),
+        allowed_cwd_prefixes: Vec::new(),
     }
 }
 
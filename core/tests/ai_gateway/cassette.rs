@@ -0,0 +1,191 @@
+use beluna::ai_gateway::{
+    chat::{ThreadOptions, TurnInput},
+    embeddings::EmbeddingsRequest,
+    types::{CassetteConfig, CassetteMode},
+};
+use serde_json::json;
+
+use crate::kit::{
+    chat::{
+        chat_for_cassette_embeddings_endpoint, chat_for_cassette_responses_endpoint, text_response,
+        user_message,
+    },
+    local_http::LocalJsonServer,
+};
+
+#[tokio::test]
+async fn recorded_interaction_replays_without_a_backend() {
+    let path = std::env::temp_dir().join(format!("beluna-cassette-{}.json", uuid::Uuid::now_v7()));
+    let input = || TurnInput {
+        messages: vec![user_message("Input IR")],
+        ..TurnInput::default()
+    };
+
+    let mut server = LocalJsonServer::start(vec![text_response("recorded ack")]).await;
+    let recording = chat_for_cassette_responses_endpoint(
+        server.endpoint(),
+        CassetteConfig {
+            path: path.clone(),
+            mode: CassetteMode::Record,
+        },
+    );
+    let recorded = recording
+        .open_thread(ThreadOptions::default())
+        .await
+        .expect("open recording thread")
+        .complete(input())
+        .await
+        .expect("recorded turn")
+        .response;
+    assert_eq!(server.remaining_requests().await.len(), 1);
+
+    let replaying = chat_for_cassette_responses_endpoint(
+        "http://127.0.0.1:9/v1".to_string(),
+        CassetteConfig {
+            path: path.clone(),
+            mode: CassetteMode::Replay,
+        },
+    );
+    let replayed = replaying
+        .open_thread(ThreadOptions::default())
+        .await
+        .expect("open replay thread")
+        .complete(input())
+        .await
+        .expect("replayed turn")
+        .response;
+
+    assert_eq!(replayed.output_text, "recorded ack");
+    assert_eq!(replayed.output_text, recorded.output_text);
+    assert_eq!(
+        serde_json::to_value(&replayed.finish_reason).expect("finish reason"),
+        serde_json::to_value(&recorded.finish_reason).expect("finish reason")
+    );
+    assert_eq!(
+        serde_json::to_value(&replayed.usage).expect("usage"),
+        serde_json::to_value(&recorded.usage).expect("usage")
+    );
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn recorded_embeddings_replay_without_a_backend() {
+    let path = std::env::temp_dir().join(format!("beluna-cassette-{}.json", uuid::Uuid::now_v7()));
+    let request = || EmbeddingsRequest {
+        inputs: vec!["first memory".to_string()],
+        ..EmbeddingsRequest::default()
+    };
+
+    let mut server = LocalJsonServer::start(vec![json!({
+        "object": "list",
+        "data": [{"object": "embedding", "index": 0, "embedding": [0.25, 0.75]}],
+        "model": "text-embedding-3-small",
+        "usage": {"prompt_tokens": 3, "total_tokens": 3}
+    })])
+    .await;
+    let recording = chat_for_cassette_embeddings_endpoint(
+        server.endpoint(),
+        CassetteConfig {
+            path: path.clone(),
+            mode: CassetteMode::Record,
+        },
+    );
+    recording
+        .embed(request())
+        .await
+        .expect("recorded embeddings");
+    assert_eq!(server.remaining_requests().await.len(), 1);
+
+    let replaying = chat_for_cassette_embeddings_endpoint(
+        "http://127.0.0.1:9/v1".to_string(),
+        CassetteConfig {
+            path: path.clone(),
+            mode: CassetteMode::Replay,
+        },
+    );
+    let replayed = replaying
+        .embed(request())
+        .await
+        .expect("replayed embeddings");
+
+    assert_eq!(replayed.embeddings, vec![vec![0.25, 0.75]]);
+    assert_eq!(replayed.usage.expect("usage").input_tokens, Some(3));
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn recording_into_an_existing_cassette_keeps_earlier_interactions() {
+    let path = std::env::temp_dir().join(format!("beluna-cassette-{}.json", uuid::Uuid::now_v7()));
+    let input = |text: &str| TurnInput {
+        messages: vec![user_message(text)],
+        ..TurnInput::default()
+    };
+    for (prompt, reply) in [("first", "first ack"), ("second", "second ack")] {
+        let server = LocalJsonServer::start(vec![text_response(reply)]).await;
+        chat_for_cassette_responses_endpoint(
+            server.endpoint(),
+            CassetteConfig {
+                path: path.clone(),
+                mode: CassetteMode::Record,
+            },
+        )
+        .open_thread(ThreadOptions::default())
+        .await
+        .expect("open recording thread")
+        .complete(input(prompt))
+        .await
+        .expect("recorded turn");
+    }
+
+    let replaying = chat_for_cassette_responses_endpoint(
+        "http://127.0.0.1:9/v1".to_string(),
+        CassetteConfig {
+            path: path.clone(),
+            mode: CassetteMode::Replay,
+        },
+    );
+    for (prompt, reply) in [("first", "first ack"), ("second", "second ack")] {
+        let replayed = replaying
+            .open_thread(ThreadOptions::default())
+            .await
+            .expect("open replay thread")
+            .complete(input(prompt))
+            .await
+            .expect("replayed turn")
+            .response;
+        assert_eq!(replayed.output_text, reply);
+    }
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn unwritable_cassette_does_not_fail_the_recorded_turn() {
+    let blocker =
+        std::env::temp_dir().join(format!("beluna-cassette-{}.blocker", uuid::Uuid::now_v7()));
+    std::fs::write(&blocker, b"not a directory").expect("write blocker file");
+
+    let server = LocalJsonServer::start(vec![text_response("still answered")]).await;
+    let response = chat_for_cassette_responses_endpoint(
+        server.endpoint(),
+        CassetteConfig {
+            path: blocker.join("cassette.json"),
+            mode: CassetteMode::Record,
+        },
+    )
+    .open_thread(ThreadOptions::default())
+    .await
+    .expect("open recording thread")
+    .complete(TurnInput {
+        messages: vec![user_message("Input IR")],
+        ..TurnInput::default()
+    })
+    .await
+    .expect("turn should succeed even though the cassette cannot be written")
+    .response;
+
+    assert_eq!(response.output_text, "still answered");
+    let _ = std::fs::remove_file(&blocker);
+}
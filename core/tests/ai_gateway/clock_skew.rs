@@ -0,0 +1,24 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use beluna::ai_gateway::clock_skew::detect_response_clock_skew;
+
+#[test]
+fn skewed_response_timestamp_is_reported_beyond_threshold() {
+    let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let stale_created_at = 1_700_000_000 - 3_600;
+
+    let skew_ms = detect_response_clock_skew(stale_created_at, now, 60_000);
+
+    assert_eq!(skew_ms, Some(-3_600_000));
+}
+
+#[test]
+fn response_timestamp_within_threshold_is_not_reported() {
+    let now = SystemTime::now();
+    let created_at = now
+        .duration_since(UNIX_EPOCH)
+        .expect("clock after epoch")
+        .as_secs() as i64;
+
+    assert_eq!(detect_response_clock_skew(created_at, now, 60_000), None);
+}
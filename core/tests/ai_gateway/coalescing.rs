@@ -0,0 +1,46 @@
+use beluna::ai_gateway::chat::{ThreadOptions, TurnInput};
+use serde_json::json;
+
+use crate::kit::{
+    chat::{chat_for_coalescing_responses_endpoint, text_response, user_message},
+    local_http::LocalJsonServer,
+};
+
+#[tokio::test]
+async fn identical_concurrent_turns_share_one_backend_call() {
+    let mut response = text_response("ack");
+    response["usage"] = json!({"input_tokens": 7, "output_tokens": 2, "total_tokens": 9});
+    let mut server = LocalJsonServer::start(vec![response]).await;
+    let chat = chat_for_coalescing_responses_endpoint(server.endpoint());
+    let first = chat
+        .open_thread(ThreadOptions::default())
+        .await
+        .expect("open first thread");
+    let second = chat
+        .open_thread(ThreadOptions::default())
+        .await
+        .expect("open second thread");
+    let input = || TurnInput {
+        messages: vec![user_message("Input IR")],
+        ..TurnInput::default()
+    };
+
+    let (first_output, second_output) =
+        tokio::join!(first.complete(input()), second.complete(input()));
+
+    let responses = [
+        first_output.expect("first turn").response,
+        second_output.expect("second turn").response,
+    ];
+    assert!(
+        responses
+            .iter()
+            .all(|response| response.output_text == "ack")
+    );
+    let counted_input_tokens = responses
+        .iter()
+        .filter_map(|response| response.usage.as_ref()?.input_tokens)
+        .collect::<Vec<_>>();
+    assert_eq!(counted_input_tokens, vec![7]);
+    assert_eq!(server.remaining_requests().await.len(), 1);
+}
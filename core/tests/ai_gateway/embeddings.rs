@@ -0,0 +1,46 @@
+use beluna::ai_gateway::embeddings::EmbeddingsRequest;
+use serde_json::json;
+
+use crate::kit::{chat::chat_for_embeddings_endpoint, local_http::LocalJsonServer};
+
+#[tokio::test]
+async fn openai_compatible_embed_posts_inputs_and_orders_vectors_by_index() {
+    let mut server = LocalJsonServer::start(vec![json!({
+        "object": "list",
+        "data": [
+            {"object": "embedding", "index": 1, "embedding": [0.5, -0.5]},
+            {"object": "embedding", "index": 0, "embedding": [0.25, 0.75]}
+        ],
+        "model": "text-embedding-3-small",
+        "usage": {"prompt_tokens": 6, "total_tokens": 6}
+    })])
+    .await;
+    let chat = chat_for_embeddings_endpoint(server.endpoint());
+
+    let response = chat
+        .embed(EmbeddingsRequest {
+            inputs: vec!["first memory".to_string(), "second memory".to_string()],
+            dimensions: Some(2),
+            ..EmbeddingsRequest::default()
+        })
+        .await
+        .expect("embeddings should succeed");
+
+    assert_eq!(response.embeddings, vec![vec![0.25, 0.75], vec![0.5, -0.5]]);
+    let usage = response.usage.expect("usage");
+    assert_eq!(usage.input_tokens, Some(6));
+    assert_eq!(usage.total_tokens, Some(6));
+    assert_eq!(response.backend_metadata["backend_id"], "embedder");
+
+    let request = server.next_request().await;
+    assert_eq!(request.path, "/v1/embeddings");
+    assert_eq!(
+        request.body,
+        json!({
+            "model": "text-embedding-3-small",
+            "input": ["first memory", "second memory"],
+            "encoding_format": "float",
+            "dimensions": 2
+        })
+    );
+}
@@ -0,0 +1,80 @@
+use beluna::ai_gateway::chat::{ThreadOptions, TurnInput};
+use serde_json::json;
+
+use crate::kit::{
+    chat::{
+        chat_for_fallback_past_unbindable_backend, chat_for_fallback_responses_endpoints,
+        text_response, user_message,
+    },
+    local_http::LocalJsonServer,
+};
+
+#[tokio::test]
+async fn open_breaker_onprimary_falls_back_to_next_backend() {
+    let mut primary =
+        LocalJsonServer::start_with_statuses(vec![(503, json!({"error": "overloaded"}))]).await;
+    let mut secondary = LocalJsonServer::start(vec![
+        text_response("served by secondary"),
+        text_response("still secondary"),
+    ])
+    .await;
+    let chat = chat_for_fallback_responses_endpoints(primary.endpoint(), secondary.endpoint());
+    let thread = chat
+        .open_thread(ThreadOptions::default())
+        .await
+        .expect("open thread");
+
+    let first = thread
+        .complete(TurnInput {
+            messages: vec![user_message("first")],
+            ..TurnInput::default()
+        })
+        .await
+        .expect("failing primary should hand off to secondary");
+    assert_eq!(first.response.output_text, "served by secondary");
+    assert_eq!(first.response.backend_metadata["backend_id"], "secondary");
+    assert_eq!(
+        first.response.backend_metadata["fallback_from"],
+        json!(["primary"])
+    );
+
+    // The primary's single failure opened its breaker; the next turn skips it outright.
+    let second = thread
+        .complete(TurnInput {
+            messages: vec![user_message("second")],
+            ..TurnInput::default()
+        })
+        .await
+        .expect("open breaker should route straight to secondary");
+    assert_eq!(second.response.output_text, "still secondary");
+    assert_eq!(second.response.backend_metadata["backend_id"], "secondary");
+    assert_eq!(primary.remaining_requests().await.len(), 1);
+    assert_eq!(secondary.remaining_requests().await.len(), 2);
+}
+
+#[tokio::test]
+async fn fallback_with_unresolvable_credentials_is_skipped() {
+    let primary =
+        LocalJsonServer::start_with_statuses(vec![(503, json!({"error": "overloaded"}))]).await;
+    let mut tertiary = LocalJsonServer::start(vec![text_response("served by tertiary")]).await;
+    let chat = chat_for_fallback_past_unbindable_backend(primary.endpoint(), tertiary.endpoint());
+
+    let output = chat
+        .open_thread(ThreadOptions::default())
+        .await
+        .expect("open thread")
+        .complete(TurnInput {
+            messages: vec![user_message("hello")],
+            ..TurnInput::default()
+        })
+        .await
+        .expect("the unbindable fallback should be passed over");
+
+    assert_eq!(output.response.output_text, "served by tertiary");
+    assert_eq!(output.response.backend_metadata["backend_id"], "tertiary");
+    assert_eq!(
+        output.response.backend_metadata["fallback_from"],
+        json!(["primary"])
+    );
+    assert_eq!(tertiary.remaining_requests().await.len(), 1);
+}
@@ -8,13 +8,200 @@ use beluna::ai_gateway::{
     },
     credentials::EnvCredentialProvider,
     types::{
-        AIGatewayConfig, BackendDialect, BackendProfile, ChatConfig, CredentialRef, ModelProfile,
-        ResilienceConfig,
+        AIGatewayConfig, BackendDialect, BackendProfile, CassetteConfig, ChatConfig, CredentialRef,
+        DeclaredModelLimits, HttpClientConfig, ModelProfile, ResilienceConfig,
     },
 };
 use serde_json::{Value, json};
 
 pub fn chat_for_responses_endpoint(endpoint: String) -> Chat {
+    chat_for_capped_responses_endpoint(endpoint, None)
+}
+
+pub fn chat_for_capped_responses_endpoint(
+    endpoint: String,
+    max_output_tokens_cap: Option<u64>,
+) -> Chat {
+    chat_for_limited_responses_endpoint(endpoint, max_output_tokens_cap, None)
+}
+
+pub fn chat_for_limited_responses_endpoint(
+    endpoint: String,
+    max_output_tokens_cap: Option<u64>,
+    limits: Option<DeclaredModelLimits>,
+) -> Chat {
+    chat_for_configured_responses_endpoint(
+        endpoint,
+        max_output_tokens_cap,
+        limits,
+        ChatConfig::default(),
+        ResilienceConfig::default(),
+    )
+}
+
+pub fn chat_for_coalescing_responses_endpoint(endpoint: String) -> Chat {
+    chat_for_configured_responses_endpoint(
+        endpoint,
+        None,
+        None,
+        ChatConfig {
+            coalesce_identical_requests: true,
+            ..ChatConfig::default()
+        },
+        ResilienceConfig::default(),
+    )
+}
+
+pub fn chat_for_retry_traced_responses_endpoint(endpoint: String) -> Chat {
+    chat_for_configured_responses_endpoint(
+        endpoint,
+        None,
+        None,
+        ChatConfig::default(),
+        ResilienceConfig {
+            backoff_base_ms: 1,
+            include_retry_trace: true,
+            ..ResilienceConfig::default()
+        },
+    )
+}
+
+pub fn chat_for_cassette_responses_endpoint(endpoint: String, cassette: CassetteConfig) -> Chat {
+    chat_for_configured_responses_endpoint(
+        endpoint,
+        None,
+        None,
+        ChatConfig {
+            cassette: Some(cassette),
+            ..ChatConfig::default()
+        },
+        ResilienceConfig::default(),
+    )
+}
+
+/// Routes `default` to `primary` with `fallback` behind it; one failure opens a breaker.
+pub fn chat_for_fallback_responses_endpoints(primary: String, fallback: String) -> Chat {
+    chat_for_fallback_chain(vec![
+        fallback_backend("primary", primary, "default", CredentialRef::None),
+        fallback_backend("secondary", fallback, "secondary", CredentialRef::None),
+    ])
+}
+
+/// Like [`chat_for_fallback_responses_endpoints`], with a backend whose credential cannot be
+/// resolved sitting between `primary` and `fallback`.
+pub fn chat_for_fallback_past_unbindable_backend(primary: String, fallback: String) -> Chat {
+    chat_for_fallback_chain(vec![
+        fallback_backend("primary", primary, "default", CredentialRef::None),
+        fallback_backend(
+            "unbindable",
+            "http://127.0.0.1:9/v1".to_string(),
+            "unbindable",
+            CredentialRef::Env {
+                var: "BELUNA_TEST_UNSET_FALLBACK_CREDENTIAL".to_string(),
+            },
+        ),
+        fallback_backend("tertiary", fallback, "tertiary", CredentialRef::None),
+    ])
+}
+
+fn fallback_backend(
+    id: &str,
+    endpoint: String,
+    alias: &str,
+    credential: CredentialRef,
+) -> BackendProfile {
+    BackendProfile {
+        id: id.to_string(),
+        dialect: BackendDialect::OpenAiResponses,
+        endpoint: Some(endpoint),
+        credential,
+        models: vec![ModelProfile {
+            id: "gpt-5".to_string(),
+            aliases: vec![alias.to_string()],
+        }],
+        max_output_tokens_cap: None,
+        limits: None,
+        capabilities: None,
+        copilot: None,
+        post_finish_content: Default::default(),
+    }
+}
+
+/// Routes `default` to the first backend with the rest behind it, in order.
+fn chat_for_fallback_chain(backends: Vec<BackendProfile>) -> Chat {
+    let fallback_aliases = backends
+        .iter()
+        .skip(1)
+        .flat_map(|backend| backend.models[0].aliases.clone())
+        .collect();
+    Chat::new(
+        &AIGatewayConfig {
+            backends,
+            chat: ChatConfig {
+                route_fallbacks: [("default".to_string(), fallback_aliases)].into(),
+                ..ChatConfig::default()
+            },
+            resilience: ResilienceConfig {
+                max_retries: 0,
+                breaker_failure_threshold: 1,
+                breaker_open_ms: 60_000,
+                ..ResilienceConfig::default()
+            },
+            http: HttpClientConfig::default(),
+        },
+        Arc::new(EnvCredentialProvider),
+    )
+    .expect("chat")
+}
+
+pub fn chat_for_embeddings_endpoint(endpoint: String) -> Chat {
+    chat_for_configured_embeddings_endpoint(endpoint, ChatConfig::default())
+}
+
+pub fn chat_for_cassette_embeddings_endpoint(endpoint: String, cassette: CassetteConfig) -> Chat {
+    chat_for_configured_embeddings_endpoint(
+        endpoint,
+        ChatConfig {
+            cassette: Some(cassette),
+            ..ChatConfig::default()
+        },
+    )
+}
+
+fn chat_for_configured_embeddings_endpoint(endpoint: String, chat: ChatConfig) -> Chat {
+    Chat::new(
+        &AIGatewayConfig {
+            backends: vec![BackendProfile {
+                id: "embedder".to_string(),
+                dialect: BackendDialect::OpenAiCompatible,
+                endpoint: Some(endpoint),
+                credential: CredentialRef::None,
+                models: vec![ModelProfile {
+                    id: "text-embedding-3-small".to_string(),
+                    aliases: vec!["default".to_string()],
+                }],
+                max_output_tokens_cap: None,
+                limits: None,
+                capabilities: None,
+                copilot: None,
+                post_finish_content: Default::default(),
+            }],
+            chat,
+            resilience: ResilienceConfig::default(),
+            http: HttpClientConfig::default(),
+        },
+        Arc::new(EnvCredentialProvider),
+    )
+    .expect("chat")
+}
+
+fn chat_for_configured_responses_endpoint(
+    endpoint: String,
+    max_output_tokens_cap: Option<u64>,
+    limits: Option<DeclaredModelLimits>,
+    chat: ChatConfig,
+    resilience: ResilienceConfig,
+) -> Chat {
     Chat::new(
         &AIGatewayConfig {
             backends: vec![BackendProfile {
@@ -26,11 +213,15 @@ pub fn chat_for_responses_endpoint(endpoint: String) -> Chat {
                     id: "gpt-5".to_string(),
                     aliases: vec!["default".to_string()],
                 }],
+                max_output_tokens_cap,
+                limits,
                 capabilities: None,
                 copilot: None,
+                post_finish_content: Default::default(),
             }],
-            chat: ChatConfig::default(),
-            resilience: ResilienceConfig::default(),
+            chat,
+            resilience,
+            http: HttpClientConfig::default(),
         },
         Arc::new(EnvCredentialProvider),
     )
@@ -1,9 +1,24 @@
+//! Scripted loopback HTTP server shared by the `ai_gateway` integration tests and, through
+//! `#[path]`, by inline tests of the `beluna` crate.
+#![allow(dead_code)]
+
+use std::{
+    collections::VecDeque,
+    net::SocketAddr,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
 use serde_json::Value;
 use tokio::{
     io::{AsyncReadExt, AsyncWriteExt},
     net::{TcpListener, TcpStream},
     sync::mpsc,
 };
+use tokio_util::sync::CancellationToken;
 
 #[derive(Debug)]
 pub struct CapturedRequest {
@@ -11,47 +26,173 @@ pub struct CapturedRequest {
     pub body: Value,
 }
 
+/// One scripted reply: status, body, extra headers, and an optional delay before it is sent.
+#[derive(Debug, Clone)]
+pub struct ScriptedResponse {
+    status: u16,
+    content_type: &'static str,
+    body: String,
+    headers: Vec<(String, String)>,
+    delay: Duration,
+}
+
+impl ScriptedResponse {
+    pub fn json(status: u16, body: Value) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: body.to_string(),
+            headers: Vec::new(),
+            delay: Duration::ZERO,
+        }
+    }
+
+    pub fn text(status: u16, body: &str) -> Self {
+        Self {
+            content_type: "text/plain",
+            body: body.to_string(),
+            ..Self::json(status, Value::Null)
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Holds the reply back after the request has been read.
+    pub fn delayed(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+/// Serves scripted responses in request order over keep-alive connections, then stops.
 pub struct LocalJsonServer {
-    endpoint: String,
+    address: SocketAddr,
     requests: mpsc::Receiver<CapturedRequest>,
+    accepted_connections: Arc<AtomicUsize>,
 }
 
 impl LocalJsonServer {
     pub async fn start(responses: Vec<Value>) -> Self {
+        Self::start_with_statuses(responses.into_iter().map(|body| (200, body)).collect()).await
+    }
+
+    /// Serves each scripted body with its paired HTTP status code.
+    pub async fn start_with_statuses(responses: Vec<(u16, Value)>) -> Self {
+        Self::start_scripted(
+            responses
+                .into_iter()
+                .map(|(status, body)| ScriptedResponse::json(status, body))
+                .collect(),
+        )
+        .await
+    }
+
+    pub async fn start_scripted(responses: Vec<ScriptedResponse>) -> Self {
         let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
-        let addr = listener.local_addr().expect("local addr");
+        let address = listener.local_addr().expect("local addr");
         let (tx, rx) = mpsc::channel(responses.len().max(1));
+        let accepted_connections = Arc::new(AtomicUsize::new(0));
+        let script = Arc::new(Mutex::new(VecDeque::from(responses)));
+        let exhausted = CancellationToken::new();
+        if script.lock().expect("script lock").is_empty() {
+            exhausted.cancel();
+        }
 
+        let accepted = Arc::clone(&accepted_connections);
         tokio::spawn(async move {
-            for response in responses {
-                let (mut socket, _) = listener.accept().await.expect("accept");
-                let request = read_request(&mut socket).await;
-                tx.send(request).await.expect("send captured request");
-                write_json_response(&mut socket, response).await;
+            loop {
+                let socket = tokio::select! {
+                    _ = exhausted.cancelled() => break,
+                    accepted = listener.accept() => accepted.expect("accept").0,
+                };
+                accepted.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(serve_connection(
+                    socket,
+                    Arc::clone(&script),
+                    tx.clone(),
+                    exhausted.clone(),
+                ));
             }
         });
 
         Self {
-            endpoint: format!("http://{addr}/v1"),
+            address,
             requests: rx,
+            accepted_connections,
         }
     }
 
     pub fn endpoint(&self) -> String {
-        self.endpoint.clone()
+        format!("{}/v1", self.base_url())
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// TCP connections accepted so far; one per client when keep-alive reuse works.
+    pub fn accepted_connections(&self) -> usize {
+        self.accepted_connections.load(Ordering::SeqCst)
     }
 
     pub async fn next_request(&mut self) -> CapturedRequest {
         self.requests.recv().await.expect("captured request")
     }
+
+    /// Drains the requests captured once every scripted response has been served.
+    pub async fn remaining_requests(&mut self) -> Vec<CapturedRequest> {
+        let mut requests = Vec::new();
+        while let Some(request) = self.requests.recv().await {
+            requests.push(request);
+        }
+        requests
+    }
 }
 
-async fn read_request(socket: &mut TcpStream) -> CapturedRequest {
+async fn serve_connection(
+    mut socket: TcpStream,
+    script: Arc<Mutex<VecDeque<ScriptedResponse>>>,
+    tx: mpsc::Sender<CapturedRequest>,
+    exhausted: CancellationToken,
+) {
+    loop {
+        let request = tokio::select! {
+            _ = exhausted.cancelled() => return,
+            request = read_request(&mut socket) => request,
+        };
+        let Some(request) = request else {
+            return;
+        };
+        let (response, last) = {
+            let mut script = script.lock().expect("script lock");
+            let Some(response) = script.pop_front() else {
+                return;
+            };
+            (response, script.is_empty())
+        };
+        tx.send(request).await.expect("send captured request");
+        tokio::time::sleep(response.delay).await;
+        write_response(&mut socket, &response).await;
+        if last {
+            exhausted.cancel();
+            return;
+        }
+    }
+}
+
+/// Reads one request; `None` when the client closed the connection before sending one.
+async fn read_request(socket: &mut TcpStream) -> Option<CapturedRequest> {
     let mut buffer = Vec::new();
     let mut chunk = [0_u8; 1024];
     let header_end = loop {
-        let n = socket.read(&mut chunk).await.expect("read request");
-        assert!(n > 0, "connection closed before headers");
+        let n = socket.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            assert!(buffer.is_empty(), "connection closed before headers");
+            return None;
+        }
         buffer.extend_from_slice(&chunk[..n]);
         if let Some(index) = find_header_end(&buffer) {
             break index;
@@ -78,25 +219,33 @@ async fn read_request(socket: &mut TcpStream) -> CapturedRequest {
         assert!(n > 0, "connection closed before body");
         buffer.extend_from_slice(&chunk[..n]);
     }
-    let body = serde_json::from_slice(&buffer[body_start..body_start + content_length])
-        .expect("json request body");
+    let body = if content_length == 0 {
+        Value::Null
+    } else {
+        serde_json::from_slice(&buffer[body_start..body_start + content_length])
+            .expect("json request body")
+    };
 
-    CapturedRequest { path, body }
+    Some(CapturedRequest { path, body })
 }
 
 fn find_header_end(buffer: &[u8]) -> Option<usize> {
     buffer.windows(4).position(|window| window == b"\r\n\r\n")
 }
 
-async fn write_json_response(socket: &mut TcpStream, response: Value) {
-    let body = response.to_string();
-    let response = format!(
-        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
-        body.len(),
-        body
+async fn write_response(socket: &mut TcpStream, response: &ScriptedResponse) {
+    let mut head = format!(
+        "HTTP/1.1 {} Scripted\r\ncontent-type: {}\r\ncontent-length: {}\r\n",
+        response.status,
+        response.content_type,
+        response.body.len()
     );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str("\r\n");
     socket
-        .write_all(response.as_bytes())
+        .write_all(format!("{head}{}", response.body).as_bytes())
         .await
         .expect("write response");
 }
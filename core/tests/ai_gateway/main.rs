@@ -1,2 +1,8 @@
+mod cassette;
+mod clock_skew;
+mod coalescing;
+mod embeddings;
+mod fallback;
 mod kit;
 mod openai_responses;
+mod retry_trace;
@@ -6,14 +6,17 @@ use beluna::ai_gateway::{
     },
     credentials::EnvCredentialProvider,
     types::{
-        AIGatewayConfig, BackendDialect, BackendProfile, ChatConfig, CredentialRef, ModelProfile,
-        ResilienceConfig,
+        AIGatewayConfig, BackendDialect, BackendProfile, ChatConfig, CredentialRef,
+        DeclaredModelLimits, HttpClientConfig, ModelProfile, ResilienceConfig,
     },
 };
 use serde_json::json;
 
 use crate::kit::{
-    chat::{EchoToolExecutor, chat_for_responses_endpoint, text_response, user_message},
+    chat::{
+        EchoToolExecutor, chat_for_capped_responses_endpoint, chat_for_limited_responses_endpoint,
+        chat_for_responses_endpoint, text_response, user_message,
+    },
     local_http::LocalJsonServer,
 };
 
@@ -67,6 +70,100 @@ async fn openai_responses_complete_posts_expected_request() {
     assert_eq!(request.body["max_output_tokens"], 512);
 }
 
+#[tokio::test]
+async fn requested_output_tokens_above_backend_cap_are_clamped() {
+    let mut server = LocalJsonServer::start(vec![text_response("ack")]).await;
+    let thread = chat_for_capped_responses_endpoint(server.endpoint(), Some(256))
+        .open_thread(ThreadOptions {
+            default_limits: Some(TurnLimits {
+                max_output_tokens: Some(4_096),
+                max_request_time_ms: Some(30_000),
+            }),
+            ..ThreadOptions::default()
+        })
+        .await
+        .expect("open thread");
+
+    thread
+        .complete(TurnInput {
+            messages: vec![user_message("Input IR")],
+            ..TurnInput::default()
+        })
+        .await
+        .expect("complete");
+
+    let request = server.next_request().await;
+    assert_eq!(request.body["max_output_tokens"], 256);
+}
+
+#[tokio::test]
+async fn declared_model_limits_are_introspectable_and_bound_output_tokens() {
+    let mut server = LocalJsonServer::start(vec![text_response("ack")]).await;
+    let limits = DeclaredModelLimits {
+        context_window_tokens: Some(128_000),
+        max_output_tokens: Some(1_024),
+    };
+    let chat =
+        chat_for_limited_responses_endpoint(server.endpoint(), Some(8_192), Some(limits.clone()));
+    assert_eq!(chat.declared_model_limits().get("openai"), Some(&limits));
+
+    let thread = chat
+        .open_thread(ThreadOptions {
+            default_limits: Some(TurnLimits {
+                max_output_tokens: Some(4_096),
+                max_request_time_ms: Some(30_000),
+            }),
+            ..ThreadOptions::default()
+        })
+        .await
+        .expect("open thread");
+    thread
+        .complete(TurnInput {
+            messages: vec![user_message("Input IR")],
+            ..TurnInput::default()
+        })
+        .await
+        .expect("complete");
+
+    let request = server.next_request().await;
+    assert_eq!(request.body["max_output_tokens"], 1_024);
+}
+
+#[tokio::test]
+async fn output_tokens_are_trimmed_to_the_declared_output_limit_not_the_context_window() {
+    let mut server =
+        LocalJsonServer::start(vec![text_response("trimmed"), text_response("untouched")]).await;
+    let chat = chat_for_limited_responses_endpoint(
+        server.endpoint(),
+        None,
+        Some(DeclaredModelLimits {
+            context_window_tokens: Some(512),
+            max_output_tokens: Some(1_024),
+        }),
+    );
+    let thread = chat
+        .open_thread(ThreadOptions::default())
+        .await
+        .expect("open thread");
+
+    for max_output_tokens in [4_096, 300] {
+        thread
+            .complete(TurnInput {
+                messages: vec![user_message("Input IR")],
+                limits: Some(TurnLimits {
+                    max_output_tokens: Some(max_output_tokens),
+                    max_request_time_ms: Some(30_000),
+                }),
+                ..TurnInput::default()
+            })
+            .await
+            .expect("complete");
+    }
+
+    assert_eq!(server.next_request().await.body["max_output_tokens"], 1_024);
+    assert_eq!(server.next_request().await.body["max_output_tokens"], 300);
+}
+
 #[tokio::test]
 async fn openai_responses_complete_maps_text_output_usage_and_finish_reason() {
     let mut server = LocalJsonServer::start(vec![json!({
@@ -266,11 +363,15 @@ async fn openai_responses_rejects_missing_endpoint() {
                     id: "gpt-5".to_string(),
                     aliases: vec!["default".to_string()],
                 }],
+                max_output_tokens_cap: None,
+                limits: None,
                 capabilities: None,
                 copilot: None,
+                post_finish_content: Default::default(),
             }],
             chat: ChatConfig::default(),
             resilience: ResilienceConfig::default(),
+            http: HttpClientConfig::default(),
         },
         Arc::new(EnvCredentialProvider),
     )
@@ -0,0 +1,42 @@
+use beluna::ai_gateway::chat::{ThreadOptions, TurnInput};
+use serde_json::json;
+
+use crate::kit::{
+    chat::{chat_for_retry_traced_responses_endpoint, text_response, user_message},
+    local_http::LocalJsonServer,
+};
+
+#[tokio::test]
+async fn transient_failure_records_retry_decision_in_trace() {
+    let mut server = LocalJsonServer::start_with_statuses(vec![
+        (503, json!({"error": "overloaded"})),
+        (200, text_response("recovered")),
+    ])
+    .await;
+    let chat = chat_for_retry_traced_responses_endpoint(server.endpoint());
+    let thread = chat
+        .open_thread(ThreadOptions::default())
+        .await
+        .expect("open thread");
+
+    let output = thread
+        .complete(TurnInput {
+            messages: vec![user_message("Input IR")],
+            ..TurnInput::default()
+        })
+        .await
+        .expect("turn should recover after one retry");
+
+    assert_eq!(output.response.output_text, "recovered");
+    assert_eq!(
+        output.response.backend_metadata["retry_trace"],
+        json!([{
+            "attempt": 0,
+            "error_kind": "backend_transient",
+            "retryable": true,
+            "can_retry": true,
+            "reason": "retryable"
+        }])
+    );
+    assert_eq!(server.remaining_requests().await.len(), 2);
+}